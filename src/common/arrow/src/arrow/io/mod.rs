@@ -28,3 +28,7 @@ pub mod flight;
 #[cfg(feature = "io_parquet")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_parquet")))]
 pub mod parquet;
+
+#[cfg(feature = "io_orc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_orc")))]
+pub mod orc;
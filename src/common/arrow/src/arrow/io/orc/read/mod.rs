@@ -0,0 +1,601 @@
+// Copyright 2020-2022 Jorge C. Leitão
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! APIs to read from [ORC format](https://orc.apache.org).
+use std::io::Read;
+
+use orc_format::proto::column_encoding::Kind as ColumnEncodingKind;
+use orc_format::proto::stream::Kind;
+use orc_format::proto::Footer;
+use orc_format::proto::Type;
+pub use orc_format::read::decode;
+pub use orc_format::read::read_metadata;
+pub use orc_format::read::read_stripe_column;
+pub use orc_format::read::read_stripe_footer;
+pub use orc_format::read::Column;
+pub use orc_format::read::FileMetadata;
+
+use crate::arrow::array::Array;
+use crate::arrow::array::BinaryArray;
+use crate::arrow::array::BooleanArray;
+use crate::arrow::array::PrimitiveArray;
+use crate::arrow::array::Utf8Array;
+use crate::arrow::bitmap::Bitmap;
+use crate::arrow::bitmap::MutableBitmap;
+use crate::arrow::datatypes::DataType;
+use crate::arrow::datatypes::Field;
+use crate::arrow::datatypes::Schema;
+use crate::arrow::datatypes::TimeUnit;
+use crate::arrow::error::Error;
+use crate::arrow::offset::Offsets;
+use crate::arrow::types::Index;
+use crate::arrow::types::NativeType;
+
+/// Infers a [`Schema`] from the files' [`Footer`].
+/// # Errors
+/// This function errors if the type is not yet supported.
+pub fn infer_schema(footer: &Footer) -> Result<Schema, Error> {
+    let types = &footer.types;
+
+    let root = types
+        .first()
+        .ok_or_else(|| Error::ExternalFormat("ORC footer has no types".to_string()))?;
+    let dt = infer_dt(root, types)?;
+    if let DataType::Struct(fields) = dt {
+        Ok(fields.into())
+    } else {
+        Err(Error::ExternalFormat(
+            "ORC root type must be a struct".to_string(),
+        ))
+    }
+}
+
+fn infer_dt(type_: &Type, types: &[Type]) -> Result<DataType, Error> {
+    use orc_format::proto::r#type::Kind::*;
+    let sub_type = |i: usize| -> Result<&Type, Error> {
+        let id = *type_.subtypes.get(i).ok_or_else(|| {
+            Error::ExternalFormat(format!("ORC {:?} type has no subtype {i}", type_.kind()))
+        })?;
+        types
+            .get(id as usize)
+            .ok_or_else(|| Error::ExternalFormat(format!("ORC field {id} not found")))
+    };
+    let dt = match type_.kind() {
+        Boolean => DataType::Boolean,
+        Byte => DataType::Int8,
+        Short => DataType::Int16,
+        Int => DataType::Int32,
+        Long => DataType::Int64,
+        Float => DataType::Float32,
+        Double => DataType::Float64,
+        String | Varchar | Char => DataType::Utf8,
+        Binary => DataType::Binary,
+        Date => DataType::Date32,
+        Timestamp => DataType::Timestamp(TimeUnit::Microsecond, None),
+        Decimal => {
+            // the files written before Hive 0.13 have no precision and scale, use
+            // the defaults of the ORC writers.
+            let precision = type_.precision.filter(|p| *p > 0).unwrap_or(38);
+            let scale = type_.scale.unwrap_or(10);
+            DataType::Decimal(precision as usize, scale as usize)
+        }
+        Struct => {
+            let sub_types = type_
+                .subtypes
+                .iter()
+                .cloned()
+                .zip(type_.field_names.iter())
+                .map(|(i, name)| {
+                    infer_dt(
+                        types.get(i as usize).ok_or_else(|| {
+                            Error::ExternalFormat(format!("ORC field {i} not found"))
+                        })?,
+                        types,
+                    )
+                    .map(|dt| Field::new(name, dt, true))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            DataType::Struct(sub_types)
+        }
+        // the nested columns are not deserialized yet, they are inferred so that
+        // the files can be read if these columns are not projected.
+        List => {
+            let item = infer_dt(sub_type(0)?, types)?;
+            DataType::List(Box::new(Field::new("item", item, true)))
+        }
+        Map => {
+            let key = infer_dt(sub_type(0)?, types)?;
+            let value = infer_dt(sub_type(1)?, types)?;
+            let entries = DataType::Struct(vec![
+                Field::new("key", key, false),
+                Field::new("value", value, true),
+            ]);
+            DataType::Map(Box::new(Field::new("entries", entries, false)), false)
+        }
+        kind => return Err(Error::nyi(format!("Reading {kind:?} from ORC"))),
+    };
+    Ok(dt)
+}
+
+/// Returns the ORC column ids of the top-level fields of the file, in the
+/// same order as the fields of [`infer_schema`].
+pub fn top_level_column_ids(footer: &Footer) -> Vec<u32> {
+    footer
+        .types
+        .first()
+        .map(|root| root.subtypes.clone())
+        .unwrap_or_default()
+}
+
+/// Seconds between the unix epoch and 2015-01-01 00:00:00, the base of ORC timestamps.
+const ORC_TIMESTAMP_BASE_SECONDS: i64 = 1_420_070_400;
+
+/// Returns the encoding of the column, only the RLE v2 encodings are supported.
+///
+/// The writers before Hive 0.12 encode the integers with RLE v1 (`Direct` and `Dictionary`).
+fn encoding_kind(column: &Column) -> Result<ColumnEncodingKind, Error> {
+    match column.encoding().kind() {
+        kind @ (ColumnEncodingKind::DirectV2 | ColumnEncodingKind::DictionaryV2) => Ok(kind),
+        kind => Err(Error::nyi(format!(
+            "Reading ORC columns with {kind:?} encoding"
+        ))),
+    }
+}
+
+fn check_direct_encoding(column: &Column) -> Result<(), Error> {
+    match encoding_kind(column)? {
+        ColumnEncodingKind::DirectV2 => Ok(()),
+        kind => Err(Error::ExternalFormat(format!(
+            "ORC column has unexpected encoding {kind:?}"
+        ))),
+    }
+}
+
+fn fewer_values_error() -> Error {
+    Error::ExternalFormat("ORC column has fewer values than expected".to_string())
+}
+
+fn deserialize_validity(column: &Column, scratch: &mut Vec<u8>) -> Result<Option<Bitmap>, Error> {
+    // the present stream is omitted by writers when the column has no nulls
+    let stream = match column.get_stream(Kind::Present, std::mem::take(scratch)) {
+        Ok(stream) => stream,
+        Err(_) => return Ok(None),
+    };
+
+    let mut stream = decode::BooleanIter::new(stream, column.number_of_rows());
+
+    let mut validity = MutableBitmap::with_capacity(column.number_of_rows());
+    for item in stream.by_ref() {
+        validity.push(item?)
+    }
+
+    *scratch = std::mem::take(&mut stream.into_inner().into_inner());
+
+    Ok(validity.into())
+}
+
+fn number_of_values(validity: &Option<Bitmap>, num_rows: usize) -> usize {
+    validity
+        .as_ref()
+        .map(|validity| validity.len() - validity.unset_bits())
+        .unwrap_or(num_rows)
+}
+
+/// Deserializes a boolean column.
+pub fn deserialize_bool(data_type: DataType, column: &Column) -> Result<BooleanArray, Error> {
+    let mut scratch = vec![];
+    let num_rows = column.number_of_rows();
+
+    let validity = deserialize_validity(column, &mut scratch)?;
+
+    let chunks = column.get_stream(Kind::Data, scratch)?;
+    let mut iter = decode::BooleanIter::new(chunks, number_of_values(&validity, num_rows));
+
+    let mut values = MutableBitmap::with_capacity(num_rows);
+    match &validity {
+        Some(validity) => {
+            for is_valid in validity {
+                if is_valid {
+                    values.push(iter.next().transpose()?.unwrap_or_default())
+                } else {
+                    values.push(false)
+                }
+            }
+        }
+        None => {
+            for item in iter {
+                values.push(item?)
+            }
+        }
+    }
+
+    BooleanArray::try_new(data_type, values.into(), validity)
+}
+
+/// Deserializes a tinyint column, stored with the byte run length encoding.
+pub fn deserialize_i8(data_type: DataType, column: &Column) -> Result<PrimitiveArray<i8>, Error> {
+    let mut scratch = vec![];
+    let num_rows = column.number_of_rows();
+
+    let validity = deserialize_validity(column, &mut scratch)?;
+    let num_values = number_of_values(&validity, num_rows);
+
+    let mut data = column.get_stream(Kind::Data, scratch)?;
+    let mut bytes = Vec::with_capacity(num_values);
+    while bytes.len() < num_values {
+        let mut header = [0u8; 1];
+        data.read_exact(&mut header)?;
+        let header = header[0] as i8;
+        if header < 0 {
+            // a sequence of -header literal bytes
+            let start = bytes.len();
+            bytes.resize(start + (-(header as i16)) as usize, 0);
+            data.read_exact(&mut bytes[start..])?;
+        } else {
+            // a run of header + 3 repeated bytes
+            let mut value = [0u8; 1];
+            data.read_exact(&mut value)?;
+            bytes.resize(bytes.len() + header as usize + 3, value[0]);
+        }
+    }
+
+    let mut bytes = bytes.into_iter().map(|b| b as i8);
+    let mut values = Vec::with_capacity(num_rows);
+    match &validity {
+        Some(validity) => {
+            for is_valid in validity {
+                if is_valid {
+                    values.push(bytes.next().ok_or_else(fewer_values_error)?)
+                } else {
+                    values.push(0)
+                }
+            }
+        }
+        None => values.extend(bytes.take(num_rows)),
+    }
+
+    PrimitiveArray::try_new(data_type, values.into(), validity)
+}
+
+/// Deserializes an integer column stored with the RLE v2 encoding.
+pub fn deserialize_int<T>(
+    data_type: DataType,
+    column: &Column,
+) -> Result<PrimitiveArray<T>, Error>
+where
+    T: NativeType + TryFrom<i64>,
+{
+    check_direct_encoding(column)?;
+    let mut scratch = vec![];
+    let num_rows = column.number_of_rows();
+
+    let validity = deserialize_validity(column, &mut scratch)?;
+
+    let chunks = column.get_stream(Kind::Data, scratch)?;
+    let mut iter =
+        decode::SignedRleV2Iter::new(chunks, number_of_values(&validity, num_rows), vec![]);
+
+    let mut next = || -> Result<T, Error> {
+        let item = iter.next().transpose()?.unwrap_or_default();
+        item.try_into()
+            .map_err(|_| Error::ExternalFormat("ORC integer value out of range".to_string()))
+    };
+
+    let mut values = Vec::with_capacity(num_rows);
+    match &validity {
+        Some(validity) => {
+            for is_valid in validity {
+                if is_valid {
+                    values.push(next()?)
+                } else {
+                    values.push(T::default())
+                }
+            }
+        }
+        None => {
+            for _ in 0..num_rows {
+                values.push(next()?)
+            }
+        }
+    }
+
+    PrimitiveArray::try_new(data_type, values.into(), validity)
+}
+
+/// Deserializes a float or double column.
+pub fn deserialize_float<T>(
+    data_type: DataType,
+    column: &Column,
+) -> Result<PrimitiveArray<T>, Error>
+where
+    T: NativeType + decode::Float,
+{
+    let mut scratch = vec![];
+    let num_rows = column.number_of_rows();
+
+    let validity = deserialize_validity(column, &mut scratch)?;
+
+    let chunks = column.get_stream(Kind::Data, scratch)?;
+    let mut iter = decode::FloatIter::<T, _>::new(chunks, number_of_values(&validity, num_rows));
+
+    let mut values = Vec::with_capacity(num_rows);
+    match &validity {
+        Some(validity) => {
+            for is_valid in validity {
+                if is_valid {
+                    values.push(iter.next().transpose()?.unwrap_or_default())
+                } else {
+                    values.push(T::default())
+                }
+            }
+        }
+        None => {
+            for item in iter {
+                values.push(item?)
+            }
+        }
+    }
+
+    PrimitiveArray::try_new(data_type, values.into(), validity)
+}
+
+fn deserialize_binary_generic(
+    column: &Column,
+) -> Result<(Offsets<i32>, Vec<u8>, Option<Bitmap>), Error> {
+    let mut scratch = vec![];
+    let num_rows = column.number_of_rows();
+
+    let validity = deserialize_validity(column, &mut scratch)?;
+    let num_values = number_of_values(&validity, num_rows);
+
+    let dictionary = encoding_kind(column)? == ColumnEncodingKind::DictionaryV2;
+
+    let lengths = column.get_stream(Kind::Length, scratch)?;
+    if dictionary {
+        // the lengths stream holds the lengths of the dictionary entries,
+        // the data stream holds the index of each value in the dictionary.
+        let dictionary_size = column.dictionary_size().unwrap_or_default();
+        let mut dict_offsets = Offsets::<i32>::with_capacity(dictionary_size);
+        for length in decode::UnsignedRleV2Iter::new(lengths, dictionary_size, vec![]) {
+            dict_offsets.try_push_usize(length? as usize)?;
+        }
+        let mut dict_values = vec![0; dict_offsets.last().to_usize()];
+        let mut dict_data = column.get_stream(Kind::DictionaryData, vec![])?;
+        dict_data.read_exact(&mut dict_values)?;
+
+        let indices = column.get_stream(Kind::Data, vec![])?;
+        let mut indices = decode::UnsignedRleV2Iter::new(indices, num_values, vec![]);
+
+        let mut offsets = Offsets::with_capacity(num_rows);
+        let mut values = vec![];
+        for row in 0..num_rows {
+            let is_valid = validity.as_ref().map_or(true, |v| v.get_bit(row));
+            if !is_valid {
+                offsets.extend_constant(1);
+                continue;
+            }
+            let index = indices.next().transpose()?.ok_or_else(fewer_values_error)? as usize;
+            if index >= dict_offsets.len_proxy() {
+                return Err(Error::ExternalFormat(
+                    "ORC dictionary index out of range".to_string(),
+                ));
+            }
+            let (start, end) = dict_offsets.start_end(index);
+            values.extend_from_slice(&dict_values[start..end]);
+            offsets.try_push_usize(end - start)?;
+        }
+        return Ok((offsets, values, validity));
+    }
+
+    let mut iter = decode::UnsignedRleV2Iter::new(lengths, num_values, vec![]);
+    let mut offsets = Offsets::with_capacity(num_rows);
+    for row in 0..num_rows {
+        let is_valid = validity.as_ref().map_or(true, |v| v.get_bit(row));
+        if is_valid {
+            let length = iter.next().transpose()?.ok_or_else(|| {
+                Error::ExternalFormat("ORC column has fewer lengths than expected".to_string())
+            })?;
+            offsets.try_push_usize(length as usize)?;
+        } else {
+            offsets.extend_constant(1)
+        }
+    }
+
+    let mut values = vec![0; offsets.last().to_usize()];
+    let mut data = column.get_stream(Kind::Data, vec![])?;
+    data.read_exact(&mut values)?;
+
+    Ok((offsets, values, validity))
+}
+
+/// Deserializes a string column, stored either directly or with a dictionary.
+pub fn deserialize_utf8(data_type: DataType, column: &Column) -> Result<Utf8Array<i32>, Error> {
+    let (offsets, values, validity) = deserialize_binary_generic(column)?;
+    Utf8Array::try_new(data_type, offsets.into(), values.into(), validity)
+}
+
+/// Deserializes a binary column.
+pub fn deserialize_binary(data_type: DataType, column: &Column) -> Result<BinaryArray<i32>, Error> {
+    let (offsets, values, validity) = deserialize_binary_generic(column)?;
+    BinaryArray::try_new(data_type, offsets.into(), values.into(), validity)
+}
+
+/// Deserializes a timestamp column into microseconds since the unix epoch.
+///
+/// ORC timestamps are the wall-clock times in the writer's time zone, they are read as UTC.
+pub fn deserialize_timestamp(
+    data_type: DataType,
+    column: &Column,
+) -> Result<PrimitiveArray<i64>, Error> {
+    check_direct_encoding(column)?;
+    let mut scratch = vec![];
+    let num_rows = column.number_of_rows();
+
+    let validity = deserialize_validity(column, &mut scratch)?;
+    let num_values = number_of_values(&validity, num_rows);
+
+    // seconds since 2015-01-01 and the encoded nanoseconds
+    let seconds = column.get_stream(Kind::Data, scratch)?;
+    let mut seconds = decode::SignedRleV2Iter::new(seconds, num_values, vec![]);
+    let nanos = column.get_stream(Kind::Secondary, vec![])?;
+    let mut nanos = decode::UnsignedRleV2Iter::new(nanos, num_values, vec![]);
+
+    let mut next = || -> Result<i64, Error> {
+        let seconds = seconds.next().transpose()?.ok_or_else(fewer_values_error)?;
+        let nanos = decode_nanos(nanos.next().transpose()?.ok_or_else(fewer_values_error)?);
+        let mut seconds = seconds + ORC_TIMESTAMP_BASE_SECONDS;
+        // the seconds before the epoch are truncated towards zero by the writers
+        if seconds < 0 && nanos > 999_999 {
+            seconds -= 1;
+        }
+        seconds
+            .checked_mul(1_000_000)
+            .and_then(|micros| micros.checked_add((nanos / 1_000) as i64))
+            .ok_or_else(|| Error::ExternalFormat("ORC timestamp out of range".to_string()))
+    };
+
+    let mut values = Vec::with_capacity(num_rows);
+    match &validity {
+        Some(validity) => {
+            for is_valid in validity {
+                if is_valid {
+                    values.push(next()?)
+                } else {
+                    values.push(0)
+                }
+            }
+        }
+        None => {
+            for _ in 0..num_rows {
+                values.push(next()?)
+            }
+        }
+    }
+
+    PrimitiveArray::try_new(data_type, values.into(), validity)
+}
+
+/// The lowest 3 bits of the encoded nanoseconds are the number of the trailing
+/// decimal zeros removed, minus one.
+fn decode_nanos(value: u64) -> u64 {
+    let zeros = value & 0x7;
+    let nanos = value >> 3;
+    if zeros == 0 {
+        nanos
+    } else {
+        nanos * 10u64.pow(zeros as u32 + 1)
+    }
+}
+
+/// Deserializes a decimal column, the values are rescaled to the scale of `data_type`.
+pub fn deserialize_decimal(
+    data_type: DataType,
+    column: &Column,
+) -> Result<PrimitiveArray<i128>, Error> {
+    check_direct_encoding(column)?;
+    let scale = match &data_type {
+        DataType::Decimal(_, scale) => *scale as i64,
+        dt => return Err(Error::oos(format!("{dt:?} is not a decimal type"))),
+    };
+    let mut scratch = vec![];
+    let num_rows = column.number_of_rows();
+
+    let validity = deserialize_validity(column, &mut scratch)?;
+    let num_values = number_of_values(&validity, num_rows);
+
+    // the unscaled values and the scale of each value
+    let mut data = column.get_stream(Kind::Data, scratch)?;
+    let scales = column.get_stream(Kind::Secondary, vec![])?;
+    let mut scales = decode::SignedRleV2Iter::new(scales, num_values, vec![]);
+
+    let mut next = || -> Result<i128, Error> {
+        let value = read_zigzag_varint(&mut data)?;
+        let value_scale = scales.next().transpose()?.ok_or_else(fewer_values_error)?;
+        rescale(value, scale - value_scale)
+            .ok_or_else(|| Error::ExternalFormat("ORC decimal value out of range".to_string()))
+    };
+
+    let mut values = Vec::with_capacity(num_rows);
+    match &validity {
+        Some(validity) => {
+            for is_valid in validity {
+                if is_valid {
+                    values.push(next()?)
+                } else {
+                    values.push(0)
+                }
+            }
+        }
+        None => {
+            for _ in 0..num_rows {
+                values.push(next()?)
+            }
+        }
+    }
+
+    PrimitiveArray::try_new(data_type, values.into(), validity)
+}
+
+/// Reads a zigzag encoded, unbounded base 128 varint.
+fn read_zigzag_varint<R: Read>(reader: &mut R) -> Result<i128, Error> {
+    let mut value = 0u128;
+    let mut shift = 0;
+    loop {
+        let mut byte = [0u8; 1];
+        reader.read_exact(&mut byte)?;
+        if shift >= 128 {
+            return Err(Error::ExternalFormat(
+                "ORC decimal value out of range".to_string(),
+            ));
+        }
+        value |= ((byte[0] & 0x7f) as u128) << shift;
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Ok((value >> 1) as i128 ^ -((value & 1) as i128))
+}
+
+fn rescale(value: i128, diff: i64) -> Option<i128> {
+    let factor = 10i128.checked_pow(u32::try_from(diff.unsigned_abs()).ok()?)?;
+    if diff >= 0 {
+        value.checked_mul(factor)
+    } else {
+        Some(value / factor)
+    }
+}
+
+/// Deserializes the [`Column`] into an arrow array of `data_type`, as inferred by [`infer_schema`].
+/// # Errors
+/// This function errors if the data type is not yet supported or the column is out of spec.
+pub fn deserialize(data_type: DataType, column: &Column) -> Result<Box<dyn Array>, Error> {
+    match data_type {
+        DataType::Boolean => deserialize_bool(data_type, column).map(|x| x.boxed()),
+        DataType::Int8 => deserialize_i8(data_type, column).map(|x| x.boxed()),
+        DataType::Int16 => deserialize_int::<i16>(data_type, column).map(|x| x.boxed()),
+        DataType::Int32 | DataType::Date32 => {
+            deserialize_int::<i32>(data_type, column).map(|x| x.boxed())
+        }
+        DataType::Int64 => deserialize_int::<i64>(data_type, column).map(|x| x.boxed()),
+        DataType::Float32 => deserialize_float::<f32>(data_type, column).map(|x| x.boxed()),
+        DataType::Float64 => deserialize_float::<f64>(data_type, column).map(|x| x.boxed()),
+        DataType::Utf8 => deserialize_utf8(data_type, column).map(|x| x.boxed()),
+        DataType::Binary => deserialize_binary(data_type, column).map(|x| x.boxed()),
+        DataType::Timestamp(_, _) => deserialize_timestamp(data_type, column).map(|x| x.boxed()),
+        DataType::Decimal(_, _) => deserialize_decimal(data_type, column).map(|x| x.boxed()),
+        dt => Err(Error::nyi(format!("Deserializing {dt:?} from ORC"))),
+    }
+}
@@ -149,6 +149,8 @@ build_exceptions! {
     UnknownCatalog(1119),
     UnknownCatalogType(1120),
     UnmatchMaskPolicyReturnType(1121),
+    // The recursive cte exceeds the setting `max_cte_recursive_depth`.
+    CteRecursionDepthExceeded(1122),
 
     // Data Related Errors
 
@@ -194,7 +196,11 @@ build_exceptions! {
     // Cloud control error codes
     CloudControlConnectError(1701),
     CloudControlNotEnabled(1702),
-    IllegalCloudControlMessageFormat(1703)
+    IllegalCloudControlMessageFormat(1703),
+
+    // Transaction error codes.
+    CurrentTransactionIsAborted(1801),
+    StatementNotAllowedInTransaction(1802)
 }
 
 // Meta service errors [2001, 3000].
@@ -125,6 +125,12 @@ impl From<common_arrow::parquet::error::Error> for ErrorCode {
     }
 }
 
+impl From<common_arrow::arrow::io::orc::format::error::Error> for ErrorCode {
+    fn from(error: common_arrow::arrow::io::orc::format::error::Error) -> Self {
+        ErrorCode::from(common_arrow::arrow::error::Error::from(error))
+    }
+}
+
 impl From<arrow_schema::ArrowError> for ErrorCode {
     fn from(error: arrow_schema::ArrowError) -> Self {
         match error {
@@ -153,6 +153,9 @@ use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::schema::UpdateIndexReply;
 use common_meta_app::schema::UpdateIndexReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -2805,39 +2808,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             }
 
             for req in &req.update_stream_meta {
-                let stream_id = TableId {
-                    table_id: req.stream_id,
-                };
-                let (stream_meta_seq, stream_meta): (_, Option<TableMeta>) =
-                    get_pb_value(self, &stream_id).await?;
-
-                if stream_meta_seq == 0 || stream_meta.is_none() {
-                    return Err(KVAppError::AppError(AppError::UnknownStreamId(
-                        UnknownStreamId::new(req.stream_id, "update_table_meta"),
-                    )));
-                }
-
-                if req.seq.match_seq(stream_meta_seq).is_err() {
-                    return Err(KVAppError::AppError(AppError::from(
-                        StreamVersionMismatched::new(
-                            req.stream_id,
-                            req.seq,
-                            stream_meta_seq,
-                            "update_table_meta",
-                        ),
-                    )));
-                }
-
-                let mut new_stream_meta = stream_meta.unwrap();
-                new_stream_meta.options = req.options.clone();
-                new_stream_meta.updated_on = Utc::now();
-
-                txn_req
-                    .condition
-                    .push(txn_cond_seq(&stream_id, Eq, stream_meta_seq));
-                txn_req
-                    .if_then
-                    .push(txn_op_put(&stream_id, serialize_struct(&new_stream_meta)?));
+                build_update_stream_meta_txn(self, req, &mut txn_req).await?;
             }
 
             if let Some(deduplicated_label) = req.deduplicated_label.clone() {
@@ -2905,6 +2876,110 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            let mut txn_req = TxnRequest {
+                condition: vec![],
+                if_then: vec![],
+                else_then: vec![],
+            };
+            let mut table_metas = Vec::with_capacity(req.update_table_metas.len());
+
+            for req in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: req.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &tbid).await?;
+
+                if tb_meta_seq == 0 || table_meta.is_none() {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(req.table_id, "update_multi_table_meta"),
+                    )));
+                }
+                if req.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            req.table_id,
+                            req.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                txn_req
+                    .if_then
+                    .push(txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?));
+
+                if let Some(copied_files) = &req.copied_files {
+                    let (conditions, match_operations) =
+                        build_upsert_table_copied_file_info_conditions(
+                            &tbid,
+                            copied_files,
+                            tb_meta_seq,
+                            copied_files.fail_if_duplicated,
+                        )?;
+                    txn_req.condition.extend(conditions);
+                    txn_req.if_then.extend(match_operations)
+                }
+
+                for req in &req.update_stream_meta {
+                    build_update_stream_meta_txn(self, req, &mut txn_req).await?;
+                }
+
+                // The versions of the tables and streams are still the same as the last try,
+                // so the txn failed because some of the files have been copied.
+                if let Some(copied_files) = &req.copied_files {
+                    if retry > 0 && copied_files.fail_if_duplicated {
+                        return Err(KVAppError::AppError(AppError::from(
+                            DuplicatedUpsertFiles::new(req.table_id, "update_multi_table_meta"),
+                        )));
+                    }
+                }
+
+                if let Some(deduplicated_label) = req.deduplicated_label.clone() {
+                    txn_req
+                        .if_then
+                        .push(build_upsert_table_deduplicated_label(deduplicated_label))
+                }
+
+                table_metas.push(table_meta.unwrap());
+            }
+
+            retry += 1;
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                succ = succ;
+                "update_multi_table_meta"
+            );
+
+            if succ {
+                let mut share_table_info = None;
+                for table_meta in &table_metas {
+                    if let Some(infos) = get_share_table_info_map(self, table_meta).await? {
+                        share_table_info.get_or_insert_with(Vec::new).extend(infos);
+                    }
+                }
+                return Ok(UpdateMultiTableMetaReply { share_table_info });
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("update_multi_table_meta", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn set_table_column_mask_policy(
@@ -4083,6 +4158,48 @@ fn build_upsert_table_copied_file_info_conditions(
     Ok((condition, if_then))
 }
 
+/// Add the conditions and operations of updating a stream's meta to `txn_req`.
+async fn build_update_stream_meta_txn(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    req: &UpdateStreamMetaReq,
+    txn_req: &mut TxnRequest,
+) -> Result<(), KVAppError> {
+    let stream_id = TableId {
+        table_id: req.stream_id,
+    };
+    let (stream_meta_seq, stream_meta): (_, Option<TableMeta>) =
+        get_pb_value(kv_api, &stream_id).await?;
+
+    if stream_meta_seq == 0 || stream_meta.is_none() {
+        return Err(KVAppError::AppError(AppError::UnknownStreamId(
+            UnknownStreamId::new(req.stream_id, "update_table_meta"),
+        )));
+    }
+
+    if req.seq.match_seq(stream_meta_seq).is_err() {
+        return Err(KVAppError::AppError(AppError::from(
+            StreamVersionMismatched::new(
+                req.stream_id,
+                req.seq,
+                stream_meta_seq,
+                "update_table_meta",
+            ),
+        )));
+    }
+
+    let mut new_stream_meta = stream_meta.unwrap();
+    new_stream_meta.options = req.options.clone();
+    new_stream_meta.updated_on = Utc::now();
+
+    txn_req
+        .condition
+        .push(txn_cond_seq(&stream_id, Eq, stream_meta_seq));
+    txn_req
+        .if_then
+        .push(txn_op_put(&stream_id, serialize_struct(&new_stream_meta)?));
+    Ok(())
+}
+
 fn build_upsert_table_deduplicated_label(deduplicated_label: String) -> TxnOp {
     let expire_at = Some(SeqV::<()>::now_ms() / 1000 + 24 * 60 * 60);
     TxnOp {
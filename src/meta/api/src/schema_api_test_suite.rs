@@ -101,6 +101,7 @@ use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -288,6 +289,9 @@ impl SchemaApiTestSuite {
             .await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite
+            .table_update_multi_table_meta(&b.build().await)
+            .await?;
         suite.table_update_mask_policy(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
@@ -2329,6 +2333,116 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_update_multi_table_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name_1 = "tb1";
+        let tbl_name_2 = "tb2";
+
+        let schema = || {
+            Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )]))
+        };
+
+        let table_meta = |created_on| TableMeta {
+            schema: schema(),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on,
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in [tbl_name_1, tbl_name_2] {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: table_meta(Utc::now()),
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let update_req = |table: &TableInfo, data_bytes: u64, seq: u64| {
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            }
+        };
+
+        info!("--- update multi table meta, normal case");
+        {
+            let table_1 = mt.get_table((tenant, db_name, tbl_name_1).into()).await?;
+            let table_2 = mt.get_table((tenant, db_name, tbl_name_2).into()).await?;
+
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: vec![
+                    update_req(table_1.as_ref(), 1, table_1.ident.seq),
+                    update_req(table_2.as_ref(), 2, table_2.ident.seq),
+                ],
+            })
+            .await?;
+
+            let table_1 = mt.get_table((tenant, db_name, tbl_name_1).into()).await?;
+            let table_2 = mt.get_table((tenant, db_name, tbl_name_2).into()).await?;
+            assert_eq!(table_1.meta.statistics.data_bytes, 1);
+            assert_eq!(table_2.meta.statistics.data_bytes, 2);
+        }
+
+        info!("--- update multi table meta, version mismatch leaves all tables unchanged");
+        {
+            let table_1 = mt.get_table((tenant, db_name, tbl_name_1).into()).await?;
+            let table_2 = mt.get_table((tenant, db_name, tbl_name_2).into()).await?;
+
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update_req(table_1.as_ref(), 3, table_1.ident.seq),
+                        update_req(table_2.as_ref(), 4, table_2.ident.seq + 1),
+                    ],
+                })
+                .await;
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            let table_1 = mt.get_table((tenant, db_name, tbl_name_1).into()).await?;
+            let table_2 = mt.get_table((tenant, db_name, tbl_name_2).into()).await?;
+            assert_eq!(table_1.meta.statistics.data_bytes, 1);
+            assert_eq!(table_2.meta.statistics.data_bytes, 2);
+        }
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_update_mask_policy<
         MT: SchemaApi + DatamaskApi + kvapi::AsKVApi<Error = MetaError>,
@@ -111,6 +111,9 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    Avro(AvroFileFormatParams),
+    Orc(OrcFileFormatParams),
+    Arrow(ArrowFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -122,6 +125,9 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
+            FileFormatParams::Orc(_) => StageFileFormatType::Orc,
+            FileFormatParams::Arrow(_) => StageFileFormatType::Arrow,
         }
     }
 
@@ -139,6 +145,13 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
+            StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
+            StageFileFormatType::Arrow => {
+                Ok(FileFormatParams::Arrow(ArrowFileFormatParams::default()))
+            }
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -154,6 +167,9 @@ impl FileFormatParams {
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
+            FileFormatParams::Avro(_) => StageFileCompression::None,
+            FileFormatParams::Orc(_) => StageFileCompression::None,
+            FileFormatParams::Arrow(_) => StageFileCompression::None,
         }
     }
 
@@ -185,6 +201,16 @@ impl FileFormatParams {
                 )?)
             }
             StageFileFormatType::Parquet => FileFormatParams::Parquet(ParquetFileFormatParams {}),
+            StageFileFormatType::Avro => {
+                let missing_field_as = ast.options.remove(MISSING_FIELD_AS);
+                let null_field_as = ast.options.remove(NULL_FIELD_AS);
+                FileFormatParams::Avro(AvroFileFormatParams::try_create(
+                    missing_field_as.as_deref(),
+                    null_field_as.as_deref(),
+                )?)
+            }
+            StageFileFormatType::Orc => FileFormatParams::Orc(OrcFileFormatParams {}),
+            StageFileFormatType::Arrow => FileFormatParams::Arrow(ArrowFileFormatParams {}),
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
                 let compression = ast.take_compression()?;
@@ -511,6 +537,53 @@ impl NdJsonFileFormatParams {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParquetFileFormatParams {}
 
+/// Avro object container files carry their own codec, so there is no `compression` option.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {
+    pub missing_field_as: JsonNullAs,
+    pub null_field_as: JsonNullAs,
+}
+
+impl AvroFileFormatParams {
+    pub fn try_create(missing_field_as: Option<&str>, null_field_as: Option<&str>) -> Result<Self> {
+        let missing_field_as =
+            JsonNullAs::parse(missing_field_as, MISSING_FIELD_AS, JsonNullAs::Error)?;
+        let null_field_as =
+            JsonNullAs::parse(null_field_as, NULL_FIELD_AS, JsonNullAs::FieldDefault)?;
+        if matches!(null_field_as, JsonNullAs::Error) {
+            return Err(ErrorCode::InvalidArgument(
+                "NULL_FIELD_AS cannot be `error`",
+            ));
+        }
+        Ok(Self {
+            missing_field_as,
+            null_field_as,
+        })
+    }
+
+    pub fn downcast_unchecked(params: &FileFormatParams) -> &AvroFileFormatParams {
+        match params {
+            FileFormatParams::Avro(p) => p,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Default for AvroFileFormatParams {
+    fn default() -> Self {
+        AvroFileFormatParams {
+            missing_field_as: JsonNullAs::Error,
+            null_field_as: JsonNullAs::FieldDefault,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcFileFormatParams {}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArrowFileFormatParams {}
+
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -556,6 +629,19 @@ impl Display for FileFormatParams {
             FileFormatParams::Parquet(_) => {
                 write!(f, "TYPE = PARQUET")
             }
+            FileFormatParams::Avro(params) => {
+                write!(
+                    f,
+                    "TYPE = AVRO, MISSING_FIELD_AS = {}, NULL_FIELD_AS = {}",
+                    params.missing_field_as, params.null_field_as
+                )
+            }
+            FileFormatParams::Orc(_) => {
+                write!(f, "TYPE = ORC")
+            }
+            FileFormatParams::Arrow(_) => {
+                write!(f, "TYPE = ARROW")
+            }
         }
     }
 }
@@ -24,6 +24,7 @@ use std::sync::Arc;
 use chrono::DateTime;
 use chrono::Utc;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::FieldIndex;
 use common_expression::TableField;
 use common_expression::TableSchema;
@@ -240,6 +241,16 @@ pub struct TableMeta {
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
     pub owner: Option<Ownership>,
+    // The inverted indexes defined on the table, keyed by the index name.
+    pub indexes: BTreeMap<String, TableIndex>,
+}
+
+/// An index built along with the data blocks of a table, such as the inverted index.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableIndex {
+    pub name: String,
+    /// The ids of the indexed columns.
+    pub column_ids: Vec<ColumnId>,
 }
 
 impl TableMeta {
@@ -355,6 +366,7 @@ impl Default for TableMeta {
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
             owner: None,
+            indexes: BTreeMap::new(),
         }
     }
 }
@@ -615,6 +627,12 @@ pub struct UpdateTableMetaReq {
     pub deduplicated_label: Option<String>,
 }
 
+/// Update the meta of several tables atomically: either all of them are updated, or none.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+}
+
 impl UpsertTableOptionReq {
     pub fn new(
         table_ident: &TableIdent,
@@ -671,6 +689,11 @@ pub struct UpdateTableMetaReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
@@ -69,3 +69,7 @@ mod v062_table_lock_meta;
 mod v063_connection;
 mod v064_ndjson_format_params;
 mod v065_least_visible_time;
+mod v066_avro_format_params;
+mod v067_orc_format_params;
+mod v068_arrow_format_params;
+mod v069_table_index;
@@ -214,6 +214,7 @@ fn new_table_meta() -> mt::TableMeta {
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
         owner: None,
+        indexes: btreemap! {},
     }
 }
 
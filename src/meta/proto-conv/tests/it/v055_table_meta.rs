@@ -94,6 +94,7 @@ fn test_decode_v55_table_meta() -> anyhow::Result<()> {
             owner_role_name: "role2".to_string(),
             updated_on: Default::default(),
         }),
+        indexes: btreemap! {},
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), bytes.as_slice(), 55, want())?;
@@ -253,6 +253,8 @@ pub enum Expr {
         unit: IntervalKind,
         date: Box<Expr>,
     },
+    /// The placeholder `?` of a prepared statement
+    Placeholder { span: Span },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -572,7 +574,8 @@ impl Expr {
             | Expr::Interval { span, .. }
             | Expr::DateAdd { span, .. }
             | Expr::DateSub { span, .. }
-            | Expr::DateTrunc { span, .. } => *span,
+            | Expr::DateTrunc { span, .. }
+            | Expr::Placeholder { span } => *span,
         }
     }
 
@@ -1293,6 +1296,9 @@ impl Display for Expr {
             Expr::DateTrunc { unit, date, .. } => {
                 write!(f, "DATE_TRUNC({unit}, {date})")?;
             }
+            Expr::Placeholder { .. } => {
+                write!(f, "?")?;
+            }
         }
 
         Ok(())
@@ -653,6 +653,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {
+        let name = "Placeholder".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_query(&mut self, query: &'ast Query) {
         let mut children = Vec::new();
         if let Some(with) = &query.with {
@@ -869,6 +876,22 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         );
         children.push(from_node);
 
+        if !copy.partition_by.is_empty() {
+            let mut partition_by_children = Vec::with_capacity(copy.partition_by.len());
+            for expr in copy.partition_by.iter() {
+                self.visit_expr(expr);
+                partition_by_children.push(self.children.pop().unwrap());
+            }
+            let partition_by_format_ctx = AstFormatContext::with_children(
+                "PartitionBy".to_string(),
+                partition_by_children.len(),
+            );
+            children.push(FormatTreeNode::with_children(
+                partition_by_format_ctx,
+                partition_by_children,
+            ));
+        }
+
         if !copy.file_format.is_empty() {
             let mut file_formats_children = Vec::with_capacity(copy.file_format.len());
             for (k, v) in copy.file_format.iter() {
@@ -1388,6 +1411,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                let mut children = Vec::with_capacity(2);
+                self.visit_table_ref(catalog, database, table);
+                children.push(self.children.pop().unwrap());
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, children.len());
+                let node = FormatTreeNode::with_children(format_ctx, children);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1681,6 +1722,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             self.visit_stream_point(point);
             children.push(self.children.pop().unwrap());
         }
+        if !stmt.append_only {
+            let append_only_format_ctx = AstFormatContext::new("AppendOnly false".to_string());
+            children.push(FormatTreeNode::new(append_only_format_ctx));
+        }
         if let Some(comment) = &stmt.comment {
             let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
             children.push(FormatTreeNode::new(comment_format_ctx));
@@ -1765,6 +1810,43 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let mut columns_children = Vec::with_capacity(stmt.columns.len());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            columns_children.push(self.children.pop().unwrap());
+        }
+        let columns_name = "Columns".to_string();
+        let columns_ctx = AstFormatContext::with_children(columns_name, columns_children.len());
+        let columns_child = FormatTreeNode::with_children(columns_ctx, columns_children);
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node = FormatTreeNode::with_children(format_ctx, vec![
+            index_child,
+            table_child,
+            columns_child,
+        ]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_column(&mut self, stmt: &'ast CreateVirtualColumnStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -2778,6 +2860,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             children.push(window_list_node);
         }
 
+        if let Some(qualify) = &stmt.qualify {
+            self.visit_expr(qualify);
+            let qualify_child = self.children.pop().unwrap();
+            let qualify_name = "Qualify".to_string();
+            let qualify_format_ctx = AstFormatContext::with_children(qualify_name, 1);
+            let qualify_node =
+                FormatTreeNode::with_children(qualify_format_ctx, vec![qualify_child]);
+            children.push(qualify_node);
+        }
+
         let name = "SelectQuery".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
@@ -2829,6 +2921,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => {
                 let mut name = String::new();
                 name.push_str("TableIdentifier ");
@@ -2852,6 +2945,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     name.push_str(&unpivot.to_string());
                 }
 
+                if let Some(sample) = sample {
+                    name.push(' ');
+                    name.push_str(&sample.to_string());
+                }
+
                 let mut children = Vec::new();
                 if let Some(travel_point) = travel_point {
                     self.visit_time_travel_point(travel_point);
@@ -3039,6 +3137,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             }
         }
 
+        if let Some(match_condition) = &join.match_condition {
+            self.visit_expr(match_condition);
+            let child = self.children.pop().unwrap();
+            let match_condition_name = "MatchCondition".to_string();
+            let match_condition_format_ctx =
+                AstFormatContext::with_children(match_condition_name, 1);
+            let match_condition_node =
+                FormatTreeNode::with_children(match_condition_format_ctx, vec![child]);
+            children.push(match_condition_node);
+        }
+
         let name = "Join".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
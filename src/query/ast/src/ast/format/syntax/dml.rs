@@ -263,6 +263,15 @@ pub(crate) fn pretty_copy_into_location(copy_stmt: CopyIntoLocationStmt) -> RcDo
                 .append(pretty_query(*query))
                 .append(RcDoc::text(")")),
         })
+        .append(if !copy_stmt.partition_by.is_empty() {
+            RcDoc::line()
+                .append(RcDoc::text("PARTITION BY "))
+                .append(inline_comma(
+                    copy_stmt.partition_by.into_iter().map(pretty_expr),
+                ))
+        } else {
+            RcDoc::nil()
+        })
         .append(pretty_file_format(&copy_stmt.file_format))
         .append(
             RcDoc::line()
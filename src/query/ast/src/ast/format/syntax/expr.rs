@@ -400,5 +400,6 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(RcDoc::space())
             .append(pretty_expr(*date))
             .append(RcDoc::text(")")),
+        Expr::Placeholder { .. } => RcDoc::text("?"),
     }
 }
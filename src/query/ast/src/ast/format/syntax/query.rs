@@ -87,7 +87,8 @@ fn pretty_body(body: SetExpr) -> RcDoc<'static> {
         .append(pretty_selection(select_stmt.selection))
         .append(pretty_group_by(select_stmt.group_by))
         .append(pretty_having(select_stmt.having))
-        .append(pretty_window(select_stmt.window_list)),
+        .append(pretty_window(select_stmt.window_list))
+        .append(pretty_qualify(select_stmt.qualify)),
         SetExpr::Query(query) => parenthesized(pretty_query(*query)),
         SetExpr::SetOperation(set_operation) => pretty_body(*set_operation.left)
             .append(
@@ -295,6 +296,16 @@ fn pretty_window(window: Option<Vec<WindowDefinition>>) -> RcDoc<'static> {
     }
 }
 
+fn pretty_qualify(qualify: Option<Expr>) -> RcDoc<'static> {
+    if let Some(qualify) = qualify {
+        RcDoc::line()
+            .append(RcDoc::text("QUALIFY").append(RcDoc::line().nest(NEST_FACTOR)))
+            .append(pretty_expr(qualify))
+    } else {
+        RcDoc::nil()
+    }
+}
+
 fn pretty_window_def(def: WindowDefinition) -> RcDoc<'static> {
     RcDoc::text(def.name.to_string())
         .append(RcDoc::space())
@@ -314,6 +325,7 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             travel_point,
             pivot,
             unpivot,
+            sample,
         } => if let Some(catalog) = catalog {
             RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
         } else {
@@ -346,6 +358,11 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             RcDoc::text(format!(" AS {alias}"))
         } else {
             RcDoc::nil()
+        })
+        .append(if let Some(sample) = sample {
+            RcDoc::text(format!(" {sample}"))
+        } else {
+            RcDoc::nil()
         }),
         TableReference::Subquery {
             span: _,
@@ -414,8 +431,18 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
                 JoinOperator::RightAnti => RcDoc::text("RIGHT ANTI JOIN"),
                 JoinOperator::LeftSemi => RcDoc::text("LEFT SEMI JOIN"),
                 JoinOperator::RightSemi => RcDoc::text("RIGHT SEMI JOIN"),
+                JoinOperator::Asof => RcDoc::text("ASOF JOIN"),
+                JoinOperator::LeftAsof => RcDoc::text("ASOF LEFT JOIN"),
             })
             .append(RcDoc::space().append(pretty_table(*join.right)))
+            .append(if let Some(match_condition) = join.match_condition {
+                RcDoc::space()
+                    .append(RcDoc::text("MATCH_CONDITION ("))
+                    .append(pretty_expr(*match_condition))
+                    .append(RcDoc::text(")"))
+            } else {
+                RcDoc::nil()
+            })
             .append(match &join.condition {
                 JoinCondition::On(expr) => RcDoc::space()
                     .append(RcDoc::text("ON"))
@@ -92,6 +92,8 @@ pub struct SelectStmt {
     pub having: Option<Expr>,
     // `WINDOW` clause
     pub window_list: Option<Vec<WindowDefinition>>,
+    // `QUALIFY` clause
+    pub qualify: Option<Expr>,
 }
 
 /// Group by Clause.
@@ -231,6 +233,31 @@ pub struct Unpivot {
     pub names: Vec<Identifier>,
 }
 
+/// `TABLESAMPLE` or `SAMPLE` clause of a table
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampleConfig {
+    pub level: SampleLevel,
+    pub value: SampleValue,
+    /// `REPEATABLE (seed)`
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleLevel {
+    /// Each row is sampled independently.
+    Row,
+    /// Each block is sampled as a whole.
+    Block,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SampleValue {
+    /// Probability in percent, `TABLESAMPLE (10)`
+    Percent(f64),
+    /// Fixed number of rows, `TABLESAMPLE (10 ROWS)`
+    Rows(u64),
+}
+
 /// A table name or a parenthesized subquery with an optional alias
 #[derive(Debug, Clone, PartialEq)]
 pub enum TableReference {
@@ -244,6 +271,7 @@ pub enum TableReference {
         travel_point: Option<TimeTravelPoint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        sample: Option<SampleConfig>,
     },
     // `TABLE(expr)[ AS alias ]`
     TableFunction {
@@ -308,6 +336,8 @@ pub struct TableAlias {
 pub struct Join {
     pub op: JoinOperator,
     pub condition: JoinCondition,
+    /// `MATCH_CONDITION (expr)` of ASOF JOIN.
+    pub match_condition: Option<Box<Expr>>,
     pub left: Box<TableReference>,
     pub right: Box<TableReference>,
 }
@@ -325,6 +355,9 @@ pub enum JoinOperator {
     RightAnti,
     // CrossJoin can only work with `JoinCondition::None`
     CrossJoin,
+    // Asof joins pick at most one row of the right table by `MATCH_CONDITION`
+    Asof,
+    LeftAsof,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -416,6 +449,24 @@ impl Display for Unpivot {
     }
 }
 
+impl Display for SampleConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TABLESAMPLE ")?;
+        match self.level {
+            SampleLevel::Row => write!(f, "ROW")?,
+            SampleLevel::Block => write!(f, "BLOCK")?,
+        }
+        match self.value {
+            SampleValue::Percent(percent) => write!(f, " ({percent})")?,
+            SampleValue::Rows(rows) => write!(f, " ({rows} ROWS)")?,
+        }
+        if let Some(seed) = self.seed {
+            write!(f, " REPEATABLE ({seed})")?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for TableReference {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -428,6 +479,7 @@ impl Display for TableReference {
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => {
                 write_dot_separated_list(
                     f,
@@ -445,6 +497,9 @@ impl Display for TableReference {
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
+                if let Some(sample) = sample {
+                    write!(f, " {sample}")?;
+                }
                 if let Some(pivot) = pivot {
                     write!(f, " {pivot}")?;
                 }
@@ -527,8 +582,17 @@ impl Display for TableReference {
                     JoinOperator::CrossJoin => {
                         write!(f, " CROSS JOIN")?;
                     }
+                    JoinOperator::Asof => {
+                        write!(f, " ASOF JOIN")?;
+                    }
+                    JoinOperator::LeftAsof => {
+                        write!(f, " ASOF LEFT JOIN")?;
+                    }
                 }
                 write!(f, " {}", join.right)?;
+                if let Some(match_condition) = &join.match_condition {
+                    write!(f, " MATCH_CONDITION ({match_condition})")?;
+                }
                 match &join.condition {
                     JoinCondition::On(expr) => {
                         write!(f, " ON {expr}")?;
@@ -662,6 +726,11 @@ impl Display for SelectStmt {
             write!(f, " HAVING {having}")?;
         }
 
+        // QUALIFY clause
+        if let Some(qualify) = &self.qualify {
+            write!(f, " QUALIFY {qualify}")?;
+        }
+
         Ok(())
     }
 }
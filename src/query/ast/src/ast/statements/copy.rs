@@ -24,8 +24,10 @@ use common_base::base::mask_string;
 use itertools::Itertools;
 use url::Url;
 
+use crate::ast::write_comma_separated_list;
 use crate::ast::write_comma_separated_map;
 use crate::ast::write_comma_separated_quoted_list;
+use crate::ast::Expr;
 use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Query;
@@ -178,6 +180,8 @@ pub struct CopyIntoLocationStmt {
     pub hints: Option<Hint>,
     pub src: CopyIntoLocationSource,
     pub dst: FileLocation,
+    /// Write the rows into Hive style `<expr>=<value>/` sub directories.
+    pub partition_by: Vec<Expr>,
     pub file_format: BTreeMap<String, String>,
     pub single: bool,
     pub max_file_size: usize,
@@ -191,6 +195,10 @@ impl Display for CopyIntoLocationStmt {
         }
         write!(f, " INTO {}", self.dst)?;
         write!(f, " FROM {}", self.src)?;
+        if !self.partition_by.is_empty() {
+            write!(f, " PARTITION BY ")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+        }
 
         if !self.file_format.is_empty() {
             write!(f, " FILE_FORMAT = (")?;
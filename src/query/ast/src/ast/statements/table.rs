@@ -136,6 +136,7 @@ pub struct CreateTableStmt {
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
     pub transient: bool,
+    pub temporary: bool,
 }
 
 impl Display for CreateTableStmt {
@@ -144,6 +145,9 @@ impl Display for CreateTableStmt {
         if self.transient {
             write!(f, "TRANSIENT ")?;
         }
+        if self.temporary {
+            write!(f, "TEMPORARY ")?;
+        }
         write!(f, "TABLE ")?;
         if self.if_not_exists {
             write!(f, "IF NOT EXISTS ")?;
@@ -160,14 +164,14 @@ impl Display for CreateTableStmt {
             write!(f, " {source}")?;
         }
 
-        if let Some(uri_location) = &self.uri_location {
-            write!(f, " {uri_location}")?;
-        }
-
         if let Some(engine) = &self.engine {
             write!(f, " ENGINE = {engine}")?;
         }
 
+        if let Some(uri_location) = &self.uri_location {
+            write!(f, " {uri_location}")?;
+        }
+
         if !self.cluster_by.is_empty() {
             write!(f, " CLUSTER BY (")?;
             write_comma_separated_list(f, &self.cluster_by)?;
@@ -222,6 +226,12 @@ pub enum CreateTableSource {
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
@@ -240,6 +250,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -619,6 +642,7 @@ pub enum Engine {
     Fuse,
     View,
     Random,
+    Delta,
 }
 
 impl Display for Engine {
@@ -629,6 +653,7 @@ impl Display for Engine {
             Engine::Fuse => write!(f, "FUSE"),
             Engine::View => write!(f, "VIEW"),
             Engine::Random => write!(f, "RANDOM"),
+            Engine::Delta => write!(f, "DELTA"),
         }
     }
 }
@@ -26,6 +26,7 @@ use crate::ast::CopyIntoTableStmt;
 use crate::ast::Statement;
 use crate::ast::Statement::CopyIntoLocation;
 use crate::ast::TableIdentifier;
+use crate::parser::expr::expr;
 use crate::parser::expr::literal_bool;
 use crate::parser::expr::literal_string;
 use crate::parser::expr::literal_u64;
@@ -106,13 +107,17 @@ fn copy_into_location(i: Input) -> IResult<Statement> {
             ~ #hint?
             ~ INTO ~ #file_location
             ~ ^FROM ~ ^#copy_into_location_source
+            ~ ( PARTITION ~ ^BY ~ ^#comma_separated_list1(expr) )?
             ~ #copy_into_location_option*
         },
-        |(_copy, opt_hints, _into, dst, _from, src, opts)| {
+        |(_copy, opt_hints, _into, dst, _from, src, opt_partition_by, opts)| {
             let mut copy_stmt = CopyIntoLocationStmt {
                 hints: opt_hints,
                 src,
                 dst,
+                partition_by: opt_partition_by
+                    .map(|(_, _, exprs)| exprs)
+                    .unwrap_or_default(),
                 file_format: Default::default(),
                 single: Default::default(),
                 max_file_size: Default::default(),
@@ -129,6 +134,7 @@ pub fn copy_into(i: Input) -> IResult<Statement> {
          #copy_into_location:"`COPY
                 INTO { internalStage | externalStage | externalLocation }
                 FROM { [<database_name>.]<table_name> | ( <query> ) }
+                [ PARTITION BY <expr> [, <expr> ...] ]
                 [ FILE_FORMAT = ( { TYPE = { CSV | JSON | PARQUET | TSV } [ formatTypeOptions ] } ) ]
                 [ copyOptions ]`"
          | #copy_into_table: "`COPY
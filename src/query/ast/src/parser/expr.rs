@@ -140,6 +140,14 @@ pub fn subexpr(min_precedence: u32) -> impl FnMut(Input) -> IResult<Expr> {
                         },
                     };
                 }
+
+                // and replace the `?` json operator to the placeholder of a prepared statement.
+                if let ExprElement::JsonOp {
+                    op: JsonOperator::Question,
+                } = &expr_elements[curr as usize].elem
+                {
+                    expr_elements[curr as usize].elem = ExprElement::Placeholder;
+                }
             }
         }
 
@@ -314,6 +322,8 @@ pub enum ExprElement {
         unit: IntervalKind,
         date: Expr,
     },
+    /// The placeholder `?` of a prepared statement
+    Placeholder,
 }
 
 struct ExprParser;
@@ -540,6 +550,9 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 unit,
                 date: Box::new(date),
             },
+            ExprElement::Placeholder => Expr::Placeholder {
+                span: transform_span(elem.span.0),
+            },
             _ => unreachable!(),
         };
         Ok(expr)
@@ -58,6 +58,7 @@ pub enum SetOperationElement {
         group_by: Option<GroupBy>,
         having: Box<Option<Expr>>,
         window_list: Option<Vec<WindowDefinition>>,
+        qualify: Box<Option<Expr>>,
     },
     SetOperation {
         op: SetOperator,
@@ -104,6 +105,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                 ~ ( GROUP ~ ^BY ~ ^#group_by_items )?
                 ~ ( HAVING ~ ^#expr )?
                 ~ ( WINDOW ~ ^#comma_separated_list1(window_clause) )?
+                ~ ( QUALIFY ~ ^#expr )?
         },
         |(
             _select,
@@ -115,6 +117,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             opt_group_by_block,
             opt_having_block,
             opt_window_block,
+            opt_qualify_block,
         )| {
             SetOperationElement::SelectStmt {
                 hints: opt_hints,
@@ -129,6 +132,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                 group_by: opt_group_by_block.map(|(_, _, group_by)| group_by),
                 having: Box::new(opt_having_block.map(|(_, having)| having)),
                 window_list: opt_window_block.map(|(_, windows)| windows),
+                qualify: Box::new(opt_qualify_block.map(|(_, qualify)| qualify)),
             }
         },
     );
@@ -222,6 +226,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 group_by,
                 having,
                 window_list,
+                qualify,
             } => SetExpr::Select(Box::new(SelectStmt {
                 span: transform_span(input.span.0),
                 hints,
@@ -232,6 +237,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 group_by,
                 having: *having,
                 window_list,
+                qualify: *qualify,
             })),
             SetOperationElement::Values(values) => SetExpr::Values {
                 span: transform_span(input.span.0),
@@ -482,6 +488,8 @@ pub fn table_alias_without_as(i: Input) -> IResult<TableAlias> {
 
 pub fn join_operator(i: Input) -> IResult<JoinOperator> {
     alt((
+        value(JoinOperator::LeftAsof, rule! { ASOF ~ LEFT ~ OUTER? }),
+        value(JoinOperator::Asof, rule! { ASOF }),
         value(JoinOperator::Inner, rule! { INNER }),
         value(JoinOperator::LeftSemi, rule! { LEFT? ~ SEMI }),
         value(JoinOperator::RightSemi, rule! { RIGHT ~ SEMI }),
@@ -552,6 +560,7 @@ pub enum TableReferenceElement {
         travel_point: Option<TimeTravelPoint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        sample: Option<SampleConfig>,
     },
     // `TABLE(expr)[ AS alias ]`
     TableFunction {
@@ -575,6 +584,8 @@ pub enum TableReferenceElement {
     },
     // ON expr | USING (ident, ...)
     JoinCondition(JoinCondition),
+    // MATCH_CONDITION (expr)
+    MatchCondition(Expr),
     Group(TableReference),
     Stage {
         location: FileLocation,
@@ -583,6 +594,36 @@ pub enum TableReferenceElement {
     },
 }
 
+// (TABLESAMPLE | SAMPLE) [ROW | BLOCK] (<probability> | <num> ROWS) [REPEATABLE (<seed>)]
+pub fn table_sample(i: Input) -> IResult<SampleConfig> {
+    let probability = map_res(
+        rule! {
+            LiteralInteger | LiteralFloat
+        },
+        |token| Ok(fast_float::parse::<f64, _>(token.text())?),
+    );
+    let percent = map(probability, SampleValue::Percent);
+    let rows = map(rule! { #literal_u64 ~ ROWS }, |(rows, _)| {
+        SampleValue::Rows(rows)
+    });
+
+    map(
+        rule! {
+            (TABLESAMPLE | SAMPLE) ~ (ROW | BLOCK)?
+            ~ "(" ~ ^(#rows | #percent) ~ ^")"
+            ~ ( REPEATABLE ~ ^"(" ~ ^#literal_u64 ~ ^")" )?
+        },
+        |(_, level, _, value, _, seed)| SampleConfig {
+            level: match level {
+                Some(token) if token.kind == BLOCK => SampleLevel::Block,
+                _ => SampleLevel::Row,
+            },
+            value,
+            seed: seed.map(|(_, _, seed, _)| seed),
+        },
+    )(i)
+}
+
 pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceElement>> {
     // PIVOT(expr FOR col IN (ident, ...))
     let pivot = map(
@@ -608,9 +649,9 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let aliased_table = map(
         rule! {
-            #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)? ~ #table_alias? ~ #pivot? ~ #unpivot?
+            #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)? ~ #table_alias? ~ #table_sample? ~ #pivot? ~ #unpivot?
         },
-        |((catalog, database, table), travel_point_opt, alias, pivot, unpivot)| {
+        |((catalog, database, table), travel_point_opt, alias, sample, pivot, unpivot)| {
             TableReferenceElement::Table {
                 catalog,
                 database,
@@ -619,6 +660,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
                 travel_point: travel_point_opt.map(|p| p.1),
                 pivot: pivot.map(Box::new),
                 unpivot: unpivot.map(Box::new),
+                sample,
             }
         },
     );
@@ -643,6 +685,12 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         },
         |(_, _, idents, _)| TableReferenceElement::JoinCondition(JoinCondition::Using(idents)),
     );
+    let match_condition = map(
+        rule! {
+            MATCH_CONDITION ~ ^"(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, expr, _)| TableReferenceElement::MatchCondition(expr),
+    );
     let table_function = map(
         rule! {
             LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
@@ -696,6 +744,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         | #join
         | #join_condition_on
         | #join_condition_using
+        | #match_condition
     })(i)?;
     Ok((rest, WithSpan { span, elem }))
 }
@@ -713,6 +762,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
         let affix = match &input.elem {
             TableReferenceElement::Join { .. } => Affix::Infix(Precedence(10), Associativity::Left),
             TableReferenceElement::JoinCondition(..) => Affix::Postfix(Precedence(5)),
+            TableReferenceElement::MatchCondition(..) => Affix::Postfix(Precedence(5)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -729,6 +779,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => TableReference::Table {
                 span: transform_span(input.span.0),
                 catalog,
@@ -738,6 +789,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             },
             TableReferenceElement::TableFunction {
                 lateral,
@@ -814,6 +866,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     join: Join {
                         op,
                         condition,
+                        match_condition: None,
                         left: Box::new(lhs),
                         right: Box::new(rhs),
                     },
@@ -852,6 +905,24 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 },
                 _ => Err("join condition must apply to a join"),
             },
+            TableReferenceElement::MatchCondition(expr) => match &mut lhs {
+                TableReference::Join {
+                    join:
+                        Join {
+                            op: JoinOperator::Asof | JoinOperator::LeftAsof,
+                            match_condition,
+                            ..
+                        },
+                    ..
+                } => match match_condition {
+                    None => {
+                        *match_condition = Some(Box::new(expr));
+                        Ok(lhs)
+                    }
+                    Some(_) => Err("match condition already set"),
+                },
+                _ => Err("match condition must apply to an asof join"),
+            },
             _ => unreachable!(),
         }
     }
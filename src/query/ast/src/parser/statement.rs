@@ -667,7 +667,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     );
     let create_table = map(
         rule! {
-            CREATE ~ TRANSIENT? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            CREATE ~ ( TRANSIENT | TEMPORARY | TEMP )? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #dot_separated_idents_1_to_3
             ~ #create_table_source?
             ~ ( #engine )?
@@ -678,7 +678,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
         |(
             _,
-            opt_transient,
+            opt_table_kind,
             _,
             opt_if_not_exists,
             (catalog, database, table),
@@ -702,7 +702,14 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                     .unwrap_or_default(),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
-                transient: opt_transient.is_some(),
+                transient: matches!(
+                    opt_table_kind.map(|token| token.kind),
+                    Some(TokenKind::TRANSIENT)
+                ),
+                temporary: matches!(
+                    opt_table_kind.map(|token| token.kind),
+                    Some(TokenKind::TEMPORARY | TokenKind::TEMP)
+                ),
             })
         },
     );
@@ -941,6 +948,39 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, opt_if_not_exists, index_name, _, (catalog, database, table), _, columns, _)| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+            })
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let create_virtual_column = map(
         rule! {
             CREATE ~ VIRTUAL ~ COLUMN ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" ~ FOR ~ #dot_separated_idents_1_to_3
@@ -1645,6 +1685,10 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT });
+    let abort = value(Statement::Abort, rule! { ABORT | ROLLBACK });
+
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
@@ -1717,6 +1761,8 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_inverted_index: "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>, ...)`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -1821,6 +1867,9 @@ AS
         | #drop_connection: "`DROP CONNECTION [IF EXISTS] <connection_name>`"
         | #desc_connection: "`DESC | DESCRIBE CONNECTION  <connection_name>`"
         | #show_connections: "`SHOW CONNECTIONS`"
+        | #begin : "`BEGIN [TRANSACTION]`"
+        | #commit : "`COMMIT`"
+        | #abort : "`ABORT | ROLLBACK`"
         ),
     ));
 
@@ -2346,10 +2395,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             table,
         },
     );
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3 ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, (catalog, database, table), opt_travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: opt_travel_point.map(|(_, travel_point)| travel_point),
+        },
+    );
 
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -2936,6 +2997,7 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Fuse, rule! { FUSE }),
         value(Engine::View, rule! { VIEW }),
         value(Engine::Random, rule! { RANDOM }),
+        value(Engine::Delta, rule! { DELTA }),
     ));
 
     map(
@@ -3081,6 +3143,7 @@ pub fn table_reference_with_alias(i: Input) -> IResult<TableReference> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         },
     )(i)
 }
@@ -3099,6 +3162,7 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         },
     )(i)
 }
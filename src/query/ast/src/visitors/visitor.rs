@@ -368,6 +368,8 @@ pub trait Visitor<'ast>: Sized {
         walk_expr(self, date);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {}
+
     fn visit_statement(&mut self, statement: &'ast Statement) {
         walk_statement(self, statement);
     }
@@ -504,6 +506,10 @@ pub trait Visitor<'ast>: Sized {
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &'ast RefreshIndexStmt) {}
 
+    fn visit_create_inverted_index(&mut self, _stmt: &'ast CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &'ast DropInvertedIndexStmt) {}
+
     fn visit_create_virtual_column(&mut self, _stmt: &'ast CreateVirtualColumnStmt) {}
 
     fn visit_alter_virtual_column(&mut self, _stmt: &'ast AlterVirtualColumnStmt) {}
@@ -646,6 +652,7 @@ pub trait Visitor<'ast>: Sized {
             selection,
             group_by,
             having,
+            qualify,
             ..
         } = stmt;
 
@@ -680,6 +687,10 @@ pub trait Visitor<'ast>: Sized {
         if let Some(having) = having {
             walk_expr(self, having);
         }
+
+        if let Some(qualify) = qualify {
+            walk_expr(self, qualify);
+        }
     }
 
     fn visit_select_target(&mut self, target: &'ast SelectTarget) {
@@ -703,6 +714,7 @@ pub trait Visitor<'ast>: Sized {
             left,
             right,
             condition,
+            match_condition,
             ..
         } = join;
 
@@ -710,6 +722,9 @@ pub trait Visitor<'ast>: Sized {
         walk_table_reference(self, right);
 
         walk_join_condition(self, condition);
+        if let Some(match_condition) = match_condition {
+            self.visit_expr(match_condition);
+        }
     }
     fn visit_window_definition(&mut self, window_definition: &'ast WindowDefinition) {
         walk_window_definition(self, window_definition);
@@ -381,6 +381,8 @@ pub trait VisitorMut: Sized {
         Self::visit_expr(self, date);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {}
+
     fn visit_statement(&mut self, statement: &mut Statement) {
         walk_statement_mut(self, statement);
     }
@@ -517,6 +519,10 @@ pub trait VisitorMut: Sized {
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &mut RefreshIndexStmt) {}
 
+    fn visit_create_inverted_index(&mut self, _stmt: &mut CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &mut DropInvertedIndexStmt) {}
+
     fn visit_create_virtual_column(&mut self, _stmt: &mut CreateVirtualColumnStmt) {}
 
     fn visit_alter_virtual_column(&mut self, _stmt: &mut AlterVirtualColumnStmt) {}
@@ -659,6 +665,7 @@ pub trait VisitorMut: Sized {
             selection,
             group_by,
             having,
+            qualify,
             ..
         } = stmt;
 
@@ -693,6 +700,10 @@ pub trait VisitorMut: Sized {
         if let Some(having) = having {
             Self::visit_expr(self, having);
         }
+
+        if let Some(qualify) = qualify {
+            Self::visit_expr(self, qualify);
+        }
     }
 
     fn visit_select_target(&mut self, target: &mut SelectTarget) {
@@ -716,6 +727,7 @@ pub trait VisitorMut: Sized {
             left,
             right,
             condition,
+            match_condition,
             ..
         } = join;
 
@@ -723,6 +735,9 @@ pub trait VisitorMut: Sized {
         self.visit_table_reference(right);
 
         walk_join_condition_mut(self, condition);
+        if let Some(match_condition) = match_condition {
+            self.visit_expr(match_condition);
+        }
     }
 
     fn visit_create_connection(&mut self, _stmt: &mut CreateConnectionStmt) {}
@@ -97,10 +97,14 @@ fn test_statement() {
         r#"create table if not exists a.b (a int, b int, c int generated always as (a + b) virtual );"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table a.b clone c.d at (snapshot => 'abc');"#,
+        r#"create temporary table t (a int);"#,
+        r#"create temp table if not exists t like t2;"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
              connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900')
              location_prefix = 'db';"#,
+        r#"create table a.b engine = delta 's3://testbucket/delta/b/' connection=(endpoint_url='http://127.0.0.1:9900');"#,
         r#"truncate table a;"#,
         r#"truncate table "a".b;"#,
         r#"drop table a;"#,
@@ -120,6 +124,7 @@ fn test_statement() {
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
         r#"create stream if not exists test2.s2 on table test.t at (stream => test1.s1) comment = 'this is a stream';"#,
+        r#"create stream s on table t append_only = false;"#,
         r#"show full streams from default.test2 like 's%';"#,
         r#"describe stream test2.s2;"#,
         r#"drop stream if exists test2.s2;"#,
@@ -344,6 +349,7 @@ fn test_statement() {
                     record_delimiter = '\n'
                     skip_header = 1
                 );"#,
+        r#"COPY INTO @my_stage FROM mytable PARTITION BY a, b;"#,
         r#"COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
                 CREDENTIALS = (
@@ -492,6 +498,8 @@ fn test_statement() {
         r#"ALTER VIRTUAL COLUMN (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"DROP VIRTUAL COLUMN FOR t"#,
         r#"REFRESH VIRTUAL COLUMN FOR t"#,
+        r#"CREATE INVERTED INDEX IF NOT EXISTS idx ON db.t(content, title)"#,
+        r#"DROP INVERTED INDEX idx ON t"#,
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"ALTER NETWORK POLICY mypolicy SET ALLOWED_IP_LIST=('192.168.10.0/24','192.168.255.1') BLOCKED_IP_LIST=('192.168.1.99') COMMENT='test'"#,
         // tasks
@@ -521,6 +529,11 @@ fn test_statement() {
         r#"ALTER PIPE mypipe SET PIPE_EXECUTION_PAUSED = true"#,
         r#"DROP PIPE mypipe"#,
         r#"DESC PIPE mypipe"#,
+        r#"BEGIN"#,
+        r#"BEGIN TRANSACTION"#,
+        r#"COMMIT"#,
+        r#"ROLLBACK"#,
+        r#"ABORT"#,
         "--各环节转各环节转各环节转各环节转各\n  select 34343",
         "-- 96477300355	31379974136	3.074486292973661\nselect 34343",
         "-- xxxxx\n  select 34343;",
@@ -673,6 +686,10 @@ fn test_query() {
         r#"select * from monthly_sales_1 unpivot(sales for month in (jan, feb, mar, april)) order by empid"#,
         r#"select * from range(1, 2)"#,
         r#"select sum(a) over w from customer window w as (partition by a order by b)"#,
+        r#"select sum(a) over w from customer window w as (partition by a order by b) qualify a = 1"#,
+        r#"select * from t tablesample block (50)"#,
+        r#"select * from t1 as t sample row (10 rows) repeatable (42)"#,
+        r#"select * from customer asof left join orders match_condition (a >= b)"#,
         r#"select a, sum(a) over w, sum(a) over w1, sum(a) over w2 from t1 window w as (partition by a), w2 as (w1 rows current row), w1 as (w order by a) order by a"#,
         r#"SELECT * FROM ((SELECT * FROM xyu ORDER BY x, y)) AS xyu"#,
         r#"SELECT * FROM (VALUES(1,1),(2,null),(null,5)) AS t(a,b)"#,
@@ -790,6 +807,7 @@ fn test_expr() {
         r#"COUNT() OVER (ORDER BY hire_date ROWS 3 PRECEDING)"#,
         r#"ARRAY_APPLY([1,2,3], x -> x + 1)"#,
         r#"ARRAY_FILTER(col, y -> y % 2 = 0)"#,
+        r#"a > ?"#,
     ];
 
     for case in cases {
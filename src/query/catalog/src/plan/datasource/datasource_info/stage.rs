@@ -33,6 +33,9 @@ pub struct StageTableInfo {
     pub stage_info: StageInfo,
     pub files_to_copy: Option<Vec<StageFileInfo>>,
     pub is_select: bool,
+    /// Hive style partition keys of `COPY INTO <location> PARTITION BY`,
+    /// the values are held by the last `partition_by.len()` columns of `schema`.
+    pub partition_by: Vec<String>,
 }
 
 impl StageTableInfo {
@@ -40,6 +43,17 @@ impl StageTableInfo {
         self.schema.clone()
     }
 
+    /// The schema of the unloaded files, without the partition columns.
+    pub fn output_schema(&self) -> Arc<TableSchema> {
+        if self.partition_by.is_empty() {
+            return self.schema.clone();
+        }
+        let num_fields = self.schema.num_fields() - self.partition_by.len();
+        Arc::new(TableSchema::new(
+            self.schema.fields()[..num_fields].to_vec(),
+        ))
+    }
+
     pub fn desc(&self) -> String {
         self.stage_info.stage_name.clone()
     }
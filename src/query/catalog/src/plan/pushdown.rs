@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 
 use common_expression::types::DataType;
+use common_expression::types::F64;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableDataType;
@@ -93,6 +94,50 @@ pub struct PushDownInfo {
     pub lazy_materialization: bool,
     /// Aggregating index information.
     pub agg_index: Option<AggIndexInfo>,
+    /// Optional sampling of the data source.
+    pub sample: Option<TableSample>,
+}
+
+/// Sampling of the data source, generated from the `TABLESAMPLE` clause.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum TableSample {
+    /// Each block is kept with the probability.
+    /// It's done while pruning, so the skipped blocks are never read.
+    Block { probability: F64, seed: Option<u64> },
+    /// Each row is kept with the probability.
+    Row { probability: F64, seed: Option<u64> },
+    /// A fixed number of rows are chosen at random.
+    FixedRows { rows: u64, seed: Option<u64> },
+}
+
+impl TableSample {
+    pub fn seed(&self) -> Option<u64> {
+        match self {
+            TableSample::Block { seed, .. }
+            | TableSample::Row { seed, .. }
+            | TableSample::FixedRows { seed, .. } => *seed,
+        }
+    }
+
+    /// Whether the sampling is done on the rows after reading.
+    pub fn is_row_level(&self) -> bool {
+        !matches!(self, TableSample::Block { .. })
+    }
+
+    /// Mix the hash of a row or a block with the seed,
+    /// the result is uniformly distributed in `u64`.
+    pub fn mix_seed(hash: u64, seed: u64) -> u64 {
+        // The finalizer of splitmix64.
+        let mut z = hash ^ seed.wrapping_add(0x9e3779b97f4a7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Whether a uniformly distributed `key` is kept with the probability.
+    pub fn hit(key: u64, probability: f64) -> bool {
+        probability >= 1.0 || (key as f64) < probability * (u64::MAX as f64)
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::path::Path;
+use std::str::FromStr;
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::uuid::Uuid;
@@ -238,3 +241,50 @@ pub fn gen_mutation_stream_meta(
         )))
     }
 }
+
+/// Table option used to read one side of the changes of a standard stream.
+pub const OPT_KEY_CHANGE_TYPE: &str = "change_type";
+
+/// The side of the changes of a standard stream.
+///
+/// The changes of a standard stream are computed by joining the rows of the blocks
+/// added since the stream offset (`Insert`) with the rows of the blocks removed since
+/// the stream offset (`Delete`) on the row id.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeType {
+    Insert,
+    Delete,
+}
+
+impl ChangeType {
+    pub fn table_alias_prefix(&self) -> &'static str {
+        match self {
+            ChangeType::Insert => "_change_insert$",
+            ChangeType::Delete => "_change_delete$",
+        }
+    }
+}
+
+impl FromStr for ChangeType {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "insert" => Ok(ChangeType::Insert),
+            "delete" => Ok(ChangeType::Delete),
+            _ => Err(ErrorCode::IllegalStream(format!(
+                "invalid change type: {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl Display for ChangeType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangeType::Insert => write!(f, "insert"),
+            ChangeType::Delete => write!(f, "delete"),
+        }
+    }
+}
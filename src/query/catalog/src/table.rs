@@ -35,6 +35,7 @@ use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_types::MetaId;
 use common_pipeline_core::Pipeline;
 use common_storage::StorageMetrics;
+use storages_common_table_meta::meta::ColumnHistogram;
 use storages_common_table_meta::meta::SnapshotId;
 use storages_common_table_meta::meta::TableSnapshot;
 
@@ -134,6 +135,12 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// Whether the table engine supports sampling blocks while pruning.
+    /// The block sampling is done on rows if not supported.
+    fn support_block_sample(&self) -> bool {
+        false
+    }
+
     /// Whether the table engine supports virtual columns optimization.
     fn support_virtual_columns(&self) -> bool {
         false
@@ -387,6 +394,11 @@ pub trait TableExt: Table {
         let name = table_info.name.clone();
         let tid = table_info.ident.table_id;
         let catalog = ctx.get_catalog(table_info.catalog()).await?;
+        // The table has uncommitted changes in the explicit transaction.
+        let buffered_table_info = ctx.txn_mgr().lock().get_table_from_buffer(tid);
+        if let Some(table_info) = buffered_table_info {
+            return catalog.get_table_by_info(&table_info);
+        }
         let (ident, meta) = catalog.get_table_meta_by_id(tid).await?;
         let table_info = TableInfo {
             ident,
@@ -456,6 +468,11 @@ pub trait ColumnStatisticsProvider: Send {
 
     // returns the num rows of the table, if any.
     fn num_rows(&self) -> Option<u64>;
+
+    // returns the histogram of the given column collected by `ANALYZE TABLE`, if any.
+    fn histogram(&self, _column_id: ColumnId) -> Option<&ColumnHistogram> {
+        None
+    }
 }
 
 pub struct DummyColumnStatisticsProvider;
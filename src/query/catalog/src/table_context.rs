@@ -54,6 +54,7 @@ use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
 use crate::query_kind::QueryKind;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 pub type MaterializedCtesBlocks = Arc<RwLock<HashMap<(usize, usize), Arc<RwLock<Vec<DataBlock>>>>>>;
 
@@ -226,4 +227,7 @@ pub trait TableContext: Send + Sync {
 
     /// Get license key from context, return empty if license is not found or error happened.
     fn get_license_key(&self) -> String;
+
+    /// Get the transaction manager of the current session.
+    fn txn_mgr(&self) -> TxnManagerRef;
 }
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use parking_lot::Mutex;
+
+/// Min id for temporary tables, they are never stored in the meta service, so the ids
+/// are allocated locally from a range that the meta service will never reach.
+pub const TEMP_TBL_ID_BEGIN: u64 = 1 << 61;
+
+static NEXT_TEMP_TBL_ID: AtomicU64 = AtomicU64::new(TEMP_TBL_ID_BEGIN);
+
+/// Temporary tables of a session.
+///
+/// A temporary table is only visible to the session that creates it, and it is
+/// dropped when the session is closed. It shadows a normal table with the same name.
+#[derive(Debug, Default)]
+pub struct TempTblMgr {
+    /// Key is `(database, table)`.
+    tables: HashMap<(String, String), TableInfo>,
+}
+
+pub type TempTblMgrRef = Arc<Mutex<TempTblMgr>>;
+
+impl TempTblMgr {
+    pub fn init() -> TempTblMgrRef {
+        Arc::new(Mutex::new(TempTblMgr::default()))
+    }
+
+    /// Returns the created table, or `None` if it already exists and `if_not_exists` is set.
+    pub fn create_table(&mut self, req: CreateTableReq) -> Result<Option<TableInfo>> {
+        let db_name = req.name_ident.db_name.clone();
+        let table_name = req.name_ident.table_name.clone();
+        let key = (db_name.clone(), table_name.clone());
+        if self.tables.contains_key(&key) {
+            return if req.if_not_exists {
+                Ok(None)
+            } else {
+                Err(ErrorCode::TableAlreadyExists(format!(
+                    "Temporary table '{}'.'{}' already exists",
+                    db_name, table_name
+                )))
+            };
+        }
+
+        let table_id = NEXT_TEMP_TBL_ID.fetch_add(1, Ordering::Relaxed);
+        let mut table_info = TableInfo::new(
+            &db_name,
+            &table_name,
+            TableIdent::new(table_id, 0),
+            req.table_meta,
+        );
+        table_info.tenant = req.name_ident.tenant;
+        self.tables.insert(key, table_info.clone());
+        Ok(Some(table_info))
+    }
+
+    pub fn get_table(&self, database: &str, table: &str) -> Option<TableInfo> {
+        self.tables
+            .get(&(database.to_string(), table.to_string()))
+            .cloned()
+    }
+
+    pub fn drop_table(&mut self, database: &str, table: &str) -> Option<TableInfo> {
+        self.tables
+            .remove(&(database.to_string(), table.to_string()))
+    }
+
+    /// Drop all the temporary tables, returns the ids of them.
+    pub fn clear(&mut self) -> Vec<u64> {
+        self.tables
+            .drain()
+            .map(|(_, table_info)| table_info.ident.table_id)
+            .collect()
+    }
+}
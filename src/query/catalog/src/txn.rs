@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use parking_lot::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxnState {
+    /// Every statement is committed on its own.
+    AutoCommit,
+    /// Inside an explicit transaction started by `BEGIN`.
+    Active,
+    /// A statement of the explicit transaction failed, it can only be rolled back.
+    Fail,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TxnBuffer {
+    /// Key is the table id, value is the pending meta update and the table info it results in.
+    mutated_tables: HashMap<u64, (UpdateTableMetaReq, TableInfo)>,
+}
+
+/// Session scoped state of the explicit transaction.
+///
+/// Within an explicit transaction, the table meta updates are buffered here instead
+/// of being sent to the meta service. They are published atomically at `COMMIT`,
+/// or discarded at `ROLLBACK`.
+#[derive(Debug)]
+pub struct TxnManager {
+    state: TxnState,
+    txn_buffer: TxnBuffer,
+}
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager {
+            state: TxnState::AutoCommit,
+            txn_buffer: TxnBuffer::default(),
+        }))
+    }
+
+    pub fn begin(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if self.state == TxnState::Active {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn is_fail(&self) -> bool {
+        self.state == TxnState::Fail
+    }
+
+    /// Buffer the meta update of a table, `table_info` is the table being mutated.
+    pub fn update_table_meta(&mut self, req: UpdateTableMetaReq, table_info: &TableInfo) {
+        let mut new_table_info = table_info.clone();
+        new_table_info.meta = req.new_table_meta.clone();
+
+        match self.txn_buffer.mutated_tables.entry(req.table_id) {
+            Entry::Occupied(mut v) => {
+                // The table has been mutated in this transaction, keep the version
+                // read by the first mutation, which will be checked at commit.
+                let (prev_req, prev_table_info) = v.get_mut();
+                prev_req.new_table_meta = req.new_table_meta;
+                match (prev_req.copied_files.as_mut(), req.copied_files) {
+                    (Some(prev), Some(files)) => {
+                        prev.file_info.extend(files.file_info);
+                        prev.fail_if_duplicated |= files.fail_if_duplicated;
+                    }
+                    (None, files) => prev_req.copied_files = files,
+                    _ => {}
+                }
+                for stream_req in req.update_stream_meta {
+                    prev_req
+                        .update_stream_meta
+                        .retain(|v| v.stream_id != stream_req.stream_id);
+                    prev_req.update_stream_meta.push(stream_req);
+                }
+                if req.deduplicated_label.is_some() {
+                    prev_req.deduplicated_label = req.deduplicated_label;
+                }
+                *prev_table_info = new_table_info;
+            }
+            Entry::Vacant(v) => {
+                v.insert((req, new_table_info));
+            }
+        }
+    }
+
+    /// Get the table info with the uncommitted changes of this transaction.
+    pub fn get_table_from_buffer(&self, table_id: u64) -> Option<TableInfo> {
+        self.txn_buffer
+            .mutated_tables
+            .get(&table_id)
+            .map(|(_, table_info)| table_info.clone())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.txn_buffer.mutated_tables.is_empty()
+    }
+
+    pub fn req(&self) -> UpdateMultiTableMetaReq {
+        UpdateMultiTableMetaReq {
+            update_table_metas: self
+                .txn_buffer
+                .mutated_tables
+                .values()
+                .map(|(req, _)| req.clone())
+                .collect(),
+        }
+    }
+
+    /// Discard the buffered changes and go back to auto commit.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.txn_buffer = TxnBuffer::default();
+    }
+}
@@ -43,10 +43,11 @@ impl VacuumHandler for RealVacuumHandler {
 
     async fn do_vacuum_drop_tables(
         &self,
+        ctx: Arc<dyn TableContext>,
         tables: Vec<Arc<dyn Table>>,
         dry_run_limit: Option<usize>,
     ) -> Result<Option<Vec<(String, String)>>> {
-        do_vacuum_drop_tables(tables, dry_run_limit).await
+        do_vacuum_drop_tables(ctx, tables, dry_run_limit).await
     }
 }
 
@@ -16,6 +16,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_storages_fuse::FuseTable;
 use futures_util::TryStreamExt;
@@ -25,6 +26,7 @@ use opendal::Metakey;
 
 #[async_backtrace::framed]
 async fn do_vacuum_drop_table(
+    ctx: &Arc<dyn TableContext>,
     table: Arc<dyn Table>,
     dry_run_limit: Option<usize>,
 ) -> Result<Option<Vec<(String, String)>>> {
@@ -44,6 +46,18 @@ async fn do_vacuum_drop_table(
         return Ok(None);
     }
     let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    // the files of the table may still be shared with its clones, ignore
+    if !fuse_table
+        .list_clone_references(ctx, false)
+        .await?
+        .is_empty()
+    {
+        info!(
+            "ignore table {} still referenced by its clones",
+            table.get_table_info().name
+        );
+        return Ok(None);
+    }
 
     let operator = fuse_table.get_operator_ref();
 
@@ -89,6 +103,7 @@ async fn do_vacuum_drop_table(
 
 #[async_backtrace::framed]
 pub async fn do_vacuum_drop_tables(
+    ctx: Arc<dyn TableContext>,
     tables: Vec<Arc<dyn Table>>,
     dry_run_limit: Option<usize>,
 ) -> Result<Option<Vec<(String, String)>>> {
@@ -98,7 +113,7 @@ pub async fn do_vacuum_drop_tables(
     let mut list_files = Vec::new();
     let mut left_limit = dry_run_limit;
     for table in tables {
-        let ret = do_vacuum_drop_table(table, left_limit).await?;
+        let ret = do_vacuum_drop_table(&ctx, table, left_limit).await?;
         if let Some(ret) = ret {
             list_files.extend(ret);
             if list_files.len() >= dry_run_limit.unwrap() {
@@ -125,14 +125,22 @@ pub async fn get_snapshot_referenced_files(
         .get_block_locations(ctx.clone(), &segments_vec, false, false)
         .await?;
 
+    // A clone also references the files of its source table, which are not orphans of it.
+    let prefix = format!("{}/", fuse_table.meta_location_generator().prefix());
     let mut segments = HashSet::with_capacity(segments_vec.len());
     segments_vec.into_iter().for_each(|(location, _)| {
-        segments.insert(location);
+        if location.starts_with(&prefix) {
+            segments.insert(location);
+        }
     });
+    let mut blocks = locations_referenced.block_location;
+    blocks.retain(|location| location.starts_with(&prefix));
+    let mut blocks_index = locations_referenced.bloom_location;
+    blocks_index.retain(|location| location.starts_with(&prefix));
     Ok(Some(SnapshotReferencedFiles {
         segments,
-        blocks: locations_referenced.block_location,
-        blocks_index: locations_referenced.bloom_location,
+        blocks,
+        blocks_index,
     }))
 }
 
@@ -141,6 +149,7 @@ pub async fn get_snapshot_referenced_files(
 async fn get_orphan_files_to_be_purged(
     fuse_table: &FuseTable,
     referenced_files: HashSet<String>,
+    referenced_by_clones: &HashSet<String>,
     retention_time: DateTime<Utc>,
 ) -> Result<Vec<String>> {
     let files_to_be_purged = match referenced_files.iter().next().cloned() {
@@ -149,7 +158,9 @@ async fn get_orphan_files_to_be_purged(
             if let Some(prefix) = prefix {
                 fuse_table
                     .list_files(prefix, |location, modified| {
-                        modified <= retention_time && !referenced_files.contains(&location)
+                        modified <= retention_time
+                            && !referenced_files.contains(&location)
+                            && !referenced_by_clones.contains(&location)
                     })
                     .await?
             } else {
@@ -184,12 +195,17 @@ pub async fn do_gc_orphan_files(
         start.elapsed().as_secs()
     );
     ctx.set_status_info(&status);
+    let referenced_by_clones = fuse_table.files_referenced_by_clones(ctx, false).await?;
 
     // 2. Purge orphan segment files.
     // 2.1 Get orphan segment files to be purged
-    let segment_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.segments, retention_time)
-            .await?;
+    let segment_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        referenced_files.segments,
+        &referenced_by_clones,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "gc orphan: read segment_locations_to_be_purged:{}, cost:{} sec, retention_time: {}",
         segment_locations_to_be_purged.len(),
@@ -215,8 +231,13 @@ pub async fn do_gc_orphan_files(
 
     // 3. Purge orphan block files.
     // 3.1 Get orphan block files to be purged
-    let block_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks, retention_time).await?;
+    let block_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        referenced_files.blocks,
+        &referenced_by_clones,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "gc orphan: read block_locations_to_be_purged:{}, cost:{} sec",
         block_locations_to_be_purged.len(),
@@ -241,9 +262,13 @@ pub async fn do_gc_orphan_files(
 
     // 4. Purge orphan block index files.
     // 4.1 Get orphan block index files to be purged
-    let index_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks_index, retention_time)
-            .await?;
+    let index_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        referenced_files.blocks_index,
+        &referenced_by_clones,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "gc orphan: read index_locations_to_be_purged:{}, cost:{} sec",
         index_locations_to_be_purged.len(),
@@ -291,11 +316,16 @@ pub async fn do_dry_run_orphan_files(
         start.elapsed().as_secs()
     );
     ctx.set_status_info(&status);
+    let referenced_by_clones = fuse_table.files_referenced_by_clones(ctx, true).await?;
 
     // 2. Get purge orphan segment files.
-    let segment_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.segments, retention_time)
-            .await?;
+    let segment_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        referenced_files.segments,
+        &referenced_by_clones,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "dry_run orphan: read segment_locations_to_be_purged:{}, cost:{} sec",
         segment_locations_to_be_purged.len(),
@@ -309,8 +339,13 @@ pub async fn do_dry_run_orphan_files(
     }
 
     // 3. Get purge orphan block files.
-    let block_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks, retention_time).await?;
+    let block_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        referenced_files.blocks,
+        &referenced_by_clones,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "dry_run orphan: read block_locations_to_be_purged:{}, cost:{} sec",
         block_locations_to_be_purged.len(),
@@ -323,9 +358,13 @@ pub async fn do_dry_run_orphan_files(
     }
 
     // 4. Get purge orphan block index files.
-    let index_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks_index, retention_time)
-            .await?;
+    let index_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        referenced_files.blocks_index,
+        &referenced_by_clones,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "dry_run orphan: read index_locations_to_be_purged:{}, cost:{} sec",
         index_locations_to_be_purged.len(),
@@ -30,8 +30,8 @@ use common_sql::plans::DropStreamPlan;
 use common_sql::plans::StreamNavigation;
 use common_storages_fuse::FuseTable;
 use common_storages_fuse::TableContext;
+use common_storages_stream::stream_table::StreamMode;
 use common_storages_stream::stream_table::StreamTable;
-use common_storages_stream::stream_table::MODE_APPEND_ONLY;
 use common_storages_stream::stream_table::OPT_KEY_DATABASE_NAME;
 use common_storages_stream::stream_table::OPT_KEY_MODE;
 use common_storages_stream::stream_table::OPT_KEY_TABLE_ID;
@@ -103,13 +103,19 @@ impl StreamHandler for RealStreamHandler {
                 );
                 options.insert(OPT_KEY_TABLE_ID.to_string(), table_id.to_string());
                 options.insert(OPT_KEY_TABLE_VER.to_string(), table_version.to_string());
-                options.insert(OPT_KEY_MODE.to_string(), MODE_APPEND_ONLY.to_string());
                 if let Some(snapshot_loc) = fuse_table.snapshot_loc().await? {
                     options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
                 }
             }
         }
 
+        let mode = if plan.append_only {
+            StreamMode::AppendOnly
+        } else {
+            StreamMode::Standard
+        };
+        options.insert(OPT_KEY_MODE.to_string(), mode.to_string());
+
         let req = CreateTableReq {
             if_not_exists: plan.if_not_exists,
             name_ident: TableNameIdent {
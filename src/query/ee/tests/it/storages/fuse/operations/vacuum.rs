@@ -32,6 +32,7 @@ async fn test_fuse_do_vacuum_drop_table() -> Result<()> {
     append_sample_data(number_of_block, &fixture).await?;
 
     let table = fixture.latest_default_table().await?;
+    let ctx = fixture.new_query_ctx().await?;
 
     check_data_dir(
         &fixture,
@@ -54,7 +55,7 @@ async fn test_fuse_do_vacuum_drop_table() -> Result<()> {
 
     // verify dry run never delete files
     {
-        do_vacuum_drop_tables(vec![table.clone()], Some(100)).await?;
+        do_vacuum_drop_tables(ctx.clone(), vec![table.clone()], Some(100)).await?;
         check_data_dir(
             &fixture,
             "test_fuse_do_vacuum_drop_table: verify generate files",
@@ -70,7 +71,7 @@ async fn test_fuse_do_vacuum_drop_table() -> Result<()> {
     }
 
     {
-        do_vacuum_drop_tables(vec![table], None).await?;
+        do_vacuum_drop_tables(ctx, vec![table], None).await?;
 
         // after vacuum drop tables, verify the files number
         check_data_dir(
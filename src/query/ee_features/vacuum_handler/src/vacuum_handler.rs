@@ -34,6 +34,7 @@ pub trait VacuumHandler: Sync + Send {
 
     async fn do_vacuum_drop_tables(
         &self,
+        ctx: Arc<dyn TableContext>,
         tables: Vec<Arc<dyn Table>>,
         dry_run_limit: Option<usize>,
     ) -> Result<Option<Vec<(String, String)>>>;
@@ -64,11 +65,12 @@ impl VacuumHandlerWrapper {
     #[async_backtrace::framed]
     pub async fn do_vacuum_drop_tables(
         &self,
+        ctx: Arc<dyn TableContext>,
         tables: Vec<Arc<dyn Table>>,
         dry_run_limit: Option<usize>,
     ) -> Result<Option<Vec<(String, String)>>> {
         self.handler
-            .do_vacuum_drop_tables(tables, dry_run_limit)
+            .do_vacuum_drop_tables(ctx, tables, dry_run_limit)
             .await
     }
 }
@@ -65,6 +65,10 @@ pub const ORIGIN_VERSION_COLUMN_ID: u32 = u32::MAX - 12;
 pub const ORIGIN_VERSION_COL_NAME: &str = "_origin_version";
 pub const ORIGIN_BLOCK_ID_COL_NAME: &str = "_origin_block_id";
 pub const ORIGIN_BLOCK_ROW_NUM_COL_NAME: &str = "_origin_block_row_num";
+// change column name of standard stream.
+pub const CHANGE_ACTION_COL_NAME: &str = "change$action";
+pub const CHANGE_IS_UPDATE_COL_NAME: &str = "change$is_update";
+pub const CHANGE_ROW_ID_COL_NAME: &str = "change$row_id";
 
 #[inline]
 pub fn all_stream_columns() -> HashSet<String> {
@@ -0,0 +1,474 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use apache_avro::schema::Name;
+use apache_avro::types::Value;
+use apache_avro::Schema;
+use chrono_tz::Tz;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::serialize::read_decimal_with_size;
+use common_expression::serialize::uniform_date;
+use common_expression::types::array::ArrayColumnBuilder;
+use common_expression::types::date::check_date;
+use common_expression::types::date::date_to_string;
+use common_expression::types::decimal::Decimal;
+use common_expression::types::decimal::DecimalColumnBuilder;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::nullable::NullableColumnBuilder;
+use common_expression::types::number::Number;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp::timestamp_to_string;
+use common_expression::types::AnyType;
+use common_expression::types::NumberColumnBuilder;
+use common_expression::with_decimal_type;
+use common_expression::with_number_mapped_type;
+use common_expression::ColumnBuilder;
+use common_io::cursor_ext::BufferReadDateTimeExt;
+use common_io::cursor_ext::DateTimeResType;
+use num::BigInt;
+use num::NumCast;
+use num::Signed;
+
+use crate::FieldDecoder;
+use crate::FileFormatOptionsExt;
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+/// Named types of an Avro schema, used to resolve `Schema::Ref`.
+pub type AvroNames<'a> = HashMap<Name, &'a Schema>;
+
+/// Decode the values of Avro records into columns.
+///
+/// The writer schema is walked together with the value, it is needed to
+/// pick the branch of unions and the scale of decimals.
+pub struct FieldAvroDecoder {
+    pub timezone: Tz,
+    pub ident_case_sensitive: bool,
+    pub is_select: bool,
+}
+
+impl FieldDecoder for FieldAvroDecoder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl FieldAvroDecoder {
+    pub fn create(options: &FileFormatOptionsExt) -> Self {
+        FieldAvroDecoder {
+            timezone: options.timezone,
+            ident_case_sensitive: options.ident_case_sensitive,
+            is_select: options.is_select,
+        }
+    }
+
+    pub fn read_field(
+        &self,
+        column: &mut ColumnBuilder,
+        value: &Value,
+        schema: &Schema,
+        names: &AvroNames,
+    ) -> Result<()> {
+        let schema = resolve_schema(schema, names);
+        if let Value::Union(idx, value) = value {
+            let schema = match schema {
+                Schema::Union(union) => union.variants().get(*idx as usize).ok_or_else(|| {
+                    ErrorCode::BadBytes(format!("Invalid avro union branch {idx}"))
+                })?,
+                _ => schema,
+            };
+            return self.read_field(column, value, schema, names);
+        }
+        match column {
+            ColumnBuilder::Null { len } => self.read_null(len, value),
+            ColumnBuilder::Nullable(c) => self.read_nullable(c, value, schema, names),
+            ColumnBuilder::Boolean(c) => self.read_bool(c, value),
+            ColumnBuilder::Number(c) => with_number_mapped_type!(|NUM_TYPE| match c {
+                NumberColumnBuilder::NUM_TYPE(c) => self.read_number(c, value),
+            }),
+            ColumnBuilder::Decimal(c) => with_decimal_type!(|DECIMAL_TYPE| match c {
+                DecimalColumnBuilder::DECIMAL_TYPE(c, size) => {
+                    self.read_decimal(c, *size, value, schema)
+                }
+            }),
+            ColumnBuilder::Date(c) => self.read_date(c, value),
+            ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::String(c) => self.read_string(c, value),
+            ColumnBuilder::Array(c) => self.read_array(c, value, schema, names),
+            ColumnBuilder::Map(c) => self.read_map(c, value, schema, names),
+            ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value, schema, names),
+            ColumnBuilder::Variant(c) => self.read_variant(c, value, schema, names),
+            _ => Err(ErrorCode::Unimplemented(format!(
+                "Unsupported data type {:?} for avro",
+                column.data_type()
+            ))),
+        }
+    }
+
+    fn read_null(&self, len: &mut usize, _value: &Value) -> Result<()> {
+        *len += 1;
+        Ok(())
+    }
+
+    fn read_nullable(
+        &self,
+        column: &mut NullableColumnBuilder<AnyType>,
+        value: &Value,
+        schema: &Schema,
+        names: &AvroNames,
+    ) -> Result<()> {
+        match value {
+            Value::Null => {
+                column.push_null();
+            }
+            other => {
+                self.read_field(&mut column.builder, other, schema, names)?;
+                column.validity.push(true);
+            }
+        }
+        Ok(())
+    }
+
+    fn read_bool(&self, column: &mut MutableBitmap, value: &Value) -> Result<()> {
+        match value {
+            Value::Boolean(v) => column.push(*v),
+            _ => return Err(ErrorCode::BadBytes("Incorrect avro value, must be boolean")),
+        }
+        Ok(())
+    }
+
+    fn read_number<T: Number>(&self, column: &mut Vec<T>, value: &Value) -> Result<()> {
+        let v: Option<T> = match value {
+            Value::Int(v) | Value::Date(v) | Value::TimeMillis(v) => NumCast::from(*v),
+            Value::Long(v)
+            | Value::TimeMicros(v)
+            | Value::TimestampMillis(v)
+            | Value::TimestampMicros(v)
+            | Value::LocalTimestampMillis(v)
+            | Value::LocalTimestampMicros(v) => NumCast::from(*v),
+            Value::Float(v) => NumCast::from(*v),
+            Value::Double(v) => NumCast::from(*v),
+            _ => return Err(ErrorCode::BadBytes("Incorrect avro value, must be number")),
+        };
+        match v {
+            Some(v) => {
+                column.push(v);
+                Ok(())
+            }
+            None => Err(ErrorCode::Overflow("Number overflow")),
+        }
+    }
+
+    fn read_decimal<D: Decimal>(
+        &self,
+        column: &mut Vec<D>,
+        size: DecimalSize,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        let v = match value {
+            Value::Decimal(v) => {
+                let scale = match schema {
+                    Schema::Decimal(decimal) => decimal.scale,
+                    _ => 0,
+                };
+                let bytes = <Vec<u8>>::try_from(v).map_err(avro_error)?;
+                let literal = decimal_to_string(&bytes, scale);
+                read_decimal_with_size::<D>(literal.as_bytes(), size, true, true)?.0
+            }
+            Value::Int(v) => D::from_i64(*v as i64)
+                .with_size(size)
+                .ok_or_else(|| ErrorCode::Overflow("Decimal overflow"))?,
+            Value::Long(v) => D::from_i64(*v)
+                .with_size(size)
+                .ok_or_else(|| ErrorCode::Overflow("Decimal overflow"))?,
+            Value::Float(v) => D::from_float(*v as f64 * 10_f64.powi(size.scale as i32)),
+            Value::Double(v) => D::from_float(*v * 10_f64.powi(size.scale as i32)),
+            Value::String(v) => read_decimal_with_size::<D>(v.as_bytes(), size, true, true)?.0,
+            _ => return Err(ErrorCode::BadBytes("Incorrect avro value, must be decimal")),
+        };
+        column.push(v);
+        Ok(())
+    }
+
+    fn read_string(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) | Value::Enum(_, v) => column.put_str(v),
+            Value::Bytes(v) | Value::Fixed(_, v) => column.put_slice(v),
+            Value::Uuid(v) => column.put_str(&v.to_string()),
+            _ => return Err(ErrorCode::BadBytes("Incorrect avro value, must be string")),
+        }
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_date(&self, column: &mut Vec<i32>, value: &Value) -> Result<()> {
+        let days = match value {
+            Value::Date(v) | Value::Int(v) => check_date(*v as i64)?,
+            Value::TimestampMicros(v) | Value::LocalTimestampMicros(v) => {
+                check_date(v.div_euclid(MICROS_PER_DAY))?
+            }
+            Value::TimestampMillis(v) | Value::LocalTimestampMillis(v) => {
+                check_date(millis_to_micros(*v)?.div_euclid(MICROS_PER_DAY))?
+            }
+            Value::String(v) => {
+                let mut reader = Cursor::new(v.as_bytes());
+                let date = reader.read_date_text(&self.timezone)?;
+                let days = uniform_date(date);
+                check_date(days as i64)?
+            }
+            _ => return Err(ErrorCode::BadBytes("Incorrect avro value, must be date")),
+        };
+        column.push(days);
+        Ok(())
+    }
+
+    fn read_timestamp(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
+        let micros = match value {
+            Value::TimestampMicros(v) | Value::LocalTimestampMicros(v) | Value::Long(v) => *v,
+            Value::TimestampMillis(v) | Value::LocalTimestampMillis(v) => millis_to_micros(*v)?,
+            Value::Date(v) => (*v as i64)
+                .checked_mul(MICROS_PER_DAY)
+                .ok_or_else(|| ErrorCode::Overflow("Timestamp overflow"))?,
+            Value::String(v) => {
+                let mut reader = Cursor::new(v.as_bytes());
+                match reader.read_timestamp_text(&self.timezone, false)? {
+                    DateTimeResType::Datetime(ts) => ts.timestamp_micros(),
+                    _ => unreachable!(),
+                }
+            }
+            _ => {
+                return Err(ErrorCode::BadBytes(
+                    "Incorrect avro value, must be timestamp",
+                ));
+            }
+        };
+        column.push(check_timestamp(micros)?);
+        Ok(())
+    }
+
+    fn read_array(
+        &self,
+        column: &mut ArrayColumnBuilder<AnyType>,
+        value: &Value,
+        schema: &Schema,
+        names: &AvroNames,
+    ) -> Result<()> {
+        match (value, schema) {
+            (Value::Array(vals), Schema::Array(items)) => {
+                for val in vals {
+                    self.read_field(&mut column.builder, val, items, names)?;
+                }
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect avro value, must be array")),
+        }
+    }
+
+    fn read_map(
+        &self,
+        column: &mut ArrayColumnBuilder<AnyType>,
+        value: &Value,
+        schema: &Schema,
+        names: &AvroNames,
+    ) -> Result<()> {
+        const KEY: usize = 0;
+        const VALUE: usize = 1;
+        let map_builder = column.builder.as_tuple_mut().unwrap();
+        match (value, schema) {
+            (Value::Map(obj), Schema::Map(values)) => {
+                // Keep the output stable, the order of `HashMap` is random.
+                let mut entries = obj.iter().collect::<Vec<_>>();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                for (key, val) in entries {
+                    let key = Value::String(key.clone());
+                    self.read_field(&mut map_builder[KEY], &key, &Schema::String, names)?;
+                    self.read_field(&mut map_builder[VALUE], val, values, names)?;
+                }
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect avro value, must be map")),
+        }
+    }
+
+    fn read_tuple(
+        &self,
+        fields: &mut [ColumnBuilder],
+        value: &Value,
+        schema: &Schema,
+        names: &AvroNames,
+    ) -> Result<()> {
+        match (value, schema) {
+            (Value::Record(vals), Schema::Record(record)) => {
+                if fields.len() != vals.len() {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Incorrect avro value, expect {} fields, but get {} fields",
+                        fields.len(),
+                        vals.len()
+                    )));
+                }
+                for ((field, (_, val)), record_field) in
+                    fields.iter_mut().zip(vals.iter()).zip(record.fields.iter())
+                {
+                    self.read_field(field, val, &record_field.schema, names)?;
+                }
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect avro value, must be record")),
+        }
+    }
+
+    fn read_variant(
+        &self,
+        column: &mut StringColumnBuilder,
+        value: &Value,
+        schema: &Schema,
+        names: &AvroNames,
+    ) -> Result<()> {
+        let v = self.to_json_value(value, schema, names)?;
+        jsonb::Value::from(&v).write_to_vec(&mut column.data);
+        column.commit_row();
+        Ok(())
+    }
+
+    /// Convert the value to JSON, which is used to build variant.
+    pub fn to_json_value(
+        &self,
+        value: &Value,
+        schema: &Schema,
+        names: &AvroNames,
+    ) -> Result<serde_json::Value> {
+        let schema = resolve_schema(schema, names);
+        let v = match value {
+            Value::Null => serde_json::Value::Null,
+            Value::Boolean(v) => serde_json::Value::Bool(*v),
+            Value::Int(v) | Value::TimeMillis(v) => serde_json::Value::from(*v),
+            Value::Long(v) | Value::TimeMicros(v) => serde_json::Value::from(*v),
+            Value::Float(v) => serde_json::Value::from(*v),
+            Value::Double(v) => serde_json::Value::from(*v),
+            Value::String(v) | Value::Enum(_, v) => serde_json::Value::String(v.clone()),
+            Value::Bytes(v) | Value::Fixed(_, v) => {
+                serde_json::Value::String(String::from_utf8_lossy(v).into_owned())
+            }
+            Value::Uuid(v) => serde_json::Value::String(v.to_string()),
+            Value::Date(v) => {
+                serde_json::Value::String(date_to_string(*v, self.timezone).to_string())
+            }
+            Value::TimestampMillis(v) | Value::LocalTimestampMillis(v) => {
+                serde_json::Value::String(
+                    timestamp_to_string(millis_to_micros(*v)?, self.timezone).to_string(),
+                )
+            }
+            Value::TimestampMicros(v) | Value::LocalTimestampMicros(v) => {
+                serde_json::Value::String(timestamp_to_string(*v, self.timezone).to_string())
+            }
+            Value::Decimal(v) => {
+                let scale = match schema {
+                    Schema::Decimal(decimal) => decimal.scale,
+                    _ => 0,
+                };
+                let bytes = <Vec<u8>>::try_from(v).map_err(avro_error)?;
+                serde_json::Value::String(decimal_to_string(&bytes, scale))
+            }
+            Value::Union(idx, v) => {
+                let schema = match schema {
+                    Schema::Union(union) => union.variants().get(*idx as usize).unwrap_or(schema),
+                    _ => schema,
+                };
+                self.to_json_value(v, schema, names)?
+            }
+            Value::Array(vals) => {
+                let items = match schema {
+                    Schema::Array(items) => items.as_ref(),
+                    _ => schema,
+                };
+                serde_json::Value::Array(
+                    vals.iter()
+                        .map(|v| self.to_json_value(v, items, names))
+                        .collect::<Result<_>>()?,
+                )
+            }
+            Value::Map(obj) => {
+                let values = match schema {
+                    Schema::Map(values) => values.as_ref(),
+                    _ => schema,
+                };
+                let mut map = serde_json::Map::with_capacity(obj.len());
+                for (key, val) in obj.iter() {
+                    map.insert(key.clone(), self.to_json_value(val, values, names)?);
+                }
+                serde_json::Value::Object(map)
+            }
+            Value::Record(vals) => {
+                let mut map = serde_json::Map::with_capacity(vals.len());
+                for (idx, (key, val)) in vals.iter().enumerate() {
+                    let field_schema = match schema {
+                        Schema::Record(record) => &record.fields[idx].schema,
+                        _ => schema,
+                    };
+                    map.insert(key.clone(), self.to_json_value(val, field_schema, names)?);
+                }
+                serde_json::Value::Object(map)
+            }
+            other => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Unsupported avro value {other:?}"
+                )));
+            }
+        };
+        Ok(v)
+    }
+}
+
+/// Resolve the reference to a named type.
+pub fn resolve_schema<'a>(schema: &'a Schema, names: &AvroNames<'a>) -> &'a Schema {
+    match schema {
+        Schema::Ref { name } => names.get(name).copied().unwrap_or(schema),
+        _ => schema,
+    }
+}
+
+/// Avro decimal is the two's-complement big-endian unscaled integer.
+fn decimal_to_string(bytes: &[u8], scale: usize) -> String {
+    let n = BigInt::from_signed_bytes_be(bytes);
+    let digits = n.abs().to_string();
+    let sign = if n.is_negative() { "-" } else { "" };
+    if scale == 0 {
+        format!("{sign}{digits}")
+    } else if digits.len() > scale {
+        let (int, frac) = digits.split_at(digits.len() - scale);
+        format!("{sign}{int}.{frac}")
+    } else {
+        format!("{sign}0.{digits:0>scale$}")
+    }
+}
+
+fn millis_to_micros(millis: i64) -> Result<i64> {
+    millis
+        .checked_mul(1000)
+        .ok_or_else(|| ErrorCode::Overflow("Timestamp overflow"))
+}
+
+fn avro_error(e: apache_avro::Error) -> ErrorCode {
+    ErrorCode::BadBytes(format!("Invalid avro value: {e}"))
+}
@@ -20,6 +20,7 @@ use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageFileFormatType;
 use common_settings::Settings;
 
+use crate::output_format::ArrowOutputFormat;
 use crate::output_format::CSVOutputFormat;
 use crate::output_format::CSVWithNamesAndTypesOutputFormat;
 use crate::output_format::CSVWithNamesOutputFormat;
@@ -157,6 +158,7 @@ impl FileFormatOptionsExt {
             }
             FileFormatParams::Parquet(_) => Box::new(ParquetOutputFormat::create(schema, self)),
             FileFormatParams::Json(_) => Box::new(JSONOutputFormat::create(schema, self)),
+            FileFormatParams::Arrow(_) => Box::new(ArrowOutputFormat::create(schema, self)),
             others => {
                 return Err(ErrorCode::InvalidArgument(format!(
                     "Unsupported output file format:{:?}",
@@ -176,6 +178,7 @@ impl FileFormatTypeExt for StageFileFormatType {
             StageFileFormatType::Parquet => "application/octet-stream",
             StageFileFormatType::NdJson => "application/x-ndjson; charset=UTF-8",
             StageFileFormatType::Json => "application/json; charset=UTF-8",
+            StageFileFormatType::Arrow => "application/vnd.apache.arrow.stream",
             _ => "text/plain; charset=UTF-8",
         }
         .to_string()
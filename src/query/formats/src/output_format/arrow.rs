@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::ipc::write::default_ipc_fields;
+use common_arrow::arrow::io::ipc::write::StreamWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow::io::ipc::IpcField;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
+
+use crate::output_format::OutputFormat;
+use crate::FileFormatOptionsExt;
+
+/// Arrow IPC streaming format.
+///
+/// The prefix is the schema message and each block is a record batch message,
+/// so the output can be flushed block by block, and concatenated into files.
+pub struct ArrowOutputFormat {
+    schema: ArrowSchema,
+    ipc_fields: Vec<IpcField>,
+}
+
+impl ArrowOutputFormat {
+    pub fn create(schema: TableSchemaRef, _options: &FileFormatOptionsExt) -> Self {
+        let schema = schema.to_arrow();
+        let ipc_fields = default_ipc_fields(&schema.fields);
+        Self { schema, ipc_fields }
+    }
+
+    fn writer() -> StreamWriter<Vec<u8>> {
+        StreamWriter::new(vec![], WriteOptions { compression: None })
+    }
+}
+
+impl OutputFormat for ArrowOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        if block.is_empty() {
+            return Ok(vec![]);
+        }
+        let chunk = Chunk::try_from(block.clone())?;
+        let mut writer = Self::writer();
+        writer.write(&chunk, Some(&self.ipc_fields))?;
+        Ok(writer.into_inner())
+    }
+
+    fn serialize_prefix(&self) -> Result<Vec<u8>> {
+        let mut writer = Self::writer();
+        writer.start(&self.schema, Some(self.ipc_fields.clone()))?;
+        Ok(writer.into_inner())
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        // the end-of-stream marker
+        let mut writer = Self::writer();
+        writer.finish()?;
+        Ok(writer.into_inner())
+    }
+}
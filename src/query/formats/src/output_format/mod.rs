@@ -14,12 +14,14 @@
 
 use common_exception::Result;
 use common_expression::DataBlock;
+pub mod arrow;
 pub mod csv;
 pub mod json;
 pub mod ndjson;
 pub mod parquet;
 pub mod tsv;
 
+pub use arrow::ArrowOutputFormat;
 pub use csv::CSVOutputFormat;
 pub use csv::CSVWithNamesAndTypesOutputFormat;
 pub use csv::CSVWithNamesOutputFormat;
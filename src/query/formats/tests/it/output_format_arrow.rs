@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_exception::Result;
+use common_expression::types::number::Int32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+
+use crate::get_output_format_clickhouse;
+use crate::output_format_utils::gen_schema_and_block;
+
+#[test]
+fn test_arrow_stream() -> Result<()> {
+    let (schema, block) = gen_schema_and_block(
+        vec![
+            TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new(
+                "b",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ],
+        vec![
+            Int32Type::from_data(vec![1i32, 2, 3]),
+            StringType::from_opt_data(vec![Some("x"), None, Some("z")]),
+        ],
+    );
+
+    let mut formatter = get_output_format_clickhouse("ArrowStream", schema.clone())?;
+    let mut buffer = formatter.serialize_prefix()?;
+    buffer.extend(formatter.serialize_block(&block)?);
+    buffer.extend(formatter.serialize_block(&block.slice(1..2))?);
+    buffer.extend(formatter.finalize()?);
+
+    let mut reader = Cursor::new(buffer);
+    let metadata = read_stream_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, schema.to_arrow());
+
+    let chunks = StreamReader::new(reader, metadata, None)
+        .map(|state| match state? {
+            StreamState::Some(chunk) => Ok(chunk),
+            StreamState::Waiting => unreachable!("the stream is finished"),
+        })
+        .collect::<common_arrow::arrow::error::Result<Vec<_>>>()?;
+
+    let expects = [
+        Chunk::try_from(block.clone())?,
+        Chunk::try_from(block.slice(1..2))?,
+    ];
+    assert_eq!(chunks.len(), expects.len());
+    for (chunk, expect) in chunks.iter().zip(expects.iter()) {
+        assert_eq!(chunk.len(), expect.len());
+        for (array, expect) in chunk.arrays().iter().zip(expect.arrays()) {
+            assert_eq!(array.as_ref(), expect.as_ref());
+        }
+    }
+    Ok(())
+}
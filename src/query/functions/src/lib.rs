@@ -57,6 +57,9 @@ pub const GENERAL_WINDOW_FUNCTIONS: [&str; 13] = [
 
 pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 3] = ["array_transform", "array_apply", "array_filter"];
 
+/// The space-filling curve functions, which can cluster a table by multiple columns.
+pub const SPACE_FILLING_CURVE_FUNCTIONS: [&str; 2] = ["hilbert", "zorder"];
+
 fn builtin_functions() -> FunctionRegistry {
     let mut registry = FunctionRegistry::empty();
 
@@ -846,7 +846,7 @@ fn decode_one(data: &[u8]) -> Option<(u8, usize)> {
 
 #[inline]
 /// Borrow from [tikv](https://github.com/tikv/tikv/blob/fe997db4db8a5a096f8a45c0db3eb3c2e5879262/components/tidb_query_expr/src/impl_like.rs)
-fn like(haystack: &[u8], pattern: &[u8]) -> bool {
+pub fn like(haystack: &[u8], pattern: &[u8]) -> bool {
     // current search positions in pattern and target.
     let (mut px, mut tx) = (0, 0);
     // positions for backtrace.
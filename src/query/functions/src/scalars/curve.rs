@@ -0,0 +1,214 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Space-filling curve functions, used to cluster a table by multiple columns.
+//!
+//! Each argument is mapped to an order-preserving 64-bit key, the keys are then
+//! mapped to a point on the curve, whose index is returned as a big-endian binary
+//! string. Sorting the rows by the index keeps the rows close in all the dimensions
+//! close in the ordering.
+
+use std::sync::Arc;
+
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::AnyType;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::EvalContext;
+use common_expression::Function;
+use common_expression::FunctionDomain;
+use common_expression::FunctionEval;
+use common_expression::FunctionRegistry;
+use common_expression::FunctionSignature;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_expression::ValueRef;
+use ethnum::i256;
+
+const SIGN_BIT: u64 = 1 << 63;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    register_curve_function(registry, "hilbert", hilbert_index);
+    register_curve_function(registry, "zorder", interleave_bits);
+}
+
+fn register_curve_function(
+    registry: &mut FunctionRegistry,
+    name: &'static str,
+    encode: fn(&mut [u64]) -> Vec<u8>,
+) {
+    registry.register_function_factory(name, move |_, args_type| {
+        if args_type.is_empty() || !args_type.iter().all(is_curve_key_type) {
+            return None;
+        }
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: name.to_string(),
+                args_type: args_type.to_vec(),
+                return_type: DataType::String,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(move |args, ctx| curve_fn(args, ctx, encode)),
+            },
+        }))
+    });
+}
+
+fn is_curve_key_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        DataType::Null
+            | DataType::Boolean
+            | DataType::Number(_)
+            | DataType::Decimal(_)
+            | DataType::String
+            | DataType::Timestamp
+            | DataType::Date
+    )
+}
+
+fn curve_fn(
+    args: &[ValueRef<AnyType>],
+    _: &mut EvalContext,
+    encode: fn(&mut [u64]) -> Vec<u8>,
+) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+
+    let size = len.unwrap_or(1);
+    let mut builder = StringColumnBuilder::with_capacity(size, size * args.len() * 8);
+    let mut keys = vec![0; args.len()];
+    for idx in 0..size {
+        for (key, arg) in keys.iter_mut().zip(args.iter()) {
+            *key = order_key(unsafe { arg.index_unchecked(idx) });
+        }
+        builder.put_slice(&encode(&mut keys));
+        builder.commit_row();
+    }
+
+    match len {
+        Some(_) => Value::Column(Column::String(builder.build())),
+        _ => Value::Scalar(Scalar::String(builder.build_scalar())),
+    }
+}
+
+/// Map the value to a 64-bit key, the order of the keys is the same as the values.
+/// Null is the smallest key, strings only keep the first 8 bytes.
+fn order_key(value: ScalarRef) -> u64 {
+    match value {
+        ScalarRef::Boolean(v) => v as u64,
+        ScalarRef::Number(NumberScalar::UInt8(v)) => v as u64,
+        ScalarRef::Number(NumberScalar::UInt16(v)) => v as u64,
+        ScalarRef::Number(NumberScalar::UInt32(v)) => v as u64,
+        ScalarRef::Number(NumberScalar::UInt64(v)) => v,
+        ScalarRef::Number(NumberScalar::Int8(v)) => signed_key(v as i64),
+        ScalarRef::Number(NumberScalar::Int16(v)) => signed_key(v as i64),
+        ScalarRef::Number(NumberScalar::Int32(v)) => signed_key(v as i64),
+        ScalarRef::Number(NumberScalar::Int64(v)) => signed_key(v),
+        ScalarRef::Number(NumberScalar::Float32(v)) => float_key(v.0 as f64),
+        ScalarRef::Number(NumberScalar::Float64(v)) => float_key(v.0),
+        ScalarRef::Decimal(DecimalScalar::Decimal128(v, _)) => {
+            signed_key(v.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+        }
+        ScalarRef::Decimal(DecimalScalar::Decimal256(v, _)) => {
+            let v = v.clamp(i256::from(i64::MIN), i256::from(i64::MAX));
+            signed_key(v.as_i64())
+        }
+        ScalarRef::Timestamp(v) => signed_key(v),
+        ScalarRef::Date(v) => signed_key(v as i64),
+        ScalarRef::String(v) => {
+            let mut bytes = [0u8; 8];
+            let len = v.len().min(8);
+            bytes[..len].copy_from_slice(&v[..len]);
+            u64::from_be_bytes(bytes)
+        }
+        _ => 0,
+    }
+}
+
+fn signed_key(v: i64) -> u64 {
+    (v as u64) ^ SIGN_BIT
+}
+
+fn float_key(v: f64) -> u64 {
+    let bits = v.to_bits();
+    if bits & SIGN_BIT != 0 {
+        !bits
+    } else {
+        bits | SIGN_BIT
+    }
+}
+
+/// Interleave the bits of the keys from the most significant bit, which is the index
+/// of the point on the Z-order curve.
+fn interleave_bits(keys: &mut [u64]) -> Vec<u8> {
+    let mut index = vec![0u8; keys.len() * 8];
+    let mut pos = 0;
+    for bit in (0..64).rev() {
+        for key in keys.iter() {
+            if (key >> bit) & 1 == 1 {
+                index[pos / 8] |= 0x80 >> (pos % 8);
+            }
+            pos += 1;
+        }
+    }
+    index
+}
+
+/// The index of the point on the Hilbert curve, using the transpose algorithm from
+/// John Skilling, "Programming the Hilbert curve", AIP Conf. Proc. 707, 381 (2004).
+#[allow(clippy::needless_range_loop)]
+fn hilbert_index(keys: &mut [u64]) -> Vec<u8> {
+    let n = keys.len();
+
+    // Inverse undo excess work.
+    let mut q = SIGN_BIT;
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..n {
+            if keys[i] & q != 0 {
+                keys[0] ^= p;
+            } else {
+                let t = (keys[0] ^ keys[i]) & p;
+                keys[0] ^= t;
+                keys[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+
+    // Gray encode.
+    for i in 1..n {
+        keys[i] ^= keys[i - 1];
+    }
+    let mut t = 0;
+    let mut q = SIGN_BIT;
+    while q > 1 {
+        if keys[n - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for key in keys.iter_mut() {
+        *key ^= t;
+    }
+
+    interleave_bits(keys)
+}
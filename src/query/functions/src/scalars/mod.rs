@@ -21,6 +21,7 @@ mod bitmap;
 mod boolean;
 mod comparison;
 mod control;
+mod curve;
 mod datetime;
 mod decimal;
 mod geo;
@@ -37,8 +38,10 @@ mod vector;
 
 pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
+pub use comparison::like;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use string::tokenize;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -60,4 +63,5 @@ pub fn register(registry: &mut FunctionRegistry) {
     decimal::register(registry);
     vector::register(registry);
     bitmap::register(registry);
+    curve::register(registry);
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::io::Write;
 
 use base64::engine::general_purpose;
@@ -23,6 +24,7 @@ use common_expression::types::number::UInt64Type;
 use common_expression::types::string::StringColumn;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::vectorize_with_builder_1_arg;
@@ -871,7 +873,43 @@ pub fn register(registry: &mut FunctionRegistry) {
                     }
                 },
             ),
-        )
+        );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+            |text, query, output, _| {
+                let query = tokenize(query).collect::<HashSet<_>>();
+                if query.is_empty() {
+                    output.push(false);
+                } else {
+                    let text = tokenize(text).collect::<HashSet<_>>();
+                    output.push(query.iter().all(|token| text.contains(token)));
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "contains",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+            |haystack, needle, output, _| {
+                output.push(haystack.contains_str(needle));
+            },
+        ),
+    );
+}
+
+/// Split the text into the tokens of the full-text search.
+///
+/// The tokens are separated by the ASCII characters which are not alphanumeric,
+/// and are converted to lowercase, so `MATCH(text, query)` is case-insensitive.
+pub fn tokenize(text: &[u8]) -> impl Iterator<Item = Vec<u8>> + '_ {
+    text.split(|c| c.is_ascii() && !c.is_ascii_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_ascii_lowercase())
 }
 
 pub(crate) mod soundex {
@@ -0,0 +1,300 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::compute::aggregate::estimated_bytes_size;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockMetaInfo;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::TableSchema;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use log::debug;
+use opendal::Operator;
+use serde::Deserializer;
+use serde::Serializer;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+/// The marker before the length of each message of an arrow stream.
+const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+
+/// Arrow IPC streaming format, one schema message followed by record batches.
+pub struct InputFormatArrow;
+
+impl InputFormatArrow {
+    /// Reads the schema message at the start of an arrow stream file.
+    ///
+    /// Only the schema message is downloaded, its length is in the prefix of the message,
+    /// which is `[0xFFFFFFFF, length]`, or `[length]` in the legacy format.
+    #[async_backtrace::framed]
+    pub async fn read_schema(op: &Operator, path: &str) -> Result<TableSchema> {
+        let size = op.stat(path).await?.content_length();
+        let prefix = op.read_with(path).range(0..size.min(8)).await?;
+        let (prefix_len, length) = if prefix.starts_with(&CONTINUATION_MARKER) {
+            (8, prefix.get(4..8))
+        } else {
+            (4, prefix.get(0..4))
+        };
+        let data = match length {
+            Some(length) => {
+                let length = i32::from_le_bytes(length.try_into().unwrap()).max(0) as u64;
+                op.read_with(path)
+                    .range(0..(prefix_len + length).min(size))
+                    .await?
+            }
+            // too short to be an arrow stream, left to the reader to report.
+            None => prefix,
+        };
+        let metadata = read_stream_metadata(&mut Cursor::new(data))
+            .map_err(|e| ErrorCode::from(e).add_message(format!(" arrow stream {path}")))?;
+        Ok(TableSchema::from(&metadata.schema))
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatArrow {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        // the stream has no index of its messages, so it is never split.
+        let mut infos = vec![];
+        for info in file_infos {
+            let size = info.size as usize;
+            let file = Arc::new(FileInfo {
+                path: info.path.clone(),
+                size,
+                num_splits: 1,
+                compress_alg: None,
+            });
+            infos.push(Arc::new(SplitInfo {
+                file,
+                seq_in_file: 0,
+                offset: 0,
+                size,
+                num_file_splits: 1,
+                format_info: None,
+            }));
+        }
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        ArrowFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        ArrowFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct ArrowFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for ArrowFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = RecordBatchInMemory;
+    type AligningState = ArrowAligningState;
+    type BlockBuilder = ArrowBlockBuilder;
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<ArrowAligningState> {
+        Ok(ArrowAligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(_ctx: &Arc<InputContext>) -> Result<ArrowBlockBuilder> {
+        Ok(ArrowBlockBuilder {})
+    }
+}
+
+pub struct RecordBatchInMemory {
+    pub path: String,
+    pub chunk: ArrowChunk<Box<dyn Array>>,
+    // the schema of `chunk`, in the order of the fields of the input schema
+    pub schema: DataSchemaRef,
+}
+
+impl Debug for RecordBatchInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RecordBatchInMemory({}, {})",
+            self.path,
+            self.chunk.len()
+        )
+    }
+}
+
+impl serde::Serialize for RecordBatchInMemory {
+    fn serialize<S>(&self, _serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where S: Serializer {
+        unimplemented!()
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for RecordBatchInMemory {
+    fn deserialize<D: Deserializer<'a>>(_deserializer: D) -> std::result::Result<Self, D::Error> {
+        unimplemented!()
+    }
+}
+
+impl RowBatchTrait for RecordBatchInMemory {
+    fn size(&self) -> usize {
+        self.chunk
+            .arrays()
+            .iter()
+            .map(|a| estimated_bytes_size(a.as_ref()))
+            .sum()
+    }
+
+    fn rows(&self) -> usize {
+        self.chunk.len()
+    }
+}
+
+#[typetag::serde(name = "row_batch_arrow")]
+impl BlockMetaInfo for RecordBatchInMemory {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("RecordBatchInMemory as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("RecordBatchInMemory as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+pub struct ArrowBlockBuilder {}
+
+impl BlockBuilderTrait for ArrowBlockBuilder {
+    type Pipe = ArrowFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<RecordBatchInMemory>) -> Result<Vec<DataBlock>> {
+        if let Some(b) = batch {
+            let block = DataBlock::from_arrow_chunk(&b.chunk, &b.schema)?;
+            Ok(vec![block])
+        } else {
+            Ok(vec![])
+        }
+    }
+}
+
+pub struct ArrowAligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for ArrowAligningState {
+    type Pipe = ArrowFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<RecordBatchInMemory>> {
+        if let Some(b) = read_batch {
+            self.buffers.push(b);
+            return Ok(vec![]);
+        }
+
+        let path = &self.split_info.file.path;
+        let data = self.buffers.concat();
+        self.buffers.clear();
+        let size = data.len();
+
+        let mut cursor = Cursor::new(data);
+        let metadata = read_stream_metadata(&mut cursor)
+            .map_err(|e| ErrorCode::from(e).add_message(format!(" arrow stream {path}")))?;
+
+        // for each field of the input schema, its index in the stream schema
+        let stream_fields = &metadata.schema.fields;
+        let mut projection = Vec::with_capacity(self.ctx.schema.num_fields());
+        let mut fields = Vec::with_capacity(self.ctx.schema.num_fields());
+        for f in self.ctx.schema.fields() {
+            match stream_fields
+                .iter()
+                .rposition(|c| c.name.eq_ignore_ascii_case(f.name()))
+            {
+                Some(i) => {
+                    projection.push(i);
+                    fields.push(DataField::from(&stream_fields[i]));
+                }
+                None => {
+                    return Err(ErrorCode::TableSchemaMismatch(format!(
+                        "arrow stream {} has no column {}",
+                        path,
+                        f.name()
+                    )));
+                }
+            }
+        }
+        let schema = Arc::new(DataSchema::new(fields));
+
+        let reader = StreamReader::new(cursor, metadata, None);
+        let mut row_batches = vec![];
+        for state in reader {
+            match state? {
+                StreamState::Some(chunk) => {
+                    let arrays = chunk.into_arrays();
+                    let arrays = projection.iter().map(|i| arrays[*i].clone()).collect();
+                    row_batches.push(RecordBatchInMemory {
+                        path: path.clone(),
+                        chunk: ArrowChunk::try_new(arrays)?,
+                        schema: schema.clone(),
+                    });
+                }
+                // the whole stream is in memory, so running out of data is the end of it,
+                // the end-of-stream marker is optional.
+                StreamState::Waiting => break,
+            }
+        }
+        debug!(
+            "align arrow stream {} of {} bytes to {} record batches",
+            path,
+            size,
+            row_batches.len()
+        );
+        Ok(row_batches)
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use apache_avro::schema::RecordSchema;
+use apache_avro::schema::ResolvedSchema;
+use apache_avro::types::Value;
+use apache_avro::Reader;
+use apache_avro::Schema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_formats::resolve_schema;
+use common_formats::AvroNames;
+use common_formats::FieldAvroDecoder;
+use common_formats::FieldDecoder;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::AvroFileFormatParams;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::JsonNullAs;
+use common_meta_app::principal::StageFileFormatType;
+use common_storage::FileParseError;
+
+use crate::input_formats::error_utils::truncate_column_data;
+use crate::input_formats::impls::input_format_xml::AligningStateWholeFile;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+pub struct InputFormatAvro {}
+
+impl InputFormatAvro {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn read_row(
+        field_decoder: &FieldAvroDecoder,
+        record: &[(String, Value)],
+        record_schema: &RecordSchema,
+        names: &AvroNames,
+        field_indices: &[Option<usize>],
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+        default_values: &Option<Vec<Scalar>>,
+        params: &AvroFileFormatParams,
+    ) -> std::result::Result<(), FileParseError> {
+        for (((column_index, field), column), field_index) in schema
+            .fields()
+            .iter()
+            .enumerate()
+            .zip(columns.iter_mut())
+            .zip(field_indices.iter())
+        {
+            let null_as = match field_index {
+                None => &params.missing_field_as,
+                Some(idx) => match &record[*idx].1 {
+                    Value::Null => &params.null_field_as,
+                    Value::Union(_, v) if matches!(v.as_ref(), Value::Null) => {
+                        &params.null_field_as
+                    }
+                    value => {
+                        field_decoder
+                            .read_field(column, value, &record_schema.fields[*idx].schema, names)
+                            .map_err(|e| FileParseError::ColumnDecodeError {
+                                column_index,
+                                column_name: field.name().to_owned(),
+                                column_type: field.data_type.to_string(),
+                                decode_error: e.message(),
+                                column_data: truncate_column_data(format!("{value:?}")),
+                            })?;
+                        continue;
+                    }
+                },
+            };
+            match null_as {
+                JsonNullAs::Error => {
+                    return Err(FileParseError::ColumnMissingError {
+                        column_index,
+                        column_name: field.name().to_owned(),
+                        column_type: field.data_type.to_string(),
+                    });
+                }
+                JsonNullAs::Null => {
+                    if field.is_nullable_or_null() {
+                        column.push_default();
+                    } else {
+                        return Err(FileParseError::ColumnDecodeError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                            decode_error: "null value is not allowed for non-nullable field"
+                                .to_owned(),
+                            column_data: "null".to_owned(),
+                        });
+                    }
+                }
+                JsonNullAs::FieldDefault => {
+                    if let Some(values) = default_values {
+                        column.push(values[column_index].as_ref());
+                    } else {
+                        column.push_default();
+                    }
+                }
+                JsonNullAs::TypeDefault => {
+                    column.push_default();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Match the columns of table to the fields of avro record by name.
+    fn match_fields(
+        record_schema: &RecordSchema,
+        schema: &TableSchemaRef,
+        ident_case_sensitive: bool,
+    ) -> Vec<Option<usize>> {
+        let lookup: HashMap<String, usize> = record_schema
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| {
+                if ident_case_sensitive {
+                    (field.name.clone(), idx)
+                } else {
+                    (field.name.to_lowercase(), idx)
+                }
+            })
+            .collect();
+        schema
+            .fields()
+            .iter()
+            .map(|field| {
+                if ident_case_sensitive {
+                    lookup.get(field.name()).copied()
+                } else {
+                    lookup.get(&field.name().to_lowercase()).copied()
+                }
+            })
+            .collect()
+    }
+}
+
+impl InputFormatTextBase for InputFormatAvro {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Avro
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldAvroDecoder::create(options))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldAvroDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+        let path = &batch.split_info.file.path;
+        if batch.data.is_empty() {
+            return Ok(());
+        }
+
+        let params = AvroFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params);
+        let reader = Reader::new(Cursor::new(&batch.data)).map_err(|e| avro_error(e, path))?;
+        let writer_schema = reader.writer_schema().clone();
+        let resolved = ResolvedSchema::try_from(&writer_schema).map_err(|e| avro_error(e, path))?;
+        let names = resolved.get_names();
+        let record_schema = match resolve_schema(&writer_schema, names) {
+            Schema::Record(record_schema) => Some(record_schema),
+            _ => None,
+        };
+        if record_schema.is_none() && !field_decoder.is_select {
+            return Err(ErrorCode::BadBytes(format!(
+                "the top level schema of avro file {path} must be a record, but got {writer_schema:?}"
+            )));
+        }
+        let field_indices = record_schema
+            .map(|record_schema| {
+                Self::match_fields(
+                    record_schema,
+                    &builder.ctx.schema,
+                    builder.ident_case_sensitive,
+                )
+            })
+            .unwrap_or_default();
+
+        for (i, value) in reader.enumerate() {
+            let value = value.map_err(|e| avro_error(e, path))?;
+            let res = if field_decoder.is_select {
+                field_decoder
+                    .read_field(&mut columns[0], &value, &writer_schema, names)
+                    .map_err(|e| FileParseError::ColumnDecodeError {
+                        column_index: 0,
+                        column_name: "$1".to_owned(),
+                        column_type: "VARIANT".to_owned(),
+                        decode_error: e.message(),
+                        column_data: truncate_column_data(format!("{value:?}")),
+                    })
+            } else {
+                match &value {
+                    Value::Record(record) => Self::read_row(
+                        field_decoder,
+                        record,
+                        record_schema.unwrap(),
+                        names,
+                        &field_indices,
+                        columns,
+                        &builder.ctx.schema,
+                        &builder.ctx.default_values,
+                        params,
+                    ),
+                    _ => unreachable!("the top level value must be record"),
+                }
+            };
+            if let Err(e) = res {
+                builder.ctx.on_error(
+                    e,
+                    Some((columns, builder.num_rows)),
+                    &mut builder.file_status,
+                    path,
+                    batch.start_row_in_split + i,
+                )?;
+            } else {
+                builder.num_rows += 1;
+                builder.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn avro_error(e: apache_avro::Error, path: &str) -> ErrorCode {
+    ErrorCode::BadBytes(format!("fail to read avro file {path}: {e}"))
+}
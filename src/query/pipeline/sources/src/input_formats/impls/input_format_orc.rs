@@ -0,0 +1,546 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::sync::Arc;
+
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::orc::read::deserialize;
+use common_arrow::arrow::io::orc::read::infer_schema;
+use common_arrow::arrow::io::orc::read::read_metadata;
+use common_arrow::arrow::io::orc::read::read_stripe_column;
+use common_arrow::arrow::io::orc::read::read_stripe_footer;
+use common_arrow::arrow::io::orc::read::top_level_column_ids;
+use common_arrow::arrow::io::orc::read::FileMetadata;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::BlockMetaInfo;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use log::debug;
+use opendal::Operator;
+use serde::Deserializer;
+use serde::Serializer;
+use storages_common_index::RangeIndex;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+/// An ORC file loaded in memory.
+///
+/// It is shared by the stage input format and the hive table source, both of which
+/// read the file stripe by stripe and may skip stripes by their column statistics.
+pub struct OrcFileReader {
+    path: String,
+    data: Vec<u8>,
+    metadata: FileMetadata,
+    schema: ArrowSchema,
+    // ORC column id of each field in `schema`
+    column_ids: Vec<u32>,
+}
+
+impl OrcFileReader {
+    pub fn try_create(path: &str, data: Vec<u8>) -> Result<Self> {
+        let metadata = read_metadata(&mut Cursor::new(&data))
+            .map_err(|e| ErrorCode::from(e).add_message(format!(" orc file {path}")))?;
+        let schema = infer_schema(&metadata.footer)?;
+        let column_ids = top_level_column_ids(&metadata.footer);
+        Ok(Self {
+            path: path.to_string(),
+            data,
+            metadata,
+            schema,
+            column_ids,
+        })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read(op: &Operator, path: &str) -> Result<Self> {
+        let data = op.read(path).await?;
+        Self::try_create(path, data)
+    }
+
+    /// Reads the schema of the file from its tail, the stripes are not read.
+    #[async_backtrace::framed]
+    pub async fn read_schema(op: &Operator, path: &str) -> Result<TableSchema> {
+        let size = op.stat(path).await?.content_length();
+        let offset = size.saturating_sub(ORC_TAIL_SIZE);
+        let data = op.read_with(path).range(offset..size).await?;
+        let mut tail = FileTail {
+            offset,
+            cursor: Cursor::new(data),
+        };
+        let metadata = read_metadata(&mut tail)
+            .map_err(|e| ErrorCode::from(e).add_message(format!(" orc file {path}")))?;
+        let schema = infer_schema(&metadata.footer)?;
+        Ok(TableSchema::from(&schema))
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn arrow_schema(&self) -> &ArrowSchema {
+        &self.schema
+    }
+
+    pub fn table_schema(&self) -> TableSchema {
+        TableSchema::from(&self.schema)
+    }
+
+    /// Index of the field in the file schema, field names are case-insensitive.
+    pub fn field_index(&self, name: &str) -> Option<usize> {
+        self.schema
+            .fields
+            .iter()
+            .rposition(|f| f.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn num_stripes(&self) -> usize {
+        self.metadata.footer.stripes.len()
+    }
+
+    pub fn stripe_num_rows(&self, stripe: usize) -> usize {
+        self.metadata.footer.stripes[stripe]
+            .number_of_rows
+            .unwrap_or_default() as usize
+    }
+
+    pub fn stripe_byte_range(&self, stripe: usize) -> Range<u64> {
+        let info = &self.metadata.footer.stripes[stripe];
+        let start = info.offset.unwrap_or_default();
+        let len = info.index_length.unwrap_or_default()
+            + info.data_length.unwrap_or_default()
+            + info.footer_length.unwrap_or_default();
+        start..start + len
+    }
+
+    /// The (min, max, null_count) of a field in the stripe, as scalars of `data_type`.
+    ///
+    /// Returns `None` if the writer did not record the statistics, or they can not
+    /// be represented as `data_type`. The stripe must not be pruned in that case.
+    pub fn stripe_statistics(
+        &self,
+        stripe: usize,
+        field: usize,
+        data_type: &TableDataType,
+    ) -> Option<(Scalar, Scalar, u64)> {
+        let column_id = *self.column_ids.get(field)? as usize;
+        let stats = self
+            .metadata
+            .metadata
+            .stripe_stats
+            .get(stripe)?
+            .col_stats
+            .get(column_id)?;
+        let num_values = stats.number_of_values?;
+        let null_count = (self.stripe_num_rows(stripe) as u64).saturating_sub(num_values);
+
+        let (min, max) = match data_type.remove_nullable() {
+            TableDataType::Number(ty) if ty.is_integer() => {
+                let s = stats.int_statistics.as_ref()?;
+                (
+                    int_to_scalar(s.minimum?, ty)?,
+                    int_to_scalar(s.maximum?, ty)?,
+                )
+            }
+            TableDataType::Number(NumberDataType::Float32) => {
+                let s = stats.double_statistics.as_ref()?;
+                (
+                    Scalar::Number(NumberScalar::Float32(F32::from(s.minimum? as f32))),
+                    Scalar::Number(NumberScalar::Float32(F32::from(s.maximum? as f32))),
+                )
+            }
+            TableDataType::Number(NumberDataType::Float64) => {
+                let s = stats.double_statistics.as_ref()?;
+                (
+                    Scalar::Number(NumberScalar::Float64(F64::from(s.minimum?))),
+                    Scalar::Number(NumberScalar::Float64(F64::from(s.maximum?))),
+                )
+            }
+            TableDataType::String => {
+                // `minimum` and `maximum` are absent if the values had to be truncated.
+                let s = stats.string_statistics.as_ref()?;
+                (
+                    Scalar::String(s.minimum.clone()?.into_bytes()),
+                    Scalar::String(s.maximum.clone()?.into_bytes()),
+                )
+            }
+            TableDataType::Date => {
+                let s = stats.date_statistics.as_ref()?;
+                (Scalar::Date(s.minimum?), Scalar::Date(s.maximum?))
+            }
+            _ => return None,
+        };
+        Some((min, max, null_count))
+    }
+
+    /// Returns true if the stripe can be skipped by `filter`.
+    ///
+    /// The statistics of `fields` are keyed by their column ids in `schema`, the schema
+    /// `filter` is created with. `statistics` holds the statistics of the columns not
+    /// stored in the file, e.g. the partition columns of hive.
+    pub fn prune_stripe(
+        &self,
+        stripe: usize,
+        filter: &RangeIndex,
+        schema: &TableSchema,
+        fields: &[TableField],
+        mut statistics: StatisticsOfColumns,
+    ) -> bool {
+        for f in fields {
+            let Some(field) = self.field_index(f.name()) else {
+                continue;
+            };
+            // the column without statistics has the full domain.
+            let Some((min, max, null_count)) = self.stripe_statistics(stripe, field, f.data_type())
+            else {
+                continue;
+            };
+            if let [column_id] = schema.leaf_columns_of(f.name())[..] {
+                let col_stats = ColumnStatistics::new(min, max, null_count, 0, None);
+                statistics.insert(column_id, col_stats);
+            }
+        }
+        matches!(filter.apply(&statistics, |_| false), Ok(false))
+    }
+
+    /// Reads the given fields of a stripe, `schema` is the schema of the output block.
+    pub fn read_stripe(
+        &self,
+        stripe: usize,
+        fields: &[usize],
+        schema: &DataSchema,
+    ) -> Result<DataBlock> {
+        let num_rows = self.stripe_num_rows(stripe);
+        if fields.is_empty() {
+            return Ok(DataBlock::new(vec![], num_rows));
+        }
+
+        let mut reader = Cursor::new(&self.data);
+        let mut scratch = vec![];
+        let footer = read_stripe_footer(&mut reader, &self.metadata, stripe, &mut scratch)?;
+
+        let mut arrays = Vec::with_capacity(fields.len());
+        for field in fields {
+            let column = read_stripe_column(
+                &mut reader,
+                &self.metadata,
+                stripe,
+                footer.clone(),
+                self.column_ids[*field],
+                vec![],
+            )?;
+            let data_type = self.schema.fields[*field].data_type.clone();
+            arrays.push(deserialize(data_type, &column).map_err(|e| {
+                ErrorCode::from(e).add_message(format!(
+                    " column {} of orc file {}",
+                    self.schema.fields[*field].name, self.path
+                ))
+            })?);
+        }
+
+        let chunk = ArrowChunk::try_new(arrays)?;
+        DataBlock::from_arrow_chunk(&chunk, schema)
+    }
+}
+
+/// The size of the tail read for the metadata, `read_metadata` never reads further
+/// than the last 16 KiB of the file.
+const ORC_TAIL_SIZE: u64 = 16 * 1024;
+
+/// The tail of a file starting at `offset`, positioned as in the whole file.
+struct FileTail {
+    offset: u64,
+    cursor: Cursor<Vec<u8>>,
+}
+
+impl Read for FileTail {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.cursor.read(buf)
+    }
+}
+
+impl Seek for FileTail {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => {
+                SeekFrom::Start(pos.checked_sub(self.offset).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "seek before the tail of the orc file",
+                    )
+                })?)
+            }
+            pos => pos,
+        };
+        Ok(self.cursor.seek(pos)? + self.offset)
+    }
+}
+
+fn int_to_scalar(v: i64, ty: NumberDataType) -> Option<Scalar> {
+    let scalar = match ty {
+        NumberDataType::Int8 => NumberScalar::Int8(v.try_into().ok()?),
+        NumberDataType::Int16 => NumberScalar::Int16(v.try_into().ok()?),
+        NumberDataType::Int32 => NumberScalar::Int32(v.try_into().ok()?),
+        NumberDataType::Int64 => NumberScalar::Int64(v),
+        NumberDataType::UInt8 => NumberScalar::UInt8(v.try_into().ok()?),
+        NumberDataType::UInt16 => NumberScalar::UInt16(v.try_into().ok()?),
+        NumberDataType::UInt32 => NumberScalar::UInt32(v.try_into().ok()?),
+        NumberDataType::UInt64 => NumberScalar::UInt64(v.try_into().ok()?),
+        _ => return None,
+    };
+    Some(Scalar::Number(scalar))
+}
+
+pub struct InputFormatOrc;
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatOrc {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        // the footer is at the end of the file, so an ORC file is never split.
+        let mut infos = vec![];
+        for info in file_infos {
+            let size = info.size as usize;
+            let file = Arc::new(FileInfo {
+                path: info.path.clone(),
+                size,
+                num_splits: 1,
+                compress_alg: None,
+            });
+            infos.push(Arc::new(SplitInfo {
+                file,
+                seq_in_file: 0,
+                offset: 0,
+                size,
+                num_file_splits: 1,
+                format_info: None,
+            }));
+        }
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct OrcFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for OrcFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = StripeInMemory;
+    type AligningState = OrcAligningState;
+    type BlockBuilder = OrcBlockBuilder;
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<OrcAligningState> {
+        Ok(OrcAligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<OrcBlockBuilder> {
+        Ok(OrcBlockBuilder {
+            schema: Arc::new(ctx.data_schema()),
+        })
+    }
+}
+
+pub struct StripeInMemory {
+    pub reader: Arc<OrcFileReader>,
+    pub stripe: usize,
+    // for each field of the input schema, its index in the file schema
+    pub fields: Arc<Vec<usize>>,
+}
+
+impl Debug for StripeInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StripeInMemory({}, {})", self.reader.path, self.stripe)
+    }
+}
+
+impl serde::Serialize for StripeInMemory {
+    fn serialize<S>(&self, _serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where S: Serializer {
+        unimplemented!()
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for StripeInMemory {
+    fn deserialize<D: Deserializer<'a>>(_deserializer: D) -> std::result::Result<Self, D::Error> {
+        unimplemented!()
+    }
+}
+
+impl RowBatchTrait for StripeInMemory {
+    fn size(&self) -> usize {
+        let range = self.reader.stripe_byte_range(self.stripe);
+        (range.end - range.start) as usize
+    }
+
+    fn rows(&self) -> usize {
+        self.reader.stripe_num_rows(self.stripe)
+    }
+}
+
+#[typetag::serde(name = "row_batch_orc")]
+impl BlockMetaInfo for StripeInMemory {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("StripeInMemory as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("StripeInMemory as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+pub struct OrcBlockBuilder {
+    schema: DataSchemaRef,
+}
+
+impl BlockBuilderTrait for OrcBlockBuilder {
+    type Pipe = OrcFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<StripeInMemory>) -> Result<Vec<DataBlock>> {
+        if let Some(b) = batch {
+            let block = b.reader.read_stripe(b.stripe, &b.fields, &self.schema)?;
+            Ok(vec![block])
+        } else {
+            Ok(vec![])
+        }
+    }
+}
+
+pub struct OrcAligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for OrcAligningState {
+    type Pipe = OrcFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<StripeInMemory>> {
+        if let Some(b) = read_batch {
+            self.buffers.push(b);
+            return Ok(vec![]);
+        }
+
+        let path = &self.split_info.file.path;
+        let reader = OrcFileReader::try_create(path, self.buffers.concat())?;
+        self.buffers.clear();
+
+        let mut fields = Vec::with_capacity(self.ctx.schema.num_fields());
+        for f in self.ctx.schema.fields() {
+            match reader.field_index(f.name()) {
+                Some(i) => fields.push(i),
+                None => {
+                    return Err(ErrorCode::TableSchemaMismatch(format!(
+                        "orc file {} has no column {}",
+                        path,
+                        f.name()
+                    )));
+                }
+            }
+        }
+        debug!(
+            "align orc file {} of {} bytes to {} stripes",
+            path,
+            reader.size(),
+            reader.num_stripes()
+        );
+
+        let stripes: Vec<usize> = match &self.ctx.range_filter {
+            Some(filter) => (0..reader.num_stripes())
+                .filter(|stripe| {
+                    !reader.prune_stripe(
+                        *stripe,
+                        filter,
+                        &self.ctx.schema,
+                        self.ctx.schema.fields(),
+                        StatisticsOfColumns::new(),
+                    )
+                })
+                .collect(),
+            None => (0..reader.num_stripes()).collect(),
+        };
+        debug!(
+            "pruned {} of {} stripes of orc file {}",
+            reader.num_stripes() - stripes.len(),
+            reader.num_stripes(),
+            path
+        );
+
+        let reader = Arc::new(reader);
+        let fields = Arc::new(fields);
+        Ok(stripes
+            .into_iter()
+            .map(|stripe| StripeInMemory {
+                reader: reader.clone(),
+                stripe,
+                fields: fields.clone(),
+            })
+            .collect())
+    }
+}
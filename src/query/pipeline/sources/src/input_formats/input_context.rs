@@ -44,9 +44,13 @@ use common_storage::FileParseError;
 use common_storage::FileStatus;
 use dashmap::DashMap;
 use opendal::Operator;
+use storages_common_index::RangeIndex;
 
+use crate::input_formats::impls::InputFormatArrow;
+use crate::input_formats::impls::InputFormatAvro;
 use crate::input_formats::impls::InputFormatCSV;
 use crate::input_formats::impls::InputFormatNDJson;
+use crate::input_formats::impls::InputFormatOrc;
 use crate::input_formats::impls::InputFormatParquet;
 use crate::input_formats::impls::InputFormatTSV;
 use crate::input_formats::impls::InputFormatXML;
@@ -134,6 +138,8 @@ pub struct InputContext {
     pub on_error_count: AtomicU64,
     pub on_error_map: Option<Arc<DashMap<String, HashMap<u16, InputError>>>>,
     pub projection: Option<Vec<usize>>,
+    // filter of the pushed down predicates, to skip the parts of the files by their statistics
+    pub range_filter: Option<RangeIndex>,
 }
 
 impl InputContext {}
@@ -157,6 +163,9 @@ impl InputContext {
             FileFormatParams::NdJson(_) => Ok(Arc::new(InputFormatNDJson::create())),
             FileFormatParams::Parquet(_) => Ok(Arc::new(InputFormatParquet {})),
             FileFormatParams::Xml(_) => Ok(Arc::new(InputFormatXML::create())),
+            FileFormatParams::Avro(_) => Ok(Arc::new(InputFormatAvro::create())),
+            FileFormatParams::Orc(_) => Ok(Arc::new(InputFormatOrc {})),
+            FileFormatParams::Arrow(_) => Ok(Arc::new(InputFormatArrow {})),
             format => Err(ErrorCode::Internal(format!(
                 "Unsupported file format: {:?}",
                 format
@@ -208,6 +217,7 @@ impl InputContext {
             on_error_map: Some(on_error_map),
             projection,
             default_values,
+            range_filter: None,
         })
     }
 
@@ -265,6 +275,7 @@ impl InputContext {
             on_error_map: None,
             projection: None,
             default_values: None,
+            range_filter: None,
         })
     }
 
@@ -308,6 +319,7 @@ impl InputContext {
             on_error_map: None,
             projection: None,
             default_values: None,
+            range_filter: None,
         })
     }
 
@@ -66,6 +66,7 @@ pub enum OperatorType {
     Limit,
     TableScan,
     CteScan,
+    RecursiveCteScan,
     Sort,
     UnionAll,
     Project,
@@ -99,6 +100,7 @@ impl Display for OperatorType {
             OperatorType::RuntimeFilter => write!(f, "RuntimeFilter"),
             OperatorType::Insert => write!(f, "Insert"),
             OperatorType::CteScan => write!(f, "CteScan"),
+            OperatorType::RecursiveCteScan => write!(f, "RecursiveCteScan"),
             OperatorType::ConstantTableScan => write!(f, "ConstantTableScan"),
             OperatorType::Udf => write!(f, "Udf"),
         }
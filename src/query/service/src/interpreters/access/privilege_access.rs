@@ -15,6 +15,7 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_catalog::catalog::CATALOG_DEFAULT;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -122,6 +123,36 @@ impl PrivilegeAccess {
         session.validate_privilege(object, privileges).await
     }
 
+    fn is_temp_table(&self, catalog_name: &str, db_name: &str, table_name: &str) -> bool {
+        catalog_name == CATALOG_DEFAULT
+            && self
+                .ctx
+                .get_current_session()
+                .temp_tbl_mgr()
+                .lock()
+                .get_table(db_name, table_name)
+                .is_some()
+    }
+
+    // The session has all the privileges on its own temporary tables.
+    // Only the plans whose interpreters resolve the table by `QueryContext::get_table`,
+    // which prefers the temporary table, may use this. The others are rejected on the
+    // temporary table by `InterpreterFactory::check_temp_table`, and they must go through
+    // `validate_access` for the normal table with the same name.
+    async fn validate_table_access(
+        &self,
+        object: &GrantObject,
+        privileges: Vec<UserPrivilegeType>,
+        verify_ownership: bool,
+    ) -> Result<()> {
+        if let GrantObject::Table(catalog_name, db_name, table_name) = object {
+            if self.is_temp_table(catalog_name, db_name, table_name) {
+                return Ok(());
+            }
+        }
+        self.validate_access(object, privileges, verify_ownership).await
+    }
+
     async fn check_udf_priv(&self, udf_names: HashSet<&String>) -> Result<()> {
         for udf in udf_names {
             self.validate_access(
@@ -215,7 +246,7 @@ impl AccessChecker for PrivilegeAccess {
                     if table.is_source_of_view() {
                         continue;
                     }
-                    self.validate_access(
+                    self.validate_table_access(
                         &GrantObject::Table(
                             table.catalog().to_string(),
                             table.database().to_string(),
@@ -280,6 +311,30 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::CreateInvertedIndex(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    false,
+                )
+                    .await?;
+            }
+            Plan::DropInvertedIndex(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    false,
+                )
+                    .await?;
+            }
             Plan::AlterVirtualColumn(plan) => {
                 self.validate_access(
                     &GrantObject::Table(
@@ -331,7 +386,7 @@ impl AccessChecker for PrivilegeAccess {
                     .await?
             }
             Plan::DescribeTable(plan) => {
-                self.validate_access(
+                self.validate_table_access(
                     &GrantObject::Table(
                         plan.catalog.clone(),
                         plan.database.clone(),
@@ -350,8 +405,24 @@ impl AccessChecker for PrivilegeAccess {
                     true,
                 )
                     .await?;
+                if let Some(clone_source) = &plan.clone_source {
+                    self.validate_access(
+                        &GrantObject::Table(
+                            clone_source.catalog.clone(),
+                            clone_source.database.clone(),
+                            clone_source.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select],
+                        true,
+                    )
+                        .await?;
+                }
             }
             Plan::DropTable(plan) => {
+                // Dropping the temporary table leaves the normal table with the same name alone.
+                if self.is_temp_table(&plan.catalog, &plan.database, &plan.table) {
+                    return Ok(());
+                }
                 self.validate_access(
                     &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
                     vec![UserPrivilegeType::Drop],
@@ -494,7 +565,7 @@ impl AccessChecker for PrivilegeAccess {
                     .await?;
             }
             Plan::TruncateTable(plan) => {
-                self.validate_access(
+                self.validate_table_access(
                     &GrantObject::Table(
                         plan.catalog.clone(),
                         plan.database.clone(),
@@ -538,7 +609,7 @@ impl AccessChecker for PrivilegeAccess {
                     .await?;
             }
             Plan::AnalyzeTable(plan) => {
-                self.validate_access(
+                self.validate_table_access(
                     &GrantObject::Table(
                         plan.catalog.clone(),
                         plan.database.clone(),
@@ -552,7 +623,7 @@ impl AccessChecker for PrivilegeAccess {
             // Others.
             Plan::Insert(plan) => {
                 //TODO(TCeason): source need to check privileges.
-                self.validate_access(
+                self.validate_table_access(
                     &GrantObject::Table(
                         plan.catalog.clone(),
                         plan.database.clone(),
@@ -565,7 +636,7 @@ impl AccessChecker for PrivilegeAccess {
             }
             Plan::Replace(plan) => {
                 //TODO(TCeason): source and delete_when need to check privileges.
-                self.validate_access(
+                self.validate_table_access(
                     &GrantObject::Table(
                         plan.catalog.clone(),
                         plan.database.clone(),
@@ -618,7 +689,7 @@ impl AccessChecker for PrivilegeAccess {
                         self.check_udf_priv(udf).await?;
                     }
                 }
-                self.validate_access(
+                self.validate_table_access(
                     &GrantObject::Table(
                         plan.catalog.clone(),
                         plan.database.clone(),
@@ -809,7 +880,7 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
                 self
-                    .validate_access(
+                    .validate_table_access(
                         &GrantObject::Table(
                             plan.catalog_info.catalog_name().to_string(),
                             plan.database_name.to_string(),
@@ -880,6 +951,10 @@ impl AccessChecker for PrivilegeAccess {
             Plan::SetRole(_) => {}
             Plan::SetSecondaryRoles(_) => {}
             Plan::ShowRoles(_) => {}
+            // Transaction statements only change the state of the session.
+            Plan::Begin => {}
+            Plan::Commit => {}
+            Plan::Abort => {}
             Plan::Presign(plan) => {
                 let stage_name = &plan.stage.stage_name;
                 let action = &plan.action;
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_catalog::plan::OPT_KEY_CHANGE_TYPE;
 use common_exception::Result;
 use common_license::license::Feature;
 use common_license::license_manager::get_license_manager;
@@ -45,14 +47,20 @@ pub async fn build_update_stream_meta_seq(
         .check_enterprise_enabled(ctx.get_license_key(), Feature::Stream)?;
 
     let mut reqs = Vec::with_capacity(tables.len());
+    let mut stream_ids = HashSet::with_capacity(tables.len());
     for table in tables.into_iter() {
         let stream = StreamTable::try_from_table(table.as_ref())?;
         let stream_info = stream.get_table_info();
+        // The changes of a standard stream read the stream more than once.
+        if !stream_ids.insert(stream_info.ident.table_id) {
+            continue;
+        }
         let source_table = stream.source_table(ctx.clone()).await?;
         let inner_fuse = FuseTable::try_from_table(source_table.as_ref())?;
 
         let table_version = inner_fuse.get_table_info().ident.seq;
         let mut options = stream.options().clone();
+        options.remove(OPT_KEY_CHANGE_TYPE);
         options.insert(OPT_KEY_TABLE_VER.to_string(), table_version.to_string());
         if let Some(snapshot_loc) = inner_fuse.snapshot_loc().await? {
             options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
@@ -140,6 +140,10 @@ fn log_query_start(ctx: &QueryContext) {
 fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
     let now = SystemTime::now();
     let session = ctx.get_current_session();
+    // A failed statement aborts the explicit transaction.
+    if error.is_some() {
+        session.txn_mgr().lock().set_fail();
+    }
 
     session.get_status().write().query_finish();
     if session.get_type().is_user_session() {
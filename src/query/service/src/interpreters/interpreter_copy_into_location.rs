@@ -93,6 +93,7 @@ impl CopyIntoLocationInterpreter {
         stage: &StageInfo,
         path: &str,
         query: &Plan,
+        partition_by: &[String],
     ) -> Result<PipelineBuildResult> {
         let (select_interpreter, data_schema) = self.build_query(query).await?;
         let plan = select_interpreter.build_physical_plan().await?;
@@ -108,6 +109,7 @@ impl CopyIntoLocationInterpreter {
             },
             files_to_copy: None,
             is_select: false,
+            partition_by: partition_by.to_vec(),
             default_values: None,
         };
         let to_table = StageTable::try_create(stage_table_info)?;
@@ -143,6 +145,7 @@ impl Interpreter for CopyIntoLocationInterpreter {
             &self.plan.stage,
             &self.plan.path,
             &self.plan.from,
+            &self.plan.partition_by,
         )
         .await
     }
@@ -15,6 +15,9 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use log::error;
 
@@ -68,15 +71,98 @@ pub struct InterpreterFactory;
 impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
+        Self::check_temp_table(&ctx, plan)?;
         // Check the access permission.
         let access_checker = Accessor::create(ctx.clone());
         access_checker.check(plan).await.map_err(|e| {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
+        Self::check_txn_state(&ctx, plan)?;
         Self::get_inner(ctx, plan)
     }
 
+    /// Check if the plan can be executed in the current state of the explicit transaction.
+    fn check_txn_state(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        let txn_mgr = ctx.txn_mgr();
+        let txn_mgr = txn_mgr.lock();
+        if txn_mgr.is_fail() && !matches!(plan, Plan::Commit | Plan::Abort) {
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "current transaction is aborted, commands ignored until end of transaction block",
+            ));
+        }
+        if txn_mgr.is_active() && !plan.allowed_in_txn() {
+            return Err(ErrorCode::StatementNotAllowedInTransaction(
+                "only queries, DML and COPY statements are allowed in an explicit transaction",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Reject the statements on a temporary table that the table doesn't support.
+    ///
+    /// The interpreters of these plans resolve the table from the catalog by name, so they
+    /// would work on the normal table with the same name, which is shadowed by the temporary table.
+    fn check_temp_table(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        let (statement, catalog, database, table) = match plan {
+            Plan::RenameTable(plan) => ("RENAME TABLE", &plan.catalog, &plan.database, &plan.table),
+            Plan::RenameTableColumn(plan) => {
+                ("ALTER TABLE", &plan.catalog, &plan.database, &plan.table)
+            }
+            Plan::AddTableColumn(plan) => {
+                ("ALTER TABLE", &plan.catalog, &plan.database, &plan.table)
+            }
+            Plan::DropTableColumn(plan) => {
+                ("ALTER TABLE", &plan.catalog, &plan.database, &plan.table)
+            }
+            Plan::ModifyTableColumn(plan) => {
+                ("ALTER TABLE", &plan.catalog, &plan.database, &plan.table)
+            }
+            Plan::AlterTableClusterKey(plan) => {
+                ("ALTER TABLE", &plan.catalog, &plan.database, &plan.table)
+            }
+            Plan::DropTableClusterKey(plan) => {
+                ("ALTER TABLE", &plan.catalog, &plan.database, &plan.table)
+            }
+            Plan::SetOptions(plan) => ("ALTER TABLE", &plan.catalog, &plan.database, &plan.table),
+            Plan::ReclusterTable(plan) => {
+                ("ALTER TABLE", &plan.catalog, &plan.database, &plan.table)
+            }
+            Plan::RevertTable(plan) => ("ALTER TABLE", &plan.catalog, &plan.database, &plan.table),
+            Plan::OptimizeTable(plan) => {
+                ("OPTIMIZE TABLE", &plan.catalog, &plan.database, &plan.table)
+            }
+            Plan::ShowCreateTable(plan) => (
+                "SHOW CREATE TABLE",
+                &plan.catalog,
+                &plan.database,
+                &plan.table,
+            ),
+            Plan::Delete(plan) => (
+                "DELETE",
+                &plan.catalog_name,
+                &plan.database_name,
+                &plan.table_name,
+            ),
+            Plan::Update(plan) => ("UPDATE", &plan.catalog, &plan.database, &plan.table),
+            _ => return Ok(()),
+        };
+
+        if catalog == CATALOG_DEFAULT
+            && ctx
+                .get_current_session()
+                .temp_tbl_mgr()
+                .lock()
+                .get_table(database, table)
+                .is_some()
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "{statement} is not supported on temporary table '{database}'.'{table}'"
+            )));
+        }
+        Ok(())
+    }
+
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
         match plan {
             Plan::Query {
@@ -262,6 +348,12 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateInvertedIndex(index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::DropInvertedIndex(index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
                 CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
@@ -501,6 +593,10 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::ShowConnections(_) => Ok(Arc::new(ShowConnectionsInterpreter::try_create(ctx)?)),
+
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
         }
     }
 }
@@ -136,19 +136,13 @@ impl MergeIntoInterpreter {
             unmatched_evaluators,
             target_table_idx,
             field_index_map,
+            block_name_index,
             ..
         } = &self.plan;
 
         // check mutability
         let check_table = self.ctx.get_table(catalog, database, table_name).await?;
         check_table.check_mutable()?;
-        // check change tracking
-        if check_table.change_tracking_enabled() {
-            return Err(ErrorCode::Unimplemented(format!(
-                "change tracking is enabled for table '{}', does not support MERGE INTO",
-                check_table.name(),
-            )));
-        }
 
         let update_stream_meta = build_update_stream_meta_seq(self.ctx.clone(), meta_data).await?;
 
@@ -205,6 +199,14 @@ impl MergeIntoInterpreter {
             ));
         }
 
+        // find block_name column index, it's only used to generate the stream columns.
+        let block_name_idx = match block_name_index {
+            Some(block_name_index) => {
+                Some(join_output_schema.index_of(&block_name_index.to_string())?)
+            }
+            None => None,
+        };
+
         if exchange.is_some() && row_number_idx.is_none() {
             return Err(ErrorCode::InvalidRowIdIndex(
                 "can't get internal row_number_idx when running merge into",
@@ -363,6 +365,7 @@ impl MergeIntoInterpreter {
                 matched,
                 field_index_of_input_schema,
                 row_id_idx,
+                block_name_idx,
                 segments,
                 distributed: false,
                 output_schema: DataSchemaRef::default(),
@@ -376,6 +379,7 @@ impl MergeIntoInterpreter {
                 matched,
                 field_index_of_input_schema,
                 row_id_idx,
+                block_name_idx,
                 segments,
                 distributed: true,
                 output_schema: DataSchemaRef::new(DataSchema::new(vec![
@@ -309,6 +309,7 @@ impl MergeIntoInterpreter {
                 *s_expr = s_expr.replace_children(vec![Arc::new(new_child)]);
             }
             RelOperator::CteScan(_) => {}
+            RelOperator::RecursiveCteScan(_) => {}
             RelOperator::Join(_) => {}
             RelOperator::Exchange(_) => {}
             RelOperator::UnionAll(_) => {}
@@ -42,6 +42,7 @@ use common_sql::plans::PREDICATE_COLUMN_NAME;
 use common_sql::BloomIndexColumns;
 use common_storage::DataOperator;
 use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::FuseTable;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
@@ -57,9 +58,12 @@ use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use storages_common_table_meta::table::OPT_KEY_CLONE_BASE_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_DELETION_VECTOR;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -146,6 +150,10 @@ impl Interpreter for CreateTableInterpreter {
             }
         }
 
+        if self.plan.temporary {
+            return self.create_temporary_table().await;
+        }
+
         match &self.plan.as_select {
             Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
             None => self.create_table().await,
@@ -256,7 +264,47 @@ impl CreateTableInterpreter {
             req.table_meta.owner = Some(Ownership::new(current_role.name));
         }
 
-        let reply = catalog.create_table(req.clone()).await?;
+        // keep the files shared with the clone from being purged by the source table,
+        // the references are written before the clone exists and removed if it is not created.
+        let mut clone_references = None;
+        if let Some(clone_source) = &self.plan.clone_source {
+            if let Some(snapshot_loc) = self.plan.options.get(OPT_KEY_SNAPSHOT_LOCATION) {
+                let source = self
+                    .ctx
+                    .get_table(
+                        &clone_source.catalog,
+                        &clone_source.database,
+                        &clone_source.table,
+                    )
+                    .await?;
+                let table_ctx: Arc<dyn TableContext> = self.ctx.clone();
+                clone_references = Some(
+                    FuseTable::try_from_table(source.as_ref())?
+                        .add_clone_references(&table_ctx, snapshot_loc)
+                        .await?,
+                );
+            }
+        }
+
+        let reply = match catalog.create_table(req.clone()).await {
+            Ok(reply) => reply,
+            Err(e) => {
+                if let Some(clone_references) = clone_references {
+                    if let Err(cause) = clone_references.rollback().await {
+                        error!("remove the references of the clone failed: {:?}", cause);
+                    }
+                }
+                return Err(e);
+            }
+        };
+
+        if let Some(clone_references) = clone_references {
+            if reply.new_table {
+                clone_references.commit(reply.table_id).await?;
+            } else {
+                clone_references.rollback().await?;
+            }
+        }
 
         // grant the ownership of the table to the current role, the above req.table_meta.owner could be removed in future.
         if let Some(current_role) = self.ctx.get_current_role() {
@@ -282,6 +330,45 @@ impl CreateTableInterpreter {
         Ok(PipelineBuildResult::create())
     }
 
+    /// Temporary table is registered in the current session rather than the meta service,
+    /// it is dropped when the session is closed.
+    #[async_backtrace::framed]
+    async fn create_temporary_table(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        // check the database exists.
+        catalog
+            .get_database(tenant.as_str(), &self.plan.database)
+            .await?;
+
+        let req = self.build_request(None)?;
+        let table_info = self
+            .ctx
+            .get_current_session()
+            .temp_tbl_mgr()
+            .lock()
+            .create_table(req)?;
+
+        match (table_info, &self.plan.as_select) {
+            (Some(table_info), Some(select_plan)) => {
+                let insert_plan = Insert {
+                    catalog: self.plan.catalog.clone(),
+                    database: self.plan.database.clone(),
+                    table: self.plan.table.clone(),
+                    table_id: table_info.ident.table_id,
+                    schema: self.plan.schema.clone(),
+                    overwrite: false,
+                    source: InsertInputSource::SelectPlan(select_plan.clone()),
+                };
+
+                InsertInterpreter::try_create(self.ctx.clone(), insert_plan)?
+                    .execute2()
+                    .await
+            }
+            _ => Ok(PipelineBuildResult::create()),
+        }
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
@@ -320,12 +407,20 @@ impl CreateTableInterpreter {
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
-        // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        // check bloom_index_columns and ngram_index_columns.
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        is_valid_ngram_index_columns(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
+        is_valid_deletion_vector(&table_meta.options)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
+            // the clone starts from the snapshot of its source table.
+            if self.plan.clone_source.is_some()
+                && (key == OPT_KEY_SNAPSHOT_LOCATION || key == OPT_KEY_CLONE_BASE_SNAPSHOT_LOCATION)
+            {
+                continue;
+            }
             if !is_valid_create_opt(&key) {
                 error!("invalid opt for fuse table in create table statement");
                 return Err(ErrorCode::TableOptionInvalid(format!(
@@ -428,11 +523,13 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_DELETION_VECTOR);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -507,9 +604,26 @@ pub fn is_valid_bloom_index_columns(
     Ok(())
 }
 
+pub fn is_valid_ngram_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, BloomIndex::supported_ngram_type)?;
+    }
+    Ok(())
+}
+
 pub fn is_valid_change_tracking(options: &BTreeMap<String, String>) -> Result<()> {
     if let Some(value) = options.get(OPT_KEY_CHANGE_TRACKING) {
         value.to_lowercase().parse::<bool>()?;
     }
     Ok(())
 }
+
+pub fn is_valid_deletion_vector(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_DELETION_VECTOR) {
+        value.to_lowercase().parse::<bool>()?;
+    }
+    Ok(())
+}
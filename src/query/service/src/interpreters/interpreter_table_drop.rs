@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_catalog::catalog::CATALOG_DEFAULT;
 use common_catalog::table::TableExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -22,6 +23,7 @@ use common_meta_app::principal::GrantObjectByID;
 use common_meta_app::schema::DropTableByIdReq;
 use common_sql::plans::DropTablePlan;
 use common_storages_fuse::FuseTable;
+use common_storages_memory::MemoryTable;
 use common_storages_share::save_share_spec;
 use common_storages_stream::stream_table::STREAM_ENGINE;
 use common_storages_view::view_table::VIEW_ENGINE;
@@ -54,6 +56,21 @@ impl Interpreter for DropTableInterpreter {
         let catalog_name = self.plan.catalog.as_str();
         let db_name = self.plan.database.as_str();
         let tbl_name = self.plan.table.as_str();
+
+        // The temporary table shadows the normal table with the same name.
+        if catalog_name == CATALOG_DEFAULT {
+            let temp_table = self
+                .ctx
+                .get_current_session()
+                .temp_tbl_mgr()
+                .lock()
+                .drop_table(db_name, tbl_name);
+            if let Some(table_info) = temp_table {
+                MemoryTable::purge_data(table_info.ident.table_id);
+                return Ok(PipelineBuildResult::create());
+            }
+        }
+
         let tbl = self
             .ctx
             .get_table(catalog_name, db_name, tbl_name)
@@ -30,6 +30,8 @@ use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_change_tracking;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_deletion_vector;
+use super::interpreter_table_create::is_valid_ngram_index_columns;
 use super::interpreter_table_create::is_valid_row_per_block;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -61,6 +63,8 @@ impl Interpreter for SetOptionsInterpreter {
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
         is_valid_change_tracking(&self.plan.set_options)?;
+        // check deletion_vector
+        is_valid_deletion_vector(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
@@ -108,8 +112,9 @@ impl Interpreter for SetOptionsInterpreter {
             )));
         };
 
-        // check bloom_index_columns.
+        // check bloom_index_columns and ngram_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        is_valid_ngram_index_columns(&self.plan.set_options, table.schema())?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
@@ -24,6 +24,7 @@ use common_expression::DataBlock;
 use common_expression::Scalar;
 use common_expression::Value;
 use common_sql::plans::ShowCreateTablePlan;
+use common_storages_stream::stream_table::StreamMode;
 use common_storages_stream::stream_table::StreamTable;
 use common_storages_stream::stream_table::STREAM_ENGINE;
 use common_storages_view::view_table::QUERY;
@@ -227,6 +228,9 @@ impl ShowCreateTableInterpreter {
             stream_table.source_table_database(),
             stream_table.source_table_name()
         );
+        if matches!(stream_table.mode(), StreamMode::Standard) {
+            create_sql.push_str(" APPEND_ONLY = false");
+        }
 
         let comment = stream_table.get_table_info().meta.comment.clone();
         if !comment.is_empty() {
@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_storages_share::save_share_table_info;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let txn_mgr = self.ctx.txn_mgr();
+        let (is_active, req) = {
+            let txn_mgr = txn_mgr.lock();
+            (txn_mgr.is_active(), txn_mgr.req())
+        };
+        // Committing a failed transaction rolls it back, and committing outside
+        // of an explicit transaction is a no-op.
+        if !is_active || req.update_table_metas.is_empty() {
+            txn_mgr.lock().clear();
+            return Ok(PipelineBuildResult::create());
+        }
+
+        info!(
+            "committing explicit transaction, mutated tables: {}",
+            req.update_table_metas.len()
+        );
+        let catalog = self.ctx.get_default_catalog()?;
+        let res = catalog.update_multi_table_meta(req).await;
+        // Whether the commit succeeds or not, the transaction is finished.
+        txn_mgr.lock().clear();
+        let res = res?;
+
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -109,6 +109,7 @@ impl Interpreter for VacuumDropTablesInterpreter {
         let handler = get_vacuum_handler();
         let files_opt = handler
             .do_vacuum_drop_tables(
+                self.ctx.clone(),
                 tables,
                 if self.plan.option.dry_run.is_some() {
                     Some(DRY_RUN_LIMIT)
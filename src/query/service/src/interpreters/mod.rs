@@ -45,6 +45,8 @@ mod interpreter_index_create;
 mod interpreter_index_drop;
 mod interpreter_index_refresh;
 mod interpreter_insert;
+mod interpreter_inverted_index_create;
+mod interpreter_inverted_index_drop;
 mod interpreter_kill;
 mod interpreter_merge_into;
 mod interpreter_merge_into_static_filter;
@@ -106,6 +108,9 @@ mod interpreter_task_describe;
 mod interpreter_task_drop;
 mod interpreter_task_execute;
 mod interpreter_tasks_show;
+mod interpreter_txn_abort;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
@@ -147,6 +152,8 @@ pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_index_refresh::RefreshIndexInterpreter;
 pub use interpreter_insert::InsertInterpreter;
+pub use interpreter_inverted_index_create::CreateInvertedIndexInterpreter;
+pub use interpreter_inverted_index_drop::DropInvertedIndexInterpreter;
 pub use interpreter_kill::KillInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_network_policies_show::ShowNetworkPoliciesInterpreter;
@@ -195,6 +202,9 @@ pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_txn_abort::AbortInterpreter;
+pub use interpreter_txn_begin::BeginInterpreter;
+pub use interpreter_txn_commit::CommitInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
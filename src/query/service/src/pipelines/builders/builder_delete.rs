@@ -26,13 +26,13 @@ use common_sql::executor::physical_plans::MutationKind;
 use common_sql::gen_mutation_stream_operator;
 use common_sql::TransformStreamKind;
 use common_storages_fuse::operations::MutationBlockPruningContext;
+use common_storages_fuse::operations::TransformAddStreamColumns;
 use common_storages_fuse::operations::TransformSerializeBlock;
 use common_storages_fuse::FuseLazyPartInfo;
 use common_storages_fuse::FuseTable;
 use common_storages_fuse::SegmentLocation;
 use log::info;
 
-use crate::pipelines::processors::TransformAddStreamColumns;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -21,9 +21,9 @@ use common_exception::Result;
 use common_expression::DataSchemaRef;
 use common_pipeline_core::Pipeline;
 use common_sql::TransformStreamKind;
+use common_storages_fuse::operations::TransformAddStreamColumns;
 
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
-use crate::pipelines::processors::transforms::TransformAddStreamColumns;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
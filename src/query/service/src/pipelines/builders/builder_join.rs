@@ -54,7 +54,7 @@ use crate::sessions::QueryContext;
 
 impl PipelineBuilder {
     pub(crate) fn build_range_join(&mut self, range_join: &RangeJoin) -> Result<()> {
-        let state = Arc::new(RangeJoinState::new(self.ctx.clone(), range_join));
+        let state = Arc::new(RangeJoinState::new(self.ctx.clone(), range_join)?);
         self.expand_right_side_pipeline(range_join, state.clone())?;
         self.build_left_side(range_join, state)?;
         if self.enable_profiling {
@@ -22,6 +22,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_expression::TableSchema;
+use common_expression::ORIGIN_VERSION_COL_NAME;
 use common_expression::ROW_NUMBER_COL_NAME;
 use common_pipeline_core::processors::InputPort;
 use common_pipeline_core::processors::OutputPort;
@@ -145,7 +147,10 @@ impl PipelineBuilder {
             .ctx
             .build_table_by_table_info(catalog_info, table_info, None)?;
         // 1.fill default columns
-        let table_default_schema = &tbl.schema().remove_computed_fields();
+        let table_default_schema = &with_origin_version(
+            tbl.as_ref(),
+            tbl.schema_with_stream().remove_computed_fields(),
+        );
         let mut builder = self.main_pipeline.add_transform_with_specified_len(
             |transform_input_port, transform_output_port| {
                 TransformResortAddOnWithoutSourceSchema::try_create(
@@ -162,7 +167,10 @@ impl PipelineBuilder {
         self.main_pipeline.add_pipe(builder.finalize());
 
         // 2.fill computed columns
-        let table_computed_schema = &tbl.schema().remove_virtual_computed_fields();
+        let table_computed_schema = &with_origin_version(
+            tbl.as_ref(),
+            tbl.schema_with_stream().remove_virtual_computed_fields(),
+        );
         let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
         let computed_schema: DataSchemaRef = Arc::new(table_computed_schema.into());
         if default_schema != computed_schema {
@@ -261,6 +269,7 @@ impl PipelineBuilder {
             matched,
             field_index_of_input_schema,
             row_id_idx,
+            block_name_idx,
             segments,
             distributed,
             ..
@@ -329,10 +338,11 @@ impl PipelineBuilder {
             let matched_split_processor = MatchedSplitProcessor::create(
                 self.ctx.clone(),
                 *row_id_idx,
+                *block_name_idx,
                 matched.clone(),
                 field_index_of_input_schema.clone(),
                 input.output_schema()?,
-                Arc::new(DataSchema::from(tbl.schema())),
+                Arc::new(DataSchema::from(tbl.schema_with_stream())),
             )?;
 
             pipe_items.push(matched_split_processor.into_pipe_item());
@@ -468,7 +478,8 @@ impl PipelineBuilder {
             self.main_pipeline.output_len() - 2
         };
         // fill default columns
-        let table_default_schema = &table.schema().remove_computed_fields();
+        let table_default_schema =
+            &with_origin_version(table, table.schema_with_stream().remove_computed_fields());
         let mut builder = self.main_pipeline.add_transform_with_specified_len(
             |transform_input_port, transform_output_port| {
                 TransformResortAddOnWithoutSourceSchema::try_create(
@@ -501,7 +512,10 @@ impl PipelineBuilder {
         };
 
         // fill computed columns
-        let table_computed_schema = &table.schema().remove_virtual_computed_fields();
+        let table_computed_schema = &with_origin_version(
+            table,
+            table.schema_with_stream().remove_virtual_computed_fields(),
+        );
         let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
         let computed_schema: DataSchemaRef = Arc::new(table_computed_schema.into());
         if default_schema != computed_schema {
@@ -656,3 +670,18 @@ impl PipelineBuilder {
         Ok(())
     }
 }
+
+// If the change tracking is enabled, the rows inserted by merge into take the table version
+// as their origin version, and the origin block id and row number are left null, the same as
+// the rows appended by insert. The updated rows carry all the stream columns already.
+fn with_origin_version(table: &dyn Table, mut schema: TableSchema) -> TableSchema {
+    if table.change_tracking_enabled() {
+        let version = table.get_table_info().ident.seq;
+        for field in schema.fields.iter_mut() {
+            if field.name() == ORIGIN_VERSION_COL_NAME {
+                *field = field.clone().with_default_expr(Some(version.to_string()));
+            }
+        }
+    }
+    schema
+}
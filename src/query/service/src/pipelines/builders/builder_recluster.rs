@@ -34,11 +34,11 @@ use common_storages_factory::Table;
 use common_storages_fuse::operations::common::CommitSink;
 use common_storages_fuse::operations::common::MutationGenerator;
 use common_storages_fuse::operations::ReclusterAggregator;
+use common_storages_fuse::operations::TransformAddStreamColumns;
 use common_storages_fuse::operations::TransformSerializeBlock;
 use common_storages_fuse::FuseTable;
 use common_storages_fuse::TableContext;
 
-use crate::pipelines::processors::TransformAddStreamColumns;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
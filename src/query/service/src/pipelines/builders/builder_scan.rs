@@ -16,6 +16,7 @@ use std::sync::Arc;
 use std::sync::Mutex;
 use std::time::Instant;
 
+use common_catalog::plan::TableSample;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -28,22 +29,47 @@ use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
 use common_sql::executor::physical_plans::ConstantTableScan;
 use common_sql::executor::physical_plans::CteScan;
+use common_sql::executor::physical_plans::RecursiveCteScan;
 use common_sql::executor::physical_plans::TableScan;
 use common_storages_fuse::operations::FillInternalColumnProcessor;
 
 use crate::pipelines::processors::transforms::MaterializedCteSource;
+use crate::pipelines::processors::transforms::MaterializedCteState;
+use crate::pipelines::processors::transforms::TransformFixedRowsSample;
+use crate::pipelines::processors::transforms::TransformRowSample;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
     pub(crate) fn build_table_scan(&mut self, scan: &TableScan) -> Result<()> {
         let table = self.ctx.build_table_from_source_plan(&scan.source)?;
         self.ctx.set_partitions(scan.source.parts.clone())?;
-        table.read_data(
-            self.ctx.clone(),
-            &scan.source,
-            &mut self.main_pipeline,
-            true,
-        )?;
+
+        // Sample the rows, the block level sample has been done while pruning.
+        let sample = scan
+            .source
+            .push_downs
+            .as_ref()
+            .and_then(|p| p.sample.clone());
+        // With a seed, the rows are sampled by their positions in the blocks, which
+        // are carried by the meta of the blocks until the internal columns are filled.
+        let sample_by_position = table.support_row_id_column()
+            && matches!(
+                sample,
+                Some(TableSample::Row { seed: Some(_), .. })
+                    | Some(TableSample::FixedRows { seed: Some(_), .. })
+            );
+        if sample_by_position && !scan.source.query_internal_columns {
+            let mut source = scan.source.clone();
+            source.query_internal_columns = true;
+            table.read_data(self.ctx.clone(), &source, &mut self.main_pipeline, true)?;
+        } else {
+            table.read_data(
+                self.ctx.clone(),
+                &scan.source,
+                &mut self.main_pipeline,
+                true,
+            )?;
+        }
 
         if self.enable_profiling {
             self.main_pipeline.add_transform(|input, output| {
@@ -70,6 +96,34 @@ impl PipelineBuilder {
             })?;
         }
 
+        let keep_meta = scan.internal_column.is_some();
+        match sample {
+            Some(TableSample::Row { probability, seed }) => {
+                self.main_pipeline.add_transform(|input, output| {
+                    Ok(TransformRowSample::create(
+                        input,
+                        output,
+                        *probability,
+                        seed,
+                        keep_meta,
+                    ))
+                })?;
+            }
+            Some(TableSample::FixedRows { rows, seed }) => {
+                self.main_pipeline.resize(1)?;
+                self.main_pipeline.add_transform(|input, output| {
+                    Ok(TransformFixedRowsSample::create(
+                        input,
+                        output,
+                        rows as usize,
+                        seed,
+                        keep_meta,
+                    ))
+                })?;
+            }
+            _ => {}
+        }
+
         // Fill internal columns if needed.
         if let Some(internal_columns) = &scan.internal_column {
             if table.support_row_id_column() {
@@ -132,6 +186,25 @@ impl PipelineBuilder {
         )
     }
 
+    pub(crate) fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        let max_threads = self.settings.get_max_threads()?;
+        // The working table is filled before each iteration of the recursive cte.
+        let state = Arc::new(MaterializedCteState::new_finished(self.ctx.clone()));
+        let offsets = (0..scan.output_schema.num_fields()).collect::<Vec<_>>();
+        self.main_pipeline.add_source(
+            |output| {
+                MaterializedCteSource::create(
+                    self.ctx.clone(),
+                    output,
+                    scan.cte_idx,
+                    state.clone(),
+                    offsets.clone(),
+                )
+            },
+            max_threads as usize,
+        )
+    }
+
     pub(crate) fn build_constant_table_scan(&mut self, scan: &ConstantTableScan) -> Result<()> {
         self.main_pipeline.add_source(
             |output| {
@@ -21,12 +21,20 @@ use common_pipeline_transforms::processors::ProcessorProfileWrapper;
 use common_sql::executor::physical_plans::UnionAll;
 use common_sql::executor::PhysicalPlan;
 
+use crate::pipelines::processors::transforms::RecursiveCteSource;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
 
 impl PipelineBuilder {
     pub fn build_union_all(&mut self, union_all: &UnionAll) -> Result<()> {
+        if union_all.cte_idx.is_some() {
+            // The recursive cte is evaluated iteratively by a single source.
+            return self.main_pipeline.add_source(
+                |output| RecursiveCteSource::create(self.ctx.clone(), output, union_all.clone()),
+                1,
+            );
+        }
         self.build_pipeline(&union_all.left)?;
         let union_all_receiver = self.expand_union_all(&union_all.right, union_all)?;
         self.main_pipeline
@@ -20,10 +20,10 @@ use common_sql::evaluator::CompoundBlockOperator;
 use common_sql::executor::physical_plans::UpdateSource;
 use common_sql::gen_mutation_stream_operator;
 use common_sql::TransformStreamKind;
+use common_storages_fuse::operations::TransformAddStreamColumns;
 use common_storages_fuse::operations::TransformSerializeBlock;
 use common_storages_fuse::FuseTable;
 
-use crate::pipelines::processors::TransformAddStreamColumns;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -22,6 +22,7 @@ use common_pipeline_core::processors::ProcessorPtr;
 use common_sql::executor::physical_plans::Window;
 
 use crate::pipelines::processors::transforms::FrameBound;
+use crate::pipelines::processors::transforms::TransformWindowPartitionTopN;
 use crate::pipelines::processors::transforms::WindowFunctionInfo;
 use crate::pipelines::processors::TransformWindow;
 use crate::pipelines::PipelineBuilder;
@@ -70,7 +71,31 @@ impl PipelineBuilder {
 
             sort_desc.extend(order_by.clone());
 
-            self.build_sort_pipeline(input_schema.clone(), sort_desc, window.plan_id, None, false)?;
+            let mut sort_limit = None;
+            if let Some(limit) = window.limit {
+                if partition_by.is_empty() {
+                    sort_limit = Some(limit);
+                } else {
+                    // Drop the rows that cannot pass the `QUALIFY` filter before sorting.
+                    self.main_pipeline.add_transform(|input, output| {
+                        Ok(TransformWindowPartitionTopN::create(
+                            input,
+                            output,
+                            partition_by.clone(),
+                            sort_desc.clone(),
+                            limit,
+                        ))
+                    })?;
+                }
+            }
+
+            self.build_sort_pipeline(
+                input_schema.clone(),
+                sort_desc,
+                window.plan_id,
+                sort_limit,
+                false,
+            )?;
         }
         // `TransformWindow` is a pipeline breaker.
         self.main_pipeline.try_resize(1)?;
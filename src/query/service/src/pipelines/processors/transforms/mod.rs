@@ -22,16 +22,17 @@ pub(crate) mod range_join;
 mod runtime_filter;
 mod transform_add_computed_columns;
 mod transform_add_const_columns;
-mod transform_add_stream_columns;
 mod transform_cast_schema;
 mod transform_create_sets;
 mod transform_limit;
 mod transform_materialized_cte;
 mod transform_merge_block;
+mod transform_recursive_cte;
 mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_table_sample;
 mod transform_udf;
 mod window;
 
@@ -43,7 +44,6 @@ pub use range_join::RangeJoinState;
 pub use runtime_filter::RuntimeFilterState;
 pub use transform_add_computed_columns::TransformAddComputedColumns;
 pub use transform_add_const_columns::TransformAddConstColumns;
-pub use transform_add_stream_columns::TransformAddStreamColumns;
 pub use transform_cast_schema::TransformCastSchema;
 pub use transform_create_sets::SubqueryReceiver;
 pub use transform_create_sets::TransformCreateSets;
@@ -52,12 +52,16 @@ pub use transform_materialized_cte::MaterializedCteSink;
 pub use transform_materialized_cte::MaterializedCteSource;
 pub use transform_materialized_cte::MaterializedCteState;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_recursive_cte::RecursiveCteSource;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
+pub use transform_table_sample::TransformFixedRowsSample;
+pub use transform_table_sample::TransformRowSample;
 pub use transform_udf::TransformUdf;
 pub use window::FrameBound;
 pub use window::TransformWindow;
+pub use window::TransformWindowPartitionTopN;
 pub use window::WindowFunctionInfo;
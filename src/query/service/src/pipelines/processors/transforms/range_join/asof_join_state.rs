@@ -0,0 +1,300 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::SortColumnDescription;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::executor::physical_plans::RangeJoin;
+use common_sql::plans::JoinType;
+use parking_lot::RwLock;
+
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+
+pub struct AsofJoinState {
+    is_left_join: bool,
+    // Used to generate the NULLs of unmatched rows if the right table is empty.
+    right_data_types: Vec<DataType>,
+    // The right table sorted by the equi keys and then the match key,
+    // the rows whose keys contain NULL are removed since they never match.
+    pub(crate) right_block: RwLock<Option<DataBlock>>,
+    // The keys of `right_block`, the first one is the match key, the others are equi keys.
+    pub(crate) right_keys: RwLock<Vec<Column>>,
+}
+
+impl AsofJoinState {
+    pub(crate) fn try_create(asof_join: &RangeJoin) -> Result<Self> {
+        let right_data_types = asof_join
+            .right
+            .output_schema()?
+            .fields()
+            .iter()
+            .map(|field| field.data_type().clone())
+            .collect();
+        Ok(Self {
+            is_left_join: asof_join.join_type == JoinType::LeftAsof,
+            right_data_types,
+            right_block: RwLock::new(None),
+            right_keys: RwLock::new(vec![]),
+        })
+    }
+}
+
+impl RangeJoinState {
+    pub(crate) fn asof_partition(&self) -> Result<()> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let right_table = self.right_table.read();
+        if !right_table.is_empty() {
+            let right_block = DataBlock::concat(&right_table)?;
+            let num_rows = right_block.num_rows();
+            let mut columns = self
+                .conditions
+                .iter()
+                .map(|condition| evaluate_key(&condition.right_expr, &right_block))
+                .collect::<Result<Vec<_>>>()?;
+            let num_keys = columns.len();
+
+            // Append the row index column to take the right table in the order of keys.
+            let mut column_builder =
+                ColumnBuilder::with_capacity(&DataType::Number(NumberDataType::UInt32), num_rows);
+            for idx in 0..num_rows {
+                column_builder.push(ScalarRef::Number(NumberScalar::UInt32(idx as u32)));
+            }
+            columns.push(column_builder.build());
+            let mut keys_block = DataBlock::new_from_columns(columns);
+
+            let mut validity = MutableBitmap::from_len_set(num_rows);
+            for row in 0..num_rows {
+                let has_null = keys_block.columns()[..num_keys]
+                    .iter()
+                    .any(|entry| unsafe { entry.value.index_unchecked(row) }.is_null());
+                if has_null {
+                    validity.set(row, false);
+                }
+            }
+            let validity: Bitmap = validity.into();
+            if validity.unset_bits() > 0 {
+                keys_block = keys_block.filter_with_bitmap(&validity)?;
+            }
+
+            if !keys_block.is_empty() {
+                // Sort by equi keys, then by match key.
+                let sort_descriptions = (1..num_keys)
+                    .chain(0..1)
+                    .map(|offset| SortColumnDescription {
+                        offset,
+                        asc: true,
+                        nulls_first: false,
+                        is_nullable: keys_block.get_by_offset(offset).data_type.is_nullable(),
+                    })
+                    .collect::<Vec<_>>();
+                let keys_block = DataBlock::sort(&keys_block, &sort_descriptions, None)?;
+                let num_rows = keys_block.num_rows();
+
+                let mut right_keys = Vec::with_capacity(num_keys);
+                for entry in keys_block.columns()[..num_keys].iter() {
+                    right_keys.push(
+                        entry
+                            .value
+                            .convert_to_full_column(&entry.data_type, num_rows),
+                    );
+                }
+                let indices = keys_block.columns()[num_keys]
+                    .value
+                    .convert_to_full_column(&DataType::Number(NumberDataType::UInt32), num_rows)
+                    .as_number()
+                    .and_then(|column| column.as_u_int32())
+                    .unwrap()
+                    .to_vec();
+                *asof_join_state.right_block.write() = Some(right_block.take(&indices, &mut None)?);
+                *asof_join_state.right_keys.write() = right_keys;
+            }
+        }
+
+        // Each task probes the whole right table with a left block.
+        let left_table = self.left_table.read();
+        let mut tasks = self.tasks.write();
+        for left_idx in 0..left_table.len() {
+            tasks.push((left_idx, 0));
+        }
+        Ok(())
+    }
+
+    pub fn asof_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let (left_idx, _) = self.tasks.read()[task_id];
+        let left_block = self.left_table.read()[left_idx].clone();
+        let num_rows = left_block.num_rows();
+        let left_keys = self
+            .conditions
+            .iter()
+            .map(|condition| evaluate_key(&condition.left_expr, &left_block))
+            .collect::<Result<Vec<_>>>()?;
+        let right_keys = asof_join_state.right_keys.read();
+        let op = self.conditions[0].operator.as_str();
+
+        let mut left_indices = Vec::with_capacity(num_rows);
+        let mut right_indices = Vec::with_capacity(num_rows);
+        let mut validity = MutableBitmap::with_capacity(num_rows);
+        for row in 0..num_rows {
+            match probe_closest_row(&left_keys, &right_keys, row, op) {
+                Some(right_row) => {
+                    left_indices.push(row as u32);
+                    right_indices.push(right_row as u32);
+                    validity.push(true);
+                }
+                None if asof_join_state.is_left_join => {
+                    left_indices.push(row as u32);
+                    right_indices.push(0);
+                    validity.push(false);
+                }
+                None => {}
+            }
+        }
+        if left_indices.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut result_block = if left_indices.len() == num_rows {
+            left_block
+        } else {
+            left_block.take(&left_indices, &mut None)?
+        };
+        let num_rows = result_block.num_rows();
+        match asof_join_state.right_block.read().as_ref() {
+            Some(right_block) => {
+                let right_result_block = right_block.take(&right_indices, &mut None)?;
+                let validity: Bitmap = validity.into();
+                for entry in right_result_block.columns() {
+                    if asof_join_state.is_left_join {
+                        let column = entry
+                            .value
+                            .convert_to_full_column(&entry.data_type, num_rows)
+                            .wrap_nullable(Some(validity.clone()));
+                        result_block.add_column(BlockEntry::new(
+                            entry.data_type.wrap_nullable(),
+                            Value::Column(column),
+                        ));
+                    } else {
+                        result_block.add_column(entry.clone());
+                    }
+                }
+            }
+            // Only left asof join reaches here, none of the rows are matched.
+            None => {
+                for data_type in asof_join_state.right_data_types.iter() {
+                    result_block.add_column(BlockEntry::new(
+                        data_type.wrap_nullable(),
+                        Value::Scalar(Scalar::Null),
+                    ));
+                }
+            }
+        }
+        Ok(vec![result_block])
+    }
+}
+
+fn evaluate_key(expr: &RemoteExpr, block: &DataBlock) -> Result<Column> {
+    let func_ctx = FunctionContext::default();
+    let evaluator = Evaluator::new(block, &func_ctx, &BUILTIN_FUNCTIONS);
+    let expr = expr.as_expr(&BUILTIN_FUNCTIONS);
+    Ok(evaluator
+        .run(&expr)?
+        .convert_to_full_column(expr.data_type(), block.num_rows()))
+}
+
+// Find the first index in `lo..hi` which doesn't satisfy `pred`,
+// `pred` must be true for a prefix of the range.
+fn partition_point(mut lo: usize, mut hi: usize, pred: impl Fn(usize) -> bool) -> usize {
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+// Find the closest right row of the `row` of left table, the right keys are sorted
+// by equi keys and then the match key.
+fn probe_closest_row(
+    left_keys: &[Column],
+    right_keys: &[Column],
+    row: usize,
+    op: &str,
+) -> Option<usize> {
+    let num_rows = right_keys.first()?.len();
+    let left_values = left_keys
+        .iter()
+        .map(|column| unsafe { column.index_unchecked(row) })
+        .collect::<Vec<_>>();
+    if left_values.iter().any(|value| value.is_null()) {
+        return None;
+    }
+
+    // The range of right rows with the same equi keys.
+    let cmp_equi_keys = |idx: usize| {
+        for (column, value) in right_keys[1..].iter().zip(left_values[1..].iter()) {
+            let order = unsafe { column.index_unchecked(idx) }.cmp(value);
+            if order != Ordering::Equal {
+                return order;
+            }
+        }
+        Ordering::Equal
+    };
+    let lo = partition_point(0, num_rows, |idx| cmp_equi_keys(idx) == Ordering::Less);
+    let hi = partition_point(lo, num_rows, |idx| cmp_equi_keys(idx) == Ordering::Equal);
+
+    let match_key = &left_values[0];
+    let cmp_match_key = |idx: usize| unsafe { right_keys[0].index_unchecked(idx) }.cmp(match_key);
+    match op {
+        // The last row less than (or equal to) the left row.
+        "gte" | "gt" => {
+            let pos = partition_point(lo, hi, |idx| match cmp_match_key(idx) {
+                Ordering::Less => true,
+                Ordering::Equal => op == "gte",
+                Ordering::Greater => false,
+            });
+            (pos > lo).then(|| pos - 1)
+        }
+        // The first row greater than (or equal to) the left row.
+        "lte" | "lt" => {
+            let pos = partition_point(lo, hi, |idx| match cmp_match_key(idx) {
+                Ordering::Less => true,
+                Ordering::Equal => op == "lt",
+                Ordering::Greater => false,
+            });
+            (pos < hi).then_some(pos)
+        }
+        _ => unreachable!(),
+    }
+}
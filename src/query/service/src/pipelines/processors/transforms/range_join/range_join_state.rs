@@ -35,6 +35,7 @@ use common_sql::executor::physical_plans::RangeJoinType;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 
+use crate::pipelines::processors::transforms::range_join::AsofJoinState;
 use crate::pipelines::processors::transforms::range_join::IEJoinState;
 use crate::sessions::QueryContext;
 
@@ -61,17 +62,24 @@ pub struct RangeJoinState {
     pub(crate) finished_tasks: AtomicU64,
     // IEJoin state
     pub(crate) ie_join_state: Option<IEJoinState>,
+    // AsofJoin state
+    pub(crate) asof_join_state: Option<AsofJoinState>,
 }
 
 impl RangeJoinState {
-    pub fn new(ctx: Arc<QueryContext>, range_join: &RangeJoin) -> Self {
+    pub fn new(ctx: Arc<QueryContext>, range_join: &RangeJoin) -> Result<Self> {
         let ie_join_state = if matches!(range_join.range_join_type, RangeJoinType::IEJoin) {
             Some(IEJoinState::new(range_join))
         } else {
             None
         };
+        let asof_join_state = if matches!(range_join.range_join_type, RangeJoinType::Asof) {
+            Some(AsofJoinState::try_create(range_join)?)
+        } else {
+            None
+        };
 
-        Self {
+        Ok(Self {
             ctx,
             left_table: RwLock::new(vec![]),
             right_table: RwLock::new(vec![]),
@@ -88,7 +96,8 @@ impl RangeJoinState {
             row_offset: RwLock::new(vec![]),
             finished_tasks: AtomicU64::new(0),
             ie_join_state,
-        }
+            asof_join_state,
+        })
     }
 
     pub(crate) fn sink_right(&self, block: DataBlock) -> Result<()> {
@@ -172,6 +181,9 @@ impl RangeJoinState {
     }
 
     pub(crate) fn partition(&self) -> Result<()> {
+        if self.asof_join_state.is_some() {
+            return self.asof_partition();
+        }
         let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
         let left_table = self.left_table.read();
         // Right table is bigger than left table
@@ -47,6 +47,14 @@ impl MaterializedCteState {
         }
     }
 
+    /// The state of the blocks materialized before the sources are built, like the
+    /// working table of a recursive cte.
+    pub fn new_finished(ctx: Arc<QueryContext>) -> Self {
+        let state = MaterializedCteState::new(ctx);
+        *state.sink_finished.lock() = true;
+        state
+    }
+
     pub fn attach_sinker(&self) -> Result<()> {
         let mut left_sinker_count = self.left_sinker_count.write();
         *left_sinker_count += 1;
@@ -0,0 +1,172 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_sql::executor::physical_plans::UnionAll;
+use common_sql::executor::PhysicalPlan;
+use futures_util::TryStreamExt;
+use parking_lot::RwLock;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::schedulers::build_local_pipeline;
+use crate::sessions::QueryContext;
+use crate::stream::PullingExecutorStream;
+
+/// Evaluates a recursive cte.
+///
+/// The anchor (left child of the union) is executed first, then the recursive term
+/// (right child of the union) is executed repeatedly. Each iteration reads the rows
+/// produced by the previous one from the working table, and the evaluation stops
+/// once an iteration produces no rows.
+///
+/// The working table is a materialized cte, which is read by the `MaterializedCteSource`
+/// of the `RecursiveCteScan` in the recursive term.
+pub struct RecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    union_plan: UnionAll,
+    left_projection: Vec<usize>,
+    right_projection: Vec<usize>,
+    max_depth: usize,
+    depth: usize,
+    finished: bool,
+    pending: VecDeque<DataBlock>,
+}
+
+impl RecursiveCteSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        union_plan: UnionAll,
+    ) -> Result<ProcessorPtr> {
+        let left_schema = union_plan.left.output_schema()?;
+        let right_schema = union_plan.right.output_schema()?;
+        let mut left_projection = Vec::with_capacity(union_plan.pairs.len());
+        let mut right_projection = Vec::with_capacity(union_plan.pairs.len());
+        for (left, right) in union_plan.pairs.iter() {
+            left_projection.push(left_schema.index_of(left)?);
+            right_projection.push(right_schema.index_of(right)?);
+        }
+        let max_depth = ctx.get_settings().get_max_cte_recursive_depth()? as usize;
+        // The recursive part reads the working table through a `MaterializedCteSource`.
+        if let Some(cte_idx) = union_plan.cte_idx {
+            ctx.set_materialized_cte(cte_idx, Arc::new(RwLock::new(vec![])))?;
+        }
+
+        AsyncSourcer::create(ctx.clone(), output_port, RecursiveCteSource {
+            ctx,
+            union_plan,
+            left_projection,
+            right_projection,
+            max_depth,
+            depth: 0,
+            finished: false,
+            pending: VecDeque::new(),
+        })
+    }
+
+    fn working_table(&self) -> Result<Arc<RwLock<Vec<DataBlock>>>> {
+        // `cte_idx` is always set for a recursive union, and the working table is
+        // registered in `create`.
+        let cte_idx = self.union_plan.cte_idx.unwrap_or_default();
+        self.ctx.get_materialized_cte(cte_idx)?.ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "Logical error, the working table {:?} of recursive cte is not found",
+                cte_idx
+            ))
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_plan(&self, plan: &PhysicalPlan) -> Result<Vec<DataBlock>> {
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let build_res = build_local_pipeline(&ctx, plan, false).await?;
+        let settings = ExecutorSettings::try_create(&ctx.get_settings(), ctx.get_id())?;
+        let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        PullingExecutorStream::create(pulling_executor)?
+            .try_collect::<Vec<DataBlock>>()
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn next_iteration(&mut self) -> Result<()> {
+        let (plan, projection) = if self.depth == 0 {
+            (self.union_plan.left.clone(), &self.left_projection)
+        } else {
+            if self.depth > self.max_depth {
+                return Err(ErrorCode::CteRecursionDepthExceeded(format!(
+                    "Recursive cte exceeds the maximum recursion depth {}, you can change it by setting max_cte_recursive_depth",
+                    self.max_depth
+                )));
+            }
+            (self.union_plan.right.clone(), &self.right_projection)
+        };
+        let projection = projection.clone();
+
+        let blocks = self
+            .execute_plan(&plan)
+            .await?
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .map(|block| {
+                let num_rows = block.num_rows();
+                let columns = projection
+                    .iter()
+                    .map(|offset| block.get_by_offset(*offset).clone())
+                    .collect::<Vec<BlockEntry>>();
+                DataBlock::new(columns, num_rows)
+            })
+            .collect::<Vec<_>>();
+        self.depth += 1;
+
+        if blocks.is_empty() {
+            self.finished = true;
+            return Ok(());
+        }
+        *self.working_table()?.write() = blocks.clone();
+        self.pending.extend(blocks);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for RecursiveCteSource {
+    const NAME: &'static str = "RecursiveCteSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.pending.pop_front() {
+                return Ok(Some(block));
+            }
+            if self.finished {
+                self.working_table()?.write().clear();
+                return Ok(None);
+            }
+            self.next_iteration().await?;
+        }
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::plan::InternalColumnMeta;
+use common_catalog::plan::TableSample;
+use common_exception::Result;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::InputPort;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_transforms::processors::AccumulatingTransform;
+use common_pipeline_transforms::processors::AccumulatingTransformer;
+use common_pipeline_transforms::processors::Transform;
+use common_pipeline_transforms::processors::Transformer;
+
+/// The buffered rows are pruned once they exceed this number.
+const PRUNE_THRESHOLD: usize = 65536;
+
+/// Generate a uniformly distributed key for each row of the block.
+///
+/// With a seed, the key only depends on the position of the row, which is the block
+/// it is read from and its offset in the block, so the same rows are chosen if the
+/// query is repeated on the same data, and the duplicate rows are chosen independently.
+fn sample_keys(block: &DataBlock, seed: Option<u64>, rows_seen: &mut u64) -> Vec<u64> {
+    let num_rows = block.num_rows();
+    let first_row = *rows_seen;
+    *rows_seen += num_rows as u64;
+    let Some(seed) = seed else {
+        return (0..num_rows).map(|_| rand::random::<u64>()).collect();
+    };
+    match block
+        .get_meta()
+        .and_then(InternalColumnMeta::downcast_ref_from)
+    {
+        Some(meta) => {
+            let mut hasher = DefaultHasher::new();
+            meta.block_location.hash(&mut hasher);
+            let block_hash = hasher.finish();
+            let row_key = |offset: usize| {
+                TableSample::mix_seed(TableSample::mix_seed(offset as u64, block_hash), seed)
+            };
+            match &meta.offsets {
+                Some(offsets) => offsets.iter().map(|offset| row_key(*offset)).collect(),
+                None => (0..num_rows).map(row_key).collect(),
+            }
+        }
+        // The rows of the tables without row positions are numbered in the order they are read.
+        None => (first_row..first_row + num_rows as u64)
+            .map(|row| TableSample::mix_seed(row, seed))
+            .collect(),
+    }
+}
+
+/// Keeps the rows at `indices` of the block.
+///
+/// The offsets of the kept rows are recorded in the meta of the block, which is used
+/// to fill the internal columns, or the meta is removed if `keep_meta` is false.
+fn take_rows(mut block: DataBlock, indices: &[u32], keep_meta: bool) -> Result<DataBlock> {
+    let meta = block
+        .take_meta()
+        .and_then(InternalColumnMeta::downcast_from)
+        .filter(|_| keep_meta);
+    let block = if indices.len() == block.num_rows() {
+        block
+    } else {
+        block.take(indices, &mut None)?
+    };
+    match meta {
+        Some(mut meta) => {
+            let offsets = match &meta.offsets {
+                Some(offsets) => indices.iter().map(|i| offsets[*i as usize]).collect(),
+                None => indices.iter().map(|i| *i as usize).collect(),
+            };
+            meta.offsets = Some(offsets);
+            block.add_meta(Some(Box::new(meta)))
+        }
+        None => Ok(block),
+    }
+}
+
+/// Keeps each row with the probability of `TABLESAMPLE ROW (p)`.
+pub struct TransformRowSample {
+    probability: f64,
+    seed: Option<u64>,
+    rows_seen: u64,
+    keep_meta: bool,
+}
+
+impl TransformRowSample {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        probability: f64,
+        seed: Option<u64>,
+        keep_meta: bool,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(Transformer::create(input, output, TransformRowSample {
+            probability,
+            seed,
+            rows_seen: 0,
+            keep_meta,
+        }))
+    }
+}
+
+impl Transform for TransformRowSample {
+    const NAME: &'static str = "RowSample";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let indices = if self.probability >= 1.0 {
+            (0..data.num_rows() as u32).collect::<Vec<_>>()
+        } else {
+            sample_keys(&data, self.seed, &mut self.rows_seen)
+                .into_iter()
+                .enumerate()
+                .filter(|(_, key)| TableSample::hit(*key, self.probability))
+                .map(|(row, _)| row as u32)
+                .collect::<Vec<_>>()
+        };
+        take_rows(data, &indices, self.keep_meta)
+    }
+}
+
+/// Keeps a fixed number of rows of `TABLESAMPLE ROW (n ROWS)`.
+///
+/// Each row gets a random key and the rows with the `rows` smallest keys are kept,
+/// so every subset of `rows` rows has the same chance to be chosen.
+/// It must be placed after the pipeline is resized to one.
+pub struct TransformFixedRowsSample {
+    rows: usize,
+    seed: Option<u64>,
+    rows_seen: u64,
+    keep_meta: bool,
+
+    // The buffered blocks with the keys of their rows, the blocks are not concatenated
+    // to keep the meta of each block.
+    blocks: Vec<(DataBlock, Vec<u64>)>,
+    num_keys: usize,
+}
+
+impl TransformFixedRowsSample {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        rows: usize,
+        seed: Option<u64>,
+        keep_meta: bool,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(AccumulatingTransformer::create(
+            input,
+            output,
+            TransformFixedRowsSample {
+                rows,
+                seed,
+                rows_seen: 0,
+                keep_meta,
+                blocks: vec![],
+                num_keys: 0,
+            },
+        ))
+    }
+
+    fn prune(&mut self) -> Result<()> {
+        if self.num_keys <= self.rows {
+            return Ok(());
+        }
+
+        // The key of the `rows`-th smallest key, the rows with smaller keys are kept,
+        // and the rows with the same key are kept until there are `rows` rows.
+        let mut keys = self
+            .blocks
+            .iter()
+            .flat_map(|(_, keys)| keys.iter().copied())
+            .collect::<Vec<_>>();
+        let (_, threshold, _) = keys.select_nth_unstable(self.rows - 1);
+        let threshold = *threshold;
+        let mut ties = self.rows - keys.iter().filter(|key| **key < threshold).count();
+
+        let blocks = std::mem::take(&mut self.blocks);
+        self.num_keys = 0;
+        for (block, keys) in blocks {
+            let mut indices = vec![];
+            let mut kept_keys = vec![];
+            for (row, key) in keys.into_iter().enumerate() {
+                let keep = key < threshold || (key == threshold && ties > 0);
+                if key == threshold && keep {
+                    ties -= 1;
+                }
+                if keep {
+                    indices.push(row as u32);
+                    kept_keys.push(key);
+                }
+            }
+            if indices.is_empty() {
+                continue;
+            }
+            self.num_keys += indices.len();
+            self.blocks
+                .push((take_rows(block, &indices, true)?, kept_keys));
+        }
+        Ok(())
+    }
+}
+
+impl AccumulatingTransform for TransformFixedRowsSample {
+    const NAME: &'static str = "FixedRowsSample";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        if data.is_empty() || self.rows == 0 {
+            return Ok(vec![]);
+        }
+        let keys = sample_keys(&data, self.seed, &mut self.rows_seen);
+        self.num_keys += keys.len();
+        self.blocks.push((data, keys));
+
+        if self.num_keys >= PRUNE_THRESHOLD.max(self.rows * 2) {
+            self.prune()?;
+        }
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output {
+            return Ok(vec![]);
+        }
+        self.prune()?;
+        std::mem::take(&mut self.blocks)
+            .into_iter()
+            .map(|(block, _)| {
+                let indices = (0..block.num_rows() as u32).collect::<Vec<_>>();
+                take_rows(block, &indices, self.keep_meta)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::SortColumnDescription;
+use common_pipeline_core::processors::InputPort;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_transforms::processors::AccumulatingTransform;
+use common_pipeline_transforms::processors::AccumulatingTransformer;
+
+/// The buffered rows are pruned once they exceed this number.
+const PRUNE_THRESHOLD: usize = 65536;
+
+/// Keeps only the first `limit` rows of each window partition.
+///
+/// It's placed before the sort of a `row_number()` window whose result is filtered by
+/// `QUALIFY row_number() OVER (...) <= limit`, the rows after the first `limit` ones
+/// of a partition can never pass the filter, so they are dropped as early as possible.
+pub struct TransformWindowPartitionTopN {
+    partition_by: Vec<usize>,
+    // Sort by the partition columns first, then the order by columns of the window.
+    sort_desc: Vec<SortColumnDescription>,
+    limit: usize,
+
+    blocks: Vec<DataBlock>,
+    num_rows: usize,
+    prune_threshold: usize,
+}
+
+impl TransformWindowPartitionTopN {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        partition_by: Vec<usize>,
+        sort_desc: Vec<SortColumnDescription>,
+        limit: usize,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(AccumulatingTransformer::create(
+            input,
+            output,
+            TransformWindowPartitionTopN {
+                partition_by,
+                sort_desc,
+                limit,
+                blocks: vec![],
+                num_rows: 0,
+                prune_threshold: PRUNE_THRESHOLD,
+            },
+        ))
+    }
+
+    fn is_same_partition(&self, block: &DataBlock, left: usize, right: usize) -> bool {
+        self.partition_by.iter().all(|offset| {
+            let entry = block.get_by_offset(*offset);
+            entry.value.index(left) == entry.value.index(right)
+        })
+    }
+
+    fn prune(&mut self) -> Result<Option<DataBlock>> {
+        if self.blocks.is_empty() {
+            return Ok(None);
+        }
+        let blocks = std::mem::take(&mut self.blocks);
+        let block = DataBlock::concat(&blocks)?;
+        let block = DataBlock::sort(&block, &self.sort_desc, None)?;
+
+        let num_rows = block.num_rows();
+        let mut indices = Vec::with_capacity(num_rows);
+        let mut row_number = 0;
+        for row in 0..num_rows {
+            if row > 0 && !self.is_same_partition(&block, row - 1, row) {
+                row_number = 0;
+            }
+            if row_number < self.limit {
+                indices.push(row as u32);
+            }
+            row_number += 1;
+        }
+        self.num_rows = 0;
+
+        if indices.len() == num_rows {
+            return Ok(Some(block));
+        }
+        Ok(Some(block.take(&indices, &mut None)?))
+    }
+}
+
+impl AccumulatingTransform for TransformWindowPartitionTopN {
+    const NAME: &'static str = "WindowPartitionTopN";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+        self.num_rows += data.num_rows();
+        self.blocks.push(data);
+
+        if self.num_rows >= self.prune_threshold {
+            if let Some(block) = self.prune()? {
+                // Most of the rows may survive the pruning if there are many partitions,
+                // raise the threshold to avoid sorting them again and again.
+                self.prune_threshold = self.prune_threshold.max(block.num_rows() * 2);
+                self.num_rows = block.num_rows();
+                self.blocks.push(block);
+            }
+        }
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output {
+            return Ok(vec![]);
+        }
+        Ok(self.prune()?.into_iter().collect())
+    }
+}
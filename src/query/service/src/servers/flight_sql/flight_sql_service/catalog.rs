@@ -14,23 +14,38 @@
 
 use std::sync::Arc;
 
+use arrow_array::builder::BinaryBuilder;
 use arrow_array::builder::StringBuilder;
+use arrow_array::new_empty_array;
 use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetTables;
 use arrow_flight::utils::batches_to_flight_data;
+use arrow_flight::IpcMessage;
+use arrow_flight::SchemaAsIpc;
+use arrow_ipc::writer::IpcWriteOptions;
 use arrow_schema::DataType;
 use arrow_schema::Field;
 use arrow_schema::Schema;
+use arrow_schema::SchemaRef;
 use common_catalog::catalog::Catalog;
 use common_catalog::catalog::CatalogManager;
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
+use common_expression::DataSchema;
+use common_functions::scalars::like;
+use common_storages_view::view_table::VIEW_ENGINE;
 use futures_util::stream;
 use log::warn;
 use tonic::Status;
 
 use crate::servers::flight_sql::flight_sql_service::DoGetStream;
 
+const TABLE_TYPE_TABLE: &str = "table";
+const TABLE_TYPE_VIEW: &str = "view";
+
 pub(super) struct CatalogInfoProvider {}
 
 impl CatalogInfoProvider {
@@ -45,85 +60,256 @@ impl CatalogInfoProvider {
         Ok(Box::pin(stream))
     }
 
-    async fn get_tables_internal(
-        ctx: Arc<dyn TableContext>,
+    /// Whether `value` matches the search pattern of a Flight SQL command,
+    /// which uses the `%` and `_` wildcards of `LIKE`.
+    fn match_pattern(pattern: &Option<String>, value: &str) -> bool {
+        match pattern {
+            Some(pattern) => like(value.as_bytes(), pattern.as_bytes()),
+            None => true,
+        }
+    }
+
+    fn table_type(table: &dyn Table) -> &'static str {
+        if table.engine() == VIEW_ENGINE {
+            TABLE_TYPE_VIEW
+        } else {
+            TABLE_TYPE_TABLE
+        }
+    }
+
+    async fn list_catalogs(
+        ctx: &Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
-    ) -> common_exception::Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>)> {
+    ) -> common_exception::Result<Vec<(String, Arc<dyn Catalog>)>> {
         let tenant = ctx.get_tenant();
         let catalog_mgr = CatalogManager::instance();
-        let catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name {
-            vec![(
+        match catalog_name.filter(|name| !name.is_empty()) {
+            Some(catalog_name) => Ok(vec![(
                 catalog_name.clone(),
                 catalog_mgr.get_catalog(&tenant, &catalog_name).await?,
-            )]
-        } else {
-            catalog_mgr
+            )]),
+            None => Ok(catalog_mgr
                 .list_catalogs(&tenant)
                 .await?
                 .iter()
                 .map(|r| (r.name(), r.clone()))
-                .collect()
-        };
+                .collect()),
+        }
+    }
+
+    async fn get_schemas_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+    ) -> common_exception::Result<(Vec<String>, Vec<String>)> {
+        let tenant = ctx.get_tenant();
+        let mut catalog_names = vec![];
+        let mut database_names = vec![];
+        let visibility_checker = ctx.get_visibility_checker().await?;
+        for (catalog_name, catalog) in Self::list_catalogs(&ctx, catalog_name).await? {
+            for db in catalog.list_databases(tenant.as_str()).await? {
+                if Self::match_pattern(&db_schema_filter_pattern, db.name())
+                    && visibility_checker.check_database_visibility(&catalog_name, db.name())
+                {
+                    catalog_names.push(catalog_name.clone());
+                    database_names.push(db.name().to_string());
+                }
+            }
+        }
+        Ok((catalog_names, database_names))
+    }
 
+    async fn get_tables_internal(
+        ctx: Arc<dyn TableContext>,
+        query: &CommandGetTables,
+    ) -> common_exception::Result<(Vec<String>, Vec<String>, Vec<Arc<dyn Table>>)> {
+        let tenant = ctx.get_tenant();
         let mut catalog_names = vec![];
         let mut database_names = vec![];
-        let mut table_names = vec![];
-        let mut table_types = vec![];
-        let table_type = "table".to_string();
-        for (catalog_name, catalog) in catalogs.into_iter() {
-            let dbs = if let Some(database_name) = &database_name {
-                vec![catalog.get_database(tenant.as_str(), database_name).await?]
-            } else {
-                catalog.list_databases(tenant.as_str()).await?
-            };
-            for db in dbs {
-                let db_name = db.name().to_string().into_boxed_str();
-                let db_name: &str = Box::leak(db_name);
-                let tables = match catalog.list_tables(tenant.as_str(), db_name).await {
+        let mut tables = vec![];
+        let visibility_checker = ctx.get_visibility_checker().await?;
+        for (catalog_name, catalog) in Self::list_catalogs(&ctx, query.catalog.clone()).await? {
+            for db in catalog.list_databases(tenant.as_str()).await? {
+                let db_name = db.name().to_string();
+                if !Self::match_pattern(&query.db_schema_filter_pattern, &db_name)
+                    || !visibility_checker.check_database_visibility(&catalog_name, &db_name)
+                {
+                    continue;
+                }
+                let db_tables = match catalog.list_tables(tenant.as_str(), &db_name).await {
                     Ok(tables) => tables,
                     Err(err) if err.code() == ErrorCode::EMPTY_SHARE_ENDPOINT_CONFIG => {
-                        warn!("list tables failed on db {}: {}", db.name(), err);
+                        warn!("list tables failed on db {}: {}", db_name, err);
                         continue;
                     }
                     Err(err) => return Err(err),
                 };
-                for table in tables {
+                for table in db_tables {
+                    if !Self::match_pattern(&query.table_name_filter_pattern, table.name())
+                        || !visibility_checker.check_table_visibility(
+                            &catalog_name,
+                            &db_name,
+                            table.name(),
+                        )
+                    {
+                        continue;
+                    }
+                    let table_type = Self::table_type(table.as_ref());
+                    if !query.table_types.is_empty()
+                        && !query
+                            .table_types
+                            .iter()
+                            .any(|t| t.eq_ignore_ascii_case(table_type))
+                    {
+                        continue;
+                    }
                     catalog_names.push(catalog_name.clone());
-                    database_names.push(db_name.to_string());
-                    table_names.push(table.name().to_string());
-                    table_types.push(table_type.clone());
+                    database_names.push(db_name.clone());
+                    tables.push(table);
                 }
             }
         }
-        Ok((catalog_names, database_names, table_names, table_types))
+        Ok((catalog_names, database_names, tables))
     }
 
-    pub(crate) async fn get_tables(
+    pub(crate) async fn get_catalogs(ctx: Arc<dyn TableContext>) -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "catalog_name",
+            DataType::Utf8,
+            false,
+        )]));
+        let catalog_names = Self::list_catalogs(&ctx, None)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(catalog_names)])
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) async fn get_schemas(
         ctx: Arc<dyn TableContext>,
-        catalog_name: Option<String>,
-        database_name: Option<String>,
+        query: CommandGetDbSchemas,
     ) -> Result<DoGetStream, Status> {
         let schema = Arc::new(Schema::new(vec![
-            Field::new("catalog_name", DataType::Utf8, false),
+            Field::new("catalog_name", DataType::Utf8, true),
             Field::new("db_schema_name", DataType::Utf8, false),
-            Field::new("table_name", DataType::Utf8, false),
-            Field::new("table_type", DataType::Utf8, false),
         ]));
-        let (catalog_name, db_schema_name, table_name, table_type) =
-            Self::get_tables_internal(ctx.clone(), catalog_name, database_name)
+        let (catalog_name, db_schema_name) =
+            Self::get_schemas_internal(ctx, query.catalog, query.db_schema_filter_pattern)
                 .await
                 .map_err(|e| Status::internal(format!("{e:?}")))?;
         let batch = RecordBatch::try_new(schema, vec![
             Self::string_array(catalog_name),
             Self::string_array(db_schema_name),
-            Self::string_array(table_name),
-            Self::string_array(table_type),
         ])
         .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
         Self::batch_to_get_stream(batch)
     }
 
+    pub(crate) async fn get_tables(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetTables,
+    ) -> Result<DoGetStream, Status> {
+        let mut fields = vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("table_type", DataType::Utf8, false),
+        ];
+        let (catalog_name, db_schema_name, tables) =
+            Self::get_tables_internal(ctx, &query)
+                .await
+                .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let mut columns = vec![
+            Self::string_array(catalog_name),
+            Self::string_array(db_schema_name),
+            Self::string_array(tables.iter().map(|t| t.name().to_string()).collect()),
+            Self::string_array(
+                tables
+                    .iter()
+                    .map(|t| Self::table_type(t.as_ref()).to_string())
+                    .collect(),
+            ),
+        ];
+        if query.include_schema {
+            fields.push(Field::new("table_schema", DataType::Binary, false));
+            let mut builder = BinaryBuilder::new();
+            for table in &tables {
+                let schema = Schema::from(&DataSchema::from(table.schema()));
+                let IpcMessage(schema_bytes) =
+                    SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+                        .try_into()
+                        .map_err(|e| Status::internal(format!("{e:?}")))?;
+                builder.append_value(&schema_bytes);
+            }
+            columns.push(Arc::new(builder.finish()));
+        }
+        let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) fn get_table_types() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "table_type",
+            DataType::Utf8,
+            false,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(vec![
+            TABLE_TYPE_TABLE.to_string(),
+            TABLE_TYPE_VIEW.to_string(),
+        ])])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    /// Databend has no primary key constraint, the result is always empty.
+    pub(crate) fn get_primary_keys() -> Result<DoGetStream, Status> {
+        Self::empty_batch_to_get_stream(Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int32, false),
+        ])))
+    }
+
+    /// Databend has no foreign key constraint, the result is always empty.
+    ///
+    /// Shared by the exported keys, imported keys and cross reference commands.
+    pub(crate) fn get_foreign_keys() -> Result<DoGetStream, Status> {
+        Self::empty_batch_to_get_stream(Arc::new(Schema::new(vec![
+            Field::new("pk_catalog_name", DataType::Utf8, true),
+            Field::new("pk_db_schema_name", DataType::Utf8, true),
+            Field::new("pk_table_name", DataType::Utf8, false),
+            Field::new("pk_column_name", DataType::Utf8, false),
+            Field::new("fk_catalog_name", DataType::Utf8, true),
+            Field::new("fk_db_schema_name", DataType::Utf8, true),
+            Field::new("fk_table_name", DataType::Utf8, false),
+            Field::new("fk_column_name", DataType::Utf8, false),
+            Field::new("key_sequence", DataType::Int32, false),
+            Field::new("fk_key_name", DataType::Utf8, true),
+            Field::new("pk_key_name", DataType::Utf8, true),
+            Field::new("update_rule", DataType::UInt8, false),
+            Field::new("delete_rule", DataType::UInt8, false),
+        ])))
+    }
+
+    fn empty_batch_to_get_stream(schema: SchemaRef) -> Result<DoGetStream, Status> {
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|f| new_empty_array(f.data_type()))
+            .collect();
+        let batch = RecordBatch::try_new(schema, columns)
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
@@ -19,22 +19,28 @@ mod query;
 mod service;
 mod session;
 mod sql_info;
+mod xdbc_type_info;
 
 use std::pin::Pin;
 use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
 
 use arrow_flight::FlightData;
 use catalog::CatalogInfoProvider;
 use common_sql::plans::Plan;
 use common_sql::PlanExtras;
-use dashmap::DashMap;
 use futures::Stream;
 use parking_lot::Mutex;
 use sql_info::SqlInfoProvider;
 use tonic::Status;
 use uuid::Uuid;
+use xdbc_type_info::XdbcTypeInfoProvider;
 
+use crate::servers::http::v1::Expirable;
 use crate::servers::http::v1::ExpiringMap;
+use crate::servers::http::v1::ExpiringState;
 use crate::sessions::Session;
 
 #[macro_export]
@@ -49,9 +55,51 @@ pub(crate) use status;
 
 type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send + 'static>>;
 
+/// The statements not accessed for this long are removed, e.g. the ones never fetched.
+const STATEMENT_MAX_IDLE_TIME: Duration = Duration::from_secs(360);
+
 pub struct FlightSqlServiceImpl {
     pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
-    statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    statements: Mutex<ExpiringMap<Uuid, PlannedStatement>>,
+}
+
+/// A planned statement, only accessible by the session that plans it.
+#[derive(Clone)]
+struct PlannedStatement {
+    owner: Weak<Session>,
+    plan: Plan,
+    plan_extras: PlanExtras,
+    last_access: Arc<Mutex<Instant>>,
+}
+
+impl PlannedStatement {
+    fn create(owner: &Arc<Session>, plan: Plan, plan_extras: PlanExtras) -> Self {
+        PlannedStatement {
+            owner: Arc::downgrade(owner),
+            plan,
+            plan_extras,
+            last_access: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    fn is_owned_by(&self, session: &Arc<Session>) -> bool {
+        self.owner.as_ptr() == Arc::as_ptr(session)
+    }
+}
+
+impl Expirable for PlannedStatement {
+    fn expire_state(&self) -> ExpiringState {
+        // The session is dropped once it expires, so are the statements of it.
+        if self.owner.strong_count() == 0 {
+            ExpiringState::Aborted { need_cleanup: true }
+        } else {
+            ExpiringState::Idle {
+                idle_time: self.last_access.lock().elapsed(),
+            }
+        }
+    }
+
+    fn on_expire(&self) {}
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -59,7 +107,7 @@ impl FlightSqlServiceImpl {
     pub fn create() -> Self {
         FlightSqlServiceImpl {
             sessions: Mutex::new(Default::default()),
-            statements: Arc::new(Default::default()),
+            statements: Mutex::new(Default::default()),
         }
     }
 }
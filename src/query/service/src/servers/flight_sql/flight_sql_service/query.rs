@@ -15,6 +15,7 @@
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Instant;
 
 use arrow_flight::FlightData;
 use arrow_flight::SchemaAsIpc;
@@ -35,10 +36,13 @@ use futures::StreamExt;
 use serde::Deserialize;
 use serde::Serialize;
 use tonic::Status;
+use uuid::Uuid;
 
 use super::status;
 use super::DoGetStream;
 use super::FlightSqlServiceImpl;
+use super::PlannedStatement;
+use super::STATEMENT_MAX_IDLE_TIME;
 use crate::interpreters::InterpreterFactory;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
@@ -83,6 +87,54 @@ impl FlightSqlServiceImpl {
         planner.plan_sql(query).await
     }
 
+    pub(super) fn add_statement(
+        &self,
+        session: &Arc<Session>,
+        handle: Uuid,
+        (plan, plan_extras): (Plan, PlanExtras),
+    ) {
+        let statement = PlannedStatement::create(session, plan, plan_extras);
+        self.statements
+            .lock()
+            .insert(handle, statement, Some(STATEMENT_MAX_IDLE_TIME));
+    }
+
+    /// Get the plan of a statement of the session, it is cloned to avoid holding the lock while executing.
+    pub(super) fn get_statement(
+        &self,
+        session: &Arc<Session>,
+        handle: &Uuid,
+    ) -> std::result::Result<(Plan, PlanExtras), Status> {
+        match self.statements.lock().get(handle) {
+            // The statements of other sessions are invisible.
+            Some(statement) if statement.is_owned_by(session) => {
+                *statement.last_access.lock() = Instant::now();
+                Ok((statement.plan, statement.plan_extras))
+            }
+            _ => Err(Status::not_found(format!(
+                "Unknown statement handle: {handle}"
+            ))),
+        }
+    }
+
+    /// Remove a statement of the session and return the plan of it.
+    pub(super) fn take_statement(
+        &self,
+        session: &Arc<Session>,
+        handle: &Uuid,
+    ) -> std::result::Result<(Plan, PlanExtras), Status> {
+        let mut statements = self.statements.lock();
+        match statements.get(handle) {
+            Some(statement) if statement.is_owned_by(session) => {
+                statements.remove(handle);
+                Ok((statement.plan, statement.plan_extras))
+            }
+            _ => Err(Status::not_found(format!(
+                "Unknown statement handle: {handle}"
+            ))),
+        }
+    }
+
     #[async_backtrace::framed]
     pub(super) async fn execute_update(
         &self,
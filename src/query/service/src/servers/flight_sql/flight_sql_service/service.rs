@@ -192,9 +192,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_get_fallback with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let (plan, plan_extras) = self.get_statement(&session, &handle)?;
         let stream = self
-            .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_query(session, &plan, &plan_extras)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let resp = Response::new(stream);
@@ -207,9 +207,28 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        info!("get_flight_info_sql_info(query={})", query.query);
-        let _session = self.get_session(&request)?;
-        Ok(simple_flight_info(query))
+        info!("get_flight_info_statement(query={})", query.query);
+        let session = self.get_session(&request)?;
+        let handle = Uuid::new_v4();
+        let plan = self
+            .plan_sql(&session, &query.query)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+        let schema = (&*plan.0.schema()).into();
+        self.add_statement(&session, handle, plan);
+
+        // The statement is removed from `statements` once it is fetched by `do_get_statement`,
+        // or it's not fetched within `STATEMENT_MAX_IDLE_TIME`.
+        let ticket = TicketStatementQuery {
+            statement_handle: handle.as_bytes().to_vec().into(),
+        };
+        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| status!("Unable to serialize schema", e))?;
+        let IpcMessage(schema_bytes) = message;
+        let mut info = simple_flight_info(ticket).into_inner();
+        info.schema = schema_bytes;
+        Ok(Response::new(info))
     }
 
     #[async_backtrace::framed]
@@ -218,14 +237,14 @@ impl FlightSqlService for FlightSqlServiceImpl {
         cmd: CommandPreparedStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        let _session = self.get_session(&request);
+        let session = self.get_session(&request)?;
         let handle = Uuid::from_slice(cmd.prepared_statement_handle.as_ref())
             .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
 
         info!("get_flight_info_prepared_statement with handle={handle}");
 
-        let handle_plan_ref = self.statements.get(&handle).unwrap();
-        let schema = handle_plan_ref.value().0.schema().as_ref().into();
+        let (plan, _) = self.get_statement(&session, &handle)?;
+        let schema = plan.schema().as_ref().into();
         let loc = Location {
             uri: "grpc+tcp://127.0.0.1".to_string(),
         };
@@ -321,48 +340,44 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        info!("get_flight_info_primary_keys({query:?})",);
-        Err(Status::unimplemented(
-            "get_flight_info_primary_keys not implemented",
-        ))
+        info!("get_flight_info_primary_keys({query:?})");
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_exported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     // do_get
@@ -370,42 +385,74 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_statement(
         &self,
         ticket: TicketStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_statement({ticket:?}");
-        Err(Status::unimplemented("do_get_statement not implemented"))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(ticket.statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        info!("do_get_statement with handle={handle}");
+
+        let (plan, plan_extras) = self.take_statement(&session, &handle)?;
+        let stream = self
+            .execute_query(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_prepared_statement(
         &self,
         query: CommandPreparedStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_prepared_statement({query:?}");
-        Err(Status::unimplemented(
-            "do_get_prepared_statement not implemented",
-        ))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        info!("do_get_prepared_statement with handle={handle}");
+
+        let (plan, plan_extras) = self.get_statement(&session, &handle)?;
+        let stream = self
+            .execute_query(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_catalogs(
         &self,
         _query: CommandGetCatalogs,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_catalogs()");
-        Err(Status::unimplemented("do_get_catalogs not implemented"))
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_catalogs(context).await?,
+        ))
     }
 
     #[async_backtrace::framed]
     async fn do_get_schemas(
         &self,
         query: CommandGetDbSchemas,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_schemas({query:?}");
-        Err(Status::unimplemented("do_get_schemas not implemented"))
+        info!("do_get_schemas({query:?})");
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_schemas(context, query).await?,
+        ))
     }
 
     #[async_backtrace::framed]
@@ -421,8 +468,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
         Ok(Response::new(
-            super::CatalogInfoProvider::get_tables(context.clone(), query.catalog.clone(), None)
-                .await?,
+            super::CatalogInfoProvider::get_tables(context, query).await?,
         ))
     }
 
@@ -430,10 +476,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_table_types(
         &self,
         _query: CommandGetTableTypes,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_table_types()");
-        Err(Status::unimplemented("do_get_table_types not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::CatalogInfoProvider::get_table_types()?))
     }
 
     #[async_backtrace::framed]
@@ -450,21 +497,25 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_primary_keys({query:?})");
-        Err(Status::unimplemented("do_get_primary_keys not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_primary_keys()?
+        ))
     }
 
     #[async_backtrace::framed]
     async fn do_get_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_exported_keys not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -472,11 +523,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_imported_keys not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -484,11 +536,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "do_get_cross_reference not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -526,9 +579,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_put_prepared_statement_query with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let (plan, plan_extras) = self.get_statement(&session, &handle)?;
         let record_count = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_update(session, &plan, &plan_extras)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let result = DoPutUpdateResult { record_count };
@@ -552,9 +605,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_put_prepared_statement_update with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let (plan, plan_extras) = self.get_statement(&session, &handle)?;
         let res = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_update(session, &plan, &plan_extras)
             .await
             .map_err(|e| status!("fail to execute", e))?;
 
@@ -590,7 +643,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
             query.query
         );
         let schema = (&*data_schema).into();
-        self.statements.insert(handle, plan);
+        self.add_statement(&session, handle, plan);
         let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
             .try_into()
             .map_err(|e| status!("Unable to serialize schema", e))?;
@@ -609,24 +662,16 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: ActionClosePreparedStatementRequest,
         request: Request<Action>,
     ) -> Result<(), Status> {
-        let handle = query.prepared_statement_handle.as_ref();
-        if let Ok(handle) = std::str::from_utf8(handle) {
-            info!(
-                "do_action_close_prepared_statement with handle {:?}",
-                handle
-            );
-            match Uuid::try_parse(handle) {
-                Ok(handle) => {
-                    if self.get_session(&request).is_ok() {
-                        self.statements.remove(&handle);
-                    }
-                }
-                Err(e) => {
-                    return Err(Status::internal(format!(
-                        "do_action_close_prepared_statement Error decoding handle: {e} {handle:?}"
-                    )));
-                }
-            }
+        // The handle is the bytes of the uuid returned by `do_action_create_prepared_statement`.
+        let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref()).map_err(|e| {
+            Status::internal(format!(
+                "do_action_close_prepared_statement Error decoding handle: {e}"
+            ))
+        })?;
+        info!("do_action_close_prepared_statement with handle={handle}");
+        if let Ok(session) = self.get_session(&request) {
+            // Closing an unknown or expired statement is not an error.
+            self.take_statement(&session, &handle).ok();
         }
         Ok(())
     }
@@ -639,19 +684,25 @@ impl FlightSqlService for FlightSqlServiceImpl {
     /// Get a FlightInfo to extract information about the supported XDBC types.
     async fn get_flight_info_xdbc_type_info(
         &self,
-        _query: CommandGetXdbcTypeInfo,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetXdbcTypeInfo,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        unimplemented!()
+        info!("get_flight_info_xdbc_type_info({query:?})");
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     /// Get a FlightDataStream containing the data related to the supported XDBC types.
     async fn do_get_xdbc_type_info(
         &self,
-        _query: CommandGetXdbcTypeInfo,
-        _request: Request<Ticket>,
+        query: CommandGetXdbcTypeInfo,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        unimplemented!()
+        info!("do_get_xdbc_type_info({query:?})");
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::XdbcTypeInfoProvider::type_info(
+            query.data_type,
+        )?))
     }
 
     async fn get_flight_info_substrait_plan(
@@ -659,7 +710,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: CommandStatementSubstraitPlan,
         _request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("Substrait plan is not supported"))
     }
 
     async fn do_put_substrait_plan(
@@ -667,7 +718,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: CommandStatementSubstraitPlan,
         _request: Request<PeekableFlightDataStream>,
     ) -> std::result::Result<i64, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("Substrait plan is not supported"))
     }
 
     async fn do_action_create_prepared_substrait_plan(
@@ -675,7 +726,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionCreatePreparedSubstraitPlanRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionCreatePreparedStatementResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("Substrait plan is not supported"))
     }
 
     async fn do_action_begin_transaction(
@@ -683,7 +734,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionBeginTransactionRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionBeginTransactionResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented(
+            "Transaction is not supported by Flight SQL, use BEGIN/COMMIT/ROLLBACK statements instead",
+        ))
     }
 
     async fn do_action_end_transaction(
@@ -691,7 +744,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionEndTransactionRequest,
         _request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        Err(Status::unimplemented(
+            "Transaction is not supported by Flight SQL, use BEGIN/COMMIT/ROLLBACK statements instead",
+        ))
     }
 
     async fn do_action_begin_savepoint(
@@ -699,7 +754,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionBeginSavepointRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionBeginSavepointResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("Savepoint is not supported"))
     }
 
     async fn do_action_end_savepoint(
@@ -707,7 +762,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionEndSavepointRequest,
         _request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        Err(Status::unimplemented("Savepoint is not supported"))
     }
 
     async fn do_action_cancel_query(
@@ -715,7 +770,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionCancelQueryRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionCancelQueryResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("Cancel query is not supported"))
     }
 }
 
@@ -0,0 +1,270 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::builder::Int32Builder;
+use arrow_array::builder::ListBuilder;
+use arrow_array::builder::StringBuilder;
+use arrow_array::ArrayRef;
+use arrow_array::RecordBatch;
+use arrow_flight::sql::Nullable;
+use arrow_flight::sql::Searchable;
+use arrow_flight::sql::XdbcDataType;
+use arrow_flight::utils::batches_to_flight_data;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
+use futures_util::stream;
+use tonic::Status;
+
+use crate::servers::flight_sql::flight_sql_service::DoGetStream;
+
+// The datetime sub codes of the ODBC `SQL_DATETIME` type.
+const SQL_CODE_DATE: i32 = 1;
+const SQL_CODE_TIMESTAMP: i32 = 3;
+
+struct XdbcTypeInfo {
+    type_name: &'static str,
+    data_type: XdbcDataType,
+    column_size: Option<i32>,
+    literal_quote: Option<&'static str>,
+    create_params: &'static [&'static str],
+    case_sensitive: bool,
+    searchable: Searchable,
+    unsigned_attribute: Option<bool>,
+    fixed_prec_scale: bool,
+    maximum_scale: Option<i32>,
+    datetime_subcode: Option<i32>,
+    num_prec_radix: Option<i32>,
+}
+
+impl XdbcTypeInfo {
+    const fn integer(
+        type_name: &'static str,
+        data_type: XdbcDataType,
+        column_size: i32,
+        unsigned: bool,
+    ) -> Self {
+        XdbcTypeInfo {
+            type_name,
+            data_type,
+            column_size: Some(column_size),
+            literal_quote: None,
+            create_params: &[],
+            case_sensitive: false,
+            searchable: Searchable::Basic,
+            unsigned_attribute: Some(unsigned),
+            fixed_prec_scale: false,
+            maximum_scale: None,
+            datetime_subcode: None,
+            num_prec_radix: Some(10),
+        }
+    }
+
+    const fn other(type_name: &'static str, data_type: XdbcDataType) -> Self {
+        XdbcTypeInfo {
+            type_name,
+            data_type,
+            column_size: None,
+            literal_quote: None,
+            create_params: &[],
+            case_sensitive: false,
+            searchable: Searchable::Basic,
+            unsigned_attribute: None,
+            fixed_prec_scale: false,
+            maximum_scale: None,
+            datetime_subcode: None,
+            num_prec_radix: None,
+        }
+    }
+
+    /// The `sql_data_type` column, which is `SQL_DATETIME` for all the datetime types.
+    fn sql_data_type(&self) -> i32 {
+        match self.datetime_subcode {
+            Some(_) => XdbcDataType::XdbcDatetime as i32,
+            None => self.data_type as i32,
+        }
+    }
+}
+
+const XDBC_TYPES: &[XdbcTypeInfo] = &[
+    XdbcTypeInfo {
+        column_size: Some(1),
+        ..XdbcTypeInfo::other("BOOLEAN", XdbcDataType::XdbcBit)
+    },
+    XdbcTypeInfo::integer("TINYINT", XdbcDataType::XdbcTinyint, 3, false),
+    XdbcTypeInfo::integer("TINYINT UNSIGNED", XdbcDataType::XdbcTinyint, 3, true),
+    XdbcTypeInfo::integer("SMALLINT", XdbcDataType::XdbcSmallint, 5, false),
+    XdbcTypeInfo::integer("SMALLINT UNSIGNED", XdbcDataType::XdbcSmallint, 5, true),
+    XdbcTypeInfo::integer("INT", XdbcDataType::XdbcInteger, 10, false),
+    XdbcTypeInfo::integer("INT UNSIGNED", XdbcDataType::XdbcInteger, 10, true),
+    XdbcTypeInfo::integer("BIGINT", XdbcDataType::XdbcBigint, 19, false),
+    XdbcTypeInfo::integer("BIGINT UNSIGNED", XdbcDataType::XdbcBigint, 20, true),
+    XdbcTypeInfo {
+        column_size: Some(24),
+        num_prec_radix: Some(2),
+        ..XdbcTypeInfo::other("FLOAT", XdbcDataType::XdbcFloat)
+    },
+    XdbcTypeInfo {
+        column_size: Some(53),
+        num_prec_radix: Some(2),
+        ..XdbcTypeInfo::other("DOUBLE", XdbcDataType::XdbcDouble)
+    },
+    XdbcTypeInfo {
+        column_size: Some(76),
+        create_params: &["precision", "scale"],
+        fixed_prec_scale: true,
+        maximum_scale: Some(76),
+        num_prec_radix: Some(10),
+        ..XdbcTypeInfo::other("DECIMAL", XdbcDataType::XdbcDecimal)
+    },
+    XdbcTypeInfo {
+        column_size: Some(10),
+        literal_quote: Some("'"),
+        datetime_subcode: Some(SQL_CODE_DATE),
+        ..XdbcTypeInfo::other("DATE", XdbcDataType::XdbcDate)
+    },
+    XdbcTypeInfo {
+        column_size: Some(26),
+        literal_quote: Some("'"),
+        maximum_scale: Some(6),
+        datetime_subcode: Some(SQL_CODE_TIMESTAMP),
+        ..XdbcTypeInfo::other("TIMESTAMP", XdbcDataType::XdbcTimestamp)
+    },
+    XdbcTypeInfo {
+        literal_quote: Some("'"),
+        case_sensitive: true,
+        searchable: Searchable::Full,
+        ..XdbcTypeInfo::other("VARCHAR", XdbcDataType::XdbcVarchar)
+    },
+    XdbcTypeInfo {
+        literal_quote: Some("'"),
+        case_sensitive: true,
+        ..XdbcTypeInfo::other("VARIANT", XdbcDataType::XdbcLongvarchar)
+    },
+];
+
+pub(super) struct XdbcTypeInfoProvider {}
+
+impl XdbcTypeInfoProvider {
+    fn type_info_batch(data_type: Option<i32>) -> Result<RecordBatch, Status> {
+        let types = XDBC_TYPES
+            .iter()
+            .filter(|t| data_type.map_or(true, |v| t.data_type as i32 == v))
+            .collect::<Vec<_>>();
+
+        let mut type_name = StringBuilder::new();
+        let mut xdbc_data_type = Int32Builder::new();
+        let mut column_size = Int32Builder::new();
+        let mut literal_prefix = StringBuilder::new();
+        let mut literal_suffix = StringBuilder::new();
+        let mut create_params = ListBuilder::new(StringBuilder::new());
+        let mut nullable = Int32Builder::new();
+        let mut case_sensitive = BooleanBuilder::new();
+        let mut searchable = Int32Builder::new();
+        let mut unsigned_attribute = BooleanBuilder::new();
+        let mut fixed_prec_scale = BooleanBuilder::new();
+        let mut auto_increment = BooleanBuilder::new();
+        let mut local_type_name = StringBuilder::new();
+        let mut minimum_scale = Int32Builder::new();
+        let mut maximum_scale = Int32Builder::new();
+        let mut sql_data_type = Int32Builder::new();
+        let mut datetime_subcode = Int32Builder::new();
+        let mut num_prec_radix = Int32Builder::new();
+        let mut interval_precision = Int32Builder::new();
+        for t in types {
+            type_name.append_value(t.type_name);
+            xdbc_data_type.append_value(t.data_type as i32);
+            column_size.append_option(t.column_size);
+            literal_prefix.append_option(t.literal_quote);
+            literal_suffix.append_option(t.literal_quote);
+            for param in t.create_params {
+                create_params.values().append_value(param);
+            }
+            create_params.append(!t.create_params.is_empty());
+            nullable.append_value(Nullable::NullabilityNullable as i32);
+            case_sensitive.append_value(t.case_sensitive);
+            searchable.append_value(t.searchable as i32);
+            unsigned_attribute.append_option(t.unsigned_attribute);
+            fixed_prec_scale.append_value(t.fixed_prec_scale);
+            auto_increment.append_value(false);
+            local_type_name.append_value(t.type_name);
+            minimum_scale.append_option(t.maximum_scale.map(|_| 0));
+            maximum_scale.append_option(t.maximum_scale);
+            sql_data_type.append_value(t.sql_data_type());
+            datetime_subcode.append_option(t.datetime_subcode);
+            num_prec_radix.append_option(t.num_prec_radix);
+            interval_precision.append_null();
+        }
+
+        let create_params: ArrayRef = Arc::new(create_params.finish());
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("type_name", DataType::Utf8, false),
+            Field::new("data_type", DataType::Int32, false),
+            Field::new("column_size", DataType::Int32, true),
+            Field::new("literal_prefix", DataType::Utf8, true),
+            Field::new("literal_suffix", DataType::Utf8, true),
+            Field::new("create_params", create_params.data_type().clone(), true),
+            Field::new("nullable", DataType::Int32, false),
+            Field::new("case_sensitive", DataType::Boolean, false),
+            Field::new("searchable", DataType::Int32, false),
+            Field::new("unsigned_attribute", DataType::Boolean, true),
+            Field::new("fixed_prec_scale", DataType::Boolean, false),
+            Field::new("auto_increment", DataType::Boolean, true),
+            Field::new("local_type_name", DataType::Utf8, true),
+            Field::new("minimum_scale", DataType::Int32, true),
+            Field::new("maximum_scale", DataType::Int32, true),
+            Field::new("sql_data_type", DataType::Int32, false),
+            Field::new("datetime_subcode", DataType::Int32, true),
+            Field::new("num_prec_radix", DataType::Int32, true),
+            Field::new("interval_precision", DataType::Int32, true),
+        ]));
+        RecordBatch::try_new(schema, vec![
+            Arc::new(type_name.finish()),
+            Arc::new(xdbc_data_type.finish()),
+            Arc::new(column_size.finish()),
+            Arc::new(literal_prefix.finish()),
+            Arc::new(literal_suffix.finish()),
+            create_params,
+            Arc::new(nullable.finish()),
+            Arc::new(case_sensitive.finish()),
+            Arc::new(searchable.finish()),
+            Arc::new(unsigned_attribute.finish()),
+            Arc::new(fixed_prec_scale.finish()),
+            Arc::new(auto_increment.finish()),
+            Arc::new(local_type_name.finish()),
+            Arc::new(minimum_scale.finish()),
+            Arc::new(maximum_scale.finish()),
+            Arc::new(sql_data_type.finish()),
+            Arc::new(datetime_subcode.finish()),
+            Arc::new(num_prec_radix.finish()),
+            Arc::new(interval_precision.finish()),
+        ])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))
+    }
+
+    /// The types supported by Databend, optionally filtered by the XDBC data type.
+    pub fn type_info(data_type: Option<i32>) -> Result<DoGetStream, Status> {
+        let batch = Self::type_info_batch(data_type)?;
+        let schema = (*batch.schema()).clone();
+        let flight_data = batches_to_flight_data(&schema, vec![batch])
+            .map_err(|e| Status::internal(format!("{e:?}")))?
+            .into_iter()
+            .map(Ok);
+        let stream = stream::iter(flight_data);
+        Ok(Box::pin(stream))
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::io::ipc::write::StreamWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+
+pub const CONTENT_TYPE_ARROW_STREAM: &str = "application/vnd.apache.arrow.stream";
+
+/// Serializes the blocks as an Arrow IPC stream.
+///
+/// The schema message is always written, so a page without rows is still a valid stream.
+/// The columns are converted to arrow arrays without copying their buffers.
+pub fn blocks_to_arrow_stream(schema: &DataSchemaRef, blocks: &[DataBlock]) -> Result<Vec<u8>> {
+    let mut buf = vec![];
+    let mut writer = StreamWriter::new(&mut buf, WriteOptions { compression: None });
+    writer.start(&schema.to_arrow(), None)?;
+    for block in blocks {
+        if block.is_empty() {
+            continue;
+        }
+        writer.write(&Chunk::try_from(block.clone())?, None)?;
+    }
+    writer.finish()?;
+    Ok(buf)
+}
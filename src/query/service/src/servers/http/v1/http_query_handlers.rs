@@ -24,12 +24,15 @@ use minitrace::prelude::*;
 use poem::error::Error as PoemError;
 use poem::error::Result as PoemResult;
 use poem::get;
+use poem::http::header;
+use poem::http::HeaderMap;
 use poem::http::StatusCode;
 use poem::post;
 use poem::web::Json;
 use poem::web::Path;
 use poem::EndpointExt;
 use poem::IntoResponse;
+use poem::Response;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
@@ -38,7 +41,11 @@ use serde_json::Value as JsonValue;
 use super::query::ExecuteStateKind;
 use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
+use super::query::PageData;
+use super::query::PageFormat;
+use super::query::ResponseData;
 use crate::servers::http::middleware::MetricsMiddleware;
+use crate::servers::http::v1::arrow_block::CONTENT_TYPE_ARROW_STREAM;
 use crate::servers::http::v1::query::Progresses;
 use crate::servers::http::v1::HttpQueryContext;
 use crate::servers::http::v1::HttpQueryManager;
@@ -49,6 +56,12 @@ use crate::sessions::QueryAffect;
 const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 const HEADER_QUERY_STATE: &str = "X-DATABEND-QUERY-STATE";
 const HEADER_QUERY_PAGE_ROWS: &str = "X-DATABEND-QUERY-PAGE-ROWS";
+const HEADER_QUERY_NEXT_URI: &str = "X-DATABEND-QUERY-NEXT-URI";
+const HEADER_QUERY_FINAL_URI: &str = "X-DATABEND-QUERY-FINAL-URI";
+const HEADER_QUERY_STATS: &str = "X-DATABEND-QUERY-STATS";
+const HEADER_QUERY_AFFECT: &str = "X-DATABEND-QUERY-AFFECT";
+const HEADER_SESSION_ID: &str = "X-DATABEND-SESSION-ID";
+const HEADER_SESSION: &str = "X-DATABEND-SESSION";
 
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
@@ -130,35 +143,38 @@ pub struct QueryResponse {
 }
 
 impl QueryResponse {
+    /// The page of result is returned as an Arrow IPC stream if it's serialized in Arrow,
+    /// with the rest of the response in the headers. Responses without a page, e.g.
+    /// errors and state polling, are always in JSON.
     pub(crate) fn from_internal(
         id: String,
         r: HttpQueryResponseInternal,
         is_final: bool,
-    ) -> impl IntoResponse {
+    ) -> PoemResult<Response> {
         let state = r.state.clone();
-        let (data, next_uri) = if is_final {
-            (JsonBlock::empty(), None)
+        let (page, next_uri) = if is_final {
+            (None, None)
         } else {
             match state.state {
                 ExecuteStateKind::Running => match r.data {
-                    None => (JsonBlock::empty(), Some(make_state_uri(&id))),
+                    None => (None, Some(make_state_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
                             None => Some(make_state_uri(&id)),
                         };
-                        (d.page.data, uri)
+                        (Some(d), uri)
                     }
                 },
-                ExecuteStateKind::Failed => (JsonBlock::empty(), Some(make_final_uri(&id))),
+                ExecuteStateKind::Failed => (None, Some(make_final_uri(&id))),
                 ExecuteStateKind::Succeeded => match r.data {
-                    None => (JsonBlock::empty(), Some(make_final_uri(&id))),
+                    None => (None, Some(make_final_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
                             None => Some(make_final_uri(&id)),
                         };
-                        (d.page.data, uri)
+                        (Some(d), uri)
                     }
                 },
             }
@@ -168,15 +184,58 @@ impl QueryResponse {
             metrics_incr_http_response_errors_count(err.name(), err.code());
         }
 
-        let schema = data.schema().clone();
         let session_id = r.session_id.clone();
         let stats = QueryStats {
             progresses: state.progresses.clone(),
             running_time_ms: state.running_time_ms,
         };
+
+        let data = match page {
+            Some(ResponseData {
+                num_rows,
+                data: PageData::Arrow(body),
+                ..
+            }) => {
+                // Serialize all the headers before the response is built.
+                let stats = serde_json::to_string(&stats).map_err(internal_error)?;
+                let affect = match &state.affect {
+                    Some(affect) => Some(serde_json::to_string(affect).map_err(internal_error)?),
+                    None => None,
+                };
+                let session = match &r.session {
+                    Some(session) => Some(serde_json::to_string(session).map_err(internal_error)?),
+                    None => None,
+                };
+
+                let mut builder = Response::builder()
+                    .content_type(CONTENT_TYPE_ARROW_STREAM)
+                    .header(HEADER_QUERY_ID, id.clone())
+                    .header(HEADER_QUERY_STATE, state.state.to_string())
+                    .header(HEADER_QUERY_PAGE_ROWS, num_rows)
+                    .header(HEADER_QUERY_FINAL_URI, make_final_uri(&id))
+                    .header(HEADER_SESSION_ID, session_id)
+                    .header(HEADER_QUERY_STATS, stats);
+                if let Some(uri) = next_uri {
+                    builder = builder.header(HEADER_QUERY_NEXT_URI, uri);
+                }
+                if let Some(affect) = affect {
+                    builder = builder.header(HEADER_QUERY_AFFECT, affect);
+                }
+                if let Some(session) = session {
+                    builder = builder.header(HEADER_SESSION, session);
+                }
+                return Ok(builder.body(body));
+            }
+            Some(ResponseData {
+                data: PageData::Json(data),
+                ..
+            }) => data,
+            None => JsonBlock::empty(),
+        };
+        let schema = data.schema().clone();
         let rows = data.data.len();
 
-        Json(QueryResponse {
+        Ok(Json(QueryResponse {
             data: data.into(),
             state: state.state,
             schema: QueryResponseField::from_schema(schema),
@@ -195,6 +254,7 @@ impl QueryResponse {
         .with_header(HEADER_QUERY_ID, id.clone())
         .with_header(HEADER_QUERY_STATE, state.state.to_string())
         .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+        .into_response())
     }
 
     pub(crate) fn fail_to_start_sql(err: &ErrorCode) -> impl IntoResponse {
@@ -241,7 +301,7 @@ async fn query_final_handler(
                         StatusCode::BAD_REQUEST,
                     ));
                 }
-                Ok(QueryResponse::from_internal(query_id, response, true))
+                QueryResponse::from_internal(query_id, response, true)
             }
             None => Err(query_id_not_found(&query_id, &ctx.node_id)),
         }
@@ -294,7 +354,7 @@ async fn query_state_handler(
         match http_query_manager.get_query(&query_id).await {
             Some(query) => {
                 let response = query.get_response_state_only().await;
-                Ok(QueryResponse::from_internal(query_id, response, false))
+                QueryResponse::from_internal(query_id, response, false)
             }
             None => Err(query_id_not_found(&query_id, &ctx.node_id)),
         }
@@ -307,6 +367,7 @@ async fn query_state_handler(
 async fn query_page_handler(
     ctx: &HttpQueryContext,
     Path((query_id, page_no)): Path<(String, usize)>,
+    headers: &HeaderMap,
 ) -> PoemResult<impl IntoResponse> {
     let trace_id = query_id_to_trace_id(&query_id);
     let root = Span::root(
@@ -319,11 +380,14 @@ async fn query_page_handler(
         match http_query_manager.get_query(&query_id).await {
             Some(query) => {
                 query.update_expire_time(true).await;
-                let resp = query.get_response_page(page_no).await.map_err(|err| {
-                    poem::Error::from_string(err.message(), StatusCode::NOT_FOUND)
-                })?;
+                let resp = query
+                    .get_response_page(page_no, page_format(headers))
+                    .await
+                    .map_err(|err| {
+                        poem::Error::from_string(err.message(), StatusCode::NOT_FOUND)
+                    })?;
                 query.update_expire_time(false).await;
-                Ok(QueryResponse::from_internal(query_id, resp, false))
+                QueryResponse::from_internal(query_id, resp, false)
             }
             None => Err(query_id_not_found(&query_id, &ctx.node_id)),
         }
@@ -336,6 +400,7 @@ async fn query_page_handler(
 #[async_backtrace::framed]
 pub(crate) async fn query_handler(
     ctx: &HttpQueryContext,
+    headers: &HeaderMap,
     Json(req): Json<HttpQueryRequest>,
 ) -> PoemResult<impl IntoResponse> {
     let trace_id = query_id_to_trace_id(&ctx.query_id);
@@ -354,20 +419,20 @@ pub(crate) async fn query_handler(
             Ok(query) => {
                 query.update_expire_time(true).await;
                 let resp = query
-                    .get_response_page(0)
+                    .get_response_page(0, page_format(headers))
                     .await
                     .map_err(|err| err.display_with_sql(&sql))
                     .map_err(|err| poem::Error::from_string(err.message(), StatusCode::NOT_FOUND))?;
                 let (rows, next_page) = match &resp.data {
                     None => (0, None),
-                    Some(p) => (p.page.data.num_rows(), p.next_page_no),
+                    Some(p) => (p.num_rows, p.next_page_no),
                 };
                 info!(
                     "http query initial response to http query_id={}, state={:?}, rows={}, next_page={:?}, sql='{}'",
                     &query.id, &resp.state, rows, next_page, mask_connection_info(&sql)
                 );
                 query.update_expire_time(false).await;
-                Ok(QueryResponse::from_internal(query.id.to_string(), resp, false).into_response())
+                QueryResponse::from_internal(query.id.to_string(), resp, false)
             }
             Err(e) => {
                 error!("{}: http query fail to start sql, error: {:?}", &ctx.query_id, e);
@@ -402,6 +467,25 @@ pub fn query_route() -> Route {
     route
 }
 
+/// The result pages are in Arrow IPC streaming format if the client accepts it.
+fn page_format(headers: &HeaderMap) -> PageFormat {
+    let accept_arrow = headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|v| v.trim().starts_with(CONTENT_TYPE_ARROW_STREAM));
+    if accept_arrow {
+        PageFormat::Arrow
+    } else {
+        PageFormat::Json
+    }
+}
+
+fn internal_error(e: impl ToString) -> PoemError {
+    PoemError::from_string(e.to_string(), StatusCode::INTERNAL_SERVER_ERROR)
+}
+
 fn query_id_not_found(query_id: &str, node_id: &str) -> PoemError {
     PoemError::from_string(
         format!("query id {} not found on {}", query_id, node_id),
@@ -76,6 +76,18 @@ impl JsonBlock {
         })
     }
 
+    pub fn from_blocks(
+        schema: DataSchemaRef,
+        blocks: &[DataBlock],
+        format: &FormatSettings,
+    ) -> Result<Self> {
+        let mut data = vec![];
+        for block in blocks {
+            data.extend(block_to_json_value(block, format)?);
+        }
+        Ok(JsonBlock { data, schema })
+    }
+
     pub fn concat(blocks: Vec<JsonBlock>) -> Self {
         if blocks.is_empty() {
             return Self::empty();
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod arrow_block;
 mod http_query_handlers;
 pub mod json_block;
 mod load;
@@ -30,6 +31,7 @@ pub(crate) use json_block::JsonBlock;
 pub use load::streaming_load;
 pub use load::LoadResponse;
 pub use query::ExecuteStateKind;
+pub use query::Expirable;
 pub use query::ExpiringMap;
 pub use query::ExpiringState;
 pub use query::HttpQueryContext;
@@ -44,6 +44,7 @@ use crate::servers::http::v1::query::sized_spsc::sized_spsc;
 use crate::servers::http::v1::query::ExecuteState;
 use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
+use crate::servers::http::v1::query::PageFormat;
 use crate::servers::http::v1::query::PageManager;
 use crate::servers::http::v1::query::ResponseData;
 use crate::servers::http::v1::query::Wait;
@@ -396,8 +397,12 @@ impl HttpQuery {
 
     #[async_backtrace::framed]
     #[minitrace::trace]
-    pub async fn get_response_page(&self, page_no: usize) -> Result<HttpQueryResponseInternal> {
-        let data = Some(self.get_page(page_no).await?);
+    pub async fn get_response_page(
+        &self,
+        page_no: usize,
+        format: PageFormat,
+    ) -> Result<HttpQueryResponseInternal> {
+        let data = Some(self.get_page(page_no, format).await?);
         let state = self.get_state().await;
         let session = self.get_response_session().await;
 
@@ -473,13 +478,15 @@ impl HttpQuery {
     }
 
     #[async_backtrace::framed]
-    async fn get_page(&self, page_no: usize) -> Result<ResponseData> {
+    async fn get_page(&self, page_no: usize, format: PageFormat) -> Result<ResponseData> {
         let mut page_manager = self.page_manager.lock().await;
         let page = page_manager
             .get_a_page(page_no, &self.request.pagination.get_wait_type())
             .await?;
+        // Serialize the page here, so the failure is reported the same as the failure of reading.
         let response = ResponseData {
-            page,
+            num_rows: page.num_rows(),
+            data: page.serialize(format)?,
             next_page_no: page_manager.next_page_no(),
         };
         Ok(response)
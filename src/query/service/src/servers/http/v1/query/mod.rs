@@ -25,6 +25,7 @@ pub(crate) use execute_state::ExecuteState;
 pub use execute_state::ExecuteStateKind;
 pub(crate) use execute_state::Executor;
 pub use execute_state::Progresses;
+pub use expirable::Expirable;
 pub use expirable::ExpiringState;
 pub use expiring_map::ExpiringMap;
 pub use http_query::HttpQuery;
@@ -36,6 +37,8 @@ pub use http_query::ResponseState;
 pub use http_query_context::HttpQueryContext;
 pub use http_query_manager::HttpQueryManager;
 pub use page_manager::Page;
+pub use page_manager::PageData;
+pub use page_manager::PageFormat;
 pub use page_manager::PageManager;
 pub use page_manager::ResponseData;
 pub use page_manager::Wait;
@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::VecDeque;
 use std::time::Instant;
 
 use common_base::base::tokio;
@@ -23,9 +22,8 @@ use common_expression::DataSchemaRef;
 use common_io::prelude::FormatSettings;
 use log::debug;
 use log::info;
-use serde_json::Value as JsonValue;
 
-use crate::servers::http::v1::json_block::block_to_json_value;
+use crate::servers::http::v1::arrow_block::blocks_to_arrow_stream;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
 use crate::servers::http::v1::JsonBlock;
 
@@ -35,14 +33,50 @@ pub enum Wait {
     Deadline(Instant),
 }
 
+/// The format of the rows of a page in the response, negotiated by each request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageFormat {
+    Json,
+    Arrow,
+}
+
+/// The rows of a page serialized in the format of the request.
+pub enum PageData {
+    Json(JsonBlock),
+    /// An Arrow IPC stream.
+    Arrow(Vec<u8>),
+}
+
+/// The rows of a page are kept as blocks, and serialized in the format
+/// negotiated by each request, so a page may be fetched again in another format.
 #[derive(Clone)]
 pub struct Page {
-    pub data: JsonBlock,
+    pub schema: DataSchemaRef,
+    pub data: Vec<DataBlock>,
     pub total_rows: usize,
+    format_settings: FormatSettings,
+}
+
+impl Page {
+    pub fn num_rows(&self) -> usize {
+        self.data.iter().map(|b| b.num_rows()).sum()
+    }
+
+    pub fn serialize(&self, format: PageFormat) -> Result<PageData> {
+        Ok(match format {
+            PageFormat::Json => PageData::Json(JsonBlock::from_blocks(
+                self.schema.clone(),
+                &self.data,
+                &self.format_settings,
+            )?),
+            PageFormat::Arrow => PageData::Arrow(blocks_to_arrow_stream(&self.schema, &self.data)?),
+        })
+    }
 }
 
 pub struct ResponseData {
-    pub page: Page,
+    pub num_rows: usize,
+    pub data: PageData,
     pub next_page_no: Option<usize>,
 }
 
@@ -55,7 +89,8 @@ pub struct PageManager {
     block_end: bool,
     schema: DataSchemaRef,
     last_page: Option<Page>,
-    row_buffer: VecDeque<Vec<JsonValue>>,
+    // the rest of the last received block, which did not fit in the last page
+    row_buffer: Option<DataBlock>,
     block_receiver: SizedChannelReceiver<DataBlock>,
     format_settings: FormatSettings,
 }
@@ -75,7 +110,7 @@ impl PageManager {
             total_pages: 0,
             end: false,
             block_end: false,
-            row_buffer: Default::default(),
+            row_buffer: None,
             schema,
             block_receiver,
             max_rows_per_page,
@@ -96,12 +131,14 @@ impl PageManager {
         let next_no = self.total_pages;
         if page_no == next_no {
             if !self.end {
-                let (block, end) = self.collect_new_page(tp).await?;
-                let num_row = block.num_rows();
+                let (blocks, end) = self.collect_new_page(tp).await?;
+                let num_row = blocks.iter().map(|b| b.num_rows()).sum::<usize>();
                 self.total_rows += num_row;
                 let page = Page {
-                    data: block,
+                    schema: self.schema.clone(),
+                    data: blocks,
                     total_rows: self.total_rows,
+                    format_settings: self.format_settings.clone(),
                 };
                 if num_row > 0 {
                     self.total_pages += 1;
@@ -130,41 +167,43 @@ impl PageManager {
         }
     }
 
+    /// Appends at most `remain` rows of the block to the page, the slices share
+    /// the buffers of the block. Returns the number of rows appended.
     fn append_block(
         &mut self,
-        rows: &mut Vec<Vec<JsonValue>>,
+        blocks: &mut Vec<DataBlock>,
         block: DataBlock,
         remain: usize,
-    ) -> Result<()> {
-        let format_settings = &self.format_settings;
-        let mut iter = block_to_json_value(&block, format_settings)?
-            .into_iter()
-            .peekable();
-        let chunk: Vec<_> = iter.by_ref().take(remain).collect();
-        rows.extend(chunk);
-        self.row_buffer = iter.by_ref().collect();
-        Ok(())
+    ) -> usize {
+        let num_rows = block.num_rows();
+        if num_rows > remain {
+            blocks.push(block.slice(0..remain));
+            self.row_buffer = Some(block.slice(remain..num_rows));
+            remain
+        } else {
+            if num_rows > 0 {
+                blocks.push(block);
+            }
+            num_rows
+        }
     }
 
     #[async_backtrace::framed]
-    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(JsonBlock, bool)> {
-        let mut res: Vec<Vec<JsonValue>> = Vec::with_capacity(self.max_rows_per_page);
-        while res.len() < self.max_rows_per_page {
-            if let Some(row) = self.row_buffer.pop_front() {
-                res.push(row)
-            } else {
-                break;
-            }
+    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(Vec<DataBlock>, bool)> {
+        let mut res: Vec<DataBlock> = vec![];
+        let mut num_rows = 0;
+        if let Some(block) = self.row_buffer.take() {
+            num_rows += self.append_block(&mut res, block, self.max_rows_per_page);
         }
         loop {
-            assert!(self.max_rows_per_page >= res.len());
-            let remain = self.max_rows_per_page - res.len();
+            assert!(self.max_rows_per_page >= num_rows);
+            let remain = self.max_rows_per_page - num_rows;
             if remain == 0 {
                 break;
             }
             match tp {
                 Wait::Async => match self.block_receiver.try_recv() {
-                    Some(block) => self.append_block(&mut res, block, remain)?,
+                    Some(block) => num_rows += self.append_block(&mut res, block, remain),
                     None => break,
                 },
                 Wait::Deadline(t) => {
@@ -177,7 +216,7 @@ impl PageManager {
                                 &self.query_id,
                                 block.num_rows()
                             );
-                            self.append_block(&mut res, block, remain)?;
+                            num_rows += self.append_block(&mut res, block, remain);
                         }
                         Ok(None) => {
                             info!("{}: http query reach end of blocks", &self.query_id);
@@ -192,17 +231,12 @@ impl PageManager {
             }
         }
 
-        let block = JsonBlock {
-            schema: self.schema.clone(),
-            data: res,
-        };
-
         // try to report 'no more data' earlier to client to avoid unnecessary http call
         if !self.block_end {
             self.block_end = self.block_receiver.is_empty();
         }
-        let end = self.block_end && self.row_buffer.is_empty();
-        Ok((block, end))
+        let end = self.block_end && self.row_buffer.is_none();
+        Ok((res, end))
     }
 
     #[async_backtrace::framed]
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -29,6 +30,7 @@ use common_expression::SendableDataBlockStream;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::UserIdentity;
 use common_metrics::mysql::*;
+use common_sql::plans::Plan;
 use common_sql::Planner;
 use common_users::CertifiedInfo;
 use common_users::UserApiProvider;
@@ -41,6 +43,7 @@ use opensrv_mysql::AsyncMysqlShim;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
+use opensrv_mysql::ParamValue;
 use opensrv_mysql::QueryResultWriter;
 use opensrv_mysql::StatementMetaWriter;
 use rand::RngCore;
@@ -48,6 +51,9 @@ use rand::RngCore;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
+use crate::servers::mysql::mysql_prepared_statement::CachedPlan;
+use crate::servers::mysql::mysql_prepared_statement::PreparedStatement;
+use crate::servers::mysql::writers::make_column_from_field;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -61,6 +67,8 @@ use crate::stream::DataBlockStream;
 
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    prepared_statements: HashMap<u32, PreparedStatement>,
+    last_statement_id: u32,
 }
 
 pub struct InteractiveWorker {
@@ -171,7 +179,17 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
             ));
         }
 
-        self.base.do_execute(id, param, writer).await
+        let mut writer = DFQueryResultWriter::create(writer, true);
+
+        let instant = Instant::now();
+        let query_result = self.base.do_execute(id, param).await;
+
+        let format = self.base.session.get_format_settings();
+
+        let write_result = writer.write(query_result, &format).await;
+        observe_mysql_process_request_duration(instant.elapsed());
+
+        write_result
     }
 
     /// https://dev.mysql.com/doc/internals/en/com-stmt-close.html
@@ -203,7 +221,7 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
                 ));
             }
 
-            let mut writer = DFQueryResultWriter::create(writer);
+            let mut writer = DFQueryResultWriter::create(writer, false);
 
             let instant = Instant::now();
             let query_result = self
@@ -271,38 +289,158 @@ impl InteractiveWorkerBase {
     }
 
     #[async_backtrace::framed]
-    async fn do_prepare<W: AsyncWrite + Unpin>(
-        &mut self,
-        _: &str,
-        writer: StatementMetaWriter<'_, W>,
+    async fn do_prepare<'a, W: AsyncWrite + Unpin>(
+        &'a mut self,
+        query: &str,
+        writer: StatementMetaWriter<'a, W>,
     ) -> Result<()> {
+        let statement = match self.prepare_statement(query).await {
+            Ok(statement) => statement,
+            Err(cause) => {
+                let cause = cause.display_with_sql(query);
+                error!("OnPrepare Error: {:?}", cause);
+                writer
+                    .error(ErrorKind::ER_UNKNOWN_ERROR, cause.to_string().as_bytes())
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        self.last_statement_id = self.last_statement_id.wrapping_add(1);
+        let id = self.last_statement_id;
+        self.prepared_statements.insert(id, statement);
+
+        let this: &'a Self = self;
+        let statement = &this.prepared_statements[&id];
         writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
+            .reply(id, &statement.params, &statement.columns)
             .await?;
         Ok(())
     }
 
     #[async_backtrace::framed]
-    async fn do_execute<W: AsyncWrite + Unpin>(
+    async fn prepare_statement(&self, query: &str) -> Result<PreparedStatement> {
+        info!("Prepare query: {}", query);
+        let mut statement = PreparedStatement::try_create(query)?;
+
+        // Describe the parameters and the result columns by planning the query with NULL
+        // parameters. Some queries can't be planned without the actual parameters (e.g.
+        // `LIMIT ?`), their result columns are described by the result set of COM_STMT_EXECUTE.
+        let schema = match self.federated_server_command_check(query) {
+            Some((schema, _)) => Some(schema),
+            None => {
+                let context = self.session.create_query_context().await?;
+                let mut planner = Planner::new(context);
+                let plan = if statement.typed {
+                    planner
+                        .plan_prepared(query, statement.null_params())
+                        .await
+                        .map(|(plan, extras, _)| {
+                            statement.describe_params(&extras.metadata.read());
+                            plan
+                        })
+                } else {
+                    planner
+                        .plan_sql(&statement.query_with_nulls())
+                        .await
+                        .map(|(plan, _)| plan)
+                };
+                match plan {
+                    Ok(plan) if plan.has_result_set() => Some(plan.schema()),
+                    _ => None,
+                }
+            }
+        };
+        if let Some(schema) = schema {
+            statement.columns = schema
+                .fields()
+                .iter()
+                .map(|field| make_column_from_field(field, true))
+                .collect::<Result<_>>()?;
+        }
+        Ok(statement)
+    }
+
+    #[async_backtrace::framed]
+    async fn do_execute(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
-        writer: QueryResultWriter<'_, W>,
-    ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        id: u32,
+        params: ParamParser<'_>,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        let (query, cached_plan) = match self.prepared_statements.get_mut(&id) {
+            Some(statement) => (statement.query().to_string(), statement.cached_plan.take()),
+            None => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Unknown prepared statement id: {}",
+                    id
+                )));
+            }
+        };
+        let params = params.into_iter().collect::<Vec<_>>();
+
+        self.do_execute_prepared(id, &query, &params, cached_plan)
+            .await
+            .map_err(|err| err.display_with_sql(&query))
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_execute_prepared(
+        &mut self,
+        id: u32,
+        query: &str,
+        params: &[ParamValue<'_>],
+        cached_plan: Option<CachedPlan>,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        let typed = self.prepared_statements[&id].typed;
+        if !typed || self.federated_server_command_check(query).is_some() {
+            let query = self.prepared_statements[&id].bind_literals(params)?;
+            return self.do_query(&query).await;
+        }
+
+        info!("Execute prepared query: {}", query);
+        let context = self.session.create_query_context().await?;
+        let tz = context.get_function_context()?.tz.tz;
+        let scalars = self.prepared_statements[&id].bind_scalars(params, &tz)?;
+        let cached_plan = match cached_plan {
+            Some(cached_plan) if cached_plan.try_reuse(&context, &scalars).await => {
+                Some(cached_plan)
+            }
+            _ => None,
+        };
+        let (plan, mask_sql, cached_plan) = match cached_plan {
+            Some(cached_plan) => {
+                let (plan, extras) = cached_plan.prepared_plan.plan(context.clone(), scalars)?;
+                (plan, extras.statement.to_mask_sql(), Some(cached_plan))
+            }
+            None => {
+                let mut planner = Planner::new(context.clone());
+                match planner.plan_prepared(query, scalars).await {
+                    Ok((plan, extras, prepared_plan)) => {
+                        let cached_plan = prepared_plan.and_then(|prepared_plan| {
+                            CachedPlan::try_create(&context, prepared_plan)
+                        });
+                        (plan, extras.statement.to_mask_sql(), cached_plan)
+                    }
+                    Err(_) => {
+                        // The binder only takes literals at some places (e.g. `LIMIT ?`), plan
+                        // the query with the literals of the parameters.
+                        let query = self.prepared_statements[&id].bind_literals(params)?;
+                        return self.do_query(&query).await;
+                    }
+                }
+            }
+        };
+        if let Some(statement) = self.prepared_statements.get_mut(&id) {
+            statement.cached_plan = cached_plan;
+        }
+
+        Self::execute_plan(context, query, &plan, mask_sql).await
+    }
+
+    #[async_backtrace::framed]
+    async fn do_close(&mut self, id: u32) {
+        self.prepared_statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -347,34 +485,42 @@ impl InteractiveWorkerBase {
 
                 let mut planner = Planner::new(context.clone());
                 let (plan, extras) = planner.plan_sql(query).await?;
+                Self::execute_plan(context, query, &plan, extras.statement.to_mask_sql()).await
+            }
+        }
+    }
 
-                context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
-
-                let has_result_set = plan.has_result_set();
-
-                match interpreter {
-                    Ok(interpreter) => {
-                        let (blocks, extra_info) =
-                            Self::exec_query(interpreter.clone(), &context).await?;
-                        let schema = plan.schema();
-                        let format = context.get_format_settings()?;
-                        Ok((
-                            QueryResult::create(
-                                blocks,
-                                extra_info,
-                                has_result_set,
-                                schema,
-                                query.to_string(),
-                            ),
-                            Some(format),
-                        ))
-                    }
-                    Err(e) => {
-                        InterpreterQueryLog::fail_to_start(context, e.clone());
-                        Err(e)
-                    }
-                }
+    #[async_backtrace::framed]
+    async fn execute_plan(
+        context: Arc<QueryContext>,
+        query: &str,
+        plan: &Plan,
+        mask_sql: String,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        context.attach_query_str(plan.kind(), mask_sql);
+        let interpreter = InterpreterFactory::get(context.clone(), plan).await;
+
+        let has_result_set = plan.has_result_set();
+
+        match interpreter {
+            Ok(interpreter) => {
+                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+                let schema = plan.schema();
+                let format = context.get_format_settings()?;
+                Ok((
+                    QueryResult::create(
+                        blocks,
+                        extra_info,
+                        has_result_set,
+                        schema,
+                        query.to_string(),
+                    ),
+                    Some(format),
+                ))
+            }
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                Err(e)
             }
         }
     }
@@ -448,7 +594,11 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                prepared_statements: HashMap::new(),
+                last_statement_id: 0,
+            },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
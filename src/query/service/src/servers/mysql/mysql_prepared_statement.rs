@@ -0,0 +1,417 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::token::Tokenizer;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::serialize::read_decimal_with_size;
+use common_expression::serialize::uniform_date;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::decimal::MAX_DECIMAL128_PRECISION;
+use common_expression::types::decimal::MAX_DECIMAL256_PRECISION;
+use common_expression::types::number::F64;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::DataField;
+use common_expression::Scalar;
+use common_meta_app::principal::UserSettingValue;
+use common_meta_app::schema::TableInfo;
+use common_sql::Metadata;
+use common_sql::PreparedPlan;
+use ethnum::i256;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
+use opensrv_mysql::ParamValue;
+use opensrv_mysql::ValueInner;
+
+use crate::servers::mysql::writers::make_column_from_field;
+use crate::sessions::QueryContext;
+
+/// A statement prepared by COM_STMT_PREPARE.
+///
+/// The parameters of COM_STMT_EXECUTE are bound to the `?` placeholders as typed values, and
+/// the bound plan of the last execution is reused if it's still valid. The statements whose
+/// values are parsed from the query text (e.g. `INSERT ... VALUES`) take the literals of the
+/// parameters in place of the placeholders instead.
+pub struct PreparedStatement {
+    query: String,
+    /// Byte ranges of the placeholders in the query.
+    placeholders: Vec<Range<usize>>,
+    /// Whether the parameters are bound as typed values, or as literals in the query text.
+    pub typed: bool,
+    /// Description of the parameters, one for each placeholder.
+    pub params: Vec<Column>,
+    /// Description of the result columns, empty if the statement has no result set or the
+    /// result set can only be known with the parameters.
+    pub columns: Vec<Column>,
+    /// The plan of the last execution.
+    pub cached_plan: Option<CachedPlan>,
+}
+
+impl PreparedStatement {
+    pub fn try_create(query: &str) -> Result<PreparedStatement> {
+        let mut placeholders = vec![];
+        let mut typed = true;
+        for (i, token) in Tokenizer::new(query).enumerate() {
+            let token = token?;
+            if i == 0 && matches!(token.kind, TokenKind::INSERT | TokenKind::REPLACE) {
+                typed = false;
+            }
+            if token.kind == TokenKind::Placeholder {
+                placeholders.push(token.span.start()..token.span.end());
+            }
+        }
+
+        let params = placeholders
+            .iter()
+            .map(|_| Column {
+                table: "".to_string(),
+                column: "?".to_string(),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            })
+            .collect();
+
+        Ok(PreparedStatement {
+            query: query.to_string(),
+            placeholders,
+            typed,
+            params,
+            columns: vec![],
+            cached_plan: None,
+        })
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// The `NULL` parameters, which are used to describe the parameters and the result columns.
+    pub fn null_params(&self) -> Vec<Scalar> {
+        vec![Scalar::Null; self.placeholders.len()]
+    }
+
+    /// The query with all the placeholders replaced by `NULL`.
+    pub fn query_with_nulls(&self) -> String {
+        self.replace_placeholders(vec!["NULL".to_string(); self.placeholders.len()])
+    }
+
+    /// Describe the parameters with the types of the placeholders inferred by the binder.
+    pub fn describe_params(&mut self, metadata: &Metadata) {
+        let spans = metadata.placeholder_values().keys();
+        for (param, span) in self.params.iter_mut().zip(spans) {
+            if let Some(data_type) = metadata.placeholder_type(*span) {
+                let field = DataField::new("?", data_type.clone());
+                if let Ok(column) = make_column_from_field(&field, false) {
+                    param.coltype = column.coltype;
+                    if matches!(
+                        data_type,
+                        DataType::Number(
+                            NumberDataType::UInt8
+                                | NumberDataType::UInt16
+                                | NumberDataType::UInt32
+                                | NumberDataType::UInt64
+                        )
+                    ) {
+                        param.colflags |= ColumnFlags::UNSIGNED_FLAG;
+                    }
+                }
+            }
+        }
+    }
+
+    /// The query with the placeholders replaced by the literals of the parameters.
+    pub fn bind_literals(&self, params: &[ParamValue<'_>]) -> Result<String> {
+        self.check_params(params)?;
+        let literals = params
+            .iter()
+            .map(param_to_literal)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.replace_placeholders(literals))
+    }
+
+    /// The typed values of the parameters, the date times are in the timezone `tz`.
+    pub fn bind_scalars(&self, params: &[ParamValue<'_>], tz: &Tz) -> Result<Vec<Scalar>> {
+        self.check_params(params)?;
+        params
+            .iter()
+            .map(|param| param_to_scalar(param, tz))
+            .collect()
+    }
+
+    fn check_params(&self, params: &[ParamValue<'_>]) -> Result<()> {
+        if params.len() != self.placeholders.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared statement expects {} parameters, but got {}",
+                self.placeholders.len(),
+                params.len()
+            )));
+        }
+        Ok(())
+    }
+
+    fn replace_placeholders(&self, literals: Vec<String>) -> String {
+        let mut query = String::with_capacity(self.query.len());
+        let mut last = 0;
+        for (placeholder, literal) in self.placeholders.iter().zip(literals) {
+            query.push_str(&self.query[last..placeholder.start]);
+            query.push_str(&literal);
+            last = placeholder.end;
+        }
+        query.push_str(&self.query[last..]);
+        query
+    }
+}
+
+/// A bound plan of a prepared statement.
+///
+/// A plan is bound to the tables, and the snapshots of the tables, read when it was built.
+/// So it's reused only if all the tables read by the query are unchanged, as well as the
+/// current database and the settings the query is planned with.
+pub struct CachedPlan {
+    pub prepared_plan: PreparedPlan,
+    current_catalog: String,
+    current_database: String,
+    settings: HashMap<String, UserSettingValue>,
+    tables: Vec<((String, String, String), TableInfo)>,
+    can_scan_from_agg_index: bool,
+}
+
+impl CachedPlan {
+    /// Returns `None` if the plan can't be reused.
+    ///
+    /// Only the plans of queries are prepared. The query with materialized CTEs is excluded,
+    /// because the CTE blocks are registered in the context when the query is bound. So is
+    /// the query with non-deterministic functions or UDFs.
+    pub fn try_create(ctx: &Arc<QueryContext>, prepared_plan: PreparedPlan) -> Option<CachedPlan> {
+        if !ctx.get_cacheable() || !ctx.get_materialized_ctes().read().is_empty() {
+            return None;
+        }
+
+        Some(CachedPlan {
+            prepared_plan,
+            current_catalog: ctx.get_current_catalog(),
+            current_database: ctx.get_current_database(),
+            settings: changed_settings(ctx),
+            tables: ctx
+                .get_tables_refs_by_name()
+                .into_iter()
+                .map(|(name, table)| (name, table.get_table_info().clone()))
+                .collect(),
+            can_scan_from_agg_index: ctx.get_can_scan_from_agg_index(),
+        })
+    }
+
+    /// Check whether the plan can be reused to execute with `params` in a new query context.
+    ///
+    /// The tables are attached to the context, the same as they are read by the binder.
+    pub async fn try_reuse(&self, ctx: &Arc<QueryContext>, params: &[Scalar]) -> bool {
+        let param_types = params
+            .iter()
+            .map(|param| param.as_ref().infer_data_type())
+            .collect::<Vec<_>>();
+        if self.prepared_plan.param_types() != param_types
+            || self.current_catalog != ctx.get_current_catalog()
+            || self.current_database != ctx.get_current_database()
+            || self.settings != changed_settings(ctx)
+        {
+            return false;
+        }
+
+        for ((catalog, database, name), table_info) in &self.tables {
+            match ctx.get_table(catalog, database, name).await {
+                Ok(table) if table.get_table_info() == table_info => {}
+                _ => return false,
+            }
+        }
+
+        if !self.can_scan_from_agg_index {
+            ctx.set_can_scan_from_agg_index(false);
+        }
+        true
+    }
+}
+
+fn changed_settings(ctx: &Arc<QueryContext>) -> HashMap<String, UserSettingValue> {
+    ctx.get_settings()
+        .get_changes()
+        .into_iter()
+        .map(|(name, change)| (name, change.value))
+        .collect()
+}
+
+fn param_to_literal(param: &ParamValue<'_>) -> Result<String> {
+    let literal = match param.value.into_inner() {
+        ValueInner::NULL => "NULL".to_string(),
+        ValueInner::Int(v) => v.to_string(),
+        ValueInner::UInt(v) => v.to_string(),
+        ValueInner::Double(v) if v.is_finite() => format!("{v:?}"),
+        ValueInner::Double(v) => format!("'{v}'::DOUBLE"),
+        ValueInner::Bytes(v) => match std::str::from_utf8(v) {
+            Ok(v) if is_decimal(param.coltype) && v.parse::<f64>().is_ok() => v.to_string(),
+            Ok(v) => quote_string(v),
+            // The bytes are not a valid string literal, pass them through unchanged.
+            Err(_) => format!("unhex('{}')", hex::encode(v)),
+        },
+        ValueInner::Date(v) => format!("'{}'", parse_date(v)?),
+        ValueInner::Datetime(v) => format!("'{}'", parse_datetime(v)?),
+        ValueInner::Time(v) => format!("'{}'", parse_time(v)?),
+    };
+    Ok(literal)
+}
+
+fn param_to_scalar(param: &ParamValue<'_>, tz: &Tz) -> Result<Scalar> {
+    let scalar = match param.value.into_inner() {
+        ValueInner::NULL => Scalar::Null,
+        ValueInner::Int(v) => Scalar::Number(NumberScalar::Int64(v)),
+        ValueInner::UInt(v) => Scalar::Number(NumberScalar::UInt64(v)),
+        ValueInner::Double(v) => Scalar::Number(NumberScalar::Float64(F64::from(v))),
+        ValueInner::Bytes(v) if is_decimal(param.coltype) => parse_decimal(v)?,
+        ValueInner::Bytes(v) => Scalar::String(v.to_vec()),
+        ValueInner::Date(v) => Scalar::Date(uniform_date(parse_date(v)?)),
+        ValueInner::Datetime(v) => {
+            let datetime = parse_datetime(v)?;
+            let datetime = tz
+                .from_local_datetime(&datetime)
+                .earliest()
+                .ok_or_else(|| invalid_param("datetime", v))?;
+            Scalar::Timestamp(datetime.timestamp_micros())
+        }
+        ValueInner::Time(v) => Scalar::String(parse_time(v)?.into_bytes()),
+    };
+    Ok(scalar)
+}
+
+fn is_decimal(coltype: ColumnType) -> bool {
+    matches!(
+        coltype,
+        ColumnType::MYSQL_TYPE_DECIMAL | ColumnType::MYSQL_TYPE_NEWDECIMAL
+    )
+}
+
+/// Parse the decimal sent as a string, the precision and scale are the digits of the string.
+fn parse_decimal(v: &[u8]) -> Result<Scalar> {
+    let invalid = || invalid_param("decimal", v);
+    let (integer, fraction) = match v.iter().position(|c| *c == b'.') {
+        Some(pos) => (&v[..pos], &v[pos + 1..]),
+        None => (v, &v[v.len()..]),
+    };
+    let integer = integer.strip_prefix(b"-").unwrap_or(integer);
+    if !integer.iter().chain(fraction).all(u8::is_ascii_digit) {
+        return Err(invalid());
+    }
+
+    let precision = (integer.len() + fraction.len()).max(1);
+    if precision > MAX_DECIMAL256_PRECISION as usize {
+        return Err(invalid());
+    }
+    let size = DecimalSize {
+        precision: precision as u8,
+        scale: fraction.len() as u8,
+    };
+    let decimal = if size.precision <= MAX_DECIMAL128_PRECISION {
+        let (n, _) = read_decimal_with_size::<i128>(v, size, true, true).map_err(|_| invalid())?;
+        DecimalScalar::Decimal128(n, size)
+    } else {
+        let (n, _) = read_decimal_with_size::<i256>(v, size, true, true).map_err(|_| invalid())?;
+        DecimalScalar::Decimal256(n, size)
+    };
+    Ok(Scalar::Decimal(decimal))
+}
+
+fn parse_date(v: &[u8]) -> Result<NaiveDate> {
+    match v {
+        [y0, y1, month, day, ..] => NaiveDate::from_ymd_opt(
+            u16::from_le_bytes([*y0, *y1]) as i32,
+            *month as u32,
+            *day as u32,
+        )
+        .ok_or_else(|| invalid_param("date", v)),
+        _ => Err(invalid_param("date", v)),
+    }
+}
+
+fn parse_datetime(v: &[u8]) -> Result<NaiveDateTime> {
+    let date = parse_date(v)?;
+    let time = match v {
+        [_, _, _, _] => Some(NaiveTime::MIN),
+        [_, _, _, _, hour, minute, second] => {
+            NaiveTime::from_hms_opt(*hour as u32, *minute as u32, *second as u32)
+        }
+        [_, _, _, _, hour, minute, second, m0, m1, m2, m3] => NaiveTime::from_hms_micro_opt(
+            *hour as u32,
+            *minute as u32,
+            *second as u32,
+            u32::from_le_bytes([*m0, *m1, *m2, *m3]),
+        ),
+        _ => None,
+    };
+    let time = time.ok_or_else(|| invalid_param("datetime", v))?;
+    Ok(date.and_time(time))
+}
+
+/// Parse the time, which is a duration that may be negative or longer than a day, to a string.
+fn parse_time(v: &[u8]) -> Result<String> {
+    match v {
+        [] => Ok("00:00:00".to_string()),
+        [negative, d0, d1, d2, d3, hour, minute, second, rest @ ..] => {
+            let sign = if *negative == 1 { "-" } else { "" };
+            let hours = u32::from_le_bytes([*d0, *d1, *d2, *d3]) * 24 + *hour as u32;
+            match rest {
+                [m0, m1, m2, m3] => {
+                    let micros = u32::from_le_bytes([*m0, *m1, *m2, *m3]);
+                    Ok(format!(
+                        "{sign}{hours:02}:{minute:02}:{second:02}.{micros:06}"
+                    ))
+                }
+                _ => Ok(format!("{sign}{hours:02}:{minute:02}:{second:02}")),
+            }
+        }
+        _ => Err(invalid_param("time", v)),
+    }
+}
+
+fn quote_string(v: &str) -> String {
+    let mut quoted = String::with_capacity(v.len() + 2);
+    quoted.push('\'');
+    for c in v.chars() {
+        match c {
+            '\'' => quoted.push_str("\\'"),
+            '\\' => quoted.push_str("\\\\"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
+
+fn invalid_param(typ: &str, v: &[u8]) -> ErrorCode {
+    ErrorCode::BadBytes(format!(
+        "Invalid {typ} parameter of prepared statement: {v:?}"
+    ))
+}
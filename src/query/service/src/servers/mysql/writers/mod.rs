@@ -16,6 +16,7 @@ mod init_result_writer;
 mod query_result_writer;
 
 pub use self::init_result_writer::DFInitResultWriter;
+pub use self::query_result_writer::make_column_from_field;
 pub use self::query_result_writer::DFQueryResultWriter;
 pub use self::query_result_writer::ProgressReporter;
 pub use self::query_result_writer::QueryResult;
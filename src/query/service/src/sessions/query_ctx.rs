@@ -43,6 +43,7 @@ use common_catalog::query_kind::QueryKind;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
@@ -207,6 +208,11 @@ impl QueryContext {
         self.shared.attach_table(catalog, database, name, table)
     }
 
+    /// Get all tables that already attached in this query, keyed by catalog, database and table name.
+    pub fn get_tables_refs_by_name(&self) -> Vec<((String, String, String), Arc<dyn Table>)> {
+        self.shared.get_tables_refs_by_name()
+    }
+
     pub fn get_exchange_manager(&self) -> Arc<DataExchangeManager> {
         DataExchangeManager::instance()
     }
@@ -819,6 +825,10 @@ impl TableContext for QueryContext {
             .unwrap_or_default()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
+
     fn get_queries_profile(&self) -> HashMap<String, Vec<Arc<Profile>>> {
         let mut queries_profile = SessionManager::instance().get_queries_profile();
 
@@ -23,6 +23,7 @@ use std::time::SystemTime;
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
 use common_catalog::catalog::CatalogManager;
+use common_catalog::catalog::CATALOG_DEFAULT;
 use common_catalog::query_kind::QueryKind;
 use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::StageAttachment;
@@ -232,6 +233,15 @@ impl QueryContextShared {
         tables.values().cloned().collect()
     }
 
+    /// Get all tables that already attached in this query, with the names they are attached by.
+    pub fn get_tables_refs_by_name(&self) -> Vec<(DatabaseAndTable, Arc<dyn Table>)> {
+        let tables = self.tables_refs.lock();
+        tables
+            .iter()
+            .map(|(name, table)| (name.clone(), table.clone()))
+            .collect()
+    }
+
     pub fn get_data_metrics(&self) -> StorageMetrics {
         let tables = self.get_tables_refs();
         let metrics: Vec<Arc<StorageMetrics>> =
@@ -296,8 +306,29 @@ impl QueryContextShared {
     ) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
+        // The temporary tables of the session shadow the normal tables with the same name.
+        let temp_table_info = if catalog == CATALOG_DEFAULT {
+            self.session
+                .temp_tbl_mgr()
+                .lock()
+                .get_table(database, table)
+        } else {
+            None
+        };
         let catalog = self.catalog_manager.get_catalog(&tenant, catalog).await?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let mut cache_table = match temp_table_info {
+            Some(table_info) => catalog.get_table_by_info(&table_info)?,
+            None => catalog.get_table(tenant.as_str(), database, table).await?,
+        };
+        // Read the uncommitted changes if the table has been mutated in the explicit transaction.
+        let buffered_table_info = self
+            .session
+            .txn_mgr()
+            .lock()
+            .get_table_from_buffer(cache_table.get_id());
+        if let Some(table_info) = buffered_table_info {
+            cache_table = catalog.get_table_by_info(&table_info)?;
+        }
 
         let mut tables_refs = self.tables_refs.lock();
 
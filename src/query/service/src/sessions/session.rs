@@ -16,6 +16,10 @@ use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use common_catalog::temp_table::TempTblMgr;
+use common_catalog::temp_table::TempTblMgrRef;
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -27,6 +31,7 @@ use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserPrivilegeType;
 use common_settings::ChangeValue;
 use common_settings::Settings;
+use common_storages_memory::MemoryTable;
 use common_users::GrantObjectVisibilityChecker;
 use log::debug;
 use parking_lot::RwLock;
@@ -50,6 +55,8 @@ pub struct Session {
     status: Arc<RwLock<SessionStatus>>,
     pub(in crate::sessions) mysql_connection_id: Option<u32>,
     format_settings: FormatSettings,
+    txn_mgr: TxnManagerRef,
+    temp_tbl_mgr: TempTblMgrRef,
 }
 
 impl Session {
@@ -69,6 +76,8 @@ impl Session {
             privilege_mgr,
             mysql_connection_id,
             format_settings: FormatSettings::default(),
+            txn_mgr: TxnManager::init(),
+            temp_tbl_mgr: TempTblMgr::init(),
         }))
     }
 
@@ -298,11 +307,23 @@ impl Session {
         self.session_ctx
             .update_query_ids_results(query_id, Some(result_cache_key))
     }
+
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
+    pub fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.temp_tbl_mgr.clone()
+    }
 }
 
 impl Drop for Session {
     fn drop(&mut self) {
         debug!("Drop session {}", self.id.clone());
+        // Temporary tables only live as long as the session.
+        for table_id in self.temp_tbl_mgr.lock().clear() {
+            MemoryTable::purge_data(table_id);
+        }
         SessionManager::instance().destroy_session(&self.id.clone());
     }
 }
@@ -21,6 +21,7 @@ use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table::Table;
 use common_catalog::table_args::TableArgs;
+use common_compress::DecompressReader;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::BooleanType;
@@ -33,27 +34,38 @@ use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
-use common_meta_app::principal::StageFileFormatType;
+use common_meta_app::principal::FileFormatParams;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::Pipeline;
+use common_pipeline_sources::input_formats::InputContext;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::binder::resolve_stage_location;
 use common_storage::init_stage_operator;
 use common_storage::read_parquet_schema_async;
 use common_storage::read_parquet_schema_async_rs;
+use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
+use futures::AsyncRead;
+use futures::AsyncReadExt;
+use opendal::Operator;
 
 use crate::pipelines::processors::OutputPort;
 use crate::sessions::TableContext;
 use crate::table_functions::infer_schema::table_args::InferSchemaArgsParsed;
+use crate::table_functions::infer_schema::text_schema::InferredSchema;
 use crate::table_functions::TableFunction;
 
 const INFER_SCHEMA: &str = "infer_schema";
 
+/// The text files are read in batches from this size, doubled each time more records are needed.
+const INFER_TEXT_READ_BATCH_SIZE: usize = 1024 * 1024;
+/// At most this many bytes (after decompression) are read from each text file.
+const INFER_TEXT_MAX_READ_BYTES: usize = 64 * 1024 * 1024;
+
 pub struct InferSchemaTable {
     table_info: TableInfo,
     args_parsed: InferSchemaArgsParsed,
@@ -187,14 +199,33 @@ impl AsyncSource for InferSchemaSource {
         };
         let operator = init_stage_operator(&stage_info)?;
 
-        let first_file = files_info.first_file(&operator).await?;
         let file_format_params = match &self.args_parsed.file_format {
             Some(f) => self.ctx.get_file_format(f).await?,
             None => stage_info.file_format_params.clone(),
         };
         let use_parquet2 = self.ctx.get_settings().get_use_parquet2()?;
-        let schema = match file_format_params.get_type() {
-            StageFileFormatType::Parquet => {
+        let schema = match file_format_params {
+            FileFormatParams::NdJson(_) | FileFormatParams::Csv(_) | FileFormatParams::Tsv(_) => {
+                let max_records = self.args_parsed.max_records_per_file;
+                let mut files = files_info
+                    .list(&operator, false, Some(self.args_parsed.max_file_count))
+                    .await?;
+                if files.is_empty() {
+                    return Err(ErrorCode::BadArguments("no file found"));
+                }
+                // Merge in a stable order, the listing order depends on the storage.
+                files.sort_by(|a, b| a.path.cmp(&b.path));
+
+                let mut inferred = InferredSchema::default();
+                for file in files {
+                    let file_schema =
+                        infer_text_file(&operator, &file, &file_format_params, max_records).await?;
+                    inferred.merge(file_schema);
+                }
+                inferred.into_table_schema()
+            }
+            FileFormatParams::Parquet(_) => {
+                let first_file = files_info.first_file(&operator).await?;
                 if use_parquet2 {
                     let arrow_schema =
                         read_parquet_schema_async(&operator, &first_file.path).await?;
@@ -211,7 +242,7 @@ impl AsyncSource for InferSchemaSource {
             }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, NDJSON, CSV and TSV",
                 ));
             }
         };
@@ -239,3 +270,59 @@ impl AsyncSource for InferSchemaSource {
         Ok(Some(block))
     }
 }
+
+/// Infer the schema from the first `max_records` records of a text file,
+/// only the leading bytes of the file that hold these records are read.
+#[async_backtrace::framed]
+async fn infer_text_file(
+    operator: &Operator,
+    file: &StageFileInfo,
+    file_format_params: &FileFormatParams,
+    max_records: usize,
+) -> Result<InferredSchema> {
+    let compression =
+        InputContext::get_compression_alg_copy(file_format_params.compression(), &file.path)?;
+    let mut reader: Box<dyn AsyncRead + Unpin + Send> = match compression {
+        Some(algo) => Box::new(DecompressReader::new(
+            operator.reader(&file.path).await?,
+            algo,
+        )),
+        None => {
+            let end = file.size.min(INFER_TEXT_MAX_READ_BYTES as u64);
+            Box::new(operator.reader_with(&file.path).range(0..end).await?)
+        }
+    };
+
+    let mut data = vec![];
+    let mut batch_size = INFER_TEXT_READ_BATCH_SIZE;
+    loop {
+        let start = data.len();
+        let want = batch_size.min(INFER_TEXT_MAX_READ_BYTES - start);
+        data.resize(start + want, 0);
+        let mut n = 0;
+        while n < want {
+            let read = reader.read(&mut data[start + n..]).await?;
+            if read == 0 {
+                break;
+            }
+            n += read;
+        }
+        data.truncate(start + n);
+        let is_eof = n < want;
+
+        let schema = match file_format_params {
+            FileFormatParams::Csv(params) => {
+                InferredSchema::infer_csv(&data, params, max_records, is_eof)?
+            }
+            FileFormatParams::Tsv(params) => {
+                InferredSchema::infer_tsv(&data, params, max_records, is_eof)?
+            }
+            _ => InferredSchema::infer_ndjson(&data, &file.path, max_records, is_eof)?,
+        };
+        if is_eof || schema.num_records() >= max_records || data.len() >= INFER_TEXT_MAX_READ_BYTES
+        {
+            return Ok(schema);
+        }
+        batch_size *= 2;
+    }
+}
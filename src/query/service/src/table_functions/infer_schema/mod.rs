@@ -14,5 +14,6 @@
 
 mod infer_schema_table;
 mod table_args;
+mod text_schema;
 
 pub use infer_schema_table::InferSchemaTable;
@@ -15,14 +15,25 @@
 use common_catalog::table_args::TableArgs;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::type_check::check_number;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_storage::StageFilesInfo;
 use common_storages_fuse::table_functions::string_value;
 
+/// Number of records sampled from each file to infer the schema of the text formats.
+const DEFAULT_MAX_RECORDS_PER_FILE: usize = 1000;
+
 #[derive(Clone)]
 pub(crate) struct InferSchemaArgsParsed {
     pub(crate) location: String,
     pub(crate) file_format: Option<String>,
     pub(crate) files_info: StageFilesInfo,
+    pub(crate) max_records_per_file: usize,
+    /// Merge the schema of at most this many files, only the first file by default.
+    pub(crate) max_file_count: usize,
 }
 
 impl InferSchemaArgsParsed {
@@ -36,6 +47,8 @@ impl InferSchemaArgsParsed {
             files: None,
             pattern: None,
         };
+        let mut max_records_per_file = DEFAULT_MAX_RECORDS_PER_FILE;
+        let mut max_file_count = 1;
 
         for (k, v) in &args {
             match k.to_lowercase().as_str() {
@@ -56,6 +69,12 @@ impl InferSchemaArgsParsed {
                 "file_format" => {
                     file_format = Some(string_value(v)?);
                 }
+                "max_records_per_file" => {
+                    max_records_per_file = positive_value(k, v)?;
+                }
+                "max_file_count" => {
+                    max_file_count = positive_value(k, v)?;
+                }
                 _ => {
                     return Err(ErrorCode::BadArguments(format!(
                         "unknown param {} for infer_schema",
@@ -73,6 +92,28 @@ impl InferSchemaArgsParsed {
             location,
             file_format,
             files_info,
+            max_records_per_file,
+            max_file_count,
         })
     }
 }
+
+fn positive_value(name: &str, value: &Scalar) -> Result<usize> {
+    let v = check_number::<_, u64>(
+        None,
+        &FunctionContext::default(),
+        &Expr::<usize>::Constant {
+            span: None,
+            scalar: value.clone(),
+            data_type: value.as_ref().infer_data_type(),
+        },
+        &BUILTIN_FUNCTIONS,
+    )?;
+    if v == 0 {
+        return Err(ErrorCode::BadArguments(format!(
+            "{} of infer_schema must be positive",
+            name
+        )));
+    }
+    Ok(v as usize)
+}
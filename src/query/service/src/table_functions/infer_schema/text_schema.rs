@@ -0,0 +1,393 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Infer the schema of text files (NDJSON, CSV and TSV) by sampling the values.
+
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_formats::RecordDelimiter;
+use common_io::constants::NULL_BYTES_ESCAPE;
+use common_meta_app::principal::CsvFileFormatParams;
+use common_meta_app::principal::TsvFileFormatParams;
+use csv_core::ReadRecordResult;
+use serde_json::Value as JsonValue;
+
+const TIMESTAMP_FORMATS: [&str; 2] = ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"];
+
+/// The type of a column inferred from the sampled values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum InferredType {
+    /// Only NULL values are seen so far.
+    Null,
+    Boolean,
+    Int64,
+    Float64,
+    Date,
+    Timestamp,
+    String,
+    /// JSON object or array.
+    Variant,
+}
+
+impl InferredType {
+    /// The narrowest type that can hold the values of both types.
+    fn merge(self, other: Self) -> Self {
+        use InferredType::*;
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Null, t) | (t, Null) => t,
+            (Int64, Float64) | (Float64, Int64) => Float64,
+            (Date, Timestamp) | (Timestamp, Date) => Timestamp,
+            (Variant, _) | (_, Variant) => Variant,
+            _ => String,
+        }
+    }
+
+    fn from_text(v: &str) -> Self {
+        if v.eq_ignore_ascii_case("true") || v.eq_ignore_ascii_case("false") {
+            return InferredType::Boolean;
+        }
+        if v.parse::<i64>().is_ok() {
+            return InferredType::Int64;
+        }
+        // Reject `inf`, `nan` and the like, they are more likely to be strings.
+        let is_number_like = match v.as_bytes().first() {
+            Some(&c) => c.is_ascii_digit() || matches!(c, b'-' | b'+' | b'.'),
+            None => false,
+        };
+        if is_number_like && v.parse::<f64>().is_ok() {
+            return InferredType::Float64;
+        }
+        Self::from_string(v)
+    }
+
+    fn from_string(v: &str) -> Self {
+        if NaiveDate::parse_from_str(v, "%Y-%m-%d").is_ok() {
+            InferredType::Date
+        } else if TIMESTAMP_FORMATS
+            .iter()
+            .any(|f| NaiveDateTime::parse_from_str(v, f).is_ok())
+            || DateTime::parse_from_rfc3339(v).is_ok()
+        {
+            InferredType::Timestamp
+        } else {
+            InferredType::String
+        }
+    }
+
+    fn from_json(v: &JsonValue) -> Self {
+        match v {
+            JsonValue::Null => InferredType::Null,
+            JsonValue::Bool(_) => InferredType::Boolean,
+            // Integers out of the range of i64 are widened to Float64.
+            JsonValue::Number(n) if n.is_i64() => InferredType::Int64,
+            JsonValue::Number(_) => InferredType::Float64,
+            JsonValue::String(s) => Self::from_string(s),
+            JsonValue::Array(_) | JsonValue::Object(_) => InferredType::Variant,
+        }
+    }
+
+    fn to_table_type(self) -> TableDataType {
+        match self {
+            InferredType::Null | InferredType::String => TableDataType::String,
+            InferredType::Boolean => TableDataType::Boolean,
+            InferredType::Int64 => TableDataType::Number(NumberDataType::Int64),
+            InferredType::Float64 => TableDataType::Number(NumberDataType::Float64),
+            InferredType::Date => TableDataType::Date,
+            InferredType::Timestamp => TableDataType::Timestamp,
+            InferredType::Variant => TableDataType::Variant,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct InferredField {
+    name: String,
+    data_type: InferredType,
+    nullable: bool,
+}
+
+/// Schema inferred from the sampled records, the columns keep the order they are first seen.
+#[derive(Debug, Default)]
+pub(crate) struct InferredSchema {
+    fields: Vec<InferredField>,
+    field_index: HashMap<String, usize>,
+    num_records: usize,
+}
+
+impl InferredSchema {
+    fn get_or_add_field(&mut self, name: &str) -> usize {
+        match self.field_index.get(name) {
+            Some(i) => *i,
+            None => {
+                // The column is missing in the previous records.
+                self.fields.push(InferredField {
+                    name: name.to_string(),
+                    data_type: InferredType::Null,
+                    nullable: self.num_records > 0,
+                });
+                self.field_index
+                    .insert(name.to_string(), self.fields.len() - 1);
+                self.fields.len() - 1
+            }
+        }
+    }
+
+    fn add_record<'a>(&mut self, values: impl Iterator<Item = (&'a str, InferredType)>) {
+        let mut seen = vec![false; self.fields.len()];
+        for (name, data_type) in values {
+            let i = self.get_or_add_field(name);
+            if i >= seen.len() {
+                seen.resize(i + 1, false);
+            }
+            seen[i] = true;
+            let field = &mut self.fields[i];
+            field.data_type = field.data_type.merge(data_type);
+            field.nullable |= data_type == InferredType::Null;
+        }
+        for (field, seen) in self.fields.iter_mut().zip(seen) {
+            field.nullable |= !seen;
+        }
+        self.num_records += 1;
+    }
+
+    /// Merge the schema inferred from another file, the columns are matched by name.
+    pub(crate) fn merge(&mut self, other: InferredSchema) {
+        if other.num_records == 0 {
+            return;
+        }
+        if self.num_records == 0 {
+            *self = other;
+            return;
+        }
+        for field in self.fields.iter_mut() {
+            field.nullable |= !other.field_index.contains_key(&field.name);
+        }
+        for other_field in other.fields {
+            let i = self.get_or_add_field(&other_field.name);
+            let field = &mut self.fields[i];
+            field.data_type = field.data_type.merge(other_field.data_type);
+            field.nullable |= other_field.nullable;
+        }
+        self.num_records += other.num_records;
+    }
+
+    pub(crate) fn into_table_schema(self) -> TableSchema {
+        let fields = self
+            .fields
+            .into_iter()
+            .map(|f| {
+                let data_type = f.data_type.to_table_type();
+                if f.nullable {
+                    TableField::new(&f.name, data_type.wrap_nullable())
+                } else {
+                    TableField::new(&f.name, data_type)
+                }
+            })
+            .collect();
+        TableSchema::new(fields)
+    }
+
+    /// Number of the records the schema is inferred from.
+    pub(crate) fn num_records(&self) -> usize {
+        self.num_records
+    }
+
+    /// Infer from the first `max_records` lines of a NDJSON file, each line must be an object.
+    ///
+    /// If `data` is only a prefix of the file, the last incomplete line is ignored.
+    pub(crate) fn infer_ndjson(
+        data: &[u8],
+        path: &str,
+        max_records: usize,
+        is_eof: bool,
+    ) -> Result<Self> {
+        let mut schema = Self::default();
+        let lines = complete_records(data, b'\n', is_eof)
+            .split(|c| *c == b'\n')
+            .enumerate()
+            .filter(|(_, line)| !line.iter().all(|c| c.is_ascii_whitespace()))
+            .take(max_records);
+        for (n, line) in lines {
+            let value: JsonValue = serde_json::from_slice(line).map_err(|e| {
+                ErrorCode::BadBytes(format!(
+                    "fail to parse line {} of {} as json: {}",
+                    n + 1,
+                    path,
+                    e
+                ))
+            })?;
+            match value {
+                JsonValue::Object(obj) => schema.add_record(
+                    obj.iter()
+                        .map(|(k, v)| (k.as_str(), InferredType::from_json(v))),
+                ),
+                _ => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "line {} of {} is not a json object",
+                        n + 1,
+                        path
+                    )));
+                }
+            }
+        }
+        Ok(schema)
+    }
+
+    pub(crate) fn infer_csv(
+        data: &[u8],
+        params: &CsvFileFormatParams,
+        max_records: usize,
+        is_eof: bool,
+    ) -> Result<Self> {
+        let escape = if params.escape.is_empty() {
+            None
+        } else {
+            Some(params.escape.as_bytes()[0])
+        };
+        let mut reader = csv_core::ReaderBuilder::new()
+            .delimiter(params.field_delimiter.as_bytes()[0])
+            .quote(params.quote.as_bytes()[0])
+            .escape(escape)
+            .terminator(match params.record_delimiter.as_str().try_into()? {
+                RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
+                RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
+            })
+            .build();
+
+        let max_records = max_records.saturating_add(params.headers as usize);
+        let mut records = vec![];
+        let mut input = data;
+        let mut output = vec![0u8; 4096];
+        let mut ends = vec![0usize; 128];
+        let mut output_pos = 0;
+        let mut ends_pos = 0;
+        while records.len() < max_records {
+            let (result, n_in, n_out, n_ends) =
+                reader.read_record(input, &mut output[output_pos..], &mut ends[ends_pos..]);
+            input = &input[n_in..];
+            output_pos += n_out;
+            ends_pos += n_ends;
+            match result {
+                // The next empty input marks the end of the file, the record in the middle
+                // of a prefix is not complete.
+                ReadRecordResult::InputEmpty if !is_eof && input.is_empty() => break,
+                ReadRecordResult::InputEmpty => {}
+                ReadRecordResult::OutputFull => output.resize(output.len() * 2, 0),
+                ReadRecordResult::OutputEndsFull => ends.resize(ends.len() * 2, 0),
+                ReadRecordResult::Record => {
+                    let mut start = 0;
+                    let record = ends[..ends_pos]
+                        .iter()
+                        .map(|end| {
+                            let field = String::from_utf8_lossy(&output[start..*end]).to_string();
+                            start = *end;
+                            field
+                        })
+                        .collect::<Vec<_>>();
+                    records.push(record);
+                    output_pos = 0;
+                    ends_pos = 0;
+                }
+                ReadRecordResult::End => break,
+            }
+        }
+        Ok(Self::infer_text_records(
+            records,
+            params.headers as usize,
+            &params.null_display,
+        ))
+    }
+
+    pub(crate) fn infer_tsv(
+        data: &[u8],
+        params: &TsvFileFormatParams,
+        max_records: usize,
+        is_eof: bool,
+    ) -> Result<Self> {
+        let field_delimiter = params.field_delimiter.as_bytes()[0];
+        let record_delimiter = match params.record_delimiter.as_str().try_into()? {
+            RecordDelimiter::Crlf => b'\n',
+            RecordDelimiter::Any(v) => v,
+        };
+        let max_records = max_records.saturating_add(params.headers as usize);
+        let records = complete_records(data, record_delimiter, is_eof)
+            .split(|c| *c == record_delimiter)
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+            .filter(|line| !line.is_empty())
+            .take(max_records)
+            .map(|line| {
+                line.split(|c| *c == field_delimiter)
+                    .map(|v| String::from_utf8_lossy(v).to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        Ok(Self::infer_text_records(
+            records,
+            params.headers as usize,
+            NULL_BYTES_ESCAPE,
+        ))
+    }
+
+    /// The column names are taken from the first header line, or named `c1`, `c2`, ... if no header.
+    fn infer_text_records(records: Vec<Vec<String>>, headers: usize, null_display: &str) -> Self {
+        let mut records = records.into_iter();
+        let mut names = vec![];
+        if headers > 0 {
+            if let Some(header) = records.next() {
+                names = header;
+            }
+            // Skip the rest header lines.
+            for _ in 1..headers {
+                records.next();
+            }
+        }
+
+        let mut schema = Self::default();
+        for record in records {
+            for i in names.len()..record.len() {
+                names.push(format!("c{}", i + 1));
+            }
+            schema.add_record(record.iter().enumerate().map(|(i, v)| {
+                let data_type = if v.is_empty() || v == null_display {
+                    InferredType::Null
+                } else {
+                    InferredType::from_text(v)
+                };
+                (names[i].as_str(), data_type)
+            }));
+        }
+        schema
+    }
+}
+
+/// Strip the last incomplete record if `data` is only a prefix of the file.
+fn complete_records(data: &[u8], record_delimiter: u8, is_eof: bool) -> &[u8] {
+    if is_eof {
+        return data;
+    }
+    match data.iter().rposition(|c| *c == record_delimiter) {
+        Some(end) => &data[..end],
+        None => &[],
+    }
+}
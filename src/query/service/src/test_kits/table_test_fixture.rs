@@ -283,6 +283,8 @@ impl TestFixture {
             ]
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
+            temporary: false,
+            clone_source: None,
             as_select: None,
             cluster_key: Some("(id)".to_string()),
         }
@@ -307,6 +309,8 @@ impl TestFixture {
             ]
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
+            temporary: false,
+            clone_source: None,
             as_select: None,
             cluster_key: None,
         }
@@ -342,6 +346,8 @@ impl TestFixture {
             ]
             .into(),
             field_comments: vec![],
+            temporary: false,
+            clone_source: None,
             as_select: None,
             cluster_key: None,
         }
@@ -386,6 +392,8 @@ impl TestFixture {
             ]
             .into(),
             field_comments: vec![],
+            temporary: false,
+            clone_source: None,
             as_select: None,
             cluster_key: None,
         }
@@ -20,8 +20,12 @@ use std::io::Write;
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetPrimaryKeys;
+use arrow_flight::sql::CommandGetTables;
 use arrow_flight::utils::flight_data_to_batches;
 use arrow_flight::FlightData;
+use arrow_flight::FlightInfo;
 use arrow_schema::ArrowError;
 use common_base::base::tokio;
 use common_config::InnerConfig;
@@ -66,15 +70,22 @@ async fn run_query(
         affected_rows.to_string()
     } else {
         let flight_info = stmt.execute().await?;
-        let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
-        let flight_data = client.do_get(ticket).await?;
-        let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
-        let batches = flight_data_to_batches(&flight_data)?;
-        pretty_format_batches(batches.as_slice())?.to_string()
+        fetch_flight_info(client, flight_info).await?
     };
     Ok(res)
 }
 
+async fn fetch_flight_info(
+    client: &mut FlightSqlServiceClient<Channel>,
+    flight_info: FlightInfo,
+) -> std::result::Result<String, ArrowError> {
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+    let batches = flight_data_to_batches(&flight_data)?;
+    Ok(pretty_format_batches(batches.as_slice())?.to_string())
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -109,7 +120,7 @@ async fn test_query() -> Result<()> {
     let request_future = async {
         let mut mint = Mint::new("tests/it/servers/flight_sql/testdata");
         let mut file = mint.new_goldenfile("query.txt").unwrap();
-        let mut client = client_with_uds(path).await;
+        let mut client = client_with_uds(path.clone()).await;
         let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
         debug!("Auth succeeded with token: {:?}", token);
         let cases = [
@@ -133,6 +144,75 @@ async fn test_query() -> Result<()> {
             };
             writeln!(file, "{}", res).unwrap();
         }
+
+        let metadata_cases = [
+            (
+                "execute: select * from test1",
+                client
+                    .execute("select * from test1".to_string(), None)
+                    .await,
+            ),
+            (
+                "get_db_schemas: catalog=default, pattern=def%",
+                client
+                    .get_db_schemas(CommandGetDbSchemas {
+                        catalog: Some("default".to_string()),
+                        db_schema_filter_pattern: Some("def%".to_string()),
+                    })
+                    .await,
+            ),
+            (
+                "get_tables: catalog=default, schema=default, pattern=test%",
+                client
+                    .get_tables(CommandGetTables {
+                        catalog: Some("default".to_string()),
+                        db_schema_filter_pattern: Some("default".to_string()),
+                        table_name_filter_pattern: Some("test%".to_string()),
+                        table_types: vec![],
+                        include_schema: false,
+                    })
+                    .await,
+            ),
+            ("get_table_types", client.get_table_types().await),
+            (
+                "get_primary_keys: test1",
+                client
+                    .get_primary_keys(CommandGetPrimaryKeys {
+                        catalog: None,
+                        db_schema: Some("default".to_string()),
+                        table: "test1".to_string(),
+                    })
+                    .await,
+            ),
+        ];
+        for (case, flight_info) in metadata_cases {
+            writeln!(file, "---------- Input ----------").unwrap();
+            writeln!(file, "{}", case).unwrap();
+            writeln!(file, "---------- Output ---------").unwrap();
+            let res = match flight_info {
+                Ok(flight_info) => fetch_flight_info(&mut client, flight_info).await,
+                Err(e) => Err(e),
+            };
+            let res = match res {
+                Ok(s) => s,
+                Err(e) => format!("{e:?}"),
+            };
+            writeln!(file, "{}", res).unwrap();
+        }
+
+        // The statement is only accessible by the session that plans it.
+        let flight_info = client.execute("select 1".to_string(), None).await.unwrap();
+        let mut other_client = client_with_uds(path).await;
+        other_client
+            .handshake(TEST_USER, TEST_PASSWORD)
+            .await
+            .unwrap();
+        assert!(
+            fetch_flight_info(&mut other_client, flight_info.clone())
+                .await
+                .is_err()
+        );
+        assert!(fetch_flight_info(&mut client, flight_info).await.is_ok());
     };
     tokio::pin!(serve_future);
 
@@ -14,11 +14,16 @@
 
 use std::collections::BTreeMap;
 use std::fs::File;
+use std::io::Cursor;
 use std::io::Read;
 use std::time::Duration;
 
 use base64::engine::general_purpose;
 use base64::prelude::*;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
 use common_base::base::get_free_tcp_port;
 use common_base::base::tokio;
 use common_exception::ErrorCode;
@@ -30,6 +35,7 @@ use common_users::EnsureUser;
 use databend_query::auth::AuthMgr;
 use databend_query::servers::http::middleware::HTTPSessionEndpoint;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::v1::arrow_block::CONTENT_TYPE_ARROW_STREAM;
 use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::make_state_uri;
@@ -562,6 +568,123 @@ async fn test_pagination() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_pagination_arrow() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select number, number::string from numbers(5)";
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 5, "max_rows_per_page": 2}});
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri("/v1/query".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::ACCEPT, CONTENT_TYPE_ARROW_STREAM)
+        .typed_header(basic.clone())
+        .body(serde_json::to_vec(&json)?);
+    let mut response = ep
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+
+    let mut numbers = vec![];
+    let mut pages = 0;
+    loop {
+        assert_eq!(response.status(), StatusCode::OK);
+        let get_header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .map(|v| v.to_str().unwrap().to_string())
+        };
+        let query_id = get_header("X-DATABEND-QUERY-ID").unwrap();
+        let next_uri = if response.content_type() == Some(CONTENT_TYPE_ARROW_STREAM) {
+            let next_uri = get_header("X-DATABEND-QUERY-NEXT-URI");
+            let rows = get_header("X-DATABEND-QUERY-PAGE-ROWS").unwrap();
+            let body = response.into_body().into_vec().await.unwrap();
+            let mut reader = Cursor::new(body);
+            let metadata = read_stream_metadata(&mut reader)?;
+            assert_eq!(metadata.schema.fields.len(), 2);
+            let mut page_rows = 0;
+            for state in StreamReader::new(reader, metadata, None) {
+                if let StreamState::Some(chunk) = state? {
+                    let array = chunk.arrays()[0]
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<u64>>()
+                        .unwrap();
+                    numbers.extend(array.values_iter().copied());
+                    page_rows += chunk.len();
+                }
+            }
+            assert_eq!(rows, page_rows.to_string());
+            pages += 1;
+            next_uri
+        } else {
+            // the query is still running when the last page is fetched
+            let (_, result) = check_response(response).await?;
+            result.next_uri
+        };
+
+        match next_uri {
+            Some(uri) if uri != make_final_uri(&query_id) => {
+                let req = Request::builder()
+                    .uri(uri.parse().unwrap())
+                    .method(Method::GET)
+                    .header(header::ACCEPT, CONTENT_TYPE_ARROW_STREAM)
+                    .typed_header(basic.clone())
+                    .finish();
+                response = ep
+                    .call(req)
+                    .await
+                    .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+            }
+            _ => break,
+        }
+    }
+    assert_eq!(pages, 3);
+    assert_eq!(numbers, vec![0, 1, 2, 3, 4]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_affect_arrow() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let json = serde_json::json!({"sql": "use system", "pagination": {"wait_time_secs": 5}});
+    let req = Request::builder()
+        .uri("/v1/query".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::ACCEPT, CONTENT_TYPE_ARROW_STREAM)
+        .typed_header(headers::Authorization::basic("root", ""))
+        .body(serde_json::to_vec(&json)?);
+    let response = ep
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.content_type(), Some(CONTENT_TYPE_ARROW_STREAM));
+    let get_header = |name: &str| response.headers().get(name).map(|v| v.as_bytes().to_vec());
+    assert_eq!(
+        get_header("X-DATABEND-QUERY-STATE"),
+        Some(b"Succeeded".to_vec())
+    );
+    let affect = match get_header("X-DATABEND-QUERY-AFFECT") {
+        Some(v) => Some(serde_json::from_slice::<QueryAffect>(&v)?),
+        None => None,
+    };
+    assert_eq!(
+        affect,
+        Some(QueryAffect::UseDB {
+            name: "system".to_string()
+        })
+    );
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_http_session() -> Result<()> {
     let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
@@ -28,6 +28,7 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestGlobalServices;
+use mysql_async::consts::ColumnType;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
@@ -55,6 +56,81 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    // Setup
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    let statement = connection
+        .prep("SELECT ? + 1, ?, 'a?'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    assert_eq!(statement.num_params(), 2);
+    assert_eq!(statement.columns().len(), 3);
+
+    for (param, expected) in [(1, "it's"), (41, "a\\b")] {
+        let row: Option<(i64, String, String)> = connection
+            .exec_first(&statement, (param, expected))
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+        assert_eq!(
+            row,
+            Some((param + 1, expected.to_string(), "a?".to_string()))
+        );
+    }
+
+    connection
+        .close(statement)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Close failed")?;
+
+    // The bytes are bound unchanged, even if they are not a valid UTF-8 string.
+    let row: Option<String> = connection
+        .exec_first("SELECT hex(?)", (vec![0xffu8, 0x00, 0x61],))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(row, Some("ff0061".to_string()));
+
+    // The cached plan is not reused after the table is changed.
+    connection
+        .query_drop("CREATE TABLE t_prepared(a INT)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Create table failed")?;
+    let statement = connection
+        .prep("SELECT count(*) FROM t_prepared WHERE a > ?")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    assert_eq!(
+        statement.params()[0].column_type(),
+        ColumnType::MYSQL_TYPE_LONG
+    );
+    for (insert, expected) in [
+        ("INSERT INTO t_prepared VALUES (1)", 1),
+        ("INSERT INTO t_prepared VALUES (2), (3)", 3),
+    ] {
+        connection
+            .query_drop(insert)
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Insert failed")?;
+        for _ in 0..2 {
+            let row: Option<u64> = connection
+                .exec_first(&statement, (0,))
+                .await
+                .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+            assert_eq!(row, Some(expected));
+        }
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_connect_with_tls() -> Result<()> {
     // Setup
@@ -33,6 +33,7 @@ use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
@@ -704,6 +705,10 @@ impl TableContext for CtxDelegation {
         self.ctx.get_license_key()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
+
     fn get_queries_profile(&self) -> HashMap<String, Vec<Arc<Profile>>> {
         todo!()
     }
@@ -80,6 +80,8 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         ]
         .into(),
         field_comments: vec![],
+        temporary: false,
+        clone_source: None,
         as_select: None,
         cluster_key: None,
     }
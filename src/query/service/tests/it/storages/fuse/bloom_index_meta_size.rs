@@ -341,6 +341,9 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         bloom_filter_index_size: 0,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        deletion_vector: None,
+        inverted_index_location: None,
+        inverted_index_size: 0,
     };
 
     let block_metas = (0..num_blocks_per_seg)
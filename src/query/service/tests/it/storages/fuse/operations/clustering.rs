@@ -52,6 +52,8 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
         ]
         .into(),
         field_comments: vec![],
+        temporary: false,
+        clone_source: None,
         as_select: None,
         cluster_key: None,
     };
@@ -20,6 +20,7 @@ use common_base::base::tokio;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_storages_fuse::io::MetaWriter;
+use common_storages_fuse::operations::pending_clone_reference;
 use common_storages_fuse::FuseTable;
 use databend_query::test_kits::table_test_fixture::append_sample_data;
 use databend_query::test_kits::table_test_fixture::check_data_dir;
@@ -124,6 +125,41 @@ async fn test_fuse_purge_normal_orphan_snapshot() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_purge_stale_pending_clone_reference() -> Result<()> {
+    let fixture = TestFixture::new().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    fixture.create_default_table().await?;
+
+    append_sample_data(1, &fixture).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot_location = fuse_table.snapshot_loc().await?.unwrap();
+
+    // a clone being created, and a clone whose creation was interrupted
+    // beyond the retention period (12 hours by default).
+    let operator = fuse_table.get_operator();
+    let location_gen = fuse_table.meta_location_generator();
+    let pending = location_gen.clone_reference_location(&pending_clone_reference(Utc::now()));
+    let stale = location_gen
+        .clone_reference_location(&pending_clone_reference(Utc::now() - Duration::days(2)));
+    operator.write(&pending, snapshot_location.clone()).await?;
+    operator.write(&stale, snapshot_location.clone()).await?;
+
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+    let references = fuse_table.list_clone_references(&table_ctx, true).await?;
+    assert_eq!(references, vec![snapshot_location.clone()]);
+    assert!(operator.is_exist(&stale).await?);
+
+    let references = fuse_table.list_clone_references(&table_ctx, false).await?;
+    assert_eq!(references, vec![snapshot_location]);
+    assert!(operator.is_exist(&pending).await?);
+    assert!(!operator.is_exist(&stale).await?);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_fuse_purge_orphan_retention() -> Result<()> {
     // verifies that:
@@ -169,6 +169,37 @@ async fn test_table_update_analyze_statistics() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_analyze_histogram() -> Result<()> {
+    let fixture = TestFixture::new().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture.execute_command("create table t(c int)").await?;
+    // half of the rows are 0, the others are distinct.
+    fixture
+        .execute_command("insert into t select if(number < 500, 0, number) from numbers(1000)")
+        .await?;
+    ctx.evict_table_from_cache("default", "default", "t")?;
+    fixture.execute_command("analyze table default.t").await?;
+
+    let table = ctx.get_table("default", "default", "t").await?;
+    let provider = table.column_statistics_provider().await?;
+    let histogram = provider.histogram(0).unwrap();
+    assert_eq!(histogram.most_common_values, vec![(
+        Scalar::Number(NumberScalar::Int32(0)),
+        500.0
+    )]);
+    assert_eq!(histogram.buckets.len(), 100);
+    let num_values: f64 = histogram.buckets.iter().map(|b| b.num_values).sum();
+    assert_eq!(num_values, 500.0);
+    assert_eq!(
+        histogram.buckets[0].lower_bound,
+        Scalar::Number(NumberScalar::Int32(500))
+    );
+
+    Ok(())
+}
+
 async fn check_column_ndv_statistics(
     table: Arc<dyn Table>,
     expected: HashMap<u32, u64>,
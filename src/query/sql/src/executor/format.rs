@@ -45,6 +45,7 @@ use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::RangeJoinType;
 use crate::executor::physical_plans::ReclusterSink;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::RuntimeFilterSource;
 use crate::executor::physical_plans::Sort;
@@ -226,6 +227,7 @@ fn to_format_tree(
             Ok(FormatTreeNode::new("MergeIntoAppendNotMatched".to_string()))
         }
         PhysicalPlan::CteScan(plan) => cte_scan_to_format_tree(plan),
+        PhysicalPlan::RecursiveCteScan(plan) => recursive_cte_scan_to_format_tree(plan, metadata),
         PhysicalPlan::MaterializedCte(plan) => {
             materialized_cte_to_format_tree(plan, metadata, profs)
         }
@@ -387,6 +389,23 @@ fn cte_scan_to_format_tree(plan: &CteScan) -> Result<FormatTreeNode<String>> {
     ]))
 }
 
+fn recursive_cte_scan_to_format_tree(
+    plan: &RecursiveCteScan,
+    metadata: &Metadata,
+) -> Result<FormatTreeNode<String>> {
+    let children = vec![
+        FormatTreeNode::new(format!("CTE index: {}", plan.cte_idx.0)),
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+    ];
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCteScan".to_string(),
+        children,
+    ))
+}
+
 fn constant_table_scan_to_format_tree(
     plan: &ConstantTableScan,
     metadata: &Metadata,
@@ -690,6 +709,10 @@ fn window_to_format_tree(
         FormatTreeNode::new(format!("frame: [{frame}]")),
     ];
 
+    if let Some(limit) = plan.limit {
+        children.push(FormatTreeNode::new(format!("limit: {limit}")));
+    }
+
     append_profile_info(&mut children, prof_span_set, plan.plan_id);
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
@@ -867,6 +890,7 @@ fn range_join_to_format_tree(
         match plan.range_join_type {
             RangeJoinType::IEJoin => "IEJoin".to_string(),
             RangeJoinType::Merge => "MergeJoin".to_string(),
+            RangeJoinType::Asof => "AsofJoin".to_string(),
         },
         children,
     ))
@@ -966,6 +990,13 @@ fn union_all_to_format_tree(
         format_output_columns(plan.output_schema()?, metadata, true)
     ))];
 
+    if let Some(cte_idx) = &plan.cte_idx {
+        children.push(FormatTreeNode::new(format!(
+            "recursive CTE index: {}",
+            cte_idx.0
+        )));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -108,6 +108,9 @@ impl PhysicalPlanBuilder {
                     .await
             }
             RelOperator::CteScan(cte_scan) => self.build_cte_scan(cte_scan, required).await,
+            RelOperator::RecursiveCteScan(scan) => {
+                self.build_recursive_cte_scan(scan, required).await
+            }
             RelOperator::MaterializedCte(cte) => {
                 self.build_materialized_cte(s_expr, cte, required).await
             }
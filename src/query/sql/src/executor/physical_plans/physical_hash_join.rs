@@ -348,6 +348,7 @@ impl PhysicalPlanBuilder {
                 ));
                 probe_fields
             }
+            JoinType::Asof | JoinType::LeftAsof => unreachable!(),
         };
         let mut projections = ColumnSet::new();
         let projected_schema = DataSchemaRefExt::create(merged_fields.clone());
@@ -30,10 +30,16 @@ pub enum PhysicalJoinType {
     Hash,
     // The first arg is range conditions, the second arg is other conditions
     RangeJoin(Vec<ScalarExpr>, Vec<ScalarExpr>),
+    AsofJoin,
 }
 
 // Choose physical join type by join conditions
 pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
+    if matches!(join.join_type, JoinType::Asof | JoinType::LeftAsof) {
+        // Asof join is always executed by the sort based range join
+        return Ok(PhysicalJoinType::AsofJoin);
+    }
+
     if !join.left_conditions.is_empty() {
         // Contain equi condition, use hash join
         return Ok(PhysicalJoinType::Hash);
@@ -154,6 +160,10 @@ impl PhysicalPlanBuilder {
                 self.build_range_join(s_expr, left_required, right_required, range, other)
                     .await
             }
+            PhysicalJoinType::AsofJoin => {
+                self.build_asof_join(join, s_expr, left_required, right_required)
+                    .await
+            }
         }
     }
 }
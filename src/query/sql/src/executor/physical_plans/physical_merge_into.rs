@@ -45,6 +45,9 @@ pub struct MergeInto {
     // used to record the index of target table's field in merge_source_schema
     pub field_index_of_input_schema: HashMap<FieldIndex, usize>,
     pub row_id_idx: usize,
+    // the index of the target table's `_block_name` in merge_source_schema,
+    // it's used to generate the stream columns of the updated rows.
+    pub block_name_idx: Option<usize>,
     pub segments: Vec<(usize, Location)>,
     pub output_schema: DataSchemaRef,
     pub distributed: bool,
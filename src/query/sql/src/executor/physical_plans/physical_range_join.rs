@@ -15,6 +15,7 @@
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::type_check::common_super_type;
+use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::RemoteExpr;
@@ -29,6 +30,8 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::SExpr;
+use crate::plans::FunctionCall;
+use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::ScalarExpr;
 use crate::TypeCheck;
@@ -44,7 +47,7 @@ pub struct RangeJoin {
     pub conditions: Vec<RangeJoinCondition>,
     // The other conditions
     pub other_conditions: Vec<RemoteExpr>,
-    // Now only support inner join, will support left/right join later
+    // Now only support inner join and asof join, will support left/right join later
     pub join_type: JoinType,
     pub range_join_type: RangeJoinType,
 
@@ -55,7 +58,16 @@ pub struct RangeJoin {
 impl RangeJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let mut fields = self.left.output_schema()?.fields().clone();
-        fields.extend(self.right.output_schema()?.fields().clone());
+        let right_fields = self.right.output_schema()?.fields().clone();
+        if self.join_type == JoinType::LeftAsof {
+            fields.extend(
+                right_fields
+                    .iter()
+                    .map(|field| DataField::new(field.name(), field.data_type().wrap_nullable())),
+            );
+        } else {
+            fields.extend(right_fields);
+        }
         Ok(DataSchemaRefExt::create(fields))
     }
 }
@@ -64,13 +76,15 @@ impl RangeJoin {
 pub enum RangeJoinType {
     IEJoin,
     Merge,
+    // The first condition is the match condition, the others are equi conditions(`eq`).
+    Asof,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct RangeJoinCondition {
     pub left_expr: RemoteExpr,
     pub right_expr: RemoteExpr,
-    // "gt" | "lt" | "gte" | "lte"
+    // "gt" | "lt" | "gte" | "lte", or "eq" for the equi conditions of asof join
     pub operator: String,
 }
 
@@ -140,6 +154,64 @@ impl PhysicalPlanBuilder {
             stat_info: Some(self.build_plan_stat_info(s_expr)?),
         }))
     }
+
+    pub async fn build_asof_join(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+    ) -> Result<PhysicalPlan> {
+        // Different from other range joins, the left side of asof join is the left child,
+        // all its rows are kept in order to find the closest row of the right side.
+        let left_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
+
+        let left_side = self.build(s_expr.child(0)?, left_required).await?;
+        let right_side = self.build(s_expr.child(1)?, right_required).await?;
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+
+        debug_assert_eq!(join.non_equi_conditions.len(), 1);
+        let equi_conditions = join
+            .left_conditions
+            .iter()
+            .zip(join.right_conditions.iter())
+            .map(|(left, right)| {
+                ScalarExpr::FunctionCall(FunctionCall {
+                    span: None,
+                    func_name: "eq".to_string(),
+                    params: vec![],
+                    arguments: vec![left.clone(), right.clone()],
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+            plan_id: self.next_plan_id(),
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            conditions: join
+                .non_equi_conditions
+                .iter()
+                .chain(equi_conditions.iter())
+                .map(|scalar| {
+                    resolve_range_condition(
+                        scalar,
+                        &left_schema,
+                        &right_schema,
+                        &left_prop,
+                        &right_prop,
+                    )
+                })
+                .collect::<Result<_>>()?,
+            other_conditions: vec![],
+            join_type: join.join_type.clone(),
+            range_join_type: RangeJoinType::Asof,
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
 }
 
 fn resolve_range_condition(
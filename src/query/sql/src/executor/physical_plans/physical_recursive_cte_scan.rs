@@ -0,0 +1,52 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::ColumnSet;
+use crate::IndexType;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCteScan {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub cte_idx: (IndexType, IndexType),
+    pub output_schema: DataSchemaRef,
+}
+
+impl RecursiveCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.output_schema.clone())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_recursive_cte_scan(
+        &mut self,
+        recursive_cte_scan: &crate::plans::RecursiveCteScan,
+        _required: ColumnSet,
+    ) -> Result<PhysicalPlan> {
+        // The working table always contains all the columns of the recursive cte,
+        // so the columns can't be pruned.
+        Ok(PhysicalPlan::RecursiveCteScan(RecursiveCteScan {
+            plan_id: self.next_plan_id(),
+            cte_idx: recursive_cte_scan.cte_idx,
+            output_schema: DataSchemaRefExt::create(recursive_cte_scan.fields.clone()),
+        }))
+    }
+}
@@ -486,17 +486,29 @@ impl PhysicalPlanBuilder {
 
         let virtual_columns = self.build_virtual_columns(&scan.columns);
 
+        // The rows are sampled after reading, so the limit and order by
+        // can't be used to skip reading.
+        let (limit, order_by) = match &scan.sample {
+            Some(sample) if sample.is_row_level() => (None, vec![]),
+            _ => (scan.limit, order_by.unwrap_or_default()),
+        };
+        // The pruning result of a sample without seed can't be cached.
+        if matches!(&scan.sample, Some(sample) if sample.seed().is_none()) {
+            is_deterministic = false;
+        }
+
         Ok(PushDownInfo {
             projection: Some(projection),
             output_columns,
             filters: push_down_filter,
             is_deterministic,
             prewhere: prewhere_info,
-            limit: scan.limit,
-            order_by: order_by.unwrap_or_default(),
+            limit,
+            order_by,
             virtual_columns,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index: None,
+            sample: scan.sample.clone(),
         })
     }
 
@@ -43,6 +43,8 @@ pub struct UnionAll {
     pub right: Box<PhysicalPlan>,
     pub pairs: Vec<(String, String)>,
     pub schema: DataSchemaRef,
+    // The working table of the recursive cte, see `crate::plans::UnionAll`.
+    pub cte_idx: Option<(IndexType, IndexType)>,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -185,6 +187,7 @@ impl PhysicalPlanBuilder {
             right: Box::new(right_plan),
             pairs,
             schema: DataSchemaRefExt::create(fields),
+            cte_idx: union_all.cte_idx,
 
             stat_info: Some(stat_info),
         }))
@@ -25,6 +25,7 @@ use common_catalog::plan::InternalColumn;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
+use common_expression::types::DataType;
 use common_expression::ColumnId;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
@@ -57,6 +58,7 @@ pub enum ExprContext {
     WhereClause,
     GroupClaue,
     HavingClause,
+    QualifyClause,
     OrderByClause,
     LimitClause,
 
@@ -160,6 +162,19 @@ pub struct CteInfo {
     pub stat_info: Option<Arc<StatInfo>>,
     // If cte is materialized, save it's columns
     pub columns: Vec<ColumnBinding>,
+    // If the cte is defined in a `WITH RECURSIVE` clause
+    pub recursive: bool,
+}
+
+/// The working table of a recursive cte, the self-references in its recursive term
+/// are bound to a `RecursiveCteScan` of this table.
+#[derive(Clone, Debug)]
+pub struct RecursiveCteWorkingTable {
+    // The materialized cte holding the working table, see `crate::plans::RecursiveCteScan`
+    pub cte_idx: (IndexType, IndexType),
+    pub columns: Vec<(String, DataType)>,
+    // Estimated by the anchor of the recursive cte
+    pub stat_info: Arc<StatInfo>,
 }
 
 impl BindContext {
@@ -26,6 +26,7 @@ use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_catalog::catalog::CatalogManager;
+use common_catalog::plan::ChangeType;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -40,6 +41,7 @@ use log::warn;
 use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
+use crate::binder::RecursiveCteWorkingTable;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::CreateFileFormatPlan;
@@ -91,6 +93,11 @@ pub struct Binder {
     /// Use `IndexMap` because need to keep the insertion order
     /// Then wrap materialized ctes to main plan.
     pub ctes_map: Box<IndexMap<String, CteInfo>>,
+    /// Working tables of the recursive ctes whose recursive term is being bound, the key is cte name.
+    pub r_cte_working_tables: HashMap<String, RecursiveCteWorkingTable>,
+    /// Sides of the changes of the standard stream being bound, the key is the table alias
+    /// of the side in the generated changes query.
+    pub stream_change_sides: HashMap<String, ChangeType>,
 }
 
 impl<'a> Binder {
@@ -109,6 +116,8 @@ impl<'a> Binder {
             eq_scalars: vec![],
             m_cte_bound_s_expr: Default::default(),
             ctes_map: Box::default(),
+            r_cte_working_tables: Default::default(),
+            stream_change_sides: Default::default(),
         }
     }
 
@@ -310,6 +319,8 @@ impl<'a> Binder {
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
             Statement::RefreshIndex(stmt) => self.bind_refresh_index(bind_context, stmt).await?,
+            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(stmt).await?,
+            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumn(stmt) => self.bind_create_virtual_column(stmt).await?,
@@ -588,6 +599,9 @@ impl<'a> Binder {
             Statement::DropPipe(_) => {
                 todo!()
             }
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Abort => Plan::Abort,
         };
         Ok(plan)
     }
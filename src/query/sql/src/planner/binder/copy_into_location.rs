@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_ast::ast::ColumnID;
 use common_ast::ast::CopyIntoLocationSource;
 use common_ast::ast::CopyIntoLocationStmt;
+use common_ast::ast::Expr;
 use common_ast::ast::Statement;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
@@ -21,9 +23,11 @@ use common_ast::Dialect;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::StageInfo;
+use itertools::Itertools;
 
 use crate::binder::copy_into_table::resolve_file_location;
 use crate::binder::Binder;
+use crate::normalize_identifier;
 use crate::plans::CopyIntoLocationPlan;
 use crate::plans::Plan;
 use crate::BindContext;
@@ -35,6 +39,12 @@ impl<'a> Binder {
         bind_context: &mut BindContext,
         stmt: &CopyIntoLocationStmt,
     ) -> Result<Plan> {
+        if !stmt.partition_by.is_empty() {
+            return self
+                .bind_partitioned_copy_into_location(bind_context, stmt)
+                .await;
+        }
+
         let query = match &stmt.src {
             CopyIntoLocationSource::Table(table) => {
                 let (catalog_name, database_name, table_name) = self
@@ -73,6 +83,87 @@ impl<'a> Binder {
             stage: Box::new(stage_info),
             path,
             from: Box::new(query),
+            partition_by: vec![],
+        }))
+    }
+
+    /// Rewrite the source as
+    /// `SELECT * EXCLUDE (<column>, ...), to_string(<expr>), ... FROM <source>`,
+    /// the appended columns are the values of the partition keys. Like Hive, the columns
+    /// used as partition keys are only kept in the paths, not in the unloaded files.
+    #[async_backtrace::framed]
+    async fn bind_partitioned_copy_into_location(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &CopyIntoLocationStmt,
+    ) -> Result<Plan> {
+        let source = match &stmt.src {
+            CopyIntoLocationSource::Table(table) => {
+                let (catalog_name, database_name, table_name) = self
+                    .normalize_object_identifier_triple(
+                        &table.catalog,
+                        &table.database,
+                        &table.table,
+                    );
+                format!("{catalog_name}.{database_name}.{table_name}")
+            }
+            CopyIntoLocationSource::Query(query) => format!("({query})"),
+        };
+        let partition_values = stmt
+            .partition_by
+            .iter()
+            .map(|expr| format!("to_string({expr})"))
+            .join(", ");
+        let partition_columns = stmt
+            .partition_by
+            .iter()
+            .filter_map(|expr| match expr {
+                Expr::ColumnRef {
+                    column: column @ ColumnID::Name(_),
+                    ..
+                } => Some(column.to_string()),
+                _ => None,
+            })
+            .unique()
+            .join(", ");
+        let subquery = if partition_columns.is_empty() {
+            format!("SELECT *, {partition_values} FROM {source}")
+        } else {
+            format!("SELECT * EXCLUDE ({partition_columns}), {partition_values} FROM {source}")
+        };
+        let tokens = tokenize_sql(&subquery)?;
+        let (sub_stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+        let query = match &sub_stmt {
+            Statement::Query(_) => self.bind_statement(bind_context, &sub_stmt).await?,
+            _ => {
+                return Err(ErrorCode::SyntaxException(
+                    "COPY INTO <location> FROM <non-query> is invalid",
+                ));
+            }
+        };
+
+        // The partition key is the column name if partitioned by a column.
+        let partition_by = stmt
+            .partition_by
+            .iter()
+            .map(|expr| match expr {
+                Expr::ColumnRef {
+                    column: ColumnID::Name(column),
+                    ..
+                } => normalize_identifier(column, &self.name_resolution_ctx).name,
+                _ => expr.to_string(),
+            })
+            .collect();
+
+        let (mut stage_info, path) = resolve_file_location(&self.ctx, &stmt.dst).await?;
+        self.apply_copy_into_location_options(stmt, &mut stage_info)
+            .await?;
+
+        Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
+            stage: Box::new(stage_info),
+            path,
+            from: Box::new(query),
+            partition_by,
         }))
     }
 
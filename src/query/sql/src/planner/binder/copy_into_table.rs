@@ -153,6 +153,7 @@ impl<'a> Binder {
                 stage_info,
                 files_to_copy: None,
                 is_select: false,
+                partition_by: vec![],
                 default_values: Some(default_values),
             },
             values_consts: vec![],
@@ -174,7 +175,7 @@ impl<'a> Binder {
     ) -> Result<Plan> {
         if matches!(
             plan.stage_table_info.stage_info.file_format_params,
-            FileFormatParams::Parquet(_)
+            FileFormatParams::Parquet(_) | FileFormatParams::Orc(_) | FileFormatParams::Arrow(_)
         ) {
             let select_list = plan
                 .required_source_schema
@@ -286,6 +287,7 @@ impl<'a> Binder {
                 stage_info,
                 files_to_copy: None,
                 is_select: false,
+                partition_by: vec![],
                 default_values: Some(default_values),
             },
             write_mode,
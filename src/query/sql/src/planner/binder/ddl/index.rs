@@ -15,7 +15,9 @@
 use std::sync::Arc;
 
 use common_ast::ast::CreateIndexStmt;
+use common_ast::ast::CreateInvertedIndexStmt;
 use common_ast::ast::DropIndexStmt;
+use common_ast::ast::DropInvertedIndexStmt;
 use common_ast::ast::ExplainKind;
 use common_ast::ast::Identifier;
 use common_ast::ast::Query;
@@ -30,6 +32,7 @@ use common_ast::Dialect;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::TableDataType;
 use common_license::license::Feature::AggregateIndex;
 use common_license::license_manager::get_license_manager;
 use common_meta_app::schema::GetIndexReq;
@@ -42,7 +45,9 @@ use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
 use crate::AggregatingIndexChecker;
@@ -223,6 +228,90 @@ impl Binder {
         Ok(Plan::DropIndex(Box::new(plan)))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(
+                "Inverted index only support FUSE engine",
+            ));
+        }
+
+        let schema = table_info.schema();
+        let mut column_names = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column = self.normalize_object_identifier(column);
+            let field = schema.field_with_name(&column)?;
+            if field.data_type().remove_nullable() != TableDataType::String {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Inverted index only support String type, but the type of column {} is {}",
+                    column,
+                    field.data_type()
+                )));
+            }
+            if column_names.contains(&column) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Column {} is duplicated in the inverted index",
+                    column
+                )));
+            }
+            column_names.push(column);
+        }
+
+        let plan = CreateInvertedIndexPlan {
+            if_not_exists: *if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns: column_names,
+        };
+        Ok(Plan::CreateInvertedIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let plan = DropInvertedIndexPlan {
+            if_exists: *if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        };
+        Ok(Plan::DropInvertedIndex(Box::new(plan)))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_refresh_index(
         &mut self,
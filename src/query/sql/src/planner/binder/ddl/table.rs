@@ -54,6 +54,7 @@ use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
 use common_ast::Dialect;
+use common_catalog::catalog::CATALOG_DEFAULT;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -68,14 +69,18 @@ use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_functions::SPACE_FILLING_CURVE_FUNCTIONS;
 use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
+use common_storages_deltalake::DeltaTable;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use log::debug;
 use log::error;
 use storages_common_table_meta::table::is_reserved_opt_key;
+use storages_common_table_meta::table::OPT_KEY_CLONE_BASE_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
@@ -98,6 +103,7 @@ use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTableSource;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
@@ -401,15 +407,42 @@ impl Binder {
             cluster_by,
             as_query,
             transient,
+            temporary,
             engine,
             uri_location,
         } = stmt;
 
+        if let Some(CreateTableSource::Clone { .. }) = source {
+            return self.bind_create_table_clone(stmt).await;
+        }
+
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
-        // Take FUSE engine AS default engine
-        let engine = engine.unwrap_or(Engine::Fuse);
+        let engine = if *temporary {
+            // Temporary tables are kept in the memory of the session that creates them.
+            if catalog != CATALOG_DEFAULT {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Temporary table can only be created in the default catalog, but got {catalog}"
+                )));
+            }
+            if uri_location.is_some() {
+                return Err(ErrorCode::BadArguments(
+                    "Temporary table can not be created with an external location",
+                ));
+            }
+            match engine {
+                None | Some(Engine::Memory) => Engine::Memory,
+                Some(engine) => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Temporary table only supports the MEMORY engine, but got {engine}"
+                    )));
+                }
+            }
+        } else {
+            // Take FUSE engine AS default engine
+            engine.unwrap_or(Engine::Fuse)
+        };
         let mut options: BTreeMap<String, String> = BTreeMap::new();
         for table_option in table_options.iter() {
             self.insert_table_option_with_validation(
@@ -452,6 +485,16 @@ impl Binder {
 
         // Build table schema
         let (schema, field_comments) = match (&source, &as_query) {
+            (None, None) if engine == Engine::Delta => {
+                // The schema of a delta table is read from its transaction log.
+                let sp = storage_params.as_ref().ok_or_else(|| {
+                    ErrorCode::BadArguments("DELTA table must be created with a location")
+                })?;
+                (Arc::new(DeltaTable::infer_schema(sp).await?), vec![])
+            }
+            _ if engine == Engine::Delta => Err(ErrorCode::BadArguments(
+                "DELTA table can not be created with columns or AS SELECT, the schema is read from the delta log",
+            ))?,
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
                 self.analyze_create_table_schema(source).await?
@@ -594,6 +637,8 @@ impl Binder {
             options,
             field_comments,
             cluster_key,
+            temporary: *temporary,
+            clone_source: None,
             as_select: if let Some(query) = as_query {
                 let mut bind_context = BindContext::new();
                 let stmt = Statement::Query(Box::new(*query.clone()));
@@ -609,6 +654,160 @@ impl Binder {
         Ok(Plan::CreateTable(Box::new(plan)))
     }
 
+    /// Bind `CREATE TABLE ... CLONE`.
+    ///
+    /// The new table starts from the snapshot of the source table, the data is
+    /// shared rather than copied.
+    #[async_backtrace::framed]
+    async fn bind_create_table_clone(&mut self, stmt: &CreateTableStmt) -> Result<Plan> {
+        let CreateTableStmt {
+            if_not_exists,
+            catalog,
+            database,
+            table,
+            source,
+            table_options,
+            cluster_by,
+            as_query,
+            transient,
+            temporary,
+            engine,
+            uri_location,
+        } = stmt;
+
+        if *temporary
+            || !matches!(engine, None | Some(Engine::Fuse))
+            || uri_location.is_some()
+            || !cluster_by.is_empty()
+            || as_query.is_some()
+        {
+            return Err(ErrorCode::BadArguments(
+                "CLONE table can not be created with engine, location, cluster key or AS SELECT, they are inherited from the source table",
+            ));
+        }
+
+        let (source_catalog, source_database, source_table, travel_point) = match source {
+            Some(CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            }) => {
+                let (catalog, database, table) =
+                    self.normalize_object_identifier_triple(catalog, database, table);
+                (catalog, database, table, travel_point)
+            }
+            _ => unreachable!(),
+        };
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        if source_catalog != catalog {
+            return Err(ErrorCode::BadArguments(
+                "CLONE table must be created in the catalog of the source table",
+            ));
+        }
+
+        let navigation_point = match travel_point {
+            Some(tp) => {
+                let mut bind_context = BindContext::new();
+                Some(
+                    self.resolve_data_travel_point(&mut bind_context, tp)
+                        .await?,
+                )
+            }
+            None => None,
+        };
+        let source = self
+            .resolve_data_source(
+                &self.ctx.get_tenant(),
+                &source_catalog,
+                &source_database,
+                &source_table,
+                &navigation_point,
+            )
+            .await?;
+        let source_info = source.get_table_info();
+        if source.engine() != "FUSE" || source_info.meta.storage_params.is_some() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Only FUSE table without external location can be cloned, but got table {source_database}.{source_table} of engine {}",
+                source.engine()
+            )));
+        }
+
+        let mut options: BTreeMap<String, String> = BTreeMap::new();
+        for table_option in table_options.iter() {
+            self.insert_table_option_with_validation(
+                &mut options,
+                table_option.0.to_lowercase(),
+                table_option.1.to_string(),
+            )?;
+        }
+        // The source options are kept unless overridden, e.g. the storage format.
+        for (key, value) in source_info.meta.options.iter() {
+            if is_reserved_opt_key(key)
+                || key == OPT_KEY_SNAPSHOT_LOCATION
+                || key.eq_ignore_ascii_case("TRANSIENT")
+            {
+                continue;
+            }
+            options.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+        if *transient {
+            options.insert("TRANSIENT".to_owned(), "T".to_owned());
+        }
+        if let Some(snapshot_location) = source_info.meta.options.get(OPT_KEY_SNAPSHOT_LOCATION) {
+            options.insert(
+                OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
+                snapshot_location.clone(),
+            );
+            // The time travel of the clone goes back to the snapshot it is created from.
+            options.insert(
+                OPT_KEY_CLONE_BASE_SNAPSHOT_LOCATION.to_owned(),
+                snapshot_location.clone(),
+            );
+        }
+
+        let db = self
+            .ctx
+            .get_catalog(&catalog)
+            .await?
+            .get_database(&self.ctx.get_tenant(), &database)
+            .await?;
+        let db_id = db.get_db_info().ident.db_id;
+        options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+
+        // The schema at the travel point may differ from the current one.
+        let schema = source.schema();
+        let field_comments = if source.field_comments().len() == schema.num_fields() {
+            source.field_comments().clone()
+        } else {
+            vec![]
+        };
+
+        Ok(Plan::CreateTable(Box::new(CreateTablePlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            catalog: catalog.clone(),
+            database,
+            table,
+            schema,
+            engine: Engine::Fuse,
+            storage_params: None,
+            read_only_attach: false,
+            part_prefix: "".to_string(),
+            options,
+            field_comments,
+            cluster_key: source_info.meta.default_cluster_key.clone(),
+            temporary: false,
+            clone_source: Some(CloneTableSource {
+                catalog,
+                database: source_database,
+                table: source_table,
+            }),
+            as_select: None,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_attach_table(
         &mut self,
@@ -673,6 +872,8 @@ impl Binder {
             options,
             field_comments: vec![],
             cluster_key: None,
+            temporary: false,
+            clone_source: None,
             as_select: None,
         })))
     }
@@ -1363,6 +1564,7 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone()))
                 }
             }
+            CreateTableSource::Clone { .. } => unreachable!(),
         }
     }
 
@@ -1432,30 +1634,52 @@ impl Binder {
         // cluster keys cannot be a udf expression.
         scalar_binder.forbid_udf();
 
-        let mut cluster_keys = Vec::with_capacity(cluster_by.len());
+        let keys_len = cluster_by.len();
+        let mut cluster_keys = Vec::with_capacity(keys_len);
         for cluster_by in cluster_by.iter() {
-            let (cluster_key, _) = scalar_binder.bind(cluster_by).await?;
-            if cluster_key.used_columns().len() != 1 || !cluster_key.evaluable() {
-                return Err(ErrorCode::InvalidClusterKeys(format!(
-                    "Cluster by expression `{:#}` is invalid",
-                    cluster_by
-                )));
-            }
+            // The space-filling curve orders the rows by all of its arguments,
+            // each argument is checked as a cluster key.
+            let keys = match cluster_by {
+                Expr::FunctionCall { name, args, .. }
+                    if SPACE_FILLING_CURVE_FUNCTIONS
+                        .contains(&name.name.to_lowercase().as_str()) =>
+                {
+                    if keys_len != 1 {
+                        return Err(ErrorCode::InvalidClusterKeys(format!(
+                            "Cluster by expression `{:#}` must be the only cluster key",
+                            cluster_by
+                        )));
+                    }
+                    scalar_binder.bind(cluster_by).await?;
+                    args.iter().collect::<Vec<_>>()
+                }
+                _ => vec![cluster_by],
+            };
 
-            let expr = cluster_key.as_expr()?;
-            if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
-                return Err(ErrorCode::InvalidClusterKeys(format!(
-                    "Cluster by expression `{:#}` is not deterministic",
-                    cluster_by
-                )));
-            }
+            for key in keys {
+                let (cluster_key, _) = scalar_binder.bind(key).await?;
+                if cluster_key.used_columns().len() != 1 || !cluster_key.evaluable() {
+                    return Err(ErrorCode::InvalidClusterKeys(format!(
+                        "Cluster by expression `{:#}` is invalid",
+                        key
+                    )));
+                }
 
-            let data_type = expr.data_type();
-            if !Self::valid_cluster_key_type(data_type) {
-                return Err(ErrorCode::InvalidClusterKeys(format!(
-                    "Unsupported data type '{}' for cluster by expression `{:#}`",
-                    data_type, cluster_by
-                )));
+                let expr = cluster_key.as_expr()?;
+                if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+                    return Err(ErrorCode::InvalidClusterKeys(format!(
+                        "Cluster by expression `{:#}` is not deterministic",
+                        key
+                    )));
+                }
+
+                let data_type = expr.data_type();
+                if !Self::valid_cluster_key_type(data_type) {
+                    return Err(ErrorCode::InvalidClusterKeys(format!(
+                        "Unsupported data type '{}' for cluster by expression `{:#}`",
+                        data_type, key
+                    )));
+                }
             }
 
             let mut cluster_by = cluster_by.clone();
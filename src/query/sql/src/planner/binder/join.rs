@@ -41,6 +41,7 @@ use crate::planner::binder::Binder;
 use crate::planner::semantic::NameResolutionContext;
 use crate::plans::BoundColumnRef;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::plans::ScalarExpr;
@@ -84,6 +85,11 @@ impl Binder {
                     "cross join should not contain join conditions".to_string(),
                 ));
             }
+            JoinOperator::Asof | JoinOperator::LeftAsof if join.match_condition.is_none() => {
+                return Err(ErrorCode::SemanticError(
+                    "asof join should contain match condition".to_string(),
+                ));
+            }
             _ => (),
         };
 
@@ -103,6 +109,13 @@ impl Binder {
             &mut bind_context,
             &join.condition,
         );
+        if let Some(match_condition) = &join.match_condition {
+            // The match condition is the only non-equi condition of asof join.
+            let match_condition = join_condition_resolver
+                .resolve_match_condition(match_condition)
+                .await?;
+            non_equi_conditions.push(match_condition);
+        }
         join_condition_resolver
             .resolve(
                 &mut left_join_conditions,
@@ -112,6 +125,13 @@ impl Binder {
                 &join.op,
             )
             .await?;
+        if matches!(join.op, JoinOperator::Asof | JoinOperator::LeftAsof)
+            && (non_equi_conditions.len() > 1 || !other_conditions.is_empty())
+        {
+            return Err(ErrorCode::SemanticError(
+                "asof join only supports equi conditions in ON clause".to_string(),
+            ));
+        }
 
         let join_conditions = JoinConditions {
             left_conditions: left_join_conditions,
@@ -136,6 +156,15 @@ impl Binder {
             JoinOperator::CrossJoin => {
                 self.bind_join_with_type(JoinType::Cross, join_conditions, left_child, right_child)
             }
+            JoinOperator::Asof => {
+                self.bind_join_with_type(JoinType::Asof, join_conditions, left_child, right_child)
+            }
+            JoinOperator::LeftAsof => self.bind_join_with_type(
+                JoinType::LeftAsof,
+                join_conditions,
+                left_child,
+                right_child,
+            ),
             JoinOperator::LeftSemi => {
                 bind_context = left_context;
                 self.bind_join_with_type(
@@ -286,7 +315,9 @@ impl Binder {
                         need_push_down = true;
                         left_push_down.push(predicate.clone());
                     }
-                    JoinType::Full => non_equi_conditions.push(predicate.clone()),
+                    JoinType::Full | JoinType::Asof | JoinType::LeftAsof => {
+                        non_equi_conditions.push(predicate.clone())
+                    }
                 },
                 JoinPredicate::Left(_) => {
                     need_push_down = true;
@@ -342,7 +373,7 @@ fn wrap_nullable_for_column(
     bind_context: &mut BindContext,
 ) {
     match join_type {
-        JoinOperator::LeftOuter => {
+        JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
             for column in left_context.all_column_bindings() {
                 bind_context.add_column_binding(column.clone());
             }
@@ -764,6 +795,67 @@ impl<'a> JoinConditionResolver<'a> {
         Ok(false)
     }
 
+    /// Resolve the match condition of asof join, it must be a comparison
+    /// between an expression of the left table and one of the right table.
+    #[async_backtrace::framed]
+    async fn resolve_match_condition(&self, match_condition: &Expr) -> Result<ScalarExpr> {
+        let mut join_context = (*self.join_context).clone();
+        wrap_nullable_for_column(
+            &JoinOperator::Inner,
+            self.left_context,
+            self.right_context,
+            &mut join_context,
+        );
+        let mut scalar_binder = ScalarBinder::new(
+            &mut join_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (predicate, _) = scalar_binder.bind(match_condition).await?;
+        let (left_columns, right_columns) = self.left_right_columns()?;
+        if let ScalarExpr::FunctionCall(func) = &predicate {
+            let mirrored_op = match func.func_name.as_str() {
+                "gt" => Some("lt"),
+                "gte" => Some("lte"),
+                "lt" => Some("gt"),
+                "lte" => Some("gte"),
+                _ => None,
+            };
+            if let (Some(mirrored_op), [left, right]) = (mirrored_op, func.arguments.as_slice()) {
+                let left_used_columns = left.used_columns();
+                let right_used_columns = right.used_columns();
+                if !left_used_columns.is_empty() && !right_used_columns.is_empty() {
+                    if left_used_columns.is_subset(&left_columns)
+                        && right_used_columns.is_subset(&right_columns)
+                    {
+                        return Ok(predicate);
+                    }
+                    if left_used_columns.is_subset(&right_columns)
+                        && right_used_columns.is_subset(&left_columns)
+                    {
+                        // Keep the expression of the left table on the left side.
+                        return Ok(FunctionCall {
+                            span: func.span,
+                            func_name: mirrored_op.to_string(),
+                            params: vec![],
+                            arguments: vec![right.clone(), left.clone()],
+                        }
+                        .into());
+                    }
+                }
+            }
+        }
+        Err(ErrorCode::SemanticError(
+            "match condition of asof join must be a comparison (>, >=, <, <=) between the left and right tables"
+                .to_string(),
+        )
+        .set_span(match_condition.span()))
+    }
+
     fn left_right_columns(&self) -> Result<(ColumnSet, ColumnSet)> {
         let left_columns: ColumnSet =
             self.left_context
@@ -31,6 +31,7 @@ use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::FieldIndex;
 use common_expression::TableSchemaRef;
+use common_expression::BLOCK_NAME_COL_NAME;
 use common_expression::ROW_ID_COL_NAME;
 use indexmap::IndexMap;
 
@@ -120,6 +121,7 @@ impl Binder {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
 
         // get_source_table_reference
@@ -224,10 +226,33 @@ impl Binder {
         // add row_id_idx
         columns_set.insert(column_binding.index);
 
+        // add internal_column (_block_name), the updated rows take the block and
+        // the row number they come from as their origin if the change tracking is enabled.
+        let has_update = self.has_update(&matched_clauses);
+        let block_name_index = if has_update && table.change_tracking_enabled() {
+            let block_name_column_binding = InternalColumnBinding {
+                database_name: Some(database_name.clone()),
+                table_name: Some(table_name.clone()),
+                internal_column: InternalColumn {
+                    column_name: BLOCK_NAME_COL_NAME.to_string(),
+                    column_type: InternalColumnType::BlockName,
+                },
+            };
+            let column_binding = target_context
+                .add_internal_column_binding(&block_name_column_binding, self.metadata.clone())?;
+            target_expr =
+                SExpr::add_internal_column_index(&target_expr, table_index, column_binding.index);
+            columns_set.insert(column_binding.index);
+            Some(column_binding.index)
+        } else {
+            None
+        };
+
         // add join,use left outer join in V1, we use _row_id to check_duplicate join row.
         let join = Join {
             op: RightOuter,
             condition: JoinCondition::On(Box::new(join_expr.clone())),
+            match_condition: None,
             left: Box::new(target_table),
             // use source as build table
             right: Box::new(source_data.clone()),
@@ -262,10 +287,9 @@ impl Binder {
 
         let column_entries = self.metadata.read().columns_by_table_index(table_index);
         let mut field_index_map = HashMap::<usize, String>::new();
-        // if true, read all columns of target table
-        let has_update = self.has_update(&matched_clauses);
+        // if true, read all columns of target table, including the stream columns.
         if has_update {
-            for (idx, field) in table_schema.fields().iter().enumerate() {
+            for (idx, field) in table.schema_with_stream().fields().iter().enumerate() {
                 let used_idx = self.find_column_index(&column_entries, &field.name())?;
                 columns_set.insert(used_idx);
                 field_index_map.insert(idx, used_idx.to_string());
@@ -323,6 +347,7 @@ impl Binder {
             unmatched_evaluators,
             target_table_idx: table_index,
             field_index_map,
+            block_name_index,
         })))
     }
 
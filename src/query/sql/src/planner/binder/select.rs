@@ -53,8 +53,10 @@ use crate::binder::scalar_common::split_conjunctions;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
 use crate::binder::ExprContext;
+use crate::binder::RecursiveCteWorkingTable;
 use crate::binder::INTERNAL_COLUMN_FACTORY;
 use crate::normalize_identifier;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::binder::scalar::ScalarBinder;
 use crate::planner::binder::BindContext;
@@ -64,10 +66,16 @@ use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
 use crate::plans::JoinType;
+use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::UnionAll;
 use crate::plans::Visitor as _;
+use crate::plans::Window;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowFuncType;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
 use crate::IndexType;
@@ -127,6 +135,7 @@ impl Binder {
                     join: Join {
                         op: JoinOperator::CrossJoin,
                         condition: JoinCondition::None,
+                        match_condition: None,
                         left: Box::new(left),
                         right: Box::new(right),
                     },
@@ -214,6 +223,16 @@ impl Binder {
             None
         };
 
+        let qualify = if let Some(qualify) = &stmt.qualify {
+            Some((
+                self.analyze_window_qualify(&mut from_context, &aliases, qualify)
+                    .await?,
+                qualify.span(),
+            ))
+        } else {
+            None
+        };
+
         let order_items = self
             .analyze_order_items(
                 &mut from_context,
@@ -261,6 +280,13 @@ impl Binder {
             s_expr = self.bind_window_function(window_info, s_expr).await?;
         }
 
+        // QUALIFY filters the results of window functions.
+        if let Some((qualify, span)) = qualify {
+            s_expr = self
+                .bind_qualify(&mut from_context, qualify, span, s_expr)
+                .await?;
+        }
+
         if stmt.distinct {
             s_expr = self.bind_distinct(
                 stmt.span,
@@ -356,6 +382,7 @@ impl Binder {
                     used_count: 0,
                     stat_info: None,
                     columns: vec![],
+                    recursive: with.recursive,
                 };
                 self.ctes_map.insert(table_name.clone(), cte_info.clone());
                 bind_context.cte_map_ref.insert(table_name, cte_info);
@@ -471,7 +498,7 @@ impl Binder {
         }
 
         match (op, all) {
-            (SetOperator::Intersect, false) => {
+            (SetOperator::Intersect, all) => {
                 // Transfer Intersect to Semi join
                 self.bind_intersect(
                     left.span(),
@@ -480,9 +507,10 @@ impl Binder {
                     right_bind_context,
                     left_expr,
                     right_expr,
+                    !all,
                 )
             }
-            (SetOperator::Except, false) => {
+            (SetOperator::Except, all) => {
                 // Transfer Except to Anti join
                 self.bind_except(
                     left.span(),
@@ -491,6 +519,7 @@ impl Binder {
                     right_bind_context,
                     left_expr,
                     right_expr,
+                    !all,
                 )
             }
             (SetOperator::Union, true) => self.bind_union(
@@ -511,9 +540,6 @@ impl Binder {
                 right_expr,
                 true,
             ),
-            _ => Err(ErrorCode::Unimplemented(
-                "Unsupported query type, currently, databend only support intersect distinct and except distinct",
-            )),
         }
     }
 
@@ -564,7 +590,10 @@ impl Binder {
             coercion_types,
         )?;
 
-        let union_plan = UnionAll { pairs };
+        let union_plan = UnionAll {
+            pairs,
+            cte_idx: None,
+        };
         let mut new_expr = SExpr::create_binary(
             Arc::new(union_plan.into()),
             Arc::new(left_expr),
@@ -584,6 +613,161 @@ impl Binder {
         Ok((new_expr, new_bind_context))
     }
 
+    /// Bind the body of a recursive cte, which must be `anchor UNION ALL recursive_term`.
+    /// The self-references in the recursive term are bound to the working table of the cte,
+    /// which holds the rows produced by the previous iteration.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_recursive_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        cte_name: &str,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = &cte_info.query;
+        let set_operation = match &query.body {
+            SetExpr::SetOperation(set_operation) if set_operation.op == SetOperator::Union => {
+                set_operation
+            }
+            // There is no recursive term, bind it as a normal cte.
+            _ => return self.bind_query(bind_context, query).await,
+        };
+        if !set_operation.all {
+            return Err(ErrorCode::SemanticError(format!(
+                "recursive cte {cte_name} must use UNION ALL to combine the anchor and the recursive term"
+            ))
+            .set_span(span));
+        }
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "WITH, ORDER BY, LIMIT and OFFSET are not supported in recursive cte {cte_name}"
+            ))
+            .set_span(span));
+        }
+
+        let (anchor_expr, anchor_context) = self
+            .bind_set_expr(bind_context, &set_operation.left, &[], 0)
+            .await?;
+        if cte_info.columns_alias.len() > anchor_context.columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "table has {} columns available but {} columns specified",
+                anchor_context.columns.len(),
+                cte_info.columns_alias.len()
+            ))
+            .set_span(span));
+        }
+        let column_names = anchor_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, column)| {
+                cte_info
+                    .columns_alias
+                    .get(idx)
+                    .cloned()
+                    .unwrap_or_else(|| column.column_name.clone())
+            })
+            .collect::<Vec<_>>();
+        let stat_info = RelExpr::with_s_expr(&anchor_expr).derive_cardinality()?;
+        // A cte can be referenced several times in a query and every reference is evaluated
+        // separately, so make the working table unique.
+        let working_table_idx = (self.metadata.read().columns().len(), 0);
+
+        // The columns of the working table have the common super types of the anchor and the
+        // recursive term, while the types of the recursive term depend on the working table.
+        // So bind the recursive term again with the widened types until they are stable.
+        let mut working_types = anchor_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect::<Vec<_>>();
+        let mut rounds = 0;
+        let (recursive_expr, recursive_context) = loop {
+            self.r_cte_working_tables
+                .insert(cte_name.to_string(), RecursiveCteWorkingTable {
+                    cte_idx: working_table_idx,
+                    columns: column_names
+                        .iter()
+                        .cloned()
+                        .zip(working_types.iter().cloned())
+                        .collect(),
+                    stat_info: stat_info.clone(),
+                });
+            let res = self
+                .bind_set_expr(bind_context, &set_operation.right, &[], 0)
+                .await;
+            self.r_cte_working_tables.remove(cte_name);
+            let (recursive_expr, recursive_context) = res?;
+
+            if recursive_context.columns.len() != working_types.len() {
+                return Err(ErrorCode::SemanticError(
+                    "SetOperation must have the same number of columns",
+                ));
+            }
+            let mut widened_types = Vec::with_capacity(working_types.len());
+            for (data_type, column) in working_types.iter().zip(recursive_context.columns.iter()) {
+                let common_type = common_super_type(
+                    data_type.clone(),
+                    *column.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                )
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "recursive cte {cte_name}'s types cannot be matched, anchor type: {:?}, recursive term column {:?}, type: {:?}",
+                        data_type, column.column_name, column.data_type
+                    ))
+                    .set_span(span)
+                })?;
+                widened_types.push(common_type);
+            }
+            if widened_types == working_types {
+                break (recursive_expr, recursive_context);
+            }
+            rounds += 1;
+            if rounds >= MAX_RECURSIVE_CTE_TYPE_WIDENING {
+                return Err(ErrorCode::SemanticError(format!(
+                    "cannot determine the column types of recursive cte {cte_name}, try to cast the anchor to wider types"
+                ))
+                .set_span(span));
+            }
+            working_types = widened_types;
+        };
+
+        // The working table is consumed by the scan, so the rows of an iteration can't be
+        // read twice.
+        let num_self_references = count_recursive_cte_scan(&recursive_expr);
+        if num_self_references > 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "recursive reference to cte {cte_name} must not appear more than once"
+            ))
+            .set_span(span));
+        }
+        let (new_bind_context, pairs, left_expr, right_expr) = self.coercion_union_type(
+            set_operation.left.span(),
+            set_operation.right.span(),
+            anchor_context,
+            recursive_context,
+            anchor_expr,
+            recursive_expr,
+            working_types,
+        )?;
+        let union_plan = UnionAll {
+            pairs,
+            cte_idx: (num_self_references > 0).then_some(working_table_idx),
+        };
+        let new_expr = SExpr::create_binary(
+            Arc::new(union_plan.into()),
+            Arc::new(left_expr),
+            Arc::new(right_expr),
+        );
+        Ok((new_expr, new_bind_context))
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn bind_intersect(
         &mut self,
         left_span: Span,
@@ -592,6 +776,7 @@ impl Binder {
         right_context: BindContext,
         left_expr: SExpr,
         right_expr: SExpr,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
         self.bind_intersect_or_except(
             left_span,
@@ -601,9 +786,11 @@ impl Binder {
             left_expr,
             right_expr,
             JoinType::LeftSemi,
+            distinct,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn bind_except(
         &mut self,
         left_span: Span,
@@ -612,6 +799,7 @@ impl Binder {
         right_context: BindContext,
         left_expr: SExpr,
         right_expr: SExpr,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
         self.bind_intersect_or_except(
             left_span,
@@ -621,9 +809,17 @@ impl Binder {
             left_expr,
             right_expr,
             JoinType::LeftAnti,
+            distinct,
         )
     }
 
+    /// `INTERSECT` and `EXCEPT` are transferred to semi join and anti join.
+    ///
+    /// For the `ALL` variants, the duplicates are numbered by `row_number()`
+    /// within each group of equal rows, and the row numbers are joined as well.
+    /// So the n-th duplicate of a row on the left matches the n-th duplicate on
+    /// the right, which keeps `min(m, n)` duplicates for `INTERSECT ALL` and
+    /// `max(m - n, 0)` duplicates for `EXCEPT ALL`.
     #[allow(clippy::too_many_arguments)]
     pub fn bind_intersect_or_except(
         &mut self,
@@ -634,16 +830,28 @@ impl Binder {
         left_expr: SExpr,
         right_expr: SExpr,
         join_type: JoinType,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
-        let left_expr = self.bind_distinct(
-            left_span,
-            &left_context,
-            left_context.all_column_bindings(),
-            &mut HashMap::new(),
-            left_expr,
-        )?;
-        let mut left_conditions = Vec::with_capacity(left_context.columns.len());
-        let mut right_conditions = Vec::with_capacity(right_context.columns.len());
+        let mut left_conditions = Vec::with_capacity(left_context.columns.len() + 1);
+        let mut right_conditions = Vec::with_capacity(right_context.columns.len() + 1);
+        let (left_expr, right_expr) = if distinct {
+            let left_expr = self.bind_distinct(
+                left_span,
+                &left_context,
+                left_context.all_column_bindings(),
+                &mut HashMap::new(),
+                left_expr,
+            )?;
+            (left_expr, right_expr)
+        } else {
+            let (left_expr, left_row_number) =
+                self.bind_duplicate_row_number(left_span, &left_context, left_expr);
+            let (right_expr, right_row_number) =
+                self.bind_duplicate_row_number(right_span, &right_context, right_expr);
+            left_conditions.push(left_row_number);
+            right_conditions.push(right_row_number);
+            (left_expr, right_expr)
+        };
         assert_eq!(left_context.columns.len(), right_context.columns.len());
         for (left_column, right_column) in left_context
             .columns
@@ -675,6 +883,63 @@ impl Binder {
         Ok((s_expr, left_context))
     }
 
+    /// Number the duplicates of each row of `child` with `row_number() over (partition by <all columns>)`.
+    fn bind_duplicate_row_number(
+        &self,
+        span: Span,
+        bind_context: &BindContext,
+        child: SExpr,
+    ) -> (SExpr, ScalarExpr) {
+        let func = WindowFuncType::RowNumber;
+        let data_type = func.return_type();
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column(func.func_name(), data_type.clone());
+        let partition_by = bind_context
+            .columns
+            .iter()
+            .map(|column| ScalarItem {
+                scalar: BoundColumnRef {
+                    span,
+                    column: column.clone(),
+                }
+                .into(),
+                index: column.index,
+            })
+            .collect();
+        let window_plan = Window {
+            span,
+            index,
+            function: func.clone(),
+            arguments: vec![],
+            partition_by,
+            order_by: vec![],
+            frame: WindowFuncFrame {
+                units: WindowFuncFrameUnits::Range,
+                start_bound: WindowFuncFrameBound::Preceding(None),
+                end_bound: WindowFuncFrameBound::Following(None),
+            },
+            limit: None,
+        };
+        let row_number = ColumnBindingBuilder::new(
+            func.func_name(),
+            index,
+            Box::new(data_type),
+            Visibility::InVisible,
+        )
+        .build();
+        let s_expr = SExpr::create_unary(Arc::new(window_plan.into()), Arc::new(child));
+        (
+            s_expr,
+            BoundColumnRef {
+                span,
+                column: row_number,
+            }
+            .into(),
+        )
+    }
+
     #[allow(clippy::type_complexity)]
     #[allow(clippy::too_many_arguments)]
     fn coercion_union_type(
@@ -800,6 +1065,7 @@ impl Binder {
         // SELECT ... FROM t WHERE ... ORDER BY ... LIMIT ...
         if stmt.group_by.is_some()
             || stmt.having.is_some()
+            || stmt.qualify.is_some()
             || stmt.distinct
             || !bind_context.aggregate_info.group_items.is_empty()
             || !bind_context.aggregate_info.aggregate_functions.is_empty()
@@ -1152,3 +1418,17 @@ impl<'a> Visitor<'a> for MaxColumnPosition {
         }
     }
 }
+
+const MAX_RECURSIVE_CTE_TYPE_WIDENING: usize = 8;
+
+fn count_recursive_cte_scan(s_expr: &SExpr) -> usize {
+    let count = s_expr
+        .children()
+        .iter()
+        .map(|child| count_recursive_cte_scan(child))
+        .sum();
+    match s_expr.plan() {
+        RelOperator::RecursiveCteScan(_) => count + 1,
+        _ => count,
+    }
+}
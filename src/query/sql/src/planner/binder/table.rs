@@ -29,6 +29,9 @@ use common_ast::ast::Indirection;
 use common_ast::ast::Join;
 use common_ast::ast::Literal;
 use common_ast::ast::Query;
+use common_ast::ast::SampleConfig;
+use common_ast::ast::SampleLevel;
+use common_ast::ast::SampleValue;
 use common_ast::ast::SelectStageOptions;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
@@ -41,8 +44,11 @@ use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::plan::ChangeType;
 use common_catalog::plan::ParquetReadOptions;
 use common_catalog::plan::StageTableInfo;
+use common_catalog::plan::TableSample;
+use common_catalog::plan::OPT_KEY_CHANGE_TYPE;
 use common_catalog::statistics::BasicColumnStatistics;
 use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
@@ -71,6 +77,9 @@ use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_types::MetaId;
+use common_pipeline_sources::input_formats::InputFormatArrow;
+use common_pipeline_sources::input_formats::OrcFileReader;
+use common_storage::init_stage_operator;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
@@ -93,7 +102,10 @@ use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
 use crate::binder::ExprContext;
+use crate::binder::RecursiveCteWorkingTable;
 use crate::binder::Visibility;
+use crate::gen_stream_changes_query;
+use crate::optimizer::histogram_from_collected;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
@@ -104,9 +116,13 @@ use crate::plans::CteScan;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
+use crate::plans::Limit;
+use crate::plans::RecursiveCteScan;
+use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Statistics;
+use crate::stream_change_alias;
 use crate::BaseTableColumn;
 use crate::BindContext;
 use crate::ColumnEntry;
@@ -194,6 +210,10 @@ impl Binder {
         } else {
             None
         };
+        // Bind the self-reference in the recursive term of a recursive cte
+        if let Some(working_table) = self.r_cte_working_tables.get(&table_name).cloned() {
+            return self.bind_r_cte_scan(bind_context, &table_name, alias, &working_table);
+        }
         let mut bind_cte = true;
         if let Some(cte_name) = &bind_context.cte_name {
             // If table name equals to cte name, then skip bind cte and find table from catalog
@@ -307,7 +327,37 @@ impl Binder {
                 }
             }
             _ => {
+                let mut table_meta = table_meta;
                 if table_meta.engine() == "STREAM" {
+                    if let Some(change_type) = table_alias_name
+                        .as_ref()
+                        .and_then(|alias| self.stream_change_sides.get(alias))
+                        .copied()
+                    {
+                        // One side of the changes of a standard stream.
+                        let mut table_info = table_meta.get_table_info().clone();
+                        table_info
+                            .meta
+                            .options
+                            .insert(OPT_KEY_CHANGE_TYPE.to_string(), change_type.to_string());
+                        table_meta = self
+                            .ctx
+                            .get_catalog(&catalog)
+                            .await?
+                            .get_table_by_info(&table_info)?;
+                    } else if table_meta.options().get("mode").map(String::as_str)
+                        == Some("standard")
+                    {
+                        return self
+                            .bind_stream_changes(
+                                bind_context,
+                                &database,
+                                &table_name,
+                                alias,
+                                table_meta,
+                            )
+                            .await;
+                    }
                     bind_context.allow_internal_columns(false);
                 }
                 let table_index = self.metadata.write().add_table(
@@ -576,6 +626,7 @@ impl Binder {
                 group_by: None,
                 having: None,
                 window_list: None,
+                qualify: None,
             };
             self.bind_select_stmt(&mut bind_context, &stmt, &[], 0)
                 .await
@@ -697,17 +748,26 @@ impl Binder {
                 travel_point,
                 pivot: _,
                 unpivot: _,
+                sample,
             } => {
-                self.bind_table(
-                    bind_context,
-                    span,
-                    catalog,
-                    database,
-                    table,
-                    alias,
-                    travel_point,
-                )
-                .await
+                let (s_expr, bind_context) = self
+                    .bind_table(
+                        bind_context,
+                        span,
+                        catalog,
+                        database,
+                        table,
+                        alias,
+                        travel_point,
+                    )
+                    .await?;
+                match sample {
+                    Some(sample) => {
+                        let s_expr = self.bind_table_sample(*span, s_expr, sample)?;
+                        Ok((s_expr, bind_context))
+                    }
+                    None => Ok((s_expr, bind_context)),
+                }
             }
             TableReference::TableFunction {
                 span,
@@ -808,7 +868,43 @@ impl Binder {
                     .await?
                 }
             }
-            FileFormatParams::NdJson(..) => {
+            FileFormatParams::Orc(..) => {
+                let operator = init_stage_operator(&stage_info)?;
+                let first_file = match &files_to_copy {
+                    Some(files) => files[0].path.clone(),
+                    None => files_info.first_file(&operator).await?.path.clone(),
+                };
+                let schema = OrcFileReader::read_schema(&operator, &first_file).await?;
+                let info = StageTableInfo {
+                    schema: Arc::new(schema),
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: false,
+                    partition_by: vec![],
+                    default_values: None,
+                };
+                StageTable::try_create(info)?
+            }
+            FileFormatParams::Arrow(..) => {
+                let operator = init_stage_operator(&stage_info)?;
+                let first_file = match &files_to_copy {
+                    Some(files) => files[0].path.clone(),
+                    None => files_info.first_file(&operator).await?.path.clone(),
+                };
+                let schema = InputFormatArrow::read_schema(&operator, &first_file).await?;
+                let info = StageTableInfo {
+                    schema: Arc::new(schema),
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: false,
+                    partition_by: vec![],
+                    default_values: None,
+                };
+                StageTable::try_create(info)?
+            }
+            FileFormatParams::NdJson(..) | FileFormatParams::Avro(..) => {
                 let schema = Arc::new(TableSchema::new(vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
@@ -819,6 +915,7 @@ impl Binder {
                     files_info,
                     files_to_copy: None,
                     is_select: true,
+                    partition_by: vec![],
                     default_values: None,
                 };
                 StageTable::try_create(info)?
@@ -846,13 +943,14 @@ impl Binder {
                     files_info,
                     files_to_copy: None,
                     is_select: true,
+                    partition_by: vec![],
                     default_values: None,
                 };
                 StageTable::try_create(info)?
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(
-                    "query stage files only support parquet/NDJson/CSV/TSV format for now",
+                    "query stage files only support parquet/ORC/Arrow/NDJson/Avro/CSV/TSV format for now",
                 ));
             }
         };
@@ -996,6 +1094,52 @@ impl Binder {
         Ok(cte_scan)
     }
 
+    // Bind the working table of a recursive cte
+    fn bind_r_cte_scan(
+        &mut self,
+        bind_context: &BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        working_table: &RecursiveCteWorkingTable,
+    ) -> Result<(SExpr, BindContext)> {
+        let alias_table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| table_name.to_string());
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let mut fields = Vec::with_capacity(working_table.columns.len());
+        for (idx, (column_name, data_type)) in working_table.columns.iter().enumerate() {
+            let column_name = alias
+                .as_ref()
+                .and_then(|alias| alias.columns.get(idx))
+                .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                .unwrap_or_else(|| column_name.clone());
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), data_type.clone());
+            let column_binding = ColumnBindingBuilder::new(
+                column_name,
+                index,
+                Box::new(data_type.clone()),
+                Visibility::Visible,
+            )
+            .table_name(Some(alias_table_name.clone()))
+            .build();
+            new_bind_context.add_column_binding(column_binding);
+            fields.push(DataField::new(&index.to_string(), data_type.clone()));
+        }
+        let s_expr = SExpr::create_leaf(Arc::new(
+            RecursiveCteScan {
+                cte_idx: working_table.cte_idx,
+                fields,
+                stat: working_table.stat_info.clone(),
+            }
+            .into(),
+        ));
+        Ok((s_expr, new_bind_context))
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn bind_cte(
         &mut self,
@@ -1023,9 +1167,13 @@ impl Binder {
             window_definitions: DashMap::new(),
         };
 
-        let (s_expr, mut res_bind_context) = self
-            .bind_query(&mut new_bind_context, &cte_info.query)
-            .await?;
+        let (s_expr, mut res_bind_context) = if cte_info.recursive {
+            self.bind_recursive_cte(span, &mut new_bind_context, table_name, cte_info)
+                .await?
+        } else {
+            self.bind_query(&mut new_bind_context, &cte_info.query)
+                .await?
+        };
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
@@ -1111,6 +1259,121 @@ impl Binder {
         Ok((s_expr, new_bind_context))
     }
 
+    /// Push the `TABLESAMPLE` clause down to the scan of a base table.
+    fn bind_table_sample(&self, span: Span, s_expr: SExpr, sample: &SampleConfig) -> Result<SExpr> {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                let table = self.metadata.read().table(scan.table_index).table();
+                let seed = sample.seed;
+                let table_sample = match (sample.level, sample.value) {
+                    (_, SampleValue::Percent(percent)) if !(0.0..=100.0).contains(&percent) => {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "sample probability must be between 0 and 100, but got {percent}"
+                        ))
+                        .set_span(span));
+                    }
+                    (SampleLevel::Block, SampleValue::Rows(_)) => {
+                        return Err(ErrorCode::SemanticError(
+                            "BLOCK sampling does not support a fixed number of rows",
+                        )
+                        .set_span(span));
+                    }
+                    (SampleLevel::Block, SampleValue::Percent(percent))
+                        if table.support_block_sample() =>
+                    {
+                        TableSample::Block {
+                            probability: (percent / 100.0).into(),
+                            seed,
+                        }
+                    }
+                    (_, SampleValue::Percent(percent)) => TableSample::Row {
+                        probability: (percent / 100.0).into(),
+                        seed,
+                    },
+                    (SampleLevel::Row, SampleValue::Rows(rows)) => {
+                        TableSample::FixedRows { rows, seed }
+                    }
+                };
+                let is_fixed_rows = matches!(table_sample, TableSample::FixedRows { .. });
+                let mut scan = scan.clone();
+                scan.sample = Some(table_sample);
+                let s_expr = s_expr.replace_plan(Arc::new(scan.into()));
+                if let (true, SampleValue::Rows(rows)) = (is_fixed_rows, sample.value) {
+                    // Each node of the cluster samples its own part of the table.
+                    let limit = Limit {
+                        before_exchange: false,
+                        limit: Some(rows as usize),
+                        offset: 0,
+                    };
+                    return Ok(SExpr::create_unary(
+                        Arc::new(limit.into()),
+                        Arc::new(s_expr),
+                    ));
+                }
+                Ok(s_expr)
+            }
+            // The internal columns of stream are filtered above the scan.
+            RelOperator::Filter(_) if s_expr.arity() == 1 => {
+                let child = self.bind_table_sample(span, s_expr.child(0)?.clone(), sample)?;
+                Ok(s_expr.replace_children([Arc::new(child)]))
+            }
+            _ => Err(
+                ErrorCode::SemanticError("TABLESAMPLE is only supported on base tables")
+                    .set_span(span),
+            ),
+        }
+    }
+
+    /// Bind the changes of a standard stream, which are generated by a query over
+    /// the insert side and the delete side of the stream.
+    #[async_backtrace::framed]
+    async fn bind_stream_changes(
+        &mut self,
+        bind_context: &mut BindContext,
+        database: &str,
+        stream: &str,
+        alias: &Option<TableAlias>,
+        table_meta: Arc<dyn Table>,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = gen_stream_changes_query(database, stream, table_meta.schema().as_ref());
+        let tokens = tokenize_sql(query.as_str())?;
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+        let query = match &stmt {
+            Statement::Query(query) => query,
+            _ => {
+                return Err(ErrorCode::Internal(format!(
+                    "Invalid changes query of stream: {}",
+                    stream
+                )));
+            }
+        };
+
+        // The sides are only recognized while binding the generated query, so the table
+        // aliases written by users never read one side of the changes.
+        let change_sides = [ChangeType::Insert, ChangeType::Delete]
+            .into_iter()
+            .map(|change_type| (stream_change_alias(stream, change_type), change_type))
+            .collect::<Vec<_>>();
+        self.stream_change_sides
+            .extend(change_sides.iter().cloned());
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let res = self.bind_query(&mut new_bind_context, query).await;
+        for (alias, _) in change_sides.iter() {
+            self.stream_change_sides.remove(alias);
+        }
+        let (s_expr, mut new_bind_context) = res?;
+        if let Some(alias) = alias {
+            new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        } else {
+            for column in new_bind_context.columns.iter_mut() {
+                column.database_name = None;
+                column.table_name = Some(stream.to_string());
+            }
+        }
+        new_bind_context.parent = Some(Box::new(bind_context.clone()));
+        Ok((s_expr, new_bind_context))
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn bind_base_table(
         &mut self,
@@ -1124,18 +1387,21 @@ impl Binder {
         let table_name = table.name();
         let table = table.table();
         let statistics_provider = table.column_statistics_provider().await?;
-        let table_version = if table.engine() == "STREAM" {
-            let options = table.options();
-            let table_version = options
-                .get("table_version")
-                .ok_or(ErrorCode::Internal("table version must be set in stream"))?
-                .parse::<u64>()?;
-            Some(table_version)
-        } else {
-            None
-        };
+        // The changes of a standard stream are not filtered by the table version.
+        let table_version =
+            if table.engine() == "STREAM" && !table.options().contains_key(OPT_KEY_CHANGE_TYPE) {
+                let options = table.options();
+                let table_version = options
+                    .get("table_version")
+                    .ok_or(ErrorCode::Internal("table version must be set in stream"))?
+                    .parse::<u64>()?;
+                Some(table_version)
+            } else {
+                None
+            };
 
         let mut col_stats: HashMap<IndexType, Option<BasicColumnStatistics>> = HashMap::new();
+        let mut histograms = HashMap::new();
         let mut predicates = Vec::new();
         let columns = self.metadata.read().columns_by_table_index(table_index);
         for column in columns.iter() {
@@ -1212,6 +1478,10 @@ impl Binder {
                             let col_stat =
                                 statistics_provider.column_statistics(col_id as ColumnId);
                             col_stats.insert(*column_index, col_stat.cloned());
+                            let histogram = statistics_provider
+                                .histogram(col_id as ColumnId)
+                                .and_then(histogram_from_collected);
+                            histograms.insert(*column_index, histogram);
                         }
                     }
                 }
@@ -1229,6 +1499,7 @@ impl Binder {
                 statistics: Statistics {
                     statistics: stat,
                     col_stats,
+                    histograms,
                 },
                 ..Default::default()
             }
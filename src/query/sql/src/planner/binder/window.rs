@@ -13,24 +13,35 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_ast::ast::Expr;
 use common_ast::ast::WindowDefinition;
 use common_ast::ast::WindowSpec;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
 
 use super::select::SelectList;
+use crate::binder::aggregate::AggregateRewriter;
+use crate::binder::split_conjunctions;
 use crate::binder::ColumnBindingBuilder;
+use crate::binder::ExprContext;
+use crate::binder::ScalarBinder;
 use crate::optimizer::SExpr;
+use crate::planner::semantic::GroupingChecker;
 use crate::plans::AggregateFunction;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
+use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
 use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
+use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::UDFLambdaCall;
@@ -45,6 +56,7 @@ use crate::Binder;
 use crate::IndexType;
 use crate::MetadataRef;
 use crate::Visibility;
+use crate::WindowChecker;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -61,6 +73,7 @@ impl Binder {
             partition_by: window_info.partition_by_items.clone(),
             order_by: window_info.order_by_items.clone(),
             frame: window_info.frame.clone(),
+            limit: None,
         };
 
         Ok(SExpr::create_unary(
@@ -555,4 +568,160 @@ impl Binder {
 
         Ok(())
     }
+
+    /// Analyze the `QUALIFY` clause, the window functions in it are rewritten
+    /// as those in select clause, so they can be bound together.
+    #[async_backtrace::framed]
+    pub(crate) async fn analyze_window_qualify(
+        &mut self,
+        bind_context: &mut BindContext,
+        aliases: &[(String, ScalarExpr)],
+        qualify: &Expr,
+    ) -> Result<ScalarExpr> {
+        let last_expr_context = bind_context.expr_context.clone();
+        bind_context.set_expr_context(ExprContext::QualifyClause);
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            aliases,
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (scalar, _) = scalar_binder.bind(qualify).await?;
+        let mut rewriter = AggregateRewriter::new(bind_context, self.metadata.clone());
+        let scalar = rewriter.visit(&scalar)?;
+
+        // The window functions referenced by alias have been rewritten already,
+        // they are kept as is to avoid computing them twice.
+        let rewritten_windows = bind_context
+            .windows
+            .window_functions_map
+            .keys()
+            .cloned()
+            .collect::<HashSet<_>>();
+        let scalar = self.rewrite_scalar_with_replacement(bind_context, &scalar, &|scalar| {
+            if let ScalarExpr::WindowFunction(window) = scalar {
+                if rewritten_windows.contains(&window.display_name) {
+                    return Ok(Some(scalar.clone()));
+                }
+            }
+            Ok(None)
+        })?;
+        bind_context.set_expr_context(last_expr_context);
+
+        Ok(scalar)
+    }
+
+    /// Bind the `QUALIFY` clause, which filters the results of window functions
+    /// as `HAVING` does for aggregate functions.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_qualify(
+        &mut self,
+        bind_context: &mut BindContext,
+        qualify: ScalarExpr,
+        span: Span,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        if bind_context.windows.window_functions.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "QUALIFY clause must refer to window functions".to_string(),
+            )
+            .set_span(span));
+        }
+
+        let scalar = if bind_context.in_grouping {
+            let grouping_checker = GroupingChecker::new(bind_context);
+            grouping_checker.resolve(&qualify, span)?
+        } else {
+            let window_checker = WindowChecker::new(bind_context);
+            window_checker.resolve(&qualify)?
+        };
+
+        let predicates = split_conjunctions(&scalar);
+
+        // `row_number() OVER (...) <= N` only keeps the first N rows of each partition,
+        // let the window know it, so the other rows can be dropped before sorting.
+        let mut child = child;
+        if let Some(limit) = Self::qualify_row_number_limit(bind_context, &predicates) {
+            if let RelOperator::Window(window) = child.plan() {
+                let mut window = window.clone();
+                window.limit = Some(limit);
+                child = child.replace_plan(Arc::new(window.into()));
+            }
+        }
+
+        let filter = Filter { predicates };
+
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(child),
+        ))
+    }
+
+    fn qualify_row_number_limit(
+        bind_context: &BindContext,
+        predicates: &[ScalarExpr],
+    ) -> Option<usize> {
+        let [window_info] = bind_context.windows.window_functions.as_slice() else {
+            return None;
+        };
+        if window_info.func != WindowFuncType::RowNumber {
+            return None;
+        }
+
+        let mut limit: Option<usize> = None;
+        for predicate in predicates {
+            let ScalarExpr::FunctionCall(func) = predicate else {
+                continue;
+            };
+            let [left, right] = func.arguments.as_slice() else {
+                continue;
+            };
+            // Normalize to `row_number <op> constant`.
+            let (op, constant) = match (left, right) {
+                (ScalarExpr::BoundColumnRef(col), ScalarExpr::ConstantExpr(constant))
+                    if col.column.index == window_info.index =>
+                {
+                    (func.func_name.as_str(), constant)
+                }
+                (ScalarExpr::ConstantExpr(constant), ScalarExpr::BoundColumnRef(col))
+                    if col.column.index == window_info.index =>
+                {
+                    let op = match func.func_name.as_str() {
+                        "gt" => "lt",
+                        "gte" => "lte",
+                        "lt" => "gt",
+                        "lte" => "gte",
+                        op => op,
+                    };
+                    (op, constant)
+                }
+                _ => continue,
+            };
+            let value = match constant.value {
+                Scalar::Number(NumberScalar::UInt8(v)) => v as u64,
+                Scalar::Number(NumberScalar::UInt16(v)) => v as u64,
+                Scalar::Number(NumberScalar::UInt32(v)) => v as u64,
+                Scalar::Number(NumberScalar::UInt64(v)) => v,
+                Scalar::Number(NumberScalar::Int8(v)) if v >= 0 => v as u64,
+                Scalar::Number(NumberScalar::Int16(v)) if v >= 0 => v as u64,
+                Scalar::Number(NumberScalar::Int32(v)) if v >= 0 => v as u64,
+                Scalar::Number(NumberScalar::Int64(v)) if v >= 0 => v as u64,
+                _ => continue,
+            };
+            let n = match op {
+                "eq" if value == 1 => 1,
+                "lte" => value as usize,
+                "lt" => value.saturating_sub(1) as usize,
+                _ => continue,
+            };
+            if n == 0 {
+                continue;
+            }
+            limit = Some(limit.map_or(n, |limit| limit.min(n)));
+        }
+        limit
+    }
 }
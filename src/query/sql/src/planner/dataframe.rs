@@ -66,6 +66,7 @@ impl Dataframe {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
 
         let settings = query_ctx.get_settings();
@@ -432,6 +433,7 @@ impl Dataframe {
             dataframe.bind_context,
             self.s_expr,
             dataframe.s_expr,
+            true,
         )?;
         self.s_expr = s_expr;
         self.bind_context = bind_context;
@@ -446,6 +448,7 @@ impl Dataframe {
             dataframe.bind_context,
             self.s_expr,
             dataframe.s_expr,
+            true,
         )?;
         self.s_expr = s_expr;
         self.bind_context = bind_context;
@@ -469,6 +472,7 @@ impl Dataframe {
                 travel_point: None,
                 pivot: None,
                 unpivot: None,
+                sample: None,
             };
             table_ref.push(table);
         }
@@ -480,6 +484,7 @@ impl Dataframe {
                 join: Join {
                     op: op.clone(),
                     condition: condition.clone(),
+                    match_condition: None,
                     left: Box::new(left),
                     right: Box::new(right),
                 },
@@ -108,6 +108,8 @@ impl Plan {
             Plan::CreateIndex(index) => Ok(format!("{:?}", index)),
             Plan::DropIndex(index) => Ok(format!("{:?}", index)),
             Plan::RefreshIndex(index) => Ok(format!("{index:?}")),
+            Plan::CreateInvertedIndex(index) => Ok(format!("{index:?}")),
+            Plan::DropInvertedIndex(index) => Ok(format!("{index:?}")),
 
             // Virtual Columns
             Plan::CreateVirtualColumn(create_virtual_column) => {
@@ -203,6 +205,10 @@ impl Plan {
             Plan::DescConnection(p) => Ok(format!("{:?}", p)),
             Plan::DropConnection(p) => Ok(format!("{:?}", p)),
             Plan::ShowConnections(p) => Ok(format!("{:?}", p)),
+
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Abort => Ok("Abort".to_string()),
         }
     }
 }
@@ -248,6 +254,7 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             order_by: None,
             prewhere: None,
             agg_index: None,
+            sample: None,
             statistics: Default::default(),
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
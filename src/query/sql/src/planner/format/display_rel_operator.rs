@@ -77,6 +77,7 @@ impl Display for FormatContext {
                 RelOperator::Window(_) => write!(f, "WindowFunc"),
                 RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
                 RelOperator::CteScan(_) => write!(f, "CteScan"),
+                RelOperator::RecursiveCteScan(_) => write!(f, "RecursiveCteScan"),
                 RelOperator::MaterializedCte(_) => write!(f, "MaterializedCte"),
                 RelOperator::Lambda(_) => write!(f, "Lambda"),
                 RelOperator::ConstantTableScan(_) => write!(f, "ConstantTableScan"),
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::fmt::Debug;
@@ -23,6 +24,7 @@ use common_ast::ast::Expr;
 use common_ast::ast::Literal;
 use common_catalog::plan::InternalColumn;
 use common_catalog::table::Table;
+use common_exception::Range;
 use common_expression::types::DataType;
 use common_expression::ComputedExpr;
 use common_expression::Scalar;
@@ -62,6 +64,11 @@ pub struct Metadata {
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
     max_column_position: usize, // for CSV
+    /// Values of the `?` placeholders of a prepared statement, identified by their spans.
+    placeholder_values: BTreeMap<Range, Scalar>,
+    /// Types of the `?` placeholders inferred from the expressions they are compared or
+    /// computed with.
+    placeholder_types: BTreeMap<Range, DataType>,
 }
 
 impl Metadata {
@@ -380,6 +387,30 @@ impl Metadata {
     pub fn get_max_column_position(&self) -> usize {
         self.max_column_position
     }
+
+    pub fn set_placeholder_values(&mut self, values: BTreeMap<Range, Scalar>) {
+        self.placeholder_values = values;
+    }
+
+    pub fn placeholder_values(&self) -> &BTreeMap<Range, Scalar> {
+        &self.placeholder_values
+    }
+
+    pub fn placeholder_value(&self, span: Range) -> Option<&Scalar> {
+        self.placeholder_values.get(&span)
+    }
+
+    pub fn is_placeholder(&self, span: Range) -> bool {
+        self.placeholder_values.contains_key(&span)
+    }
+
+    pub fn set_placeholder_type(&mut self, span: Range, data_type: DataType) {
+        self.placeholder_types.entry(span).or_insert(data_type);
+    }
+
+    pub fn placeholder_type(&self, span: Range) -> Option<&DataType> {
+        self.placeholder_types.get(&span)
+    }
 }
 
 #[derive(Clone)]
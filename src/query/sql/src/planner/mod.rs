@@ -17,6 +17,7 @@ mod format;
 mod metadata;
 #[allow(clippy::module_inception)]
 mod planner;
+mod prepared;
 mod semantic;
 
 pub mod binder;
@@ -42,5 +43,6 @@ pub use metadata::*;
 pub use planner::PlanExtras;
 pub use planner::Planner;
 pub use plans::ScalarExpr;
+pub use prepared::PreparedPlan;
 pub use semantic::*;
 pub use stream_column::*;
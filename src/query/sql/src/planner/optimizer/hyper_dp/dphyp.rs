@@ -263,6 +263,8 @@ impl DPhpy {
             | RelOperator::ConstantTableScan(_)
             | RelOperator::CteScan(_)
             | RelOperator::MaterializedCte(_) => Ok((s_expr, true)),
+            // Keep the join order of recursive term unchanged.
+            RelOperator::RecursiveCteScan(_) => Ok((s_expr, false)),
         }
     }
 
@@ -30,6 +30,7 @@ use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::hyper_dp::DPhpy;
 use crate::optimizer::runtime_filter::try_add_runtime_filter_nodes;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::util::contains_recursive_cte;
 use crate::optimizer::HeuristicOptimizer;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -110,13 +111,17 @@ pub fn optimize(
         Plan::ExplainAnalyze { plan } => Ok(Plan::ExplainAnalyze {
             plan: Box::new(optimize(ctx, opt_ctx, *plan)?),
         }),
-        Plan::CopyIntoLocation(CopyIntoLocationPlan { stage, path, from }) => {
-            Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
-                stage,
-                path,
-                from: Box::new(optimize(ctx, opt_ctx, *from)?),
-            }))
-        }
+        Plan::CopyIntoLocation(CopyIntoLocationPlan {
+            stage,
+            path,
+            from,
+            partition_by,
+        }) => Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
+            stage,
+            path,
+            from: Box::new(optimize(ctx, opt_ctx, *from)?),
+            partition_by,
+        })),
         Plan::CopyIntoTable(mut plan) if !plan.no_file_to_copy => {
             plan.enable_distributed = opt_ctx.config.enable_distributed_optimization
                 && ctx.get_settings().get_enable_distributed_copy()?;
@@ -179,6 +184,7 @@ pub fn optimize_query(
     s_expr: SExpr,
 ) -> Result<SExpr> {
     let contains_local_table_scan = contains_local_table_scan(&s_expr, &metadata);
+    let contains_recursive_cte = contains_recursive_cte(&s_expr);
 
     let heuristic = HeuristicOptimizer::new(ctx.get_function_context()?, metadata.clone());
     let mut result = heuristic.pre_optimize(s_expr)?;
@@ -196,8 +202,10 @@ pub fn optimize_query(
     result = cascades.optimize(result)?;
     // So far, we don't have ability to execute distributed query
     // with reading data from local tales(e.g. system tables).
-    let enable_distributed_query =
-        opt_ctx.config.enable_distributed_optimization && !contains_local_table_scan;
+    // Recursive ctes iterate with a local pipeline, so they also stay on one node.
+    let enable_distributed_query = opt_ctx.config.enable_distributed_optimization
+        && !contains_local_table_scan
+        && !contains_recursive_cte;
     // Add runtime filter related nodes after cbo
     // Because cbo may change join order and we don't want to
     // break optimizer due to new added nodes by runtime filter.
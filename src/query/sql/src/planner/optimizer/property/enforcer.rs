@@ -146,6 +146,10 @@ fn check_merge(s_expr: &SExpr) -> bool {
     if let RelOperator::CteScan(_) = s_expr.plan.as_ref() {
         return true;
     }
+    // Recursive cte is always executed locally
+    if let RelOperator::RecursiveCteScan(_) = s_expr.plan.as_ref() {
+        return true;
+    }
     if let RelOperator::Exchange(op) = s_expr.plan.as_ref() {
         if op == &Exchange::Merge {
             return true;
@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use common_exception::Result;
 use common_expression::arithmetics_type::ResultTypeOfUnary;
 use common_storage::Datum;
+use storages_common_table_meta::meta::ColumnHistogram;
 
 pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 
@@ -37,14 +38,22 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// constructed from NDV(number of distinct values) and the total number
 /// of rows instead of maintaining a real histogram for each column,
 /// which brings the assumption that the data is uniformly distributed.
+/// If the table has been analyzed, the histogram collected by `ANALYZE TABLE`
+/// is used instead, see [`histogram_from_collected`].
 #[derive(Debug, Clone)]
 pub struct Histogram {
     pub buckets: Vec<HistogramBucket>,
+    /// Most common values with their number of occurrences, only available for
+    /// collected histograms. They are counted in the buckets as well.
+    pub most_common_values: Vec<(Datum, f64)>,
 }
 
 impl Histogram {
     pub fn new(buckets: Vec<HistogramBucket>) -> Self {
-        Self { buckets }
+        Self {
+            buckets,
+            most_common_values: vec![],
+        }
     }
 
     /// Get number of buckets
@@ -73,6 +82,31 @@ impl Histogram {
     ) -> impl Iterator<Item = &HistogramBucket> + DoubleEndedIterator<Item = &HistogramBucket> {
         self.buckets.iter()
     }
+
+    /// Estimate the selectivity of `column = datum` with the most common values.
+    ///
+    /// Returns `None` if there are no most common values to use.
+    pub fn equal_selectivity(&self, datum: &Datum, ndv: f64) -> Option<f64> {
+        let (first, _) = self.most_common_values.first()?;
+        if !first.type_comparable(datum) {
+            return None;
+        }
+        let num_values = self.num_values();
+        if num_values <= 0.0 {
+            return None;
+        }
+        let mut num_common_values = 0.0;
+        for (value, count) in self.most_common_values.iter() {
+            if value.compare(datum).ok()? == Ordering::Equal {
+                return Some(f64::min(count / num_values, 1.0));
+            }
+            num_common_values += count;
+        }
+        // Assume the other values are uniformly distributed.
+        let num_other_distinct = f64::max(ndv - self.most_common_values.len() as f64, 1.0);
+        let num_other_values = f64::max(num_values - num_common_values, 0.0);
+        Some(num_other_values / num_values / num_other_distinct)
+    }
 }
 
 /// Construct a histogram from NDV and total number of rows.
@@ -95,7 +129,7 @@ pub fn histogram_from_ndv(
                 ndv, num_rows
             ))
         } else {
-            Ok(Histogram { buckets: vec![] })
+            Ok(Histogram::new(vec![]))
         };
     }
 
@@ -150,7 +184,79 @@ pub fn histogram_from_ndv(
         buckets.push(bucket);
     }
 
-    Ok(Histogram { buckets })
+    Ok(Histogram::new(buckets))
+}
+
+/// Construct a histogram from the one collected by `ANALYZE TABLE`.
+///
+/// The most common values are merged into the buckets covering them, or become
+/// buckets of their own, so that the buckets describe all the values of the column.
+/// Returns `None` if the values can't be converted to [`Datum`].
+pub fn histogram_from_collected(collected: &ColumnHistogram) -> Option<Histogram> {
+    let mut ranges =
+        Vec::with_capacity(collected.buckets.len() + collected.most_common_values.len());
+    for bucket in collected.buckets.iter() {
+        let lower_bound = Datum::from_scalar(bucket.lower_bound.clone())?;
+        let upper_bound = Datum::from_scalar(bucket.upper_bound.clone())?;
+        ranges.push((
+            lower_bound,
+            upper_bound,
+            bucket.num_values,
+            bucket.num_distinct,
+        ));
+    }
+
+    let mut most_common_values = Vec::with_capacity(collected.most_common_values.len());
+    for (value, count) in collected.most_common_values.iter() {
+        let value = Datum::from_scalar(value.clone())?;
+        let mut position = ranges.len();
+        let mut merged = false;
+        for (idx, (lower_bound, upper_bound, num_values, num_distinct)) in
+            ranges.iter_mut().enumerate()
+        {
+            if value.compare(upper_bound).ok()? != Ordering::Greater {
+                if value.compare(lower_bound).ok()? != Ordering::Less {
+                    *num_values += count;
+                    *num_distinct += 1.0;
+                    merged = true;
+                }
+                position = idx;
+                break;
+            }
+        }
+        if !merged {
+            ranges.insert(position, (value.clone(), value.clone(), *count, 1.0));
+        }
+        most_common_values.push((value, *count));
+    }
+
+    let min = ranges.first()?.0.clone();
+    let mut buckets = Vec::with_capacity(ranges.len() + 1);
+    // The first bucket is a dummy bucket which records the min value of the column.
+    buckets.push(HistogramBucket {
+        upper_bound: min.clone(),
+        num_values: 0.0,
+        num_distinct: 0.0,
+    });
+    for (_, upper_bound, num_values, num_distinct) in ranges {
+        if buckets.len() == 1 && upper_bound == min {
+            // A bucket holding only the min value, merge it into the dummy bucket
+            // to avoid an empty range.
+            buckets[0].num_values += num_values;
+            buckets[0].num_distinct += num_distinct;
+            continue;
+        }
+        buckets.push(HistogramBucket {
+            upper_bound,
+            num_values,
+            num_distinct,
+        });
+    }
+
+    Some(Histogram {
+        buckets,
+        most_common_values,
+    })
 }
 
 #[derive(Debug, Clone)]
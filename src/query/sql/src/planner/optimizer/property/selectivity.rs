@@ -132,9 +132,11 @@ impl<'a> SelectivityEstimator<'a> {
 
             return match op {
                 ComparisonOp::Equal => {
-                    // For equal predicate, we just use cardinality of a single
-                    // value to estimate the selectivity. This assumes that
-                    // the column is in a uniform distribution.
+                    // For equal predicate, we use the frequency of the value if it's
+                    // one of the most common values collected by `ANALYZE TABLE`,
+                    // otherwise we just use cardinality of a single value to estimate
+                    // the selectivity, which assumes that the column is in a uniform
+                    // distribution.
                     let selectivity = evaluate_equal(column_stat, constant);
                     if update {
                         update_statistic(
@@ -296,6 +298,9 @@ impl<'a> SelectivityEstimator<'a> {
                         for bucket in histogram.buckets.iter_mut() {
                             bucket.update(selectivity);
                         }
+                        for (_, count) in histogram.most_common_values.iter_mut() {
+                            *count *= selectivity;
+                        }
                     }
                 }
             }
@@ -354,6 +359,13 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
                 return 0.0;
             }
         }
+        if let Some(selectivity) = column_stat
+            .histogram
+            .as_ref()
+            .and_then(|histogram| histogram.equal_selectivity(constant_datum, column_stat.ndv))
+        {
+            return selectivity;
+        }
     }
 
     if column_stat.ndv == 0.0 {
@@ -384,7 +396,8 @@ fn update_statistic(
         let num_values = histogram.num_values();
         let new_num_values = (num_values * selectivity).ceil() as u64;
         let new_ndv = new_ndv as u64;
-        if new_ndv <= 2 {
+        // The histogram can't be constructed from ndv for strings.
+        if new_ndv <= 2 || new_min.is_bytes() {
             column_stat.histogram = None;
             return Ok(());
         }
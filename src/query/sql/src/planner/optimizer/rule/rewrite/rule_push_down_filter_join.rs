@@ -161,7 +161,11 @@ pub fn try_push_down_filter_join(
                 left_push_down.push(predicate);
             }
             JoinPredicate::Right(_) => {
-                if matches!(join.join_type, JoinType::Left) {
+                // The predicates on the right side of asof join would change the closest row.
+                if matches!(
+                    join.join_type,
+                    JoinType::Left | JoinType::Asof | JoinType::LeftAsof
+                ) {
                     original_predicates.push(predicate);
                     continue;
                 }
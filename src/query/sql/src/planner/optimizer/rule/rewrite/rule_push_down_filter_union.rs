@@ -93,6 +93,10 @@ impl Rule for RulePushDownFilterUnion {
         let filter: Filter = s_expr.plan().clone().try_into()?;
         let union_s_expr = s_expr.child(0)?;
         let union: UnionAll = union_s_expr.plan().clone().try_into()?;
+        // The filter can't be pushed into the recursive term of a recursive cte.
+        if union.cte_idx.is_some() {
+            return Ok(());
+        }
 
         // Create a filter which matches union's right child.
         let index_pairs: HashMap<IndexType, IndexType> =
@@ -367,6 +367,7 @@ impl SExpr {
             | RelOperator::Sort(_)
             | RelOperator::DummyTableScan(_)
             | RelOperator::CteScan(_)
+            | RelOperator::RecursiveCteScan(_)
             | RelOperator::AddRowNumber(_)
             | RelOperator::RuntimeFilterSource(_)
             | RelOperator::Pattern(_)
@@ -434,6 +435,7 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::Sort(_)
         | RelOperator::DummyTableScan(_)
         | RelOperator::CteScan(_)
+        | RelOperator::RecursiveCteScan(_)
         | RelOperator::AddRowNumber(_)
         | RelOperator::RuntimeFilterSource(_)
         | RelOperator::Pattern(_)
@@ -28,3 +28,12 @@ pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool
             false
         }
 }
+
+/// Check if a query contains a recursive cte, whose iterations must run on a single node.
+pub fn contains_recursive_cte(s_expr: &SExpr) -> bool {
+    s_expr
+        .children()
+        .iter()
+        .any(|s_expr| contains_recursive_cte(s_expr))
+        || matches!(s_expr.plan(), RelOperator::UnionAll(union) if union.cte_idx.is_some())
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_ast::ast::Expr;
@@ -23,14 +24,19 @@ use common_ast::parser::token::TokenKind;
 use common_ast::parser::token::Tokenizer;
 use common_ast::walk_statement_mut;
 use common_ast::Dialect;
+use common_ast::Visitor;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::query_kind::QueryKind;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::Scalar;
 use parking_lot::RwLock;
 
+use super::prepared::PlaceholderCollector;
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
+use super::PreparedPlan;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
@@ -167,6 +173,65 @@ impl Planner {
         }
     }
 
+    /// Plan a statement with `?` placeholders, the placeholders are bound to `params` in order.
+    ///
+    /// Returns the bound plan as well if it's a query, which can be planned again with other
+    /// parameters of the same types.
+    #[async_backtrace::framed]
+    pub async fn plan_prepared(
+        &mut self,
+        sql: &str,
+        params: Vec<Scalar>,
+    ) -> Result<(Plan, PlanExtras, Option<PreparedPlan>)> {
+        let settings = self.ctx.get_settings();
+        let sql_dialect = settings.get_sql_dialect()?;
+        let tokens: Vec<Token> = Tokenizer::new(sql).collect::<Result<_>>()?;
+        let (mut stmt, format) = parse_sql(&tokens, sql_dialect)?;
+
+        let mut collector = PlaceholderCollector::default();
+        collector.visit_statement(&stmt);
+        let mut spans = collector.spans;
+        spans.sort();
+        spans.dedup();
+        if spans.len() != params.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared statement expects {} parameters, but got {}",
+                spans.len(),
+                params.len()
+            )));
+        }
+        let params = spans.into_iter().zip(params).collect::<BTreeMap<_, _>>();
+
+        self.replace_stmt(&mut stmt, sql_dialect);
+
+        let mut metadata = Metadata::default();
+        metadata.set_placeholder_values(params.clone());
+        let metadata = Arc::new(RwLock::new(metadata));
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            metadata.clone(),
+        );
+        let plan = binder.bind(&stmt).await?;
+        let prepared = PreparedPlan::try_create(&plan, params, format.clone(), stmt.clone())?;
+
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
+        }));
+        let optimized_plan = optimize(self.ctx.clone(), opt_ctx, plan)?;
+        Ok((
+            optimized_plan,
+            PlanExtras {
+                metadata,
+                format,
+                statement: stmt,
+            },
+            prepared,
+        ))
+    }
+
     fn add_max_rows_limit(&self, statement: &mut Statement) {
         let max_rows = self.ctx.get_settings().get_max_result_rows().unwrap();
         if max_rows == 0 {
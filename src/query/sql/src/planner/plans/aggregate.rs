@@ -222,6 +222,9 @@ impl Operator for Aggregate {
                         for bucket in histogram.buckets.iter_mut() {
                             bucket.aggregate_values();
                         }
+                        for (_, count) in histogram.most_common_values.iter_mut() {
+                            *count = 1.0;
+                        }
                     }
                 }
             }
@@ -24,6 +24,8 @@ pub struct CopyIntoLocationPlan {
     pub stage: Box<StageInfo>,
     pub path: String,
     pub from: Box<Plan>,
+    /// Hive style partition keys, their values are the last columns of `from`.
+    pub partition_by: Vec<String>,
 }
 
 impl Debug for CopyIntoLocationPlan {
@@ -33,6 +35,9 @@ impl Debug for CopyIntoLocationPlan {
             "Copy into {:?}/{} from {:?}",
             self.stage, self.path, self.from
         )?;
+        if !self.partition_by.is_empty() {
+            write!(f, " partition by {:?}", self.partition_by)?;
+        }
         Ok(())
     }
 }
@@ -37,6 +37,25 @@ pub struct DropIndexPlan {
     pub index: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateInvertedIndexPlan {
+    pub if_not_exists: bool,
+    pub index_name: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub columns: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropInvertedIndexPlan {
+    pub if_exists: bool,
+    pub index_name: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct RefreshIndexPlan {
     pub index_id: u64,
@@ -50,6 +50,8 @@ pub struct CreateTablePlan {
     pub options: TableOptions,
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
+    pub temporary: bool,
+    pub clone_source: Option<CloneTableSource>,
     pub as_select: Option<Box<Plan>>,
 }
 
@@ -59,6 +61,14 @@ impl CreateTablePlan {
     }
 }
 
+/// The table that `CREATE TABLE ... CLONE` shares the snapshot with.
+#[derive(Clone, Debug)]
+pub struct CloneTableSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -61,6 +61,11 @@ pub enum JoinType {
     /// Single Join is a special kind of join that is used to process correlated scalar subquery.
     LeftSingle,
     RightSingle,
+    /// Asof Join matches each row of the left side with at most one row of the right side,
+    /// which is the closest one satisfying the match condition (the only non-equi condition).
+    Asof,
+    /// Left Asof Join also returns the unmatched rows of the left side.
+    LeftAsof,
 }
 
 impl JoinType {
@@ -131,6 +136,12 @@ impl Display for JoinType {
             JoinType::RightSingle => {
                 write!(f, "RIGHT SINGLE")
             }
+            JoinType::Asof => {
+                write!(f, "ASOF")
+            }
+            JoinType::LeftAsof => {
+                write!(f, "LEFT ASOF")
+            }
         }
     }
 }
@@ -300,7 +311,7 @@ impl Join {
             for (idx, left) in left_statistics.column_stats.iter_mut() {
                 if *idx == left_column_index {
                     if left.histogram.is_some() {
-                        left.histogram = if left.ndv as u64 <= 2 {
+                        left.histogram = if left.ndv as u64 <= 2 || left.min.is_bytes() {
                             None
                         } else {
                             if matches!(left.min, Datum::Int(_) | Datum::UInt(_) | Datum::Float(_))
@@ -324,7 +335,7 @@ impl Join {
             for (idx, right) in right_statistics.column_stats.iter_mut() {
                 if *idx == right_column_index {
                     if right.histogram.is_some() {
-                        right.histogram = if right.ndv as u64 <= 2 {
+                        right.histogram = if right.ndv as u64 <= 2 || right.min.is_bytes() {
                             None
                         } else {
                             if matches!(right.min, Datum::Int(_) | Datum::UInt(_) | Datum::Float(_))
@@ -447,9 +458,14 @@ impl Operator for Join {
                     + f64::max(right_cardinality, inner_join_cardinality)
                     - inner_join_cardinality
             }
-            JoinType::LeftSemi => f64::min(left_cardinality, inner_join_cardinality),
+            JoinType::LeftSemi | JoinType::Asof => {
+                f64::min(left_cardinality, inner_join_cardinality)
+            }
             JoinType::RightSemi => f64::min(right_cardinality, inner_join_cardinality),
-            JoinType::LeftSingle | JoinType::RightMark | JoinType::LeftAnti => left_cardinality,
+            JoinType::LeftSingle
+            | JoinType::RightMark
+            | JoinType::LeftAnti
+            | JoinType::LeftAsof => left_cardinality,
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
         };
         // Derive column statistics
@@ -58,6 +58,9 @@ pub struct MergeInto {
     pub unmatched_evaluators: Vec<UnmatchedEvaluator>,
     pub target_table_idx: usize,
     pub field_index_map: HashMap<FieldIndex, String>,
+    // the index of the target table's `_block_name`, only used to
+    // generate the stream columns of the updated rows.
+    pub block_name_index: Option<IndexType>,
 }
 
 impl std::fmt::Debug for MergeInto {
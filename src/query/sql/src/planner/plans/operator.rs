@@ -41,6 +41,7 @@ use crate::plans::CteScan;
 use crate::plans::Exchange;
 use crate::plans::Lambda;
 use crate::plans::ProjectSet;
+use crate::plans::RecursiveCteScan;
 use crate::plans::Udf;
 use crate::plans::Window;
 
@@ -88,6 +89,7 @@ pub enum RelOp {
     ConstantTableScan,
     AddRowNumber,
     Udf,
+    RecursiveCteScan,
 
     // Pattern
     Pattern,
@@ -115,6 +117,7 @@ pub enum RelOperator {
     Lambda(Lambda),
     ConstantTableScan(ConstantTableScan),
     Udf(Udf),
+    RecursiveCteScan(RecursiveCteScan),
     Pattern(PatternPlan),
 }
 
@@ -141,6 +144,7 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::AddRowNumber(rel_op) => rel_op.rel_op(),
             RelOperator::Udf(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -166,6 +170,7 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -191,6 +196,7 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -216,6 +222,7 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_cardinality(rel_expr),
         }
     }
 
@@ -287,6 +294,9 @@ impl Operator for RelOperator {
             RelOperator::Udf(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -647,3 +657,23 @@ impl TryFrom<RelOperator> for Udf {
         }
     }
 }
+
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(value: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
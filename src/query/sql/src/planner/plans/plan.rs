@@ -45,6 +45,7 @@ use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateDatamaskPolicyPlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
 use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateShareEndpointPlan;
@@ -70,6 +71,7 @@ use crate::plans::DropDatabasePlan;
 use crate::plans::DropDatamaskPolicyPlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
 use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropShareEndpointPlan;
@@ -222,6 +224,8 @@ pub enum Plan {
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
     RefreshIndex(Box<RefreshIndexPlan>),
+    CreateInvertedIndex(Box<CreateInvertedIndexPlan>),
+    DropInvertedIndex(Box<DropInvertedIndexPlan>),
 
     // Virtual Columns
     CreateVirtualColumn(Box<CreateVirtualColumnPlan>),
@@ -308,6 +312,11 @@ pub enum Plan {
     DescribeTask(Box<DescribeTaskPlan>),
     ShowTasks(Box<ShowTasksPlan>),
     ExecuteTask(Box<ExecuteTaskPlan>),
+
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
 }
 
 #[derive(Clone, Debug)]
@@ -359,6 +368,35 @@ impl Plan {
             _ => QueryKind::Other,
         }
     }
+
+    /// Whether the plan can be executed within an explicit transaction.
+    pub fn allowed_in_txn(&self) -> bool {
+        matches!(
+            self,
+            Plan::Query { .. }
+                | Plan::Explain { .. }
+                | Plan::ExplainAst { .. }
+                | Plan::ExplainSyntax { .. }
+                | Plan::ExplainAnalyze { .. }
+                | Plan::Insert(_)
+                | Plan::Replace(_)
+                | Plan::Delete(_)
+                | Plan::Update(_)
+                | Plan::MergeInto(_)
+                | Plan::CopyIntoTable(_)
+                | Plan::CopyIntoLocation(_)
+                | Plan::UseDatabase(_)
+                | Plan::SetVariable(_)
+                | Plan::UnSetVariable(_)
+                | Plan::ShowCreateDatabase(_)
+                | Plan::ShowCreateTable(_)
+                | Plan::DescribeTable(_)
+                | Plan::ExistsTable(_)
+                | Plan::Begin
+                | Plan::Commit
+                | Plan::Abort
+        )
+    }
 }
 
 impl Display for Plan {
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_catalog::plan::TableSample;
 use common_catalog::statistics::BasicColumnStatistics;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
@@ -29,6 +30,7 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
 use crate::optimizer::ColumnStatSet;
 use crate::optimizer::Distribution;
+use crate::optimizer::Histogram;
 use crate::optimizer::PhysicalProperty;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
@@ -83,6 +85,8 @@ pub struct Statistics {
     pub statistics: Option<TableStatistics>,
     // statistics will be ignored in comparison and hashing
     pub col_stats: HashMap<IndexType, Option<BasicColumnStatistics>>,
+    // histograms collected by `ANALYZE TABLE`, will be ignored in comparison and hashing
+    pub histograms: HashMap<IndexType, Option<Histogram>>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -94,6 +98,8 @@ pub struct Scan {
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
+    // `TABLESAMPLE` of the table
+    pub sample: Option<TableSample>,
 
     pub statistics: Statistics,
 }
@@ -107,6 +113,13 @@ impl Scan {
             .filter(|(col, _)| columns.contains(*col))
             .map(|(col, stat)| (*col, stat.clone()))
             .collect();
+        let histograms = self
+            .statistics
+            .histograms
+            .iter()
+            .filter(|(col, _)| columns.contains(*col))
+            .map(|(col, histogram)| (*col, histogram.clone()))
+            .collect();
 
         Scan {
             table_index: self.table_index,
@@ -117,9 +130,11 @@ impl Scan {
             statistics: Statistics {
                 statistics: self.statistics.statistics,
                 col_stats,
+                histograms,
             },
             prewhere,
             agg_index: self.agg_index.clone(),
+            sample: self.sample.clone(),
         }
     }
 
@@ -199,13 +214,16 @@ impl Operator for Scan {
                 let min = col_stat.min.unwrap();
                 let max = col_stat.max.unwrap();
                 let ndv = col_stat.ndv.unwrap();
-                let histogram = histogram_from_ndv(
-                    ndv,
-                    num_rows,
-                    Some((min.clone(), max.clone())),
-                    DEFAULT_HISTOGRAM_BUCKETS,
-                )
-                .ok();
+                let histogram = match self.statistics.histograms.get(k) {
+                    Some(Some(histogram)) => Some(histogram.clone()),
+                    _ => histogram_from_ndv(
+                        ndv,
+                        num_rows,
+                        Some((min.clone(), max.clone())),
+                        DEFAULT_HISTOGRAM_BUCKETS,
+                    )
+                    .ok(),
+                };
                 let column_stat = ColumnStat {
                     min,
                     max,
@@ -245,8 +263,16 @@ impl Operator for Scan {
             (_, _) => 0.0,
         };
 
-        // If prewhere is not none, we can't get precise cardinality
-        let precise_cardinality = if self.prewhere.is_none() {
+        let cardinality = match &self.sample {
+            Some(TableSample::Block { probability, .. } | TableSample::Row { probability, .. }) => {
+                cardinality * probability.into_inner()
+            }
+            Some(TableSample::FixedRows { rows, .. }) => cardinality.min(*rows as f64),
+            None => cardinality,
+        };
+
+        // If prewhere or sample is not none, we can't get precise cardinality
+        let precise_cardinality = if self.prewhere.is_none() && self.sample.is_none() {
             precise_cardinality
         } else {
             None
@@ -33,6 +33,10 @@ use crate::IndexType;
 pub struct UnionAll {
     // Pairs of unioned columns
     pub pairs: Vec<(IndexType, IndexType)>,
+    // If the union is the body of a recursive cte, `cte_idx` is the materialized cte
+    // holding its working table, see `RecursiveCteScan`.
+    // The left child is the anchor and the right child is the recursive term.
+    pub cte_idx: Option<(IndexType, IndexType)>,
 }
 
 impl UnionAll {
@@ -64,6 +64,9 @@ pub struct Window {
     pub order_by: Vec<WindowOrderByInfo>,
     // window frames
     pub frame: WindowFuncFrame,
+    // only the first `limit` rows of each partition are needed,
+    // it's set by `QUALIFY row_number() OVER (...) <= limit`.
+    pub limit: Option<usize>,
 }
 
 impl Window {
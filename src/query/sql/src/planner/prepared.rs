@@ -0,0 +1,338 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use common_ast::ast::Statement;
+use common_ast::Visitor;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Range;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::DataType;
+use common_expression::Scalar;
+use parking_lot::RwLock;
+
+use crate::optimizer::optimize;
+use crate::optimizer::OptimizerConfig;
+use crate::optimizer::OptimizerContext;
+use crate::optimizer::SExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::Exchange;
+use crate::plans::Plan;
+use crate::plans::RelOperator;
+use crate::plans::SubqueryExpr;
+use crate::plans::VisitorMut;
+use crate::plans::WindowFuncType;
+use crate::PlanExtras;
+
+/// The bound plan of a query with `?` placeholders.
+///
+/// The placeholders are bound as constants identified by their spans. To execute the query
+/// with other parameters of the same types, the constants are replaced in the bound plan
+/// and the plan is optimized again, the query is not parsed nor bound again.
+#[derive(Clone, Debug)]
+pub struct PreparedPlan {
+    /// The bound `Plan::Query` before optimization.
+    plan: Plan,
+    /// The parameters the plan is bound with, keyed by the spans of the placeholders.
+    params: BTreeMap<Range, Scalar>,
+    format: Option<String>,
+    statement: Statement,
+}
+
+impl PreparedPlan {
+    /// Returns `None` if the plan is not a query, or some placeholders are not bound as
+    /// constants (e.g. they are folded with other constants), so it can't be reused.
+    pub(crate) fn try_create(
+        plan: &Plan,
+        params: BTreeMap<Range, Scalar>,
+        format: Option<String>,
+        statement: Statement,
+    ) -> Result<Option<PreparedPlan>> {
+        let Plan::Query { s_expr, .. } = plan else {
+            return Ok(None);
+        };
+
+        let mut replacer = PlaceholderReplacer::new(&params, &params);
+        replace_placeholders(s_expr, &mut replacer)?;
+        if !replacer.is_complete() {
+            return Ok(None);
+        }
+
+        Ok(Some(PreparedPlan {
+            plan: plan.clone(),
+            params,
+            format,
+            statement,
+        }))
+    }
+
+    /// The types of the parameters the plan is bound with.
+    pub fn param_types(&self) -> Vec<DataType> {
+        self.params
+            .values()
+            .map(|param| param.as_ref().infer_data_type())
+            .collect()
+    }
+
+    /// Build the optimized plan with the parameters, which must have the same types as
+    /// the parameters the plan is bound with.
+    pub fn plan(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        params: Vec<Scalar>,
+    ) -> Result<(Plan, PlanExtras)> {
+        let types = params
+            .iter()
+            .map(|param| param.as_ref().infer_data_type())
+            .collect::<Vec<_>>();
+        if types != self.param_types() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared plan is bound with parameters of types {:?}, but got {:?}",
+                self.param_types(),
+                types
+            )));
+        }
+        let new_params = self.params.keys().cloned().zip(params).collect();
+
+        let Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            rewrite_kind,
+            formatted_ast,
+            ignore_result,
+        } = &self.plan
+        else {
+            unreachable!("only the plan of a query is prepared");
+        };
+
+        let mut replacer = PlaceholderReplacer::new(&self.params, &new_params);
+        let s_expr = replace_placeholders(s_expr, &mut replacer)?;
+        let mut metadata = metadata.read().clone();
+        metadata.set_placeholder_values(new_params);
+        let metadata = Arc::new(RwLock::new(metadata));
+
+        let plan = Plan::Query {
+            s_expr: Box::new(s_expr),
+            metadata: metadata.clone(),
+            bind_context: bind_context.clone(),
+            rewrite_kind: rewrite_kind.clone(),
+            formatted_ast: formatted_ast.clone(),
+            ignore_result: *ignore_result,
+        };
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: !ctx.get_cluster().is_empty(),
+        }));
+        let optimized_plan = optimize(ctx, opt_ctx, plan)?;
+        Ok((optimized_plan, PlanExtras {
+            metadata,
+            format: self.format.clone(),
+            statement: self.statement.clone(),
+        }))
+    }
+}
+
+/// Collect the spans of the placeholders in a statement.
+#[derive(Default)]
+pub(crate) struct PlaceholderCollector {
+    pub(crate) spans: Vec<Range>,
+}
+
+impl<'ast> Visitor<'ast> for PlaceholderCollector {
+    fn visit_placeholder(&mut self, span: Span) {
+        if let Some(span) = span {
+            self.spans.push(span);
+        }
+    }
+}
+
+/// Replace the values of the placeholders bound as constants.
+///
+/// A constant is a placeholder only if it's at the span of a placeholder and holds the value
+/// the placeholder is bound with, otherwise it's rewritten by the binder and the placeholder
+/// is incomplete.
+struct PlaceholderReplacer<'a> {
+    bound: &'a BTreeMap<Range, Scalar>,
+    params: &'a BTreeMap<Range, Scalar>,
+    found: BTreeSet<Range>,
+    rewritten: bool,
+}
+
+impl<'a> PlaceholderReplacer<'a> {
+    fn new(bound: &'a BTreeMap<Range, Scalar>, params: &'a BTreeMap<Range, Scalar>) -> Self {
+        PlaceholderReplacer {
+            bound,
+            params,
+            found: BTreeSet::new(),
+            rewritten: false,
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        !self.rewritten && self.found.len() == self.bound.len()
+    }
+}
+
+impl VisitorMut<'_> for PlaceholderReplacer<'_> {
+    fn visit_constant_expr(&mut self, constant: &mut ConstantExpr) -> Result<()> {
+        let Some(span) = constant.span else {
+            return Ok(());
+        };
+        if let (Some(bound), Some(param)) = (self.bound.get(&span), self.params.get(&span)) {
+            if &constant.value == bound {
+                self.found.insert(span);
+                constant.value = param.clone();
+            } else {
+                self.rewritten = true;
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_subquery_expr(&mut self, subquery: &mut SubqueryExpr) -> Result<()> {
+        if let Some(child_expr) = subquery.child_expr.as_mut() {
+            self.visit(child_expr)?;
+        }
+        *subquery.subquery = replace_placeholders(&subquery.subquery, self)?;
+        Ok(())
+    }
+}
+
+fn replace_placeholders(s_expr: &SExpr, replacer: &mut PlaceholderReplacer) -> Result<SExpr> {
+    let mut plan = s_expr.plan().clone();
+    match &mut plan {
+        RelOperator::Scan(scan) => {
+            for predicate in scan.push_down_predicates.iter_mut().flatten() {
+                replacer.visit(predicate)?;
+            }
+            if let Some(prewhere) = scan.prewhere.as_mut() {
+                for predicate in prewhere.predicates.iter_mut() {
+                    replacer.visit(predicate)?;
+                }
+            }
+            if let Some(agg_index) = scan.agg_index.as_mut() {
+                for predicate in agg_index.predicates.iter_mut() {
+                    replacer.visit(predicate)?;
+                }
+                for selection in agg_index.selection.iter_mut() {
+                    replacer.visit(&mut selection.scalar)?;
+                }
+            }
+        }
+        RelOperator::Exchange(Exchange::Hash(hash)) => {
+            for hash in hash.iter_mut() {
+                replacer.visit(hash)?;
+            }
+        }
+        RelOperator::Join(join) => {
+            for condition in join
+                .left_conditions
+                .iter_mut()
+                .chain(join.right_conditions.iter_mut())
+                .chain(join.non_equi_conditions.iter_mut())
+            {
+                replacer.visit(condition)?;
+            }
+        }
+        RelOperator::EvalScalar(eval_scalar) => {
+            for item in eval_scalar.items.iter_mut() {
+                replacer.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::Filter(filter) => {
+            for predicate in filter.predicates.iter_mut() {
+                replacer.visit(predicate)?;
+            }
+        }
+        RelOperator::Aggregate(aggregate) => {
+            for item in aggregate
+                .group_items
+                .iter_mut()
+                .chain(aggregate.aggregate_functions.iter_mut())
+            {
+                replacer.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::Window(window) => {
+            match &mut window.function {
+                WindowFuncType::Aggregate(agg) => {
+                    for arg in agg.args.iter_mut() {
+                        replacer.visit(arg)?;
+                    }
+                }
+                WindowFuncType::LagLead(lag_lead) => {
+                    replacer.visit(&mut lag_lead.arg)?;
+                    if let Some(default) = lag_lead.default.as_mut() {
+                        replacer.visit(default)?;
+                    }
+                }
+                WindowFuncType::NthValue(nth) => {
+                    replacer.visit(&mut nth.arg)?;
+                }
+                _ => {}
+            }
+            for item in window
+                .arguments
+                .iter_mut()
+                .chain(window.partition_by.iter_mut())
+            {
+                replacer.visit(&mut item.scalar)?;
+            }
+            for order_by in window.order_by.iter_mut() {
+                replacer.visit(&mut order_by.order_by_item.scalar)?;
+            }
+        }
+        RelOperator::ProjectSet(project_set) => {
+            for srf in project_set.srfs.iter_mut() {
+                replacer.visit(&mut srf.scalar)?;
+            }
+        }
+        RelOperator::Lambda(lambda) => {
+            for item in lambda.items.iter_mut() {
+                replacer.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::Udf(udf) => {
+            for item in udf.items.iter_mut() {
+                replacer.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::Exchange(_)
+        | RelOperator::Limit(_)
+        | RelOperator::UnionAll(_)
+        | RelOperator::Sort(_)
+        | RelOperator::DummyTableScan(_)
+        | RelOperator::CteScan(_)
+        | RelOperator::RecursiveCteScan(_)
+        | RelOperator::AddRowNumber(_)
+        | RelOperator::RuntimeFilterSource(_)
+        | RelOperator::Pattern(_)
+        | RelOperator::MaterializedCte(_)
+        | RelOperator::ConstantTableScan(_) => {}
+    }
+
+    let children = s_expr
+        .children()
+        .iter()
+        .map(|child| Ok(Arc::new(replace_placeholders(child, replacer)?)))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(s_expr
+        .replace_plan(Arc::new(plan))
+        .replace_children(children))
+}
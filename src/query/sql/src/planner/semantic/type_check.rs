@@ -1136,6 +1136,21 @@ impl<'a> TypeChecker<'a> {
             Expr::Map { span, kvs, .. } => self.resolve_map(*span, kvs).await?,
 
             Expr::Tuple { span, exprs, .. } => self.resolve_tuple(*span, exprs).await?,
+
+            Expr::Placeholder { span } => {
+                let value = span
+                    .and_then(|span| self.metadata.read().placeholder_value(span).cloned())
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(
+                            "placeholder `?` is only allowed in prepared statements",
+                        )
+                        .set_span(*span)
+                    })?;
+                // The value is not shrunk nor folded with the other constants, so that it can
+                // be replaced with the parameters of the next execution.
+                let data_type = value.as_ref().infer_data_type();
+                Box::new((ConstantExpr { span: *span, value }.into(), data_type))
+            }
         };
 
         Ok(Box::new((scalar, data_type)))
@@ -1847,6 +1862,7 @@ impl<'a> TypeChecker<'a> {
             args: arguments,
         };
         let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS)?;
+        self.infer_placeholder_types(&args)?;
 
         // Run constant folding for arguments of the scalar function.
         // This will be helpful to simplify some constant expressions, especially
@@ -3683,11 +3699,61 @@ impl<'a> TypeChecker<'a> {
         Ok(result)
     }
 
+    /// Record the type of the unknown (`NULL`) placeholders in the arguments, which is the type
+    /// of the first argument that is neither a constant nor a placeholder.
+    fn infer_placeholder_types(&self, args: &[ScalarExpr]) -> Result<()> {
+        let mut metadata = self.metadata.write();
+        if metadata.placeholder_values().is_empty() {
+            return Ok(());
+        }
+
+        let placeholders = args
+            .iter()
+            .filter_map(|arg| match arg {
+                ScalarExpr::ConstantExpr(ConstantExpr {
+                    span: Some(span),
+                    value: Scalar::Null,
+                }) if metadata.is_placeholder(*span) => Some(*span),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if placeholders.is_empty() {
+            return Ok(());
+        }
+        if let Some(arg) = args
+            .iter()
+            .find(|arg| !matches!(arg, ScalarExpr::ConstantExpr(_)))
+        {
+            let data_type = arg.data_type()?.remove_nullable();
+            for span in placeholders {
+                metadata.set_placeholder_type(span, data_type.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Check whether the expression contains the placeholders of a prepared statement.
+    fn contains_placeholder<Index: ColumnIndex>(
+        &self,
+        expr: &common_expression::Expr<Index>,
+    ) -> bool {
+        match expr {
+            common_expression::Expr::Constant { span, .. } => {
+                matches!(span, Some(span) if self.metadata.read().is_placeholder(*span))
+            }
+            common_expression::Expr::ColumnRef { .. } => false,
+            common_expression::Expr::Cast { expr, .. } => self.contains_placeholder(expr),
+            common_expression::Expr::FunctionCall { args, .. } => {
+                args.iter().any(|arg| self.contains_placeholder(arg))
+            }
+        }
+    }
+
     fn try_fold_constant<Index: ColumnIndex>(
         &self,
         expr: &common_expression::Expr<Index>,
     ) -> Option<Box<(ScalarExpr, DataType)>> {
-        if expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        if expr.is_deterministic(&BUILTIN_FUNCTIONS) && !self.contains_placeholder(expr) {
             if let (common_expression::Expr::Constant { scalar, .. }, _) =
                 ConstantFolder::fold(expr, &self.func_ctx, &BUILTIN_FUNCTIONS)
             {
@@ -14,11 +14,17 @@
 
 use std::sync::Arc;
 
+use common_ast::parser::quote::quote_ident;
+use common_catalog::plan::ChangeType;
 use common_catalog::plan::StreamColumn;
 use common_catalog::plan::StreamColumnMeta;
 use common_catalog::plan::StreamColumnType;
 use common_exception::Result;
+use common_expression::Expr;
 use common_expression::TableSchema;
+use common_expression::CHANGE_ACTION_COL_NAME;
+use common_expression::CHANGE_IS_UPDATE_COL_NAME;
+use common_expression::CHANGE_ROW_ID_COL_NAME;
 use common_expression::ORIGIN_BLOCK_ID_COL_NAME;
 use common_expression::ORIGIN_BLOCK_ROW_NUM_COL_NAME;
 
@@ -50,107 +56,34 @@ pub fn gen_mutation_stream_operator(
 
     let input_schema = schema.remove_virtual_computed_fields();
     let fields_num = input_schema.fields().len();
-    let mut exprs = Vec::with_capacity(2);
 
     let origin_block_id_col =
         StreamColumn::new(ORIGIN_BLOCK_ID_COL_NAME, StreamColumnType::OriginBlockId);
-    let block_id_type = Box::new(origin_block_id_col.data_type());
     let origin_block_id_index = input_schema
         .index_of(origin_block_id_col.column_name())
         .unwrap();
-    let origin_block_id_scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
-        span: None,
-        column: ColumnBindingBuilder::new(
-            origin_block_id_col.column_name().to_string(),
-            origin_block_id_index,
-            block_id_type.clone(),
-            Visibility::Visible,
-        )
-        .build(),
-    });
-    let current_block_id_scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
-        span: None,
-        column: ColumnBindingBuilder::new(
-            CURRENT_BLOCK_ID_COL_NAME.to_string(),
-            fields_num,
-            block_id_type,
-            Visibility::Visible,
-        )
-        .build(),
-    });
-    let block_id_predicate = ScalarExpr::FunctionCall(FunctionCall {
-        span: None,
-        func_name: "is_not_null".to_string(),
-        params: vec![],
-        arguments: vec![origin_block_id_scalar.clone()],
-    });
-    // if(is_not_null(_origin_block_id), _origin_block_id, _current_block_id)
-    let block_id_scalar = ScalarExpr::FunctionCall(FunctionCall {
-        span: None,
-        func_name: "if".to_string(),
-        params: vec![],
-        arguments: vec![
-            block_id_predicate,
-            origin_block_id_scalar,
-            current_block_id_scalar,
-        ],
-    });
-    exprs.push(
-        block_id_scalar
-            .as_expr()?
-            .project_column_ref(|col| col.index),
-    );
-
     let origin_row_num_col = StreamColumn::new(
         ORIGIN_BLOCK_ROW_NUM_COL_NAME,
         StreamColumnType::OriginRowNum,
     );
-    let row_num_type = Box::new(origin_row_num_col.data_type());
     let origin_row_num_index = input_schema
         .index_of(origin_row_num_col.column_name())
         .unwrap();
-    let origin_row_num_scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
-        span: None,
-        column: ColumnBindingBuilder::new(
-            origin_row_num_col.column_name().to_string(),
+
+    let exprs = vec![
+        gen_mutation_stream_expr(
+            &origin_block_id_col,
+            origin_block_id_index,
+            CURRENT_BLOCK_ID_COL_NAME,
+            fields_num,
+        )?,
+        gen_mutation_stream_expr(
+            &origin_row_num_col,
             origin_row_num_index,
-            row_num_type.clone(),
-            Visibility::Visible,
-        )
-        .build(),
-    });
-    let current_row_num_scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
-        span: None,
-        column: ColumnBindingBuilder::new(
-            CURRENT_BLOCK_ROW_NUM_COL_NAME.to_string(),
+            CURRENT_BLOCK_ROW_NUM_COL_NAME,
             fields_num + 1,
-            row_num_type,
-            Visibility::Visible,
-        )
-        .build(),
-    });
-    let row_num_predicate = ScalarExpr::FunctionCall(FunctionCall {
-        span: None,
-        func_name: "is_not_null".to_string(),
-        params: vec![],
-        arguments: vec![origin_row_num_scalar.clone()],
-    });
-    // if(is_not_null(_origin_block_row_num), _origin_block_row_num, _current_block_row_num)
-    let row_num_scalar = ScalarExpr::FunctionCall(FunctionCall {
-        span: None,
-        func_name: "if".to_string(),
-        params: vec![],
-        arguments: vec![
-            row_num_predicate,
-            origin_row_num_scalar,
-            current_row_num_scalar,
-        ],
-    });
-    exprs.push(
-        row_num_scalar
-            .as_expr()?
-            .project_column_ref(|col| col.index),
-    );
+        )?,
+    ];
 
     let mut projections = Vec::with_capacity(fields_num);
     for i in 0..fields_num {
@@ -175,3 +108,112 @@ pub fn gen_mutation_stream_operator(
     let stream_columns = vec![origin_block_id_col, origin_row_num_col];
     Ok((stream_columns, operators))
 }
+
+/// Generate the expr of the stream column for mutation, which keeps the origin
+/// of the row if it is not null and takes the current one otherwise, e.g.
+/// `if(is_not_null(_origin_block_id), _origin_block_id, _current_block_id)`.
+pub fn gen_mutation_stream_expr(
+    stream_column: &StreamColumn,
+    origin_index: usize,
+    current_name: &str,
+    current_index: usize,
+) -> Result<Expr> {
+    let data_type = Box::new(stream_column.data_type());
+    let origin_scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
+        span: None,
+        column: ColumnBindingBuilder::new(
+            stream_column.column_name().to_string(),
+            origin_index,
+            data_type.clone(),
+            Visibility::Visible,
+        )
+        .build(),
+    });
+    let current_scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
+        span: None,
+        column: ColumnBindingBuilder::new(
+            current_name.to_string(),
+            current_index,
+            data_type,
+            Visibility::Visible,
+        )
+        .build(),
+    });
+    let predicate = ScalarExpr::FunctionCall(FunctionCall {
+        span: None,
+        func_name: "is_not_null".to_string(),
+        params: vec![],
+        arguments: vec![origin_scalar.clone()],
+    });
+    let scalar = ScalarExpr::FunctionCall(FunctionCall {
+        span: None,
+        func_name: "if".to_string(),
+        params: vec![],
+        arguments: vec![predicate, origin_scalar, current_scalar],
+    });
+    Ok(scalar.as_expr()?.project_column_ref(|col| col.index))
+}
+
+/// The table alias of one side of the changes of a standard stream in the generated changes query.
+pub fn stream_change_alias(stream: &str, change_type: ChangeType) -> String {
+    format!("{}{}", change_type.table_alias_prefix(), stream)
+}
+
+/// Generate the query of the changes of a standard stream.
+///
+/// The rows of the blocks added since the stream offset (insert side) are joined with
+/// the rows of the blocks removed since the stream offset (delete side) on the row id,
+/// which is the origin of the row. A row only in the insert side is inserted, a row only
+/// in the delete side is deleted, and a row in both sides with different values is updated.
+/// The rows in both sides with the same values are moved by mutations and are not changes.
+pub fn gen_stream_changes_query(database: &str, stream: &str, schema: &TableSchema) -> String {
+    let quote = |ident: &str| quote_ident(ident, '"', true);
+
+    let table = format!("{}.{}", quote(database), quote(stream));
+    let columns = schema
+        .remove_virtual_computed_fields()
+        .fields()
+        .iter()
+        .map(|field| quote(field.name()))
+        .collect::<Vec<_>>();
+    let row_id = quote(CHANGE_ROW_ID_COL_NAME);
+    let side = |change_type: ChangeType| {
+        format!(
+            "(SELECT {}, concat(to_string({}), '_', to_string({})) AS {} FROM {} AS {})",
+            columns.join(", "),
+            ORIGIN_BLOCK_ID_COL_NAME,
+            ORIGIN_BLOCK_ROW_NUM_COL_NAME,
+            row_id,
+            table,
+            quote(&stream_change_alias(stream, change_type)),
+        )
+    };
+    let insert_side = side(ChangeType::Insert);
+    let delete_side = side(ChangeType::Delete);
+    let unchanged = columns
+        .iter()
+        .map(|column| format!("i.{column} IS NOT DISTINCT FROM d.{column}"))
+        .collect::<Vec<_>>()
+        .join(" AND ");
+    let project = |alias: &str| {
+        columns
+            .iter()
+            .map(|column| format!("{alias}.{column}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    format!(
+        "SELECT {}, 'INSERT' AS {}, d.{row_id} IS NOT NULL AS {}, i.{row_id} AS {row_id} \
+        FROM {insert_side} AS i LEFT JOIN {delete_side} AS d ON i.{row_id} = d.{row_id} \
+        WHERE d.{row_id} IS NULL OR NOT ({unchanged}) \
+        UNION ALL \
+        SELECT {}, 'DELETE', i.{row_id} IS NOT NULL, d.{row_id} \
+        FROM {delete_side} AS d LEFT JOIN {insert_side} AS i ON d.{row_id} = i.{row_id} \
+        WHERE i.{row_id} IS NULL OR NOT ({unchanged})",
+        project("i"),
+        quote(CHANGE_ACTION_COL_NAME),
+        quote(CHANGE_IS_UPDATE_COL_NAME),
+        project("d"),
+    )
+}
@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
+use common_sql::optimizer::histogram_from_collected;
 use common_sql::optimizer::Histogram;
 use common_sql::optimizer::HistogramBucket;
 use common_storage::Datum;
+use storages_common_table_meta::meta::ColumnHistogram;
+use storages_common_table_meta::meta::HistogramBucket as CollectedBucket;
 
 #[test]
 fn test_histogram() {
@@ -28,3 +33,45 @@ fn test_histogram() {
     assert_eq!(histogram.num_values(), 4.0);
     assert_eq!(histogram.num_distinct_values(), 2.0);
 }
+
+#[test]
+fn test_histogram_from_collected() {
+    let int = |v: i32| Scalar::Number(NumberScalar::Int32(v));
+    let collected = ColumnHistogram {
+        most_common_values: vec![(int(0), 50.0), (int(15), 30.0), (int(40), 20.0)],
+        buckets: vec![
+            CollectedBucket {
+                lower_bound: int(1),
+                upper_bound: int(10),
+                num_values: 10.0,
+                num_distinct: 10.0,
+            },
+            CollectedBucket {
+                lower_bound: int(11),
+                upper_bound: int(20),
+                num_values: 10.0,
+                num_distinct: 9.0,
+            },
+        ],
+    };
+
+    let histogram = histogram_from_collected(&collected).unwrap();
+    // The dummy bucket holds the min value, 15 is merged into the second bucket,
+    // and 40 becomes a bucket of its own.
+    assert_eq!(histogram.num_buckets(), 4);
+    assert_eq!(histogram.buckets[0].upper_bound(), &Datum::Int(0));
+    assert_eq!(histogram.buckets[0].num_values(), 50.0);
+    assert_eq!(histogram.buckets[2].num_values(), 40.0);
+    assert_eq!(histogram.buckets[3].upper_bound(), &Datum::Int(40));
+    assert_eq!(histogram.num_values(), 120.0);
+
+    let selectivity = histogram.equal_selectivity(&Datum::UInt(15), 22.0).unwrap();
+    assert_eq!(selectivity, 30.0 / 120.0);
+    let selectivity = histogram.equal_selectivity(&Datum::UInt(5), 22.0).unwrap();
+    assert_eq!(selectivity, 20.0 / 120.0 / 19.0);
+    assert!(
+        histogram
+            .equal_selectivity(&Datum::Bytes(b"a".to_vec()), 22.0)
+            .is_none()
+    );
+}
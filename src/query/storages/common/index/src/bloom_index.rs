@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -25,12 +26,14 @@ use common_exception::Result;
 use common_exception::Span;
 use common_expression::converts::scalar_to_datavalue;
 use common_expression::eval_function;
+use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::AnyType;
 use common_expression::types::DataType;
 use common_expression::types::MapType;
 use common_expression::types::NullableType;
 use common_expression::types::Number;
 use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
 use common_expression::types::UInt64Type;
 use common_expression::types::ValueType;
 use common_expression::BlockEntry;
@@ -58,6 +61,9 @@ use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
 use crate::Index;
 
+/// Length in bytes of the grams stored in an n-gram filter.
+const NGRAM_SIZE: usize = 3;
+
 #[derive(Clone)]
 pub struct BloomIndexMeta {
     pub columns: Vec<(String, SingleColumnMeta)>,
@@ -123,6 +129,10 @@ impl TryFrom<ThriftFileMetaData> for BloomIndexMeta {
 ///         |  123456789abcd |  ac2345bcd   |
 ///         +----------------+--------------+
 /// ```
+///
+/// String columns listed in `ngram_index_columns` additionally get an `Ngram(name)` filter,
+/// which holds the distinct 3-byte grams of the column values, folded to ASCII lower case.
+/// It is used to prune blocks for `LIKE '%substr%'`, `contains` and `regexp`.
 pub struct BloomIndex {
     pub func_ctx: FunctionContext,
 
@@ -178,6 +188,7 @@ impl BloomIndex {
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -267,6 +278,51 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        for (index, field) in ngram_columns_map.into_iter() {
+            let field_type = &data_blocks_tobe_indexed[0].get_by_offset(index).data_type;
+            if !Self::supported_ngram_data_type(field_type) {
+                continue;
+            }
+            let source_columns_iter = data_blocks_tobe_indexed.iter().map(|block| {
+                let value = &block.get_by_offset(index).value;
+                value.convert_to_full_column(field_type, block.num_rows())
+            });
+            let column = Column::concat_columns(source_columns_iter)?.remove_nullable();
+            let column = StringType::try_downcast_column(&column).unwrap();
+
+            let mut grams = HashSet::<[u8; NGRAM_SIZE]>::new();
+            for value in column.iter() {
+                for gram in value.windows(NGRAM_SIZE) {
+                    let mut gram: [u8; NGRAM_SIZE] = gram.try_into().unwrap();
+                    gram.make_ascii_lowercase();
+                    grams.insert(gram);
+                }
+            }
+            if grams.is_empty() {
+                continue;
+            }
+
+            let mut builder =
+                StringColumnBuilder::with_capacity(grams.len(), grams.len() * NGRAM_SIZE);
+            for gram in grams.iter() {
+                builder.put_slice(gram);
+                builder.commit_row();
+            }
+            let (digests, _) = Self::calculate_nullable_column_digest(
+                &func_ctx,
+                &Column::String(builder.build()),
+                &DataType::String,
+            )?;
+
+            let mut filter_builder = Xor8Builder::create();
+            filter_builder.add_digests(digests.deref());
+            let filter = filter_builder.build()?;
+
+            let filter_name = Self::build_ngram_column_name(version, &field)?;
+            filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+            filters.push(Arc::new(filter));
+        }
+
         if filter_fields.is_empty() {
             return Ok(None);
         }
@@ -327,6 +383,24 @@ impl BloomIndex {
             },
         )?;
 
+        visit_expr_column_ngram_constant(&mut expr, &mut |span, col_name, grams, return_type| {
+            let filter_column = &Self::build_ngram_column_name(
+                self.version,
+                data_schema.field_with_name(col_name)?,
+            )?;
+
+            // If the column doesn't contain all the grams, we rewrite the expression to `false`.
+            if self.find_ngrams(filter_column, grams, scalar_map)? == FilterEvalResult::MustFalse {
+                Ok(Some(Expr::Constant {
+                    span,
+                    scalar: Scalar::Boolean(false),
+                    data_type: return_type.clone(),
+                }))
+            } else {
+                Ok(None)
+            }
+        })?;
+
         let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
         match new_expr {
@@ -423,6 +497,22 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all columns that match the pattern of `like(col, <constant>)`, `contains(col, <constant>)`
+    /// or `regexp(col, <constant>)` in the expression, with the grams the column must contain.
+    pub fn find_ngram_columns(
+        expr: &Expr<String>,
+        fields: Vec<TableField>,
+    ) -> Result<Vec<(TableField, Vec<Scalar>)>> {
+        let mut cols = Vec::new();
+        visit_expr_column_ngram_constant(&mut expr.clone(), &mut |_, col_name, grams, _| {
+            if let Some(v) = fields.iter().find(|f: &&TableField| f.name() == col_name) {
+                cols.push((v.clone(), grams.to_vec()));
+            }
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -438,6 +528,20 @@ impl BloomIndex {
         }
     }
 
+    /// The n-gram filter of a column will be stored with field name 'Ngram(column_name)'
+    pub fn build_ngram_column_name(version: u64, field: &TableField) -> Result<String> {
+        let index_version = BlockBloomFilterIndexVersion::try_from(version)?;
+        match index_version {
+            BlockBloomFilterIndexVersion::V0(_) => Err(ErrorCode::DeprecatedIndexFormat(
+                "bloom filter index version(v0) is deprecated",
+            )),
+            BlockBloomFilterIndexVersion::V2(_) | BlockBloomFilterIndexVersion::V3(_) => {
+                Ok(format!("Ngram({})", field.name()))
+            }
+            BlockBloomFilterIndexVersion::V4(_) => Ok(format!("Ngram({})", field.column_id())),
+        }
+    }
+
     fn find(
         &self,
         filter_column: &str,
@@ -472,6 +576,33 @@ impl BloomIndex {
         }
     }
 
+    fn find_ngrams(
+        &self,
+        filter_column: &str,
+        grams: &[Scalar],
+        scalar_map: &HashMap<Scalar, u64>,
+    ) -> Result<FilterEvalResult> {
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a n-gram filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+
+        let contains = grams.iter().all(|gram| {
+            scalar_map
+                .get(gram)
+                .map_or(true, |digest| filter.contains_digest(*digest))
+        });
+
+        if contains {
+            Ok(FilterEvalResult::Uncertain)
+        } else {
+            Ok(FilterEvalResult::MustFalse)
+        }
+    }
+
     pub fn supported_type(data_type: &TableDataType) -> bool {
         let data_type = DataType::from(data_type);
         Self::supported_data_type(&data_type)
@@ -488,6 +619,15 @@ impl BloomIndex {
         Xor8Filter::supported_type(data_type)
     }
 
+    pub fn supported_ngram_type(data_type: &TableDataType) -> bool {
+        let data_type = DataType::from(data_type);
+        Self::supported_ngram_data_type(&data_type)
+    }
+
+    pub fn supported_ngram_data_type(data_type: &DataType) -> bool {
+        data_type.remove_nullable() == DataType::String
+    }
+
     /// Checks if the average length of a string column exceeds 256 bytes.
     /// If it does, the bloom index for the column will not be established.
     fn check_large_string(column: &Column) -> bool {
@@ -612,3 +752,175 @@ fn visit_map_column(
     }
     Ok(None)
 }
+
+fn visit_expr_column_ngram_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &[Scalar], &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `like(Column, <constant>)`, `contains(Column, <constant>)`,
+    // `regexp(Column, <constant>)` or `regexp_like(Column, <constant>)`
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        if let [Expr::ColumnRef { id, data_type, .. }, pattern] = args.as_slice() {
+            if BloomIndex::supported_ngram_data_type(data_type) {
+                if let Some(pattern) = constant_string(pattern) {
+                    let grams = pattern_ngrams(&function.signature.name, pattern);
+                    if !grams.is_empty() {
+                        if let Some(new_expr) = visitor(*span, id, &grams, return_type)? {
+                            *expr = new_expr;
+                            return Ok(());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_ngram_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_ngram_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+fn constant_string(expr: &Expr<String>) -> Option<&[u8]> {
+    match expr {
+        Expr::Constant {
+            scalar: Scalar::String(s),
+            ..
+        } => Some(s),
+        Expr::Cast { expr, .. } => constant_string(expr),
+        _ => None,
+    }
+}
+
+/// Returns the distinct grams that a value must contain to match the pattern,
+/// folded to ASCII lower case like the grams in the n-gram filter.
+fn pattern_ngrams(function_name: &str, pattern: &[u8]) -> Vec<Scalar> {
+    let literals = match function_name {
+        "like" => like_literals(pattern),
+        "contains" => vec![pattern.to_vec()],
+        "regexp" | "regexp_like" => regexp_literals(pattern),
+        _ => vec![],
+    };
+
+    let mut grams = HashSet::new();
+    for literal in literals.iter() {
+        for gram in literal.windows(NGRAM_SIZE) {
+            grams.insert(gram.to_ascii_lowercase());
+        }
+    }
+    grams.into_iter().map(Scalar::String).collect()
+}
+
+/// Split a like pattern into the literal segments between wildcards.
+fn like_literals(pattern: &[u8]) -> Vec<Vec<u8>> {
+    let mut literals = vec![];
+    let mut literal = vec![];
+    let mut i = 0;
+    while i < pattern.len() {
+        match pattern[i] {
+            b'%' | b'_' => literals.push(std::mem::take(&mut literal)),
+            b'\\' => {
+                match pattern.get(i + 1) {
+                    Some(c @ (b'%' | b'_' | b'\\')) => literal.push(*c),
+                    // Other escapes are not matched the same way by all the paths of `like`.
+                    _ => literals.push(std::mem::take(&mut literal)),
+                }
+                i += 1;
+            }
+            c => literal.push(c),
+        }
+        i += 1;
+    }
+    literals.push(literal);
+    literals
+}
+
+/// Collect the literal segments that every match of a regular expression must contain.
+///
+/// Regular expressions are case-insensitive by default, so only ASCII characters
+/// whose case folding stays in ASCII are kept in the literals.
+fn regexp_literals(pattern: &[u8]) -> Vec<Vec<u8>> {
+    // Alternations and groups may turn any literal into an optional one.
+    if pattern.iter().any(|c| matches!(c, b'|' | b'(' | b')')) {
+        return vec![];
+    }
+
+    let mut literals = vec![];
+    let mut literal = vec![];
+    let mut i = 0;
+    while i < pattern.len() {
+        match pattern[i] {
+            b'\\' => {
+                match pattern.get(i + 1) {
+                    Some(c) if c.is_ascii_punctuation() => literal.push(*c),
+                    // Character classes and assertions without operands.
+                    Some(b'd' | b'D' | b'w' | b'W' | b's' | b'S' | b'b' | b'B' | b'A' | b'z') => {
+                        literals.push(std::mem::take(&mut literal))
+                    }
+                    // Escapes like `\x41`, `\u0041` or `\pL` take operands that
+                    // must not be read as literals, give up on them.
+                    _ => return vec![],
+                }
+                i += 1;
+            }
+            c @ (b'?' | b'*' | b'{') => {
+                // The quantified character may not appear at all.
+                literal.pop();
+                literals.push(std::mem::take(&mut literal));
+                if c == b'{' {
+                    match pattern[i..].iter().position(|c| *c == b'}') {
+                        Some(end) => i += end,
+                        None => return vec![],
+                    }
+                }
+            }
+            b'[' => {
+                literals.push(std::mem::take(&mut literal));
+                let mut end = i + 1;
+                if pattern.get(end) == Some(&b'^') {
+                    end += 1;
+                }
+                if pattern.get(end) == Some(&b']') {
+                    end += 1;
+                }
+                loop {
+                    match pattern.get(end) {
+                        Some(b']') => break,
+                        Some(b'\\') => end += 2,
+                        Some(b'[') | None => return vec![],
+                        Some(_) => end += 1,
+                    }
+                }
+                i = end;
+            }
+            b'.' | b'+' | b'^' | b'$' | b']' | b'}' => {
+                literals.push(std::mem::take(&mut literal));
+            }
+            c if c.is_ascii() && !matches!(c.to_ascii_lowercase(), b'k' | b's') => {
+                literal.push(c);
+            }
+            // `k` and `s` also match non-ASCII characters when case-insensitive.
+            _ => literals.push(std::mem::take(&mut literal)),
+        }
+        i += 1;
+    }
+    literals.push(literal);
+    literals
+}
@@ -0,0 +1,252 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::DataType;
+use common_expression::ColumnId;
+use common_expression::ConstantFolder;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_functions::scalars::tokenize;
+use common_functions::BUILTIN_FUNCTIONS;
+use roaring::RoaringBitmap;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::FilterEvalResult;
+use crate::Index;
+
+/// The inverted index of a block.
+///
+/// For each indexed column, it maps the tokens of the texts to the offsets
+/// of the rows containing them, which are called postings.
+/// The index is built together with the block, and serialized into a standalone file.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct InvertedIndex {
+    pub postings: BTreeMap<ColumnId, BTreeMap<Vec<u8>, RoaringBitmap>>,
+}
+
+impl InvertedIndex {
+    /// Create the inverted index of the columns of a block.
+    ///
+    /// `index_columns` maps the offsets of the indexed columns in the block to their column ids.
+    pub fn try_create(
+        block: &DataBlock,
+        index_columns: &BTreeMap<FieldIndex, ColumnId>,
+    ) -> Result<Option<Self>> {
+        if index_columns.is_empty() {
+            return Ok(None);
+        }
+
+        let mut postings = BTreeMap::new();
+        for (offset, column_id) in index_columns {
+            let entry = block.get_by_offset(*offset);
+            if !Self::supported_type(&entry.data_type) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "inverted index is not supported on column of type {}",
+                    entry.data_type
+                )));
+            }
+
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+            let mut column_postings = BTreeMap::<Vec<u8>, RoaringBitmap>::new();
+            for (row, value) in column.iter().enumerate() {
+                if let ScalarRef::String(text) = value {
+                    for token in tokenize(text) {
+                        column_postings.entry(token).or_default().insert(row as u32);
+                    }
+                }
+            }
+            postings.insert(*column_id, column_postings);
+        }
+
+        Ok(Some(Self { postings }))
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serialize(self).map_err(|e| {
+            ErrorCode::StorageOther(format!("failed to serialize inverted index: {}", e))
+        })
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        bincode::deserialize(data).map_err(|e| {
+            ErrorCode::StorageOther(format!("failed to deserialize inverted index: {}", e))
+        })
+    }
+
+    /// Apply the predicate expression, return `MustFalse` if none of the rows
+    /// can satisfy it, e.g. the tokens of a `match(column, query)` are missing.
+    ///
+    /// Otherwise return `Uncertain`.
+    #[minitrace::trace]
+    pub fn apply(
+        &self,
+        func_ctx: &FunctionContext,
+        mut expr: Expr<String>,
+        column_ids: &HashMap<String, ColumnId>,
+    ) -> Result<FilterEvalResult> {
+        visit_expr_match(&mut expr, &mut |span, col_name, query, return_type| {
+            match self.matched_rows(column_ids, col_name, query) {
+                // If no rows contain all the tokens, we rewrite the expression to `false`.
+                Some(rows) if rows.is_empty() => Ok(Some(Expr::Constant {
+                    span,
+                    scalar: Scalar::Boolean(false),
+                    data_type: return_type.clone(),
+                })),
+                _ => Ok(None),
+            }
+        })?;
+
+        let (new_expr, _) = ConstantFolder::fold(&expr, func_ctx, &BUILTIN_FUNCTIONS);
+
+        match new_expr {
+            Expr::Constant {
+                scalar: Scalar::Boolean(false),
+                ..
+            } => Ok(FilterEvalResult::MustFalse),
+            _ => Ok(FilterEvalResult::Uncertain),
+        }
+    }
+
+    /// Find the offsets of the rows which may satisfy the predicate expression,
+    /// by the `match(column, query)` in the conjunctions of the expression.
+    ///
+    /// Return `None` if no such conjunction can be evaluated by the index.
+    pub fn filter_rows(
+        &self,
+        expr: &Expr<String>,
+        column_ids: &HashMap<String, ColumnId>,
+    ) -> Option<RoaringBitmap> {
+        match expr {
+            Expr::FunctionCall { function, args, .. }
+                if function.signature.name == "and" || function.signature.name == "and_filters" =>
+            {
+                args.iter()
+                    .filter_map(|arg| self.filter_rows(arg, column_ids))
+                    .reduce(|acc, rows| acc & rows)
+            }
+            Expr::FunctionCall { function, args, .. } if function.signature.name == "match" => {
+                match args.as_slice() {
+                    [
+                        Expr::ColumnRef { id, .. },
+                        Expr::Constant {
+                            scalar: Scalar::String(query),
+                            ..
+                        },
+                    ] => self.matched_rows(column_ids, id, query.as_slice()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Find all columns that match the pattern of `match(column, <constant>)` in the expression.
+    pub fn find_match_columns(expr: &Expr<String>) -> Result<Vec<String>> {
+        let mut cols = Vec::new();
+        visit_expr_match(&mut expr.clone(), &mut |_, col_name, _, _| {
+            cols.push(col_name.to_string());
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
+    // The rows containing all the tokens of the query, `None` if the column is not indexed.
+    fn matched_rows(
+        &self,
+        column_ids: &HashMap<String, ColumnId>,
+        col_name: &str,
+        query: &[u8],
+    ) -> Option<RoaringBitmap> {
+        let postings = self.postings.get(column_ids.get(col_name)?)?;
+        let mut rows: Option<RoaringBitmap> = None;
+        for token in tokenize(query) {
+            let token_rows = match postings.get(&token) {
+                Some(token_rows) => token_rows,
+                None => return Some(RoaringBitmap::new()),
+            };
+            rows = Some(match rows {
+                Some(rows) => rows & token_rows,
+                None => token_rows.clone(),
+            });
+        }
+        // The query without any tokens matches nothing.
+        Some(rows.unwrap_or_default())
+    }
+}
+
+impl Index for InvertedIndex {
+    fn supported_type(data_type: &DataType) -> bool {
+        matches!(data_type.remove_nullable(), DataType::String)
+    }
+}
+
+fn visit_expr_match(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &[u8], &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `match(Column, <constant>)`.
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        if function.signature.name == "match" {
+            if let [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(query),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                // If the visitor returns a new expression, then replace with the current expression.
+                if let Some(new_expr) = visitor(*span, id, query.as_slice(), return_type)? {
+                    *expr = new_expr;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_match(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_match(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
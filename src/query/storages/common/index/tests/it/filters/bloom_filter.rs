@@ -118,6 +118,7 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -251,6 +252,7 @@ fn test_specify_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -291,6 +293,7 @@ fn test_string_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -309,6 +312,67 @@ fn test_string_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ngram_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let blocks = vec![DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        StringType::from_data(vec!["ERROR: disk full on node-1", "ok"]),
+    ])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let ngram_columns = bloom_columns_map(schema.clone(), vec![1]);
+    let fields = ngram_columns.values().cloned().collect::<Vec<_>>();
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &blocks_ref,
+        BTreeMap::new(),
+        ngram_columns,
+    )?
+    .unwrap();
+
+    for (func_name, pattern, expected) in [
+        ("like", "%disk full%", FilterEvalResult::Uncertain),
+        ("like", "%Error:%node_1", FilterEvalResult::Uncertain),
+        ("like", "%out of memory%", FilterEvalResult::MustFalse),
+        ("like", "%no_e-2%", FilterEvalResult::MustFalse),
+        ("like", "%ab%", FilterEvalResult::Uncertain),
+        ("contains", "full on", FilterEvalResult::Uncertain),
+        ("contains", "node-2", FilterEvalResult::MustFalse),
+        ("regexp", "disk\\s+full", FilterEvalResult::Uncertain),
+        ("regexp", "^error.*node-[0-9]$", FilterEvalResult::Uncertain),
+        ("regexp", "memory.*exhausted", FilterEvalResult::MustFalse),
+        ("regexp", "node-2?", FilterEvalResult::Uncertain),
+        ("regexp", "memory|disk", FilterEvalResult::Uncertain),
+        ("regexp", "memory\\s+exhausted", FilterEvalResult::MustFalse),
+        ("regexp", "disk\\x20full", FilterEvalResult::Uncertain),
+        ("regexp", "node\\x2d1", FilterEvalResult::Uncertain),
+        ("regexp", "\\u0045RROR", FilterEvalResult::Uncertain),
+        ("regexp", "\\U00000045RROR", FilterEvalResult::Uncertain),
+        ("regexp", "disk \\pLull", FilterEvalResult::Uncertain),
+    ] {
+        assert_eq!(
+            expected,
+            eval_ngram_index(
+                &index,
+                func_name,
+                "1",
+                fields.clone(),
+                schema.clone(),
+                pattern
+            ),
+            "{func_name}(1, '{pattern}')"
+        );
+    }
+
+    Ok(())
+}
+
 fn eval_index(
     index: &BloomIndex,
     col_name: &str,
@@ -352,6 +416,53 @@ fn eval_index(
     index.apply(expr, &scalar_map, schema).unwrap()
 }
 
+fn eval_ngram_index(
+    index: &BloomIndex,
+    func_name: &str,
+    col_name: &str,
+    fields: Vec<TableField>,
+    schema: Arc<TableSchema>,
+    pattern: &str,
+) -> FilterEvalResult {
+    let expr = check_function(
+        None,
+        func_name,
+        &[],
+        &[
+            Expr::ColumnRef {
+                span: None,
+                id: col_name.to_string(),
+                data_type: DataType::String,
+                display_name: col_name.to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(pattern.as_bytes().to_vec()),
+                data_type: DataType::String,
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    let ngram_query_cols = BloomIndex::find_ngram_columns(&expr, fields).unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    let func_ctx = FunctionContext::default();
+    for (_, grams) in ngram_query_cols.iter() {
+        for gram in grams.iter() {
+            if !scalar_map.contains_key(gram) {
+                let digest =
+                    BloomIndex::calculate_scalar_digest(&func_ctx, gram, &DataType::String)
+                        .unwrap();
+                scalar_map.insert(gram.clone(), digest);
+            }
+        }
+    }
+
+    index.apply(expr, &scalar_map, schema).unwrap()
+}
+
 #[allow(clippy::too_many_arguments)]
 fn eval_map_index(
     index: &BloomIndex,
@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt8Type;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use roaring::RoaringBitmap;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+
+#[test]
+fn test_inverted_index() -> Result<()> {
+    let block = DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2, 3]),
+        StringType::from_data(vec![
+            "Connection reset by peer",
+            "query finished",
+            "connection refused, query failed",
+        ]),
+    ]);
+    let index_columns = BTreeMap::from([(1, 1)]);
+    let index = InvertedIndex::try_create(&block, &index_columns)?.unwrap();
+
+    // The index is kept unchanged after the serialization.
+    let index = InvertedIndex::from_bytes(&index.to_bytes()?)?;
+
+    let column_ids = HashMap::<String, ColumnId>::from([("1".to_string(), 1)]);
+    let func_ctx = FunctionContext::default();
+
+    let expr = match_expr("1", "CONNECTION");
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        index.apply(&func_ctx, expr.clone(), &column_ids)?
+    );
+    assert_eq!(
+        Some(RoaringBitmap::from_iter([0, 2])),
+        index.filter_rows(&expr, &column_ids)
+    );
+
+    let expr = match_expr("1", "query connection");
+    assert_eq!(
+        Some(RoaringBitmap::from_iter([2])),
+        index.filter_rows(&expr, &column_ids)
+    );
+
+    let expr = match_expr("1", "timeout");
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        index.apply(&func_ctx, expr.clone(), &column_ids)?
+    );
+    assert_eq!(
+        Some(RoaringBitmap::new()),
+        index.filter_rows(&expr, &column_ids)
+    );
+
+    // The column without the inverted index can not be evaluated.
+    let column_ids = HashMap::<String, ColumnId>::from([("1".to_string(), 2)]);
+    let expr = match_expr("1", "timeout");
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        index.apply(&func_ctx, expr.clone(), &column_ids)?
+    );
+    assert_eq!(None, index.filter_rows(&expr, &column_ids));
+
+    // Only string columns are supported.
+    let index_columns = BTreeMap::from([(0, 0)]);
+    assert!(InvertedIndex::try_create(&block, &index_columns).is_err());
+
+    Ok(())
+}
+
+fn match_expr(col_name: &str, query: &str) -> Expr<String> {
+    check_function(
+        None,
+        "match",
+        &[],
+        &[
+            Expr::ColumnRef {
+                span: None,
+                id: col_name.to_string(),
+                data_type: DataType::String,
+                display_name: col_name.to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(query.as_bytes().to_vec()),
+                data_type: DataType::String,
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap()
+}
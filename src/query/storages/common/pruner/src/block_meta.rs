@@ -36,6 +36,9 @@ pub struct BlockMetaIndex {
     pub block_location: String,
     pub segment_location: String,
     pub snapshot_location: Option<String>,
+    /// The offsets of the rows which may match the full-text search conditions,
+    /// found by the inverted index. `None` means all the rows.
+    pub matched_rows: Option<Vec<u32>>,
 }
 
 #[typetag::serde(name = "block_meta_index")]
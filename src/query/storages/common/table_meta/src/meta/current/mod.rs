@@ -13,18 +13,20 @@
 // limitations under the License.
 
 pub use v0::ColumnMeta as SingleColumnMeta;
-pub use v1::TableSnapshotStatistics;
 pub use v2::BlockMeta;
 pub use v2::ClusterStatistics;
+pub use v2::ColumnHistogram;
 pub use v2::ColumnMeta;
 pub use v2::ColumnStatistics;
+pub use v2::DeletionVectorMeta;
+pub use v2::HistogramBucket;
 pub use v2::Statistics;
+pub use v2::TableSnapshotStatistics;
 pub use v4::CompactSegmentInfo;
 pub use v4::SegmentInfo;
 pub use v4::TableSnapshot;
 pub use v4::TableSnapshotLite;
 
 use super::v0;
-use super::v1;
 use super::v2;
 use super::v4;
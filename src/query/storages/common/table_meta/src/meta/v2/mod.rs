@@ -15,11 +15,16 @@
 mod segment;
 mod snapshot;
 pub mod statistics;
+mod table_snapshot_statistics;
 
 pub use segment::BlockMeta;
 pub use segment::ColumnMeta;
+pub use segment::DeletionVectorMeta;
 pub use segment::SegmentInfo;
 pub use snapshot::TableSnapshot;
 pub use statistics::ClusterStatistics;
 pub use statistics::ColumnStatistics;
 pub use statistics::Statistics;
+pub use table_snapshot_statistics::ColumnHistogram;
+pub use table_snapshot_statistics::HistogramBucket;
+pub use table_snapshot_statistics::TableSnapshotStatistics;
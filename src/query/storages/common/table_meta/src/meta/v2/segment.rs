@@ -80,6 +80,30 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// the rows deleted from the block without rewriting it.
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorMeta>,
+
+    /// location of inverted index
+    #[serde(default)]
+    pub inverted_index_location: Option<Location>,
+    #[serde(default)]
+    pub inverted_index_size: u64,
+}
+
+/// Meta information of the deletion vector of a block.
+///
+/// The deletion vector is a roaring bitmap of the offsets of the deleted rows,
+/// stored in a standalone file, the block data is left untouched.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletionVectorMeta {
+    /// location of the deletion vector file
+    pub location: Location,
+    /// size of the deletion vector file
+    pub size: u64,
+    /// number of the deleted rows
+    pub deleted_rows: u64,
 }
 
 impl BlockMeta {
@@ -109,6 +133,9 @@ impl BlockMeta {
             bloom_filter_index_size,
             compression,
             create_on,
+            deletion_vector: None,
+            inverted_index_location: None,
+            inverted_index_size: 0,
         }
     }
 
@@ -116,6 +143,15 @@ impl BlockMeta {
         self.compression
     }
 
+    /// Get the number of rows not deleted by the deletion vector.
+    pub fn live_row_count(&self) -> u64 {
+        self.row_count
+            - self
+                .deletion_vector
+                .as_ref()
+                .map_or(0, |deletion_vector| deletion_vector.deleted_rows)
+    }
+
     /// Get the page size of the block.
     /// - If the format is parquet, its page size is its row count.
     /// - If the format is native, its page size is the row count of each page.
@@ -254,6 +290,9 @@ impl BlockMeta {
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
             create_on: None,
+            deletion_vector: None,
+            inverted_index_location: None,
+            inverted_index_size: 0,
         }
     }
 
@@ -285,6 +324,9 @@ impl BlockMeta {
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
             create_on: None,
+            deletion_vector: None,
+            inverted_index_location: None,
+            inverted_index_size: 0,
         }
     }
 }
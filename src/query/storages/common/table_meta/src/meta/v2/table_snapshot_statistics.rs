@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_expression::ColumnId;
+use common_expression::Scalar;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::meta::v1;
+use crate::meta::FormatVersion;
+use crate::meta::SnapshotId;
+use crate::meta::Versioned;
+
+/// A bucket of an equi-height histogram.
+///
+/// The counts are estimated for the whole table, scaled up from the sampled rows.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HistogramBucket {
+    pub lower_bound: Scalar,
+    pub upper_bound: Scalar,
+    pub num_values: f64,
+    pub num_distinct: f64,
+}
+
+/// The distribution of the non-null values of a column, collected by `ANALYZE TABLE`.
+///
+/// The most common values are kept apart with their estimated count, the
+/// buckets describe the rest of the values.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ColumnHistogram {
+    pub most_common_values: Vec<(Scalar, f64)>,
+    pub buckets: Vec<HistogramBucket>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TableSnapshotStatistics {
+    /// format version of snapshot
+    pub format_version: FormatVersion,
+
+    /// id of snapshot
+    pub snapshot_id: SnapshotId,
+
+    pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    pub histograms: HashMap<ColumnId, ColumnHistogram>,
+}
+
+impl TableSnapshotStatistics {
+    pub fn new(
+        column_distinct_values: HashMap<ColumnId, u64>,
+        histograms: HashMap<ColumnId, ColumnHistogram>,
+    ) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: SnapshotId::new_v4(),
+            column_distinct_values,
+            histograms,
+        }
+    }
+
+    pub fn format_version(&self) -> u64 {
+        self.format_version
+    }
+
+    pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
+        &self.column_distinct_values
+    }
+
+    pub fn get_histograms(&self) -> &HashMap<ColumnId, ColumnHistogram> {
+        &self.histograms
+    }
+}
+
+impl From<v1::TableSnapshotStatistics> for TableSnapshotStatistics {
+    fn from(value: v1::TableSnapshotStatistics) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: value.snapshot_id,
+            column_distinct_values: value.column_distinct_values,
+            histograms: HashMap::new(),
+        }
+    }
+}
@@ -62,6 +62,13 @@ pub struct SegmentInfo {
 }
 
 impl SegmentInfo {
+    /// Format version of the segments which contain blocks with deletion vectors.
+    ///
+    /// The layout is the same as the current version, the version is bumped so that the
+    /// readers not aware of deletion vectors refuse these segments instead of returning
+    /// the deleted rows. Version 5 is reserved for the v5 segment layout.
+    pub const DELETION_VECTOR_VERSION: FormatVersion = 6;
+
     pub fn new(blocks: Vec<Arc<BlockMeta>>, summary: Statistics) -> Self {
         assert!(
             blocks.len() <= MAX_SEGMENT_BLOCK_NUMBER,
@@ -71,12 +78,24 @@ impl SegmentInfo {
         );
 
         Self {
-            format_version: SegmentInfo::VERSION,
+            format_version: Self::format_version_of(&blocks),
             blocks,
             summary,
         }
     }
 
+    /// The format version a segment comprising `blocks` is written with.
+    ///
+    /// Segments with deletion vectors are written with [`Self::DELETION_VECTOR_VERSION`],
+    /// the others with the current version.
+    pub fn format_version_of(blocks: &[Arc<BlockMeta>]) -> FormatVersion {
+        if blocks.iter().any(|block| block.deletion_vector.is_some()) {
+            Self::DELETION_VECTOR_VERSION
+        } else {
+            SegmentInfo::VERSION
+        }
+    }
+
     // Total block bytes of this segment.
     pub fn total_bytes(&self) -> u64 {
         self.summary.uncompressed_byte_size
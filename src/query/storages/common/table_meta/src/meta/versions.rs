@@ -110,17 +110,20 @@ impl SnapshotVersion {
 }
 
 impl Versioned<0> for v1::TableSnapshotStatistics {}
+impl Versioned<2> for v2::TableSnapshotStatistics {}
 
 impl Versioned<2> for DataBlock {}
 
 pub enum TableSnapshotStatisticsVersion {
     V0(PhantomData<v1::TableSnapshotStatistics>),
+    V2(PhantomData<v2::TableSnapshotStatistics>),
 }
 
 impl TableSnapshotStatisticsVersion {
     pub fn version(&self) -> u64 {
         match self {
             TableSnapshotStatisticsVersion::V0(a) => Self::ver(a),
+            TableSnapshotStatisticsVersion::V2(a) => Self::ver(a),
         }
     }
 
@@ -153,8 +156,12 @@ mod converters {
                 2 => Ok(SegmentInfoVersion::V2(testify_version::<_, 2>(PhantomData))),
                 3 => Ok(SegmentInfoVersion::V3(testify_version::<_, 3>(PhantomData))),
                 4 => Ok(SegmentInfoVersion::V4(testify_version::<_, 4>(PhantomData))),
+                // segments with deletion vectors share the layout of v4
+                v4::SegmentInfo::DELETION_VECTOR_VERSION => {
+                    Ok(SegmentInfoVersion::V4(testify_version::<_, 4>(PhantomData)))
+                }
                 _ => Err(ErrorCode::Internal(format!(
-                    "unknown segment version {value}, versions supported: 0, 1, 2, 3, 4"
+                    "unknown segment version {value}, versions supported: 0, 1, 2, 3, 4, 6"
                 ))),
             }
         }
@@ -183,8 +190,11 @@ mod converters {
                 0 => Ok(TableSnapshotStatisticsVersion::V0(testify_version::<_, 0>(
                     PhantomData,
                 ))),
+                2 => Ok(TableSnapshotStatisticsVersion::V2(testify_version::<_, 2>(
+                    PhantomData,
+                ))),
                 _ => Err(ErrorCode::Internal(format!(
-                    "unknown table snapshot statistics version {value}, versions supported: 0"
+                    "unknown table snapshot statistics version {value}, versions supported: 0, 2"
                 ))),
             }
         }
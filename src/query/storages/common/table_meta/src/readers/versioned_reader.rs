@@ -36,7 +36,11 @@ impl VersionedReader<TableSnapshotStatistics> for TableSnapshotStatisticsVersion
         let mut buffer: Vec<u8> = vec![];
         reader.read_to_end(&mut buffer).await?;
         let r = match self {
-            TableSnapshotStatisticsVersion::V0(v) => load_json(&buffer, v).await?,
+            TableSnapshotStatisticsVersion::V0(v) => {
+                let ts = load_json(&buffer, v).await?;
+                TableSnapshotStatistics::from(ts)
+            }
+            TableSnapshotStatisticsVersion::V2(v) => load_json(&buffer, v).await?,
         };
         Ok(r)
     }
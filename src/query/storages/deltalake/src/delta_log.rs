@@ -0,0 +1,314 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replay of the Delta transaction log.
+//!
+//! The state of a table at version `v` is rebuilt from the newest checkpoint
+//! not later than `v`, followed by the json commits up to `v`.
+//!
+//! See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md>
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+use opendal::Metakey;
+use opendal::Operator;
+use parquet::file::reader::FileReader;
+use parquet::file::serialized_reader::SerializedFileReader;
+use serde::Deserialize;
+
+pub const DELTA_LOG_DIR: &str = "_delta_log/";
+
+/// Reader features that do not change how the data files are read.
+const SUPPORTED_READER_FEATURES: &[&str] = &["timestampNtz"];
+
+/// One line of a commit file, or one row of a checkpoint.
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct Action {
+    add: Option<AddFile>,
+    remove: Option<RemoveFile>,
+    meta_data: Option<Metadata>,
+    protocol: Option<Protocol>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AddFile {
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: HashMap<String, Option<String>>,
+    pub size: i64,
+    pub stats: Option<String>,
+    deletion_vector: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug)]
+struct RemoveFile {
+    path: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: HashMap<String, Option<String>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Protocol {
+    min_reader_version: i32,
+    reader_features: Option<Vec<String>>,
+}
+
+/// The live data files and the metadata of a table version.
+#[derive(Debug)]
+pub struct DeltaSnapshot {
+    pub version: u64,
+    pub metadata: Metadata,
+    pub files: Vec<AddFile>,
+}
+
+#[derive(Default)]
+struct LogListing {
+    /// Commit version -> (path, last modified).
+    commits: BTreeMap<u64, (String, Option<DateTime<Utc>>)>,
+    /// Checkpoint version -> (number of parts, paths of the parts that are present).
+    checkpoints: BTreeMap<u64, (u64, Vec<String>)>,
+}
+
+impl LogListing {
+    #[async_backtrace::framed]
+    async fn list(op: &Operator) -> Result<LogListing> {
+        let mut listing = LogListing::default();
+        let mut lister = op
+            .lister_with(DELTA_LOG_DIR)
+            .metakey(Metakey::Mode | Metakey::LastModified)
+            .await?;
+        while let Some(de) = lister.try_next().await? {
+            if !de.metadata().is_file() {
+                continue;
+            }
+            let name = de.name();
+            let Some((version, rest)) = name.split_once('.') else {
+                continue;
+            };
+            let Ok(version) = version.parse::<u64>() else {
+                continue;
+            };
+            let path = de.path().to_string();
+            match rest.split('.').collect::<Vec<_>>().as_slice() {
+                ["json"] => {
+                    listing
+                        .commits
+                        .insert(version, (path, de.metadata().last_modified()));
+                }
+                ["checkpoint", "parquet"] => {
+                    listing.checkpoints.insert(version, (1, vec![path]));
+                }
+                ["checkpoint", _, parts, "parquet"] => {
+                    let Ok(parts) = parts.parse::<u64>() else {
+                        continue;
+                    };
+                    let entry = listing
+                        .checkpoints
+                        .entry(version)
+                        .or_insert_with(|| (parts, vec![]));
+                    entry.1.push(path);
+                }
+                // `.crc`, temporary files, etc.
+                _ => {}
+            }
+        }
+
+        if listing.commits.is_empty() && listing.checkpoints.is_empty() {
+            return Err(ErrorCode::TableInfoError(format!(
+                "no delta log is found in '{}{}'",
+                op.info().root(),
+                DELTA_LOG_DIR
+            )));
+        }
+        Ok(listing)
+    }
+
+    fn latest_version(&self) -> u64 {
+        let commit = self.commits.keys().next_back().copied();
+        let checkpoint = self.checkpoints.keys().next_back().copied();
+        commit.max(checkpoint).unwrap_or_default()
+    }
+
+    /// The newest complete checkpoint not later than `version`.
+    fn checkpoint_before(&self, version: u64) -> Option<(u64, Vec<String>)> {
+        self.checkpoints
+            .range(..=version)
+            .rev()
+            .find(|(_, (parts, paths))| paths.len() as u64 == *parts)
+            .map(|(v, (_, paths))| {
+                let mut paths = paths.clone();
+                paths.sort();
+                (*v, paths)
+            })
+    }
+}
+
+/// The version of the latest commit made at or before `point`.
+#[async_backtrace::framed]
+pub async fn version_at(op: &Operator, point: DateTime<Utc>) -> Result<u64> {
+    let listing = LogListing::list(op).await?;
+    listing
+        .commits
+        .iter()
+        .rev()
+        .find(|(_, (_, modified))| modified.is_some_and(|m| m <= point))
+        .map(|(v, _)| *v)
+        .ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "no delta version is committed before {point}"
+            ))
+        })
+}
+
+/// Load the snapshot of `version`, or of the latest version if it is `None`.
+#[async_backtrace::framed]
+pub async fn load_snapshot(op: &Operator, version: Option<u64>) -> Result<DeltaSnapshot> {
+    let listing = LogListing::list(op).await?;
+    let latest = listing.latest_version();
+    let version = version.unwrap_or(latest);
+    if version > latest {
+        return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+            "delta version {version} does not exist, the latest version is {latest}"
+        )));
+    }
+
+    let mut replay = LogReplay::default();
+    let start = match listing.checkpoint_before(version) {
+        Some((cp_version, paths)) => {
+            for path in paths {
+                let data = op.read(&path).await?;
+                for action in read_checkpoint(data)? {
+                    replay.apply(action)?;
+                }
+            }
+            cp_version + 1
+        }
+        None => 0,
+    };
+
+    for v in start..=version {
+        let Some((path, _)) = listing.commits.get(&v) else {
+            return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "commit of delta version {v} is missing, it may have been cleaned up"
+            )));
+        };
+        let data = op.read(path).await?;
+        for line in data.split(|c| *c == b'\n') {
+            if line.iter().all(|c| c.is_ascii_whitespace()) {
+                continue;
+            }
+            replay.apply(serde_json::from_slice(line)?)?;
+        }
+    }
+
+    replay.finish(version)
+}
+
+fn read_checkpoint(data: Vec<u8>) -> Result<Vec<Action>> {
+    let reader = SerializedFileReader::new(Bytes::from(data))?;
+    let mut actions = vec![];
+    for row in reader.get_row_iter(None)? {
+        let action: Action = serde_json::from_value(row?.to_json_value())?;
+        // Checkpoints also keep `txn` and `commitInfo` rows, which are all null here.
+        if action.add.is_some() || action.meta_data.is_some() || action.protocol.is_some() {
+            actions.push(action);
+        }
+    }
+    Ok(actions)
+}
+
+#[derive(Default)]
+struct LogReplay {
+    metadata: Option<Metadata>,
+    protocol: Option<Protocol>,
+    files: HashMap<String, AddFile>,
+}
+
+impl LogReplay {
+    fn apply(&mut self, action: Action) -> Result<()> {
+        if let Some(add) = action.add {
+            if add.deletion_vector.is_some() {
+                return Err(ErrorCode::Unimplemented(
+                    "delta tables with deletion vectors are not supported",
+                ));
+            }
+            self.files.insert(add.path.clone(), add);
+        }
+        if let Some(remove) = action.remove {
+            self.files.remove(&remove.path);
+        }
+        if let Some(metadata) = action.meta_data {
+            self.metadata = Some(metadata);
+        }
+        if let Some(protocol) = action.protocol {
+            self.protocol = Some(protocol);
+        }
+        Ok(())
+    }
+
+    fn finish(self, version: u64) -> Result<DeltaSnapshot> {
+        let metadata = self.metadata.ok_or_else(|| {
+            ErrorCode::TableInfoError(format!("metadata of delta version {version} is missing"))
+        })?;
+
+        if let Some(protocol) = &self.protocol {
+            if protocol.min_reader_version > 3 {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "delta reader version {} is not supported",
+                    protocol.min_reader_version
+                )));
+            }
+            for feature in protocol.reader_features.iter().flatten() {
+                if !SUPPORTED_READER_FEATURES.contains(&feature.as_str()) {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "delta reader feature '{feature}' is not supported"
+                    )));
+                }
+            }
+        }
+        if let Some(Some(mode)) = metadata.configuration.get("delta.columnMapping.mode") {
+            if mode != "none" {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "delta column mapping mode '{mode}' is not supported"
+                )));
+            }
+        }
+
+        let mut files = self.files.into_values().collect::<Vec<_>>();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(DeltaSnapshot {
+            version,
+            metadata,
+            files,
+        })
+    }
+}
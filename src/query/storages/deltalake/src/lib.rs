@@ -0,0 +1,38 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Read-only support of [Delta Lake](https://delta.io/) tables.
+//!
+//! A delta table is created on the location of an existing delta table, the
+//! schema is read from the latest version of the transaction log:
+//!
+//! ```sql
+//! CREATE TABLE t ENGINE = DELTA 's3://bkt/path/to/delta/' CONNECTION = ( ... );
+//! SELECT * FROM t AT (SNAPSHOT => '3');
+//! SELECT * FROM t AT (TIMESTAMP => '2023-11-01 00:00:00'::TIMESTAMP);
+//! ```
+//!
+//! The data files are pruned by the partition values and the min/max stats
+//! kept in the log. Tables with deletion vectors or column mapping are not
+//! supported yet.
+
+#![allow(clippy::diverging_sub_expression)]
+
+mod delta_log;
+mod partition;
+mod schema;
+mod table;
+mod table_source;
+
+pub use table::DeltaTable;
@@ -0,0 +1,282 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::serialize::read_decimal_with_size;
+use common_expression::serialize::uniform_date;
+use common_expression::types::date::string_to_date;
+use common_expression::types::decimal::DecimalDataType;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::timestamp::string_to_timestamp;
+use common_expression::types::Number;
+use common_expression::types::NumberDataType;
+use common_expression::types::F32;
+use common_expression::types::F64;
+use common_expression::with_integer_mapped_type;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use serde::Deserialize;
+use serde_json::Value;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+/// Convert the `schemaString` of the delta metadata to [`TableSchema`].
+pub fn table_schema(schema_string: &str) -> Result<TableSchema> {
+    let schema: Value = serde_json::from_str(schema_string)?;
+    let fields = struct_fields(&schema)?
+        .into_iter()
+        .map(|(name, ty)| TableField::new(&name, ty))
+        .collect();
+    Ok(TableSchema::new(fields))
+}
+
+fn struct_fields(ty: &Value) -> Result<Vec<(String, TableDataType)>> {
+    let fields = ty["fields"].as_array().ok_or_else(|| invalid_schema(ty))?;
+    fields
+        .iter()
+        .map(|field| {
+            let name = field["name"]
+                .as_str()
+                .ok_or_else(|| invalid_schema(field))?;
+            let ty = data_type(&field["type"])?;
+            let ty = wrap_nullable(ty, field["nullable"].as_bool().unwrap_or(true));
+            Ok((name.to_string(), ty))
+        })
+        .collect()
+}
+
+fn data_type(ty: &Value) -> Result<TableDataType> {
+    if let Some(primitive) = ty.as_str() {
+        return primitive_type(primitive);
+    }
+    match ty["type"].as_str() {
+        Some("struct") => {
+            let (fields_name, fields_type) = struct_fields(ty)?.into_iter().unzip();
+            Ok(TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            })
+        }
+        Some("array") => {
+            let inner = data_type(&ty["elementType"])?;
+            let inner = wrap_nullable(inner, ty["containsNull"].as_bool().unwrap_or(true));
+            Ok(TableDataType::Array(Box::new(inner)))
+        }
+        Some("map") => {
+            let key = data_type(&ty["keyType"])?;
+            let value = data_type(&ty["valueType"])?;
+            let value = wrap_nullable(value, ty["valueContainsNull"].as_bool().unwrap_or(true));
+            Ok(TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![key, value],
+            })))
+        }
+        _ => Err(invalid_schema(ty)),
+    }
+}
+
+fn primitive_type(ty: &str) -> Result<TableDataType> {
+    let ty = match ty {
+        "string" | "binary" => TableDataType::String,
+        "boolean" => TableDataType::Boolean,
+        "byte" => TableDataType::Number(NumberDataType::Int8),
+        "short" => TableDataType::Number(NumberDataType::Int16),
+        "integer" => TableDataType::Number(NumberDataType::Int32),
+        "long" => TableDataType::Number(NumberDataType::Int64),
+        "float" => TableDataType::Number(NumberDataType::Float32),
+        "double" => TableDataType::Number(NumberDataType::Float64),
+        "date" => TableDataType::Date,
+        "timestamp" | "timestamp_ntz" => TableDataType::Timestamp,
+        _ => {
+            // decimal(p,s)
+            let size = ty
+                .strip_prefix("decimal(")
+                .and_then(|v| v.strip_suffix(')'))
+                .and_then(|v| v.split_once(','))
+                .and_then(|(p, s)| {
+                    Some(DecimalSize {
+                        precision: p.trim().parse().ok()?,
+                        scale: s.trim().parse().ok()?,
+                    })
+                })
+                .filter(|size| size.precision <= 38 && size.scale <= size.precision);
+            match size {
+                Some(size) => TableDataType::Decimal(DecimalDataType::Decimal128(size)),
+                None => {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "delta type '{ty}' is not supported"
+                    )));
+                }
+            }
+        }
+    };
+    Ok(ty)
+}
+
+fn wrap_nullable(ty: TableDataType, nullable: bool) -> TableDataType {
+    if nullable { ty.wrap_nullable() } else { ty }
+}
+
+fn invalid_schema(v: &Value) -> ErrorCode {
+    ErrorCode::TableInfoError(format!("invalid delta schema: {v}"))
+}
+
+/// Parse the string form of a partition value, `None` stands for null.
+pub fn parse_partition_value(ty: &TableDataType, value: Option<&str>) -> Result<Scalar> {
+    let Some(value) = value else {
+        return Ok(Scalar::Null);
+    };
+    let scalar = match ty.remove_nullable() {
+        TableDataType::String => Some(Scalar::String(value.as_bytes().to_vec())),
+        TableDataType::Boolean => value.parse().ok().map(Scalar::Boolean),
+        TableDataType::Number(ty) => with_integer_mapped_type!(|NUM_TYPE| match ty {
+            NumberDataType::NUM_TYPE => value
+                .parse::<NUM_TYPE>()
+                .ok()
+                .map(|v| Scalar::Number(NUM_TYPE::upcast_scalar(v))),
+            NumberDataType::Float32 => value
+                .parse::<f32>()
+                .ok()
+                .map(|v| Scalar::Number(F32::upcast_scalar(F32::from(v)))),
+            NumberDataType::Float64 => value
+                .parse::<f64>()
+                .ok()
+                .map(|v| Scalar::Number(F64::upcast_scalar(F64::from(v)))),
+        }),
+        TableDataType::Date => {
+            string_to_date(value, Tz::UTC).map(|d| Scalar::Date(uniform_date(d)))
+        }
+        TableDataType::Timestamp => {
+            string_to_timestamp(value, Tz::UTC).map(|t| Scalar::Timestamp(t.timestamp_micros()))
+        }
+        TableDataType::Decimal(DecimalDataType::Decimal128(size)) => {
+            read_decimal_with_size::<i128>(value.as_bytes(), size, true, true)
+                .ok()
+                .map(|(v, _)| Scalar::Decimal(DecimalScalar::Decimal128(v, size)))
+        }
+        _ => None,
+    };
+    scalar.ok_or_else(|| {
+        ErrorCode::BadBytes(format!(
+            "cannot parse delta partition value '{value}' as {ty}"
+        ))
+    })
+}
+
+/// The `stats` of an add action, which is a json string.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct FileStats {
+    num_records: Option<u64>,
+    #[serde(default)]
+    min_values: HashMap<String, Value>,
+    #[serde(default)]
+    max_values: HashMap<String, Value>,
+    #[serde(default)]
+    null_count: HashMap<String, Value>,
+}
+
+/// Build the statistics of a data file, from the `stats` of the add action and
+/// the partition values. Only top level columns are collected.
+///
+/// Returns the statistics and the number of rows, if they are known.
+pub fn file_stats(
+    schema: &TableSchema,
+    stats: Option<&str>,
+    partition_values: &[(usize, Scalar)],
+) -> (StatisticsOfColumns, Option<u64>) {
+    let stats = stats.and_then(|s| serde_json::from_str::<FileStats>(s).ok());
+    let num_records = stats.as_ref().and_then(|s| s.num_records);
+    let mut columns = StatisticsOfColumns::new();
+
+    if let Some(stats) = &stats {
+        for field in schema.fields() {
+            let name = field.name();
+            let (Some(min), Some(max), Some(null_count)) = (
+                stats.min_values.get(name),
+                stats.max_values.get(name),
+                stats.null_count.get(name).and_then(|v| v.as_u64()),
+            ) else {
+                continue;
+            };
+            let ty = field.data_type().remove_nullable();
+            let (Some(min), Some(mut max)) = (stats_value(&ty, min), stats_value(&ty, max)) else {
+                continue;
+            };
+            // Timestamps in the stats are truncated to milliseconds.
+            if let Scalar::Timestamp(v) = &mut max {
+                *v += 999;
+            }
+            columns.insert(
+                field.column_id(),
+                ColumnStatistics::new(min, max, null_count, 0, None),
+            );
+        }
+    }
+
+    for (index, value) in partition_values {
+        let null_count = match (value, num_records) {
+            (Scalar::Null, Some(n)) => n,
+            (Scalar::Null, None) => continue,
+            _ => 0,
+        };
+        columns.insert(
+            schema.fields()[*index].column_id(),
+            ColumnStatistics::new(value.clone(), value.clone(), null_count, 0, None),
+        );
+    }
+
+    (columns, num_records)
+}
+
+fn stats_value(ty: &TableDataType, value: &Value) -> Option<Scalar> {
+    match ty {
+        TableDataType::Boolean => value.as_bool().map(Scalar::Boolean),
+        TableDataType::Number(ty) => with_integer_mapped_type!(|NUM_TYPE| match ty {
+            NumberDataType::NUM_TYPE => {
+                let v = NUM_TYPE::try_from(value.as_i64()?).ok()?;
+                Some(Scalar::Number(NUM_TYPE::upcast_scalar(v)))
+            }
+            NumberDataType::Float32 => {
+                let v = value.as_f64()? as f32;
+                Some(Scalar::Number(F32::upcast_scalar(F32::from(v))))
+            }
+            NumberDataType::Float64 => {
+                let v = value.as_f64()?;
+                Some(Scalar::Number(F64::upcast_scalar(F64::from(v))))
+            }
+        }),
+        TableDataType::Date => {
+            string_to_date(value.as_str()?, Tz::UTC).map(|d| Scalar::Date(uniform_date(d)))
+        }
+        TableDataType::Timestamp => {
+            let value = value.as_str()?;
+            let micros = match DateTime::parse_from_rfc3339(value) {
+                Ok(t) => t.timestamp_micros(),
+                Err(_) => string_to_timestamp(value, Tz::UTC)?.timestamp_micros(),
+            };
+            Some(Scalar::Timestamp(micros))
+        }
+        // Strings are truncated, and decimals are written as floats.
+        _ => None,
+    }
+}
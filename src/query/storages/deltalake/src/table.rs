@@ -0,0 +1,323 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::NavigationPoint;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::storage::StorageParams;
+use common_pipeline_core::Pipeline;
+use common_storage::DataOperator;
+use common_storages_parquet::ParquetRSReaderBuilder;
+use percent_encoding::percent_decode_str;
+use storages_common_pruner::RangePrunerCreator;
+use tokio::sync::OnceCell;
+
+use crate::delta_log::load_snapshot;
+use crate::delta_log::version_at;
+use crate::delta_log::DeltaSnapshot;
+use crate::partition::DeltaPartInfo;
+use crate::schema::file_stats;
+use crate::schema::parse_partition_value;
+use crate::schema::table_schema;
+use crate::table_source::ColumnSource;
+use crate::table_source::DeltaTableSource;
+
+/// The delta version a table is navigated to, kept in the table options so that
+/// the table rebuilt from the [`TableInfo`] reads the same version.
+pub const OPT_KEY_DELTA_VERSION: &str = "delta_version";
+
+/// A read-only table on the delta table at `storage_params`.
+pub struct DeltaTable {
+    info: TableInfo,
+    op: DataOperator,
+    /// `None` for the latest version.
+    version: Option<u64>,
+
+    snapshot: OnceCell<DeltaSnapshot>,
+}
+
+impl DeltaTable {
+    pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
+        let sp = info.meta.storage_params.as_ref().ok_or_else(|| {
+            ErrorCode::TableInfoError(format!("location of delta table {} is missing", info.desc))
+        })?;
+        let op = DataOperator::try_new(sp)?;
+        let version = info
+            .meta
+            .options
+            .get(OPT_KEY_DELTA_VERSION)
+            .map(|v| v.parse::<u64>())
+            .transpose()
+            .map_err(|e| ErrorCode::TableInfoError(format!("invalid delta version: {e}")))?;
+        Ok(Box::new(Self {
+            info,
+            op,
+            version,
+            snapshot: OnceCell::new(),
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: "DELTA".to_string(),
+            comment: "DELTA Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Read the schema of the latest version of the delta table at `sp`.
+    #[async_backtrace::framed]
+    pub async fn infer_schema(sp: &StorageParams) -> Result<TableSchema> {
+        let op = DataOperator::try_new(sp)?.operator();
+        let snapshot = load_snapshot(&op, None).await?;
+        table_schema(&snapshot.metadata.schema_string)
+    }
+
+    async fn snapshot(&self) -> Result<&DeltaSnapshot> {
+        self.snapshot
+            .get_or_try_init(|| async { load_snapshot(&self.op.operator(), self.version).await })
+            .await
+    }
+
+    /// Indices of the partition columns in the table schema.
+    fn partition_columns(&self, snapshot: &DeltaSnapshot) -> Vec<usize> {
+        let partition_columns = &snapshot.metadata.partition_columns;
+        self.schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| partition_columns.contains(f.name()))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let snapshot = self.snapshot().await?;
+        let schema = self.schema();
+        let partition_columns = self.partition_columns(snapshot);
+
+        let filter = push_downs.as_ref().and_then(|extra| {
+            extra
+                .filters
+                .as_ref()
+                .map(|f| f.filter.as_expr(&BUILTIN_FUNCTIONS))
+        });
+        let pruner =
+            RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, filter.as_ref())?;
+
+        let mut read_rows = 0;
+        let mut read_bytes = 0;
+        let mut parts = Vec::with_capacity(snapshot.files.len());
+        for file in &snapshot.files {
+            let partition_values = partition_columns
+                .iter()
+                .map(|i| {
+                    let field = &schema.fields()[*i];
+                    // An empty string stands for null as well.
+                    let value = file
+                        .partition_values
+                        .get(field.name())
+                        .and_then(|v| v.as_deref())
+                        .filter(|v| !v.is_empty());
+                    Ok((*i, parse_partition_value(field.data_type(), value)?))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let (stats, num_rows) = file_stats(&schema, file.stats.as_deref(), &partition_values);
+            if !pruner.should_keep(&stats, None) {
+                continue;
+            }
+
+            if file.path.contains("://") {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "delta data file out of the table location is not supported: {}",
+                    file.path
+                )));
+            }
+            let path = percent_decode_str(&file.path)
+                .decode_utf8()
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid delta file path: {e}")))?;
+
+            read_rows += num_rows.unwrap_or_default() as usize;
+            read_bytes += file.size as usize;
+            parts.push(Arc::new(Box::new(DeltaPartInfo {
+                path: path.into_owned(),
+                size: file.size as u64,
+                partition_columns: partition_columns.clone(),
+                partition_values: partition_values.into_iter().map(|(_, v)| v).collect(),
+            }) as Box<dyn PartInfo>));
+        }
+
+        Ok((
+            PartStatistics::new_estimated(
+                Some(snapshot.version.to_string()),
+                read_rows,
+                read_bytes,
+                parts.len(),
+                snapshot.files.len(),
+            ),
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    fn do_read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        partition_columns: &[usize],
+    ) -> Result<()> {
+        let parts_len = plan.parts.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(parts_len, max_threads);
+
+        // The partition columns are not stored in the data files.
+        let table_schema = self.schema();
+        let mut column_sources = Vec::with_capacity(table_schema.num_fields());
+        let mut data_fields = vec![];
+        for (i, field) in table_schema.fields().iter().enumerate() {
+            match partition_columns.iter().position(|c| *c == i) {
+                Some(n) => column_sources.push(ColumnSource::Partition(n)),
+                None => {
+                    column_sources.push(ColumnSource::Data(data_fields.len()));
+                    data_fields.push(field.clone());
+                }
+            }
+        }
+        let data_schema: TableSchemaRef = Arc::new(TableSchema::new(data_fields));
+
+        let arrow_schema = data_schema.to_arrow();
+        let arrow_fields = arrow_schema
+            .fields
+            .into_iter()
+            .map(|f| f.into())
+            .collect::<Vec<arrow_schema::Field>>();
+        let arrow_schema = arrow_schema::Schema::new(arrow_fields);
+
+        // TODO: push down the projection and the prewhere.
+        let mut builder = ParquetRSReaderBuilder::create(
+            ctx.clone(),
+            self.op.operator(),
+            data_schema.clone(),
+            &arrow_schema,
+        )?;
+        let parquet_reader = Arc::new(builder.build_full_reader()?);
+
+        let output_schema = Arc::new(DataSchema::from(plan.schema()));
+        let data_schema = Arc::new(DataSchema::from(data_schema));
+        let column_sources = Arc::new(column_sources);
+        pipeline.add_source(
+            |output| {
+                DeltaTableSource::create(
+                    ctx.clone(),
+                    output,
+                    output_schema.clone(),
+                    data_schema.clone(),
+                    column_sources.clone(),
+                    parquet_reader.clone(),
+                )
+            },
+            max_threads.max(1),
+        )
+    }
+}
+
+#[async_trait]
+impl Table for DeltaTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_partitions(ctx, push_downs).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        // The table may be rebuilt from the plan without loading the snapshot,
+        // and all the parts of a snapshot share the same partition columns.
+        let partition_columns = match plan.parts.partitions.first() {
+            Some(part) => DeltaPartInfo::from_part(part)?.partition_columns.clone(),
+            None => vec![],
+        };
+        self.do_read_data(ctx, plan, pipeline, &partition_columns)
+    }
+
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let op = self.op.operator();
+        let version = match point {
+            NavigationPoint::SnapshotID(id) => id
+                .parse::<u64>()
+                .map_err(|_| ErrorCode::BadArguments(format!("invalid delta version '{id}'")))?,
+            NavigationPoint::TimePoint(point) => version_at(&op, *point).await?,
+        };
+        let snapshot = load_snapshot(&op, Some(version)).await?;
+
+        let mut info = self.info.clone();
+        info.meta.schema = Arc::new(table_schema(&snapshot.metadata.schema_string)?);
+        info.meta
+            .options
+            .insert(OPT_KEY_DELTA_VERSION.to_string(), version.to_string());
+        Ok(Arc::new(DeltaTable {
+            info,
+            op: self.op.clone(),
+            version: Some(version),
+            snapshot: OnceCell::new_with(Some(snapshot)),
+        }))
+    }
+}
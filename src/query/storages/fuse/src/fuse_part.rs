@@ -31,6 +31,7 @@ use common_expression::Scalar;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::DeletionVectorMeta;
 use storages_common_table_meta::meta::Location;
 
 /// Fuse table partition information.
@@ -45,6 +46,7 @@ pub struct FusePartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    pub deletion_vector: Option<DeletionVectorMeta>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -75,6 +77,7 @@ impl FusePartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        deletion_vector: Option<DeletionVectorMeta>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -84,6 +87,7 @@ impl FusePartInfo {
             compression,
             sort_min_max,
             block_meta_index,
+            deletion_vector,
         }))
     }
 
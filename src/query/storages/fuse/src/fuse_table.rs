@@ -14,6 +14,8 @@
 
 use std::any::Any;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::str;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -31,7 +33,10 @@ use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockThresholds;
+use common_expression::ColumnId;
+use common_expression::FieldIndex;
 use common_expression::RemoteExpr;
+use common_expression::TableSchema;
 use common_expression::ORIGIN_BLOCK_ID_COL_NAME;
 use common_expression::ORIGIN_BLOCK_ROW_NUM_COL_NAME;
 use common_expression::ORIGIN_VERSION_COL_NAME;
@@ -65,8 +70,11 @@ use storages_common_table_meta::table::table_storage_prefix;
 use storages_common_table_meta::table::TableCompression;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use storages_common_table_meta::table::OPT_KEY_CLONE_BASE_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_DELETION_VECTOR;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -103,6 +111,7 @@ pub struct FuseTable {
     pub(crate) storage_format: FuseStorageFormat,
     pub(crate) table_compression: TableCompression,
     pub(crate) bloom_index_cols: BloomIndexColumns,
+    pub(crate) ngram_index_cols: BloomIndexColumns,
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
@@ -178,16 +187,29 @@ impl FuseTable {
             .and_then(|s| s.parse::<BloomIndexColumns>().ok())
             .unwrap_or(BloomIndexColumns::All);
 
+        let ngram_index_cols = table_info
+            .options()
+            .get(OPT_KEY_NGRAM_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
         let part_prefix = table_info.meta.part_prefix.clone();
 
-        let meta_location_generator =
-            TableMetaLocationGenerator::with_prefix(storage_prefix).with_part_prefix(part_prefix);
+        let clone_base_snapshot = table_info
+            .options()
+            .get(OPT_KEY_CLONE_BASE_SNAPSHOT_LOCATION)
+            .cloned();
+
+        let meta_location_generator = TableMetaLocationGenerator::with_prefix(storage_prefix)
+            .with_part_prefix(part_prefix)
+            .with_clone_base_snapshot(clone_base_snapshot);
 
         Ok(Box::new(FuseTable {
             table_info,
             meta_location_generator,
             cluster_key_meta,
             bloom_index_cols,
+            ngram_index_cols,
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
@@ -261,7 +283,7 @@ impl FuseTable {
     }
 
     pub fn table_snapshot_statistics_format_version(&self, location: &String) -> u64 {
-        TableMetaLocationGenerator::snapshot_version(location)
+        TableMetaLocationGenerator::snapshot_statistics_version(location)
     }
 
     #[minitrace::trace]
@@ -402,6 +424,40 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    pub fn ngram_index_cols(&self) -> BloomIndexColumns {
+        self.ngram_index_cols.clone()
+    }
+
+    /// The columns of the inverted indexes, mapping the field index in `schema` to the column id.
+    pub fn inverted_index_columns(&self, schema: &TableSchema) -> BTreeMap<FieldIndex, ColumnId> {
+        let column_ids = self
+            .table_info
+            .meta
+            .indexes
+            .values()
+            .flat_map(|index| index.column_ids.iter())
+            .collect::<HashSet<_>>();
+        schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| column_ids.contains(&field.column_id()))
+            .map(|(index, field)| (index, field.column_id()))
+            .collect()
+    }
+
+    /// Whether DELETE marks the deleted rows in deletion vectors instead of rewriting blocks.
+    ///
+    /// Only the parquet storage format is supported, and the change tracking relies on the
+    /// rewritten blocks to capture the deleted rows, so they can not be used together.
+    /// UPDATE, MERGE INTO and REPLACE INTO still rewrite the mutated blocks, which drops
+    /// the rows deleted by their deletion vectors.
+    pub fn deletion_vector_enabled(&self) -> bool {
+        matches!(self.storage_format, FuseStorageFormat::Parquet)
+            && !self.change_tracking_enabled()
+            && self.get_option(OPT_KEY_DELETION_VECTOR, false)
+    }
+
     // Check if table is attached.
     fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -724,12 +780,14 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     Some(table_statistics.column_distinct_values.clone()),
+                    table_statistics.histograms.clone(),
                     snapshot.summary.row_count,
                 )
             } else {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     None,
+                    HashMap::new(),
                     snapshot.summary.row_count,
                 )
             }
@@ -807,6 +865,10 @@ impl Table for FuseTable {
         true
     }
 
+    fn support_block_sample(&self) -> bool {
+        true
+    }
+
     fn support_virtual_columns(&self) -> bool {
         true
     }
@@ -24,6 +24,9 @@ use storages_common_table_meta::meta::Versioned;
 use uuid::Uuid;
 
 use crate::constants::FUSE_TBL_BLOCK_PREFIX;
+use crate::constants::FUSE_TBL_CLONE_REFERENCE_PREFIX;
+use crate::constants::FUSE_TBL_DELETION_VECTOR_PREFIX;
+use crate::constants::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
@@ -33,6 +36,12 @@ use crate::FUSE_TBL_AGG_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
+/// The version of the serialized deletion vector, a portable roaring bitmap.
+const DELETION_VECTOR_VERSION: u64 = 0;
+
+/// The version of the serialized inverted index.
+const INVERTED_INDEX_VERSION: u64 = 0;
+
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
 static SNAPSHOT_V1: SnapshotVersion = SnapshotVersion::V1(PhantomData);
 static SNAPSHOT_V2: SnapshotVersion = SnapshotVersion::V2(PhantomData);
@@ -41,11 +50,15 @@ static SNAPSHOT_V4: SnapshotVersion = SnapshotVersion::V4(PhantomData);
 
 static SNAPSHOT_STATISTICS_V0: TableSnapshotStatisticsVersion =
     TableSnapshotStatisticsVersion::V0(PhantomData);
+static SNAPSHOT_STATISTICS_V2: TableSnapshotStatisticsVersion =
+    TableSnapshotStatisticsVersion::V2(PhantomData);
 
 #[derive(Clone)]
 pub struct TableMetaLocationGenerator {
     prefix: String,
     part_prefix: String,
+    // The snapshot that a clone is created from, located under the prefix of the source table.
+    clone_base_snapshot: Option<String>,
 }
 
 impl TableMetaLocationGenerator {
@@ -53,6 +66,7 @@ impl TableMetaLocationGenerator {
         Self {
             prefix,
             part_prefix: "".to_string(),
+            clone_base_snapshot: None,
         }
     }
 
@@ -61,6 +75,11 @@ impl TableMetaLocationGenerator {
         self
     }
 
+    pub fn with_clone_base_snapshot(mut self, clone_base_snapshot: Option<String>) -> Self {
+        self.clone_base_snapshot = clone_base_snapshot;
+        self
+    }
+
     pub fn prefix(&self) -> &str {
         &self.prefix
     }
@@ -96,6 +115,33 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn block_inverted_index_location(&self, block_id: &Uuid) -> Location {
+        (
+            format!(
+                "{}/{}/{}_v{}.bin",
+                &self.prefix,
+                FUSE_TBL_INVERTED_INDEX_PREFIX,
+                block_id.as_simple(),
+                INVERTED_INDEX_VERSION,
+            ),
+            INVERTED_INDEX_VERSION,
+        )
+    }
+
+    pub fn gen_deletion_vector_location(&self) -> Location {
+        let deletion_vector_uuid = Uuid::new_v4();
+        (
+            format!(
+                "{}/{}/{}_v{}.bin",
+                &self.prefix,
+                FUSE_TBL_DELETION_VECTOR_PREFIX,
+                deletion_vector_uuid.as_simple(),
+                DELETION_VECTOR_VERSION,
+            ),
+            DELETION_VECTOR_VERSION,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
@@ -109,7 +155,14 @@ impl TableMetaLocationGenerator {
 
     pub fn snapshot_location_from_uuid(&self, id: &Uuid, version: u64) -> Result<String> {
         let snapshot_version = SnapshotVersion::try_from(version)?;
-        Ok(snapshot_version.create(id, &self.prefix))
+        let location = snapshot_version.create(id, &self.prefix);
+        // The history of a clone goes back to the snapshot of the source table it is created from.
+        if let Some(base) = &self.clone_base_snapshot {
+            if base.rsplit('/').next() == location.rsplit('/').next() {
+                return Ok(base.clone());
+            }
+        }
+        Ok(location)
     }
 
     pub fn snapshot_version(location: impl AsRef<str>) -> u64 {
@@ -135,14 +188,31 @@ impl TableMetaLocationGenerator {
         Ok(statistics_version.create(id, &self.prefix))
     }
 
-    pub fn snapshot_statistics_version(_location: impl AsRef<str>) -> u64 {
-        SNAPSHOT_STATISTICS_V0.version()
+    pub fn snapshot_statistics_version(location: impl AsRef<str>) -> u64 {
+        if location
+            .as_ref()
+            .ends_with(SNAPSHOT_STATISTICS_V2.suffix().as_str())
+        {
+            SNAPSHOT_STATISTICS_V2.version()
+        } else {
+            SNAPSHOT_STATISTICS_V0.version()
+        }
     }
 
     pub fn gen_last_snapshot_hint_location(&self) -> String {
         format!("{}/{}", &self.prefix, FUSE_TBL_LAST_SNAPSHOT_HINT)
     }
 
+    pub fn clone_reference_prefix(&self) -> String {
+        format!("{}/{}/", &self.prefix, FUSE_TBL_CLONE_REFERENCE_PREFIX)
+    }
+
+    /// The location of the file recording the snapshot that the clone `reference`
+    /// was created from, `reference` is the table id of the clone.
+    pub fn clone_reference_location(&self, reference: &str) -> String {
+        format!("{}{}", self.clone_reference_prefix(), reference)
+    }
+
     pub fn gen_virtual_block_location(location: &str) -> String {
         location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VIRTUAL_BLOCK_PREFIX)
     }
@@ -198,6 +268,7 @@ impl SnapshotLocationCreator for TableSnapshotStatisticsVersion {
     fn suffix(&self) -> String {
         match self {
             TableSnapshotStatisticsVersion::V0(_) => "_ts_v0.json".to_string(),
+            TableSnapshotStatisticsVersion::V2(_) => "_ts_v2.json".to_string(),
         }
     }
 }
@@ -21,6 +21,8 @@ mod write;
 
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
+pub use read::live_rows_bitmap;
+pub use read::serialize_deletion_vector;
 pub use read::AggIndexReader;
 pub use read::BlockReader;
 pub use read::BloomBlockFilterReader;
@@ -44,6 +46,7 @@ pub use write::write_data;
 pub use write::BlockBuilder;
 pub use write::BlockSerialization;
 pub use write::CachedMetaWriter;
+pub use write::DeletionVectorSerialization;
 pub use write::MetaWriter;
 pub use write::SegmentWriter;
 pub use write::WriteSettings;
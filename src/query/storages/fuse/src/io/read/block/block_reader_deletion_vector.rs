@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+use storages_common_table_meta::meta::DeletionVectorMeta;
+
+use crate::io::BlockReader;
+
+impl BlockReader {
+    #[async_backtrace::framed]
+    pub async fn read_deletion_vector(
+        &self,
+        deletion_vector: &Option<DeletionVectorMeta>,
+    ) -> Result<Option<RoaringBitmap>> {
+        read_deletion_vector(&self.operator, deletion_vector).await
+    }
+
+    pub fn sync_read_deletion_vector(
+        &self,
+        deletion_vector: &Option<DeletionVectorMeta>,
+    ) -> Result<Option<RoaringBitmap>> {
+        match deletion_vector {
+            None => Ok(None),
+            Some(meta) => {
+                let data = self.operator.blocking().read(&meta.location.0)?;
+                deserialize_deletion_vector(&data).map(Some)
+            }
+        }
+    }
+}
+
+#[async_backtrace::framed]
+async fn read_deletion_vector(
+    operator: &Operator,
+    deletion_vector: &Option<DeletionVectorMeta>,
+) -> Result<Option<RoaringBitmap>> {
+    match deletion_vector {
+        None => Ok(None),
+        Some(meta) => {
+            let data = operator.read(&meta.location.0).await?;
+            deserialize_deletion_vector(&data).map(Some)
+        }
+    }
+}
+
+pub fn serialize_deletion_vector(deleted_rows: &RoaringBitmap) -> Result<Vec<u8>> {
+    let mut data = Vec::with_capacity(deleted_rows.serialized_size());
+    deleted_rows.serialize_into(&mut data)?;
+    Ok(data)
+}
+
+fn deserialize_deletion_vector(data: &[u8]) -> Result<RoaringBitmap> {
+    RoaringBitmap::deserialize_from(data).map_err(|e| {
+        ErrorCode::StorageOther(format!("failed to deserialize deletion vector: {}", e))
+    })
+}
+
+/// Build the selection of the rows that are not deleted, for a block of `num_rows` rows.
+pub fn live_rows_bitmap(num_rows: usize, deleted_rows: &RoaringBitmap) -> Bitmap {
+    let mut bitmap = MutableBitmap::from_len_set(num_rows);
+    for row in deleted_rows.iter() {
+        let row = row as usize;
+        if row < num_rows {
+            bitmap.set(row, false);
+        }
+    }
+    bitmap.into()
+}
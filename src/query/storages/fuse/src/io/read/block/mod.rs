@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod block_reader;
+mod block_reader_deletion_vector;
 mod block_reader_deserialize;
 mod block_reader_merge_io;
 mod block_reader_merge_io_async;
@@ -23,6 +24,8 @@ mod block_reader_parquet_deserialize;
 mod decompressor;
 
 pub use block_reader::BlockReader;
+pub use block_reader_deletion_vector::live_rows_bitmap;
+pub use block_reader_deletion_vector::serialize_deletion_vector;
 pub use block_reader_deserialize::DeserializedArray;
 pub use block_reader_deserialize::FieldDeserializationContext;
 pub use block_reader_merge_io::MergeIOReadResult;
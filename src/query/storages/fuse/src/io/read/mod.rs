@@ -22,6 +22,8 @@ mod utils;
 mod virtual_column;
 
 pub use agg_index::AggIndexReader;
+pub use block::live_rows_bitmap;
+pub use block::serialize_deletion_vector;
 pub use block::BlockReader;
 pub use block::MergeIOReadResult;
 pub use block::NativeReaderExt;
@@ -28,7 +28,6 @@ use storages_common_cache_manager::CacheManager;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
-use storages_common_table_meta::meta::Versioned;
 
 use crate::io::MetaReaders;
 
@@ -121,7 +120,7 @@ impl SegmentsIO {
     pub async fn write_segment(dal: Operator, serialized_segment: SerializedSegment) -> Result<()> {
         assert_eq!(
             serialized_segment.segment.format_version,
-            SegmentInfo::VERSION
+            SegmentInfo::format_version_of(&serialized_segment.segment.blocks)
         );
         let raw_bytes = serialized_segment.segment.to_bytes()?;
         let compact_segment_info = CompactSegmentInfo::from_slice(&raw_bytes)?;
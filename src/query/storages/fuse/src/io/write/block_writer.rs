@@ -29,18 +29,23 @@ use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use opendal::Operator;
+use roaring::RoaringBitmap;
 use storages_common_blocks::blocks_to_parquet;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::meta::DeletionVectorMeta;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::table::TableCompression;
 
+use crate::io::serialize_deletion_vector;
 use crate::io::write::WriteSettings;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::util;
 use crate::statistics::gen_columns_statistics;
+use crate::statistics::get_traverse_columns_dfs;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseStorageFormat;
 
@@ -118,6 +123,7 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     ) -> Result<Option<Self>> {
         // write index
         let maybe_bloom_index = BloomIndex::try_create(
@@ -125,6 +131,7 @@ impl BloomIndexState {
             location.1,
             &[block],
             bloom_columns_map,
+            ngram_columns_map,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -150,11 +157,44 @@ impl BloomIndexState {
     }
 }
 
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        block: &DataBlock,
+        location: Location,
+        index_columns: &BTreeMap<FieldIndex, ColumnId>,
+    ) -> Result<Option<Self>> {
+        let maybe_inverted_index = InvertedIndex::try_create(block, index_columns)?;
+        if let Some(inverted_index) = maybe_inverted_index {
+            let data = inverted_index.to_bytes()?;
+            let size = data.len() as u64;
+            Ok(Some(Self {
+                data,
+                size,
+                location,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub inverted_index_state: Option<InvertedIndexState>,
+}
+
+pub struct DeletionVectorSerialization {
+    pub data: Vec<u8>,
+    pub block_meta: BlockMeta,
 }
 
 #[derive(Clone)]
@@ -165,6 +205,9 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+    /// The columns of the inverted indexes, mapping the field index to the column id.
+    pub inverted_index_columns: BTreeMap<FieldIndex, ColumnId>,
 }
 
 impl BlockBuilder {
@@ -180,11 +223,19 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            self.ngram_columns_map.clone(),
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
 
+        let inverted_index_location = self.meta_locations.block_inverted_index_location(&block_id);
+        let inverted_index_state = InvertedIndexState::try_create(
+            &data_block,
+            inverted_index_location,
+            &self.inverted_index_columns,
+        )?;
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
@@ -213,6 +264,12 @@ impl BlockBuilder {
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.try_into()?,
             create_on: Some(Utc::now()),
+            deletion_vector: None,
+            inverted_index_location: inverted_index_state.as_ref().map(|v| v.location.clone()),
+            inverted_index_size: inverted_index_state
+                .as_ref()
+                .map(|v| v.size)
+                .unwrap_or_default(),
         };
 
         let serialized = BlockSerialization {
@@ -220,7 +277,49 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            inverted_index_state,
         };
         Ok(serialized)
     }
+
+    /// Mark the rows of an existing block as deleted without rewriting the block.
+    ///
+    /// `deleted_rows` is the whole deletion vector of the block, while `deleted_block`
+    /// only contains the rows newly deleted by this mutation, it is used to keep the
+    /// null counts of the column statistics exact.
+    pub fn build_deletion_vector(
+        &self,
+        origin: &BlockMeta,
+        deleted_rows: RoaringBitmap,
+        deleted_block: &DataBlock,
+    ) -> Result<DeletionVectorSerialization> {
+        let mut col_stats = origin.col_stats.clone();
+        let deleted_block = deleted_block.convert_to_full();
+        let leaves = get_traverse_columns_dfs(&deleted_block)?;
+        let leaf_column_ids = self.source_schema.to_leaf_column_ids();
+        for ((_, col, _), column_id) in leaves.iter().zip(leaf_column_ids) {
+            if let Some(stats) = col_stats.get_mut(&column_id) {
+                let (is_all_null, bitmap) = col.validity();
+                let unset_bits = match (is_all_null, bitmap) {
+                    (true, _) => col.len(),
+                    (false, Some(bitmap)) => bitmap.unset_bits(),
+                    (false, None) => 0,
+                };
+                stats.null_count = stats.null_count.saturating_sub(unset_bits as u64);
+            }
+        }
+
+        let data = serialize_deletion_vector(&deleted_rows)?;
+        let deletion_vector = DeletionVectorMeta {
+            location: self.meta_locations.gen_deletion_vector_location(),
+            size: data.len() as u64,
+            deleted_rows: deleted_rows.len(),
+        };
+        let block_meta = BlockMeta {
+            col_stats,
+            deletion_vector: Some(deletion_vector),
+            ..origin.clone()
+        };
+        Ok(DeletionVectorSerialization { data, block_meta })
+    }
 }
@@ -22,6 +22,8 @@ pub use block_writer::write_data;
 pub use block_writer::BlockBuilder;
 pub use block_writer::BlockSerialization;
 pub use block_writer::BloomIndexState;
+pub use block_writer::DeletionVectorSerialization;
+pub use block_writer::InvertedIndexState;
 pub use meta_writer::CachedMetaWriter;
 pub use meta_writer::MetaWriter;
 pub use segment_writer::SegmentWriter;
@@ -14,9 +14,9 @@
 
 use common_exception::Result;
 use opendal::Operator;
+use storages_common_table_meta::meta::FormatVersion;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
-use storages_common_table_meta::meta::Versioned;
 
 use super::meta_writer::MetaWriter;
 use crate::io::CachedMetaWriter;
@@ -41,7 +41,7 @@ impl<'a> SegmentWriter<'a> {
 
     #[async_backtrace::framed]
     pub async fn write_segment(&self, segment: SegmentInfo) -> Result<Location> {
-        let location = self.generate_location();
+        let location = self.generate_location(segment.format_version);
         segment
             .write_meta_through_cache(self.data_accessor, &location.0)
             .await?;
@@ -50,15 +50,15 @@ impl<'a> SegmentWriter<'a> {
 
     #[async_backtrace::framed]
     pub async fn write_segment_no_cache(&self, segment: &SegmentInfo) -> Result<Location> {
-        let location = self.generate_location();
+        let location = self.generate_location(segment.format_version);
         segment
             .write_meta(self.data_accessor, location.0.as_str())
             .await?;
         Ok(location)
     }
 
-    fn generate_location(&self) -> Location {
+    fn generate_location(&self, format_version: FormatVersion) -> Location {
         let path = self.location_generator.gen_segment_info_location();
-        (path, SegmentInfo::VERSION)
+        (path, format_version)
     }
 }
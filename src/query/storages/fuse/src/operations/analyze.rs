@@ -16,20 +16,35 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ColumnId;
 use log::warn;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnHistogram;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::StatisticsOfColumns;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 
+use crate::io::live_rows_bitmap;
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
+use crate::statistics::histogram_supported_type;
 use crate::statistics::reduce_block_statistics;
 use crate::statistics::reduce_cluster_statistics;
+use crate::statistics::HistogramBuilder;
 use crate::FuseTable;
 
+/// The max number of blocks read by `ANALYZE TABLE` to build the histograms.
+const ANALYZE_SAMPLE_BLOCKS: usize = 32;
+/// The max number of rows sampled from each block to build the histograms.
+const ANALYZE_SAMPLE_ROWS_PER_BLOCK: usize = 4096;
+
 impl FuseTable {
     #[async_backtrace::framed]
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
@@ -59,6 +74,7 @@ impl FuseTable {
             let mut read_segment_count = 0;
             let mut col_stats = HashMap::new();
             let mut cluster_stats = None;
+            let mut blocks = Vec::new();
 
             let start = Instant::now();
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
@@ -79,10 +95,11 @@ impl FuseTable {
                     let segment = segment?;
                     stats_of_columns.push(segment.summary.col_stats.clone());
                     blocks_cluster_stats.push(segment.summary.cluster_stats.clone());
-                    segment.blocks.iter().for_each(|block| {
-                        let block = block.as_ref();
+                    segment.blocks.iter().for_each(|block_meta| {
+                        let block = block_meta.as_ref();
                         let row_count = block.row_count;
                         if row_count != 0 {
+                            blocks.push(block_meta.clone());
                             block_count_sum += 1;
                             row_count_sum += row_count;
                             for (i, col_stat) in block.col_stats.iter() {
@@ -127,8 +144,13 @@ impl FuseTable {
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
 
-            // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            // 3. Sample blocks to build the histograms.
+            let histograms = self
+                .build_histograms(ctx, &blocks, &col_stats, row_count_sum, &ndv_map)
+                .await?;
+
+            // 4. Generate new table statistics
+            let table_statistics = TableSnapshotStatistics::new(ndv_map, histograms);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -136,7 +158,7 @@ impl FuseTable {
                     table_statistics.format_version(),
                 )?;
 
-            // 4. Save table statistics
+            // 5. Save table statistics
             let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
             new_snapshot.summary.col_stats = col_stats;
             new_snapshot.summary.cluster_stats = cluster_stats;
@@ -155,4 +177,69 @@ impl FuseTable {
 
         Ok(())
     }
+
+    /// Build the histograms of the columns from the rows sampled evenly
+    /// from at most `ANALYZE_SAMPLE_BLOCKS` blocks.
+    #[async_backtrace::framed]
+    async fn build_histograms(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        blocks: &[Arc<BlockMeta>],
+        col_stats: &StatisticsOfColumns,
+        row_count: u64,
+        ndv_map: &HashMap<ColumnId, u64>,
+    ) -> Result<HashMap<ColumnId, ColumnHistogram>> {
+        let schema = self.schema();
+        let fields = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| histogram_supported_type(&DataType::from(f.data_type())))
+            .collect::<Vec<_>>();
+        if fields.is_empty() || blocks.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let projection = Projection::Columns(fields.iter().map(|(i, _)| *i).collect());
+        let block_reader =
+            self.create_block_reader(ctx.clone(), projection, false, false, false)?;
+        let settings = ReadSettings::from_ctx(ctx)?;
+        let mut builders = fields
+            .iter()
+            .map(|_| HistogramBuilder::default())
+            .collect::<Vec<_>>();
+        let block_step = blocks.len().div_ceil(ANALYZE_SAMPLE_BLOCKS);
+        for block_meta in blocks.iter().step_by(block_step) {
+            let mut block = block_reader
+                .read_by_meta(&settings, block_meta, &self.storage_format)
+                .await?;
+            // The deleted rows must not be sampled.
+            if let Some(deleted_rows) = block_reader
+                .read_deletion_vector(&block_meta.deletion_vector)
+                .await?
+            {
+                let live_rows = live_rows_bitmap(block.num_rows(), &deleted_rows);
+                block = block.filter_with_bitmap(&live_rows)?;
+            }
+            let num_rows = block.num_rows();
+            let row_step = num_rows.div_ceil(ANALYZE_SAMPLE_ROWS_PER_BLOCK);
+            for (builder, entry) in builders.iter_mut().zip(block.columns()) {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                builder.add_column(&column, row_step);
+            }
+        }
+
+        let mut histograms = HashMap::new();
+        for ((_, field), builder) in fields.into_iter().zip(builders) {
+            let column_id = field.column_id();
+            let null_count = col_stats.get(&column_id).map_or(0, |s| s.null_count);
+            let ndv = ndv_map.get(&column_id).copied().unwrap_or_default();
+            if let Some(histogram) = builder.build(row_count.saturating_sub(null_count), ndv) {
+                histograms.insert(column_id, histogram);
+            }
+        }
+        Ok(histograms)
+    }
 }
@@ -23,8 +23,10 @@ use common_expression::BlockThresholds;
 use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::Expr;
+use common_expression::RemoteExpr;
 use common_expression::SortColumnDescription;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_functions::SPACE_FILLING_CURVE_FUNCTIONS;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_pipeline_transforms::processors::create_dummy_items;
@@ -227,8 +229,13 @@ impl FuseTable {
         let mut extra_key_num = 0;
 
         let mut exprs = Vec::with_capacity(cluster_keys.len());
+        let mut keep_full_string = false;
 
         for remote_expr in &cluster_keys {
+            if let RemoteExpr::FunctionCall { id, .. } = remote_expr {
+                keep_full_string |= SPACE_FILLING_CURVE_FUNCTIONS.contains(&id.name().as_ref());
+            }
+
             let expr: Expr = remote_expr
                 .as_expr(&BUILTIN_FUNCTIONS)
                 .project_column_ref(|name| input_schema.index_of(name).unwrap());
@@ -266,7 +273,8 @@ impl FuseTable {
             operators,
             merged,
             ctx.get_function_context()?,
-        ))
+        )
+        .with_keep_full_string(keep_full_string))
     }
 
     pub fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
@@ -0,0 +1,264 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use log::info;
+use log::warn;
+use opendal::Operator;
+use storages_common_cache::LoadParams;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::SnapshotsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+/// The prefix of the clone references written before the clone is created.
+const PENDING_CLONE_REFERENCE_PREFIX: &str = "pending_";
+
+/// Returns the name of a pending clone reference created at `created_on`,
+/// which is `pending_{created_on in milliseconds}_{uuid}`.
+pub fn pending_clone_reference(created_on: DateTime<Utc>) -> String {
+    format!(
+        "{}{}_{}",
+        PENDING_CLONE_REFERENCE_PREFIX,
+        created_on.timestamp_millis(),
+        Uuid::new_v4().simple()
+    )
+}
+
+/// Returns the creation time of the pending clone reference `reference`.
+fn pending_clone_reference_created_on(reference: &str) -> Option<DateTime<Utc>> {
+    let (millis, _) = reference
+        .strip_prefix(PENDING_CLONE_REFERENCE_PREFIX)?
+        .split_once('_')?;
+    Utc.timestamp_millis_opt(millis.parse().ok()?).single()
+}
+
+/// The references of a clone that is being created, see [`FuseTable::add_clone_references`].
+pub struct CloneReferences {
+    operator: Operator,
+    snapshot_location: String,
+    // The prefixes of the tables owning the files of the snapshot.
+    owners: Vec<String>,
+    pending: Vec<String>,
+}
+
+impl CloneReferences {
+    /// Replaces the pending references by the references of the created clone.
+    #[async_backtrace::framed]
+    pub async fn commit(self, clone_table_id: u64) -> Result<()> {
+        let reference = clone_table_id.to_string();
+        for owner in &self.owners {
+            let location = TableMetaLocationGenerator::with_prefix(owner.clone())
+                .clone_reference_location(&reference);
+            self.operator
+                .write(&location, self.snapshot_location.clone())
+                .await?;
+        }
+        self.rollback().await
+    }
+
+    /// Removes the pending references, the clone is not created.
+    #[async_backtrace::framed]
+    pub async fn rollback(self) -> Result<()> {
+        self.operator.remove(self.pending).await?;
+        Ok(())
+    }
+}
+
+impl FuseTable {
+    /// Records that a table is being cloned from the snapshot `snapshot_location` of this table.
+    ///
+    /// The snapshot may share files with the tables that this table is cloned from, so
+    /// a reference is written under the prefix of every table owning a file of the snapshot,
+    /// which makes the gc of each of them keep the files shared with the clone.
+    ///
+    /// The references are pending until the clone is created. The gc can not tell whether
+    /// the clone of a pending reference exists, so it only removes the pending references
+    /// older than the retention period, whose creation is taken as interrupted.
+    #[async_backtrace::framed]
+    pub async fn add_clone_references(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot_location: &str,
+    ) -> Result<CloneReferences> {
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            ver: TableMetaLocationGenerator::snapshot_version(snapshot_location),
+            location: snapshot_location.to_string(),
+            len_hint: None,
+            put_cache: false,
+        };
+        let snapshot = reader.read(&params).await?;
+        let locations = self
+            .get_block_locations(ctx.clone(), &snapshot.segments, false, false)
+            .await?;
+
+        // The locations of the files of a table are `{table prefix}/{kind}/{file}`.
+        let mut owners = BTreeSet::new();
+        for location in std::iter::once(snapshot_location)
+            .chain(snapshot.table_statistics_location.as_deref())
+            .chain(snapshot.segments.iter().map(|loc| loc.0.as_str()))
+            .chain(locations.block_location.iter().map(String::as_str))
+            .chain(locations.bloom_location.iter().map(String::as_str))
+            .chain(
+                locations
+                    .deletion_vector_location
+                    .iter()
+                    .map(String::as_str),
+            )
+        {
+            if let Some(owner) = location.rsplitn(3, '/').nth(2) {
+                owners.insert(owner.to_string());
+            }
+        }
+
+        let reference = pending_clone_reference(Utc::now());
+        let mut references = CloneReferences {
+            operator: self.operator.clone(),
+            snapshot_location: snapshot_location.to_string(),
+            owners: owners.into_iter().collect(),
+            pending: vec![],
+        };
+        for owner in &references.owners {
+            let location = TableMetaLocationGenerator::with_prefix(owner.clone())
+                .clone_reference_location(&reference);
+            if let Err(e) = self
+                .operator
+                .write(&location, snapshot_location.to_string())
+                .await
+            {
+                references.rollback().await?;
+                return Err(e.into());
+            }
+            references.pending.push(location);
+        }
+        Ok(references)
+    }
+
+    /// Returns the locations of the snapshots of this table that the existing
+    /// clones are created from.
+    ///
+    /// The references of the clones that no longer exist, and the pending references
+    /// older than the retention period, are removed unless `dry_run`.
+    #[async_backtrace::framed]
+    pub async fn list_clone_references(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        dry_run: bool,
+    ) -> Result<Vec<String>> {
+        let prefix = self.meta_location_generator().clone_reference_prefix();
+        let files = SnapshotsIO::list_files(self.get_operator(), &prefix, None).await?;
+        if files.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let retention = Duration::hours(ctx.get_settings().get_retention_period()? as i64);
+        let retention_point = Utc::now() - retention;
+        let mut snapshot_locations = Vec::with_capacity(files.len());
+        for file in files {
+            let reference = &file[prefix.len()..];
+            if reference.starts_with(PENDING_CLONE_REFERENCE_PREFIX) {
+                // The clone may be being created, or its creation was interrupted.
+                match pending_clone_reference_created_on(reference) {
+                    Some(created_on) if created_on < retention_point => {
+                        info!(
+                            "pending clone reference {} of table {} is beyond the retention period, remove it",
+                            file, self.table_info.desc
+                        );
+                        if !dry_run {
+                            self.operator.delete(&file).await?;
+                        }
+                        continue;
+                    }
+                    Some(_) => {}
+                    None => {
+                        warn!("found unexpected file in {:}, found: {:?}", prefix, file);
+                    }
+                }
+                let snapshot_location = self.operator.read(&file).await?;
+                snapshot_locations.push(String::from_utf8(snapshot_location)?);
+                continue;
+            }
+            let clone_table_id = match reference.parse::<u64>() {
+                Ok(id) => id,
+                Err(_) => {
+                    warn!("found unexpected file in {:}, found: {:?}", prefix, file);
+                    continue;
+                }
+            };
+            // A dropped clone may still be undropped, only the vacuumed one is gone.
+            match catalog.get_table_meta_by_id(clone_table_id).await {
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => {
+                    info!(
+                        "clone {} of table {} no longer exists, remove its reference",
+                        clone_table_id, self.table_info.desc
+                    );
+                    if !dry_run {
+                        self.operator.delete(&file).await?;
+                    }
+                    continue;
+                }
+                Err(e) => return Err(e),
+                Ok(_) => {}
+            }
+            let snapshot_location = self.operator.read(&file).await?;
+            snapshot_locations.push(String::from_utf8(snapshot_location)?);
+        }
+        Ok(snapshot_locations)
+    }
+
+    /// Returns all the files of this table that are still referenced by its clones.
+    #[async_backtrace::framed]
+    pub async fn files_referenced_by_clones(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        dry_run: bool,
+    ) -> Result<HashSet<String>> {
+        let mut files = HashSet::new();
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        for snapshot_location in self.list_clone_references(ctx, dry_run).await? {
+            let params = LoadParams {
+                ver: TableMetaLocationGenerator::snapshot_version(snapshot_location.as_str()),
+                location: snapshot_location.clone(),
+                len_hint: None,
+                put_cache: false,
+            };
+            let snapshot = reader.read(&params).await?;
+            let locations = self
+                .get_block_locations(ctx.clone(), &snapshot.segments, false, false)
+                .await?;
+
+            files.insert(snapshot_location);
+            files.extend(snapshot.table_statistics_location.clone());
+            files.extend(snapshot.segments.iter().map(|loc| loc.0.clone()));
+            files.extend(locations.block_location);
+            files.extend(locations.bloom_location);
+            files.extend(locations.deletion_vector_location);
+        }
+        Ok(files)
+    }
+}
@@ -28,6 +28,7 @@ pub struct AbortOperation {
     pub segments: Vec<String>,
     pub blocks: Vec<String>,
     pub bloom_filter_indexes: Vec<String>,
+    pub deletion_vectors: Vec<String>,
 }
 
 impl AbortOperation {
@@ -35,14 +36,26 @@ impl AbortOperation {
         self.segments.extend(rhs.segments);
         self.blocks.extend(rhs.blocks);
         self.bloom_filter_indexes.extend(rhs.bloom_filter_indexes);
+        self.deletion_vectors.extend(rhs.deletion_vectors);
     }
 
     pub fn add_block(&mut self, block: &BlockMeta) {
+        // The block with a deletion vector reuses the data of the old block,
+        // only the deletion vector is newly written.
+        if let Some(deletion_vector) = &block.deletion_vector {
+            self.deletion_vectors
+                .push(deletion_vector.location.0.clone());
+            return;
+        }
+
         let block_location = block.location.clone();
         self.blocks.push(block_location.0);
         if let Some(index) = block.bloom_filter_index_location.clone() {
             self.bloom_filter_indexes.push(index.0);
         }
+        if let Some(index) = block.inverted_index_location.clone() {
+            self.bloom_filter_indexes.push(index.0);
+        }
     }
 
     pub fn add_segment(&mut self, segment: String) {
@@ -63,6 +76,7 @@ impl AbortOperation {
             .blocks
             .into_iter()
             .chain(self.bloom_filter_indexes.into_iter())
+            .chain(self.deletion_vectors.into_iter())
             .chain(self.segments.into_iter());
         fuse_file.remove_file_in_batch(locations).await
     }
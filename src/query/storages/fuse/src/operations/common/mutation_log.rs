@@ -195,6 +195,12 @@ fn merge_commit_meta(
                 .into_iter()
                 .chain(r.abort_operation.bloom_filter_indexes)
                 .collect(),
+            deletion_vectors: l
+                .abort_operation
+                .deletion_vectors
+                .into_iter()
+                .chain(r.abort_operation.deletion_vectors)
+                .collect(),
         },
     }
 }
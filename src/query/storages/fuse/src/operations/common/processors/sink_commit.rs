@@ -343,7 +343,9 @@ where F: SnapshotGenerator + Send + 'static
                 .await
                 {
                     Ok(_) => {
-                        if self.transient {
+                        // The new snapshot of a transient table is only buffered within an
+                        // explicit transaction, purging the historical data is not safe.
+                        if self.transient && !self.ctx.txn_mgr().lock().is_active() {
                             // Removes historical data, if table is transient
                             let latest = self.table.refresh(self.ctx.as_ref()).await?;
                             let tbl = FuseTable::try_from_table(latest.as_ref())?;
@@ -37,7 +37,6 @@ use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
-use storages_common_table_meta::meta::Versioned;
 
 use crate::io::SegmentsIO;
 use crate::io::SerializedSegment;
@@ -235,14 +234,13 @@ impl TableMutationAggregator {
                     for result in results {
                         if let Some((location, summary)) = result.new_segment_info {
                             // replace the old segment location with the new one.
-                            self.abort_operation.add_segment(location.clone());
+                            self.abort_operation.add_segment(location.0.clone());
                             merge_statistics_mut(
                                 &mut merged_statistics,
                                 &summary,
                                 self.default_cluster_key_id,
                             );
-                            replaced_segments
-                                .insert(result.index, (location, SegmentInfo::VERSION));
+                            replaced_segments.insert(result.index, location);
                         } else {
                             self.removed_segment_indexes.push(result.index);
                         }
@@ -369,6 +367,7 @@ impl TableMutationAggregator {
                 }
                 // create new segment info
                 let new_segment = SegmentInfo::new(new_blocks, new_summary.clone());
+                let format_version = new_segment.format_version;
 
                 // write the segment info.
                 let serialized_segment = SerializedSegment {
@@ -379,7 +378,7 @@ impl TableMutationAggregator {
 
                 Ok(SegmentLite {
                     index,
-                    new_segment_info: Some((location, new_summary)),
+                    new_segment_info: Some(((location, format_version), new_summary)),
                     origin_summary,
                 })
             });
@@ -433,7 +432,7 @@ struct SegmentLite {
     // segment index.
     index: usize,
     // new segment location and summary.
-    new_segment_info: Option<(String, Statistics)>,
+    new_segment_info: Option<(Location, Statistics)>,
     // origin segment summary.
     origin_summary: Option<Statistics>,
 }
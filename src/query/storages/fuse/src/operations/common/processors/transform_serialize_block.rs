@@ -36,10 +36,12 @@ use storages_common_index::BloomIndex;
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
+use crate::io::DeletionVectorSerialization;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
 use crate::operations::mutation::ClusterStatsGenType;
+use crate::operations::mutation::DeletionVectorBlock;
 use crate::operations::mutation::SerializeDataMeta;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
@@ -55,6 +57,14 @@ enum State {
         serialized: BlockSerialization,
         index: Option<BlockMetaIndex>,
     },
+    NeedSerializeDeletionVector {
+        block: DataBlock,
+        deletion_vector: DeletionVectorBlock,
+    },
+    DeletionVectorSerialized {
+        serialized: DeletionVectorSerialization,
+        index: BlockMetaIndex,
+    },
 }
 
 pub struct TransformSerializeBlock {
@@ -79,6 +89,10 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_columns_map = table
+            .ngram_index_cols
+            .bloom_index_fields(source_schema.clone(), BloomIndex::supported_ngram_type)?;
+        let inverted_index_columns = table.inverted_index_columns(&source_schema);
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -86,6 +100,8 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            ngram_columns_map,
+            inverted_index_columns,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -131,11 +147,17 @@ impl Processor for TransformSerializeBlock {
     }
 
     fn event(&mut self) -> Result<Event> {
-        if matches!(self.state, State::NeedSerialize { .. }) {
+        if matches!(
+            self.state,
+            State::NeedSerialize { .. } | State::NeedSerializeDeletionVector { .. }
+        ) {
             return Ok(Event::Sync);
         }
 
-        if matches!(self.state, State::Serialized { .. }) {
+        if matches!(
+            self.state,
+            State::Serialized { .. } | State::DeletionVectorSerialized { .. }
+        ) {
             return Ok(Event::Async);
         }
 
@@ -193,6 +215,14 @@ impl Processor for TransformSerializeBlock {
                         Ok(Event::Sync)
                     }
                 }
+                SerializeDataMeta::DeletionVector(deletion_vector) => {
+                    // mark the deleted rows of the old block
+                    self.state = State::NeedSerializeDeletionVector {
+                        block: input_data,
+                        deletion_vector,
+                    };
+                    Ok(Event::Sync)
+                }
                 SerializeDataMeta::CompactExtras(compact_extras) => {
                     // compact extras
                     let data_block = Self::mutation_logs(MutationLogEntry::CompactExtras {
@@ -241,6 +271,20 @@ impl Processor for TransformSerializeBlock {
 
                 self.state = State::Serialized { serialized, index };
             }
+            State::NeedSerializeDeletionVector {
+                block,
+                deletion_vector,
+            } => {
+                let serialized = self.block_builder.build_deletion_vector(
+                    &deletion_vector.block_meta,
+                    deletion_vector.deleted_rows,
+                    &block,
+                )?;
+                self.state = State::DeletionVectorSerialized {
+                    serialized,
+                    index: deletion_vector.index,
+                };
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
@@ -283,6 +327,14 @@ impl Processor for TransformSerializeBlock {
                         );
                     }
                 }
+                if let Some(inverted_index_state) = serialized.inverted_index_state {
+                    write_data(
+                        inverted_index_state.data,
+                        &self.dal,
+                        &inverted_index_state.location.0,
+                    )
+                    .await?;
+                }
 
                 let data_block = if let Some(index) = index {
                     Self::mutation_logs(MutationLogEntry::ReplacedBlock {
@@ -303,6 +355,16 @@ impl Processor for TransformSerializeBlock {
                 };
                 self.output_data = Some(data_block);
             }
+            State::DeletionVectorSerialized { serialized, index } => {
+                let block_meta = serialized.block_meta;
+                let location = &block_meta.deletion_vector.as_ref().unwrap().location.0;
+                write_data(serialized.data, &self.dal, location).await?;
+
+                self.output_data = Some(Self::mutation_logs(MutationLogEntry::ReplacedBlock {
+                    index,
+                    block_meta: Arc::new(block_meta),
+                }));
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
@@ -34,7 +34,6 @@ use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CachedObject;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::SegmentInfo;
-use storages_common_table_meta::meta::Versioned;
 
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::AbortOperation;
@@ -206,7 +205,7 @@ impl Processor for TransformSerializeSegment {
                 }
                 abort_operation.add_segment(location.clone());
 
-                let format_version = SegmentInfo::VERSION;
+                let format_version = segment.format_version;
 
                 // emit log entry.
                 // for newly created segment, always use the latest version
@@ -219,6 +219,7 @@ impl FuseTable {
         let mut projection = (0..source_col_indices.len()).collect::<Vec<_>>();
         projection.sort_by_key(|&i| source_col_indices[i]);
         let ops = vec![BlockOperator::Project { projection }];
+        let use_deletion_vector = self.deletion_vector_enabled();
 
         let max_threads = (ctx.get_settings().get_max_threads()? as usize)
             .min(ctx.partition_num())
@@ -236,6 +237,7 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     query_row_id_col,
+                    use_deletion_vector,
                 )
             },
             max_threads,
@@ -315,6 +317,7 @@ impl FuseTable {
             self.schema_with_stream(),
             &push_down,
             self.bloom_index_cols(),
+            self.ngram_index_cols(),
         )?;
 
         if let Some(inverse) = filters.map(|f| f.inverted_filter) {
@@ -393,6 +396,7 @@ impl FuseTable {
                             cluster_stats,
                             inner_part,
                             whole_block_mutation,
+                            block_meta,
                         })));
                     part_info_ptr
                 })
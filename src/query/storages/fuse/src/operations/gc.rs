@@ -88,7 +88,13 @@ impl FuseTable {
             })
             .await?;
 
-        // 2. Read snapshot fields by chunk size.
+        // 2. Collect the files that must outlive the history of this table.
+        let retained_files = RetainedFiles {
+            prefix: format!("{}/", location_gen.prefix()),
+            referenced_by_clones: self.files_referenced_by_clones(ctx, dry_run).await?,
+        };
+
+        // 3. Read snapshot fields by chunk size.
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         for chunk in snapshot_files.chunks(chunk_size).rev() {
             if let Err(err) = ctx.check_aborting() {
@@ -166,6 +172,7 @@ impl FuseTable {
                         ctx,
                         &mut dry_run_purge_files,
                         &root_snapshot_info.referenced_locations,
+                        &retained_files,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -181,6 +188,7 @@ impl FuseTable {
                         ctx,
                         &mut counter,
                         &root_snapshot_info.referenced_locations,
+                        &retained_files,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -221,6 +229,7 @@ impl FuseTable {
                     ctx,
                     &mut dry_run_purge_files,
                     &root_snapshot_info.referenced_locations,
+                    &retained_files,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -232,6 +241,7 @@ impl FuseTable {
                     ctx,
                     &mut counter,
                     &root_snapshot_info.referenced_locations,
+                    &retained_files,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -245,7 +255,7 @@ impl FuseTable {
             return Ok(Some(dry_run_purge_files));
         }
 
-        // 3. purge root snapshots.
+        // 4. purge root snapshots.
         if !keep_last_snapshot {
             self.purge_root_snapshot(
                 ctx,
@@ -253,6 +263,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
+                &retained_files,
                 &table_agg_index_ids,
             )
             .await?;
@@ -316,11 +327,16 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         purge_files: &mut Vec<String>,
         locations_referenced_by_root: &LocationTuple,
-        segments_to_be_purged: HashSet<Location>,
-        ts_to_be_purged: HashSet<String>,
-        snapshots_to_be_purged: HashSet<String>,
+        retained_files: &RetainedFiles,
+        mut segments_to_be_purged: HashSet<Location>,
+        mut ts_to_be_purged: HashSet<String>,
+        mut snapshots_to_be_purged: HashSet<String>,
         table_agg_index_ids: &[u64],
     ) -> Result<()> {
+        segments_to_be_purged.retain(|loc| !retained_files.contains(&loc.0));
+        ts_to_be_purged.retain(|loc| !retained_files.contains(loc));
+        snapshots_to_be_purged.retain(|loc| !retained_files.contains(loc));
+
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
         let segment_locations = Vec::from_iter(segments_to_be_purged);
@@ -331,7 +347,9 @@ impl FuseTable {
                 .await?;
 
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || retained_files.contains(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string());
@@ -345,7 +363,20 @@ impl FuseTable {
             }
 
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || retained_files.contains(loc)
+                {
+                    continue;
+                }
+                purge_files.push(loc.to_string())
+            }
+
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                    || retained_files.contains(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string())
@@ -365,11 +396,16 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         locations_referenced_by_root: &LocationTuple,
-        segments_to_be_purged: HashSet<Location>,
-        ts_to_be_purged: HashSet<String>,
-        snapshots_to_be_purged: HashSet<String>,
+        retained_files: &RetainedFiles,
+        mut segments_to_be_purged: HashSet<Location>,
+        mut ts_to_be_purged: HashSet<String>,
+        mut snapshots_to_be_purged: HashSet<String>,
         table_agg_index_ids: &[u64],
     ) -> Result<()> {
+        segments_to_be_purged.retain(|loc| !retained_files.contains(&loc.0));
+        ts_to_be_purged.retain(|loc| !retained_files.contains(loc));
+        snapshots_to_be_purged.retain(|loc| !retained_files.contains(loc));
+
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
         let mut count = 0;
@@ -383,7 +419,9 @@ impl FuseTable {
             let mut blocks_to_be_purged = HashSet::new();
             let mut agg_indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || retained_files.contains(loc)
+                {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
@@ -398,12 +436,26 @@ impl FuseTable {
 
             let mut blooms_to_be_purged = HashSet::new();
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || retained_files.contains(loc)
+                {
                     continue;
                 }
                 blooms_to_be_purged.insert(loc.to_string());
             }
 
+            // The deletion vectors are purged along with the blocks.
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                    || retained_files.contains(loc)
+                {
+                    continue;
+                }
+                blocks_to_be_purged.insert(loc.to_string());
+            }
+
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
@@ -438,13 +490,15 @@ impl FuseTable {
             .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         root_snapshot: Arc<SnapshotLiteExtended>,
-        root_location_tuple: LocationTuple,
+        mut root_location_tuple: LocationTuple,
         root_snapshot_location: String,
+        retained_files: &RetainedFiles,
         table_agg_index_ids: &[u64],
     ) -> Result<()> {
         let segment_locations_to_be_purged = HashSet::from_iter(
@@ -452,8 +506,18 @@ impl FuseTable {
                 .segments
                 .iter()
                 .map(|loc| loc.0.clone())
+                .filter(|loc| !retained_files.contains(loc))
                 .collect::<Vec<_>>(),
         );
+        root_location_tuple
+            .block_location
+            .retain(|loc| !retained_files.contains(loc));
+        root_location_tuple
+            .bloom_location
+            .retain(|loc| !retained_files.contains(loc));
+        root_location_tuple
+            .deletion_vector_location
+            .retain(|loc| !retained_files.contains(loc));
 
         let mut agg_indexes_to_be_purged = HashSet::new();
         for index_id in table_agg_index_ids {
@@ -464,10 +528,13 @@ impl FuseTable {
             }));
         }
 
+        let mut blocks_to_be_purged = root_location_tuple.block_location;
+        blocks_to_be_purged.extend(root_location_tuple.deletion_vector_location);
+
         self.purge_block_segments(
             ctx,
             counter,
-            root_location_tuple.block_location,
+            blocks_to_be_purged,
             agg_indexes_to_be_purged,
             root_location_tuple.bloom_location,
             segment_locations_to_be_purged,
@@ -478,13 +545,11 @@ impl FuseTable {
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
             ts_to_be_purged.insert(ts);
         }
-        self.purge_ts_snapshots(
-            ctx,
-            counter,
-            ts_to_be_purged,
-            HashSet::from([root_snapshot_location]),
-        )
-        .await
+        ts_to_be_purged.retain(|loc| !retained_files.contains(loc));
+        let mut snapshots_to_be_purged = HashSet::from([root_snapshot_location]);
+        snapshots_to_be_purged.retain(|loc| !retained_files.contains(loc));
+        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
+            .await
     }
 
     async fn purge_block_segments(
@@ -623,6 +688,7 @@ impl FuseTable {
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -646,12 +712,14 @@ impl FuseTable {
                 };
                 blocks.extend(location_tuple.block_location.into_iter());
                 blooms.extend(location_tuple.bloom_location.into_iter());
+                deletion_vectors.extend(location_tuple.deletion_vector_location.into_iter());
             }
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            deletion_vector_location: deletion_vectors,
         })
     }
 
@@ -665,6 +733,21 @@ impl FuseTable {
     }
 }
 
+/// The files that the gc of a table must not purge.
+struct RetainedFiles {
+    /// The prefix of the table. A clone shares the files of its source table,
+    /// which are located outside of this prefix.
+    prefix: String,
+    /// The files of the table that are still referenced by its clones.
+    referenced_by_clones: HashSet<String>,
+}
+
+impl RetainedFiles {
+    fn contains(&self, location: &str) -> bool {
+        !location.starts_with(&self.prefix) || self.referenced_by_clones.contains(location)
+    }
+}
+
 struct RootSnapshotInfo {
     snapshot_location: String,
     referenced_locations: LocationTuple,
@@ -675,6 +758,7 @@ struct RootSnapshotInfo {
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
     pub bloom_location: HashSet<String>,
+    pub deletion_vector_location: HashSet<String>,
 }
 
 impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
@@ -682,16 +766,25 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     fn try_from(value: Arc<CompactSegmentInfo>) -> Result<Self> {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
+        let mut deletion_vector_location = HashSet::new();
         let block_metas = value.block_metas()?;
         for block_meta in block_metas.into_iter() {
             block_location.insert(block_meta.location.0.clone());
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            // The inverted index files are purged along with the bloom index files.
+            if let Some(inverted_index_loc) = &block_meta.inverted_index_location {
+                bloom_location.insert(inverted_index_loc.0.clone());
+            }
+            if let Some(deletion_vector) = &block_meta.deletion_vector {
+                deletion_vector_location.insert(deletion_vector.location.0.clone());
+            }
         }
         Ok(Self {
             block_location,
             bloom_location,
+            deletion_vector_location,
         })
     }
 }
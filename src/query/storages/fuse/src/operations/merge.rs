@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_base::base::tokio::sync::Semaphore;
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_pipeline_core::PipeItem;
@@ -79,9 +81,17 @@ impl FuseTable {
         segment_locations: Vec<(SegmentIndex, Location)>,
     ) -> Result<PipeItem> {
         let read_settings = ReadSettings::from_ctx(&ctx)?;
+        let block_reader = self.create_block_reader(
+            ctx.clone(),
+            Projection::Columns(self.all_column_indices()),
+            false,
+            self.change_tracking_enabled(),
+            false,
+        )?;
         let aggregator = MatchedAggregator::create(
             ctx.clone(),
             self.table_info.schema(),
+            block_reader,
             self.get_operator(),
             self.get_write_settings(),
             read_settings,
@@ -25,9 +25,10 @@ use common_base::base::tokio::sync::Semaphore;
 use common_base::base::ProgressValues;
 use common_base::runtime::GlobalIORuntime;
 use common_base::runtime::TrySpawn;
+use common_catalog::plan::gen_mutation_stream_meta;
 use common_catalog::plan::split_prefix;
 use common_catalog::plan::split_row_id;
-use common_catalog::plan::Projection;
+use common_catalog::plan::StreamColumn;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -35,9 +36,11 @@ use common_expression::types::NumberColumn;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::Column;
 use common_expression::DataBlock;
+use common_expression::FunctionContext;
 use common_expression::TableSchemaRef;
 use common_metrics::storage::*;
-use itertools::Itertools;
+use common_sql::evaluator::BlockOperator;
+use common_sql::gen_mutation_stream_operator;
 use log::info;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
@@ -68,6 +71,9 @@ struct AggregationContext {
     read_settings: ReadSettings,
     block_builder: BlockBuilder,
     block_reader: Arc<BlockReader>,
+    func_ctx: FunctionContext,
+    stream_columns: Vec<StreamColumn>,
+    stream_operators: Vec<BlockOperator>,
 }
 
 pub struct MatchedAggregator {
@@ -83,6 +89,7 @@ impl MatchedAggregator {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         target_table_schema: TableSchemaRef,
+        block_reader: Arc<BlockReader>,
         data_accessor: Operator,
         write_settings: WriteSettings,
         read_settings: ReadSettings,
@@ -91,21 +98,13 @@ impl MatchedAggregator {
         segment_locations: Vec<(SegmentIndex, Location)>,
     ) -> Result<Self> {
         let segment_reader =
-            MetaReaders::segment_info_reader(data_accessor.clone(), target_table_schema.clone());
+            MetaReaders::segment_info_reader(data_accessor.clone(), target_table_schema);
 
-        let block_reader = {
-            let projection =
-                Projection::Columns((0..target_table_schema.num_fields()).collect_vec());
-            BlockReader::create(
-                ctx.clone(),
-                data_accessor.clone(),
-                target_table_schema,
-                projection,
-                false,
-                false,
-                false,
-            )
-        }?;
+        let func_ctx = ctx.get_function_context()?;
+        let (stream_columns, stream_operators) = gen_mutation_stream_operator(
+            block_reader.schema(),
+            block_reader.update_stream_columns(),
+        )?;
 
         Ok(Self {
             aggregation_ctx: Arc::new(AggregationContext {
@@ -115,6 +114,9 @@ impl MatchedAggregator {
                 data_accessor,
                 block_builder,
                 block_reader,
+                func_ctx,
+                stream_columns,
+                stream_operators,
             }),
             io_request_semaphore,
             segment_reader,
@@ -288,7 +290,7 @@ impl AggregationContext {
             bytes: 0,
         };
         self.ctx.get_write_progress().incr(&progress_values);
-        let origin_data_block = read_block(
+        let mut origin_data_block = read_block(
             self.write_settings.storage_format,
             &self.block_reader,
             block_meta,
@@ -296,10 +298,30 @@ impl AggregationContext {
         )
         .await?;
         let origin_num_rows = origin_data_block.num_rows();
-        // apply delete
+        // the remaining rows take the current block and their row numbers as origin,
+        // if they have not been set.
+        if !self.stream_columns.is_empty() {
+            let stream_meta = gen_mutation_stream_meta(None, &block_meta.location.0)?;
+            for stream_column in self.stream_columns.iter() {
+                let entry = stream_column.generate_column_values(&stream_meta, origin_num_rows);
+                origin_data_block.add_column(entry);
+            }
+            origin_data_block = self
+                .stream_operators
+                .iter()
+                .try_fold(origin_data_block, |input, op| {
+                    op.execute(&self.func_ctx, input)
+                })?;
+        }
+        let deleted_rows = self
+            .block_reader
+            .read_deletion_vector(&block_meta.deletion_vector)
+            .await?
+            .unwrap_or_default();
+        // apply delete, the rows deleted by the deletion vector are removed as well.
         let mut bitmap = MutableBitmap::new();
         for row in 0..origin_num_rows {
-            if modified_offsets.contains(&row) {
+            if modified_offsets.contains(&row) || deleted_rows.contains(row as u32) {
                 bitmap.push(false);
             } else {
                 bitmap.push(true);
@@ -342,6 +364,9 @@ impl AggregationContext {
         let new_block_raw_data = serialized.block_raw_data;
         let data_accessor = self.data_accessor.clone();
         write_data(new_block_raw_data, &data_accessor, &new_block_location).await?;
+        if let Some(index_state) = serialized.inverted_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
+        }
 
         metrics_inc_merge_into_replace_blocks_counter(1);
         metrics_inc_merge_into_replace_blocks_rows_counter(origin_num_rows as u32);
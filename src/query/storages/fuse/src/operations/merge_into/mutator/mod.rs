@@ -19,6 +19,7 @@ mod split_by_expr_mutator;
 mod update_by_expr_mutator;
 mod utils;
 pub use delete_by_expr_mutator::DeleteByExprMutator;
+pub(crate) use matched_mutator::get_row_id;
 pub use matched_mutator::MatchedAggregator;
 pub use merge_into_split_mutator::MergeIntoSplitMutator;
 pub use split_by_expr_mutator::SplitByExprMutator;
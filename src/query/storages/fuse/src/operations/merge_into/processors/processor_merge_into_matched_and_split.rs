@@ -17,16 +17,31 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_catalog::plan::gen_mutation_stream_meta;
+use common_catalog::plan::split_row_id;
+use common_catalog::plan::StreamColumn;
+use common_catalog::plan::StreamColumnMeta;
+use common_catalog::plan::StreamColumnType;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::decimal::DecimalColumn;
 use common_expression::types::BooleanType;
+use common_expression::types::DecimalSize;
+use common_expression::types::UInt64Type;
+use common_expression::BlockEntry;
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
+use common_expression::Column;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
 use common_expression::Expr;
 use common_expression::FieldIndex;
+use common_expression::FromData;
+use common_expression::ScalarRef;
 use common_expression::Value;
+use common_expression::ORIGIN_BLOCK_ID_COL_NAME;
+use common_expression::ORIGIN_BLOCK_ROW_NUM_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_metrics::storage::*;
 use common_pipeline_core::processors::Event;
@@ -37,8 +52,12 @@ use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::PipeItem;
 use common_sql::evaluator::BlockOperator;
 use common_sql::executor::physical_plans::MatchExpr;
+use common_sql::gen_mutation_stream_expr;
+use common_sql::CURRENT_BLOCK_ID_COL_NAME;
+use common_sql::CURRENT_BLOCK_ROW_NUM_COL_NAME;
 
 use crate::operations::common::MutationLogs;
+use crate::operations::merge_into::mutator::get_row_id;
 use crate::operations::merge_into::mutator::DeleteByExprMutator;
 use crate::operations::merge_into::mutator::UpdateByExprMutator;
 
@@ -114,8 +133,9 @@ pub struct MatchedSplitProcessor {
     output_port_updated: Arc<OutputPort>,
     ops: Vec<MutationKind>,
     ctx: Arc<dyn TableContext>,
-    update_projections: Vec<usize>,
+    update_operator: BlockOperator,
     row_id_idx: usize,
+    block_name_idx: Option<usize>,
     input_data: Option<DataBlock>,
     output_data_row_id_data: Vec<DataBlock>,
     output_data_updated_data: Option<DataBlock>,
@@ -123,9 +143,11 @@ pub struct MatchedSplitProcessor {
 }
 
 impl MatchedSplitProcessor {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         ctx: Arc<dyn TableContext>,
         row_id_idx: usize,
+        block_name_idx: Option<usize>,
         matched: MatchExpr,
         field_index_of_input_schema: HashMap<FieldIndex, usize>,
         input_schema: DataSchemaRef,
@@ -166,6 +188,45 @@ impl MatchedSplitProcessor {
         for field_index in 0..field_index_of_input_schema.len() {
             update_projections.push(*field_index_of_input_schema.get(&field_index).unwrap());
         }
+        // if the change tracking is enabled, the origin of the updated rows is kept
+        // if they have been set, otherwise the current block id and row number are taken.
+        let update_operator = if block_name_idx.is_some() {
+            // the updated block has the filter column at the tail, followed by the
+            // current block id and row number columns.
+            let num_columns = input_schema.num_fields() + 1;
+            let stream_columns = [
+                (
+                    StreamColumn::new(ORIGIN_BLOCK_ID_COL_NAME, StreamColumnType::OriginBlockId),
+                    CURRENT_BLOCK_ID_COL_NAME,
+                ),
+                (
+                    StreamColumn::new(
+                        ORIGIN_BLOCK_ROW_NUM_COL_NAME,
+                        StreamColumnType::OriginRowNum,
+                    ),
+                    CURRENT_BLOCK_ROW_NUM_COL_NAME,
+                ),
+            ];
+            let mut exprs = Vec::with_capacity(stream_columns.len());
+            for (idx, (stream_column, current_name)) in stream_columns.iter().enumerate() {
+                let field_index = target_table_schema.index_of(stream_column.column_name())?;
+                exprs.push(gen_mutation_stream_expr(
+                    stream_column,
+                    update_projections[field_index],
+                    current_name,
+                    num_columns + idx,
+                )?);
+                update_projections[field_index] = num_columns + stream_columns.len() + idx;
+            }
+            BlockOperator::Map {
+                exprs,
+                projections: Some(update_projections),
+            }
+        } else {
+            BlockOperator::Project {
+                projection: update_projections,
+            }
+        };
         let input_port = InputPort::create();
         let output_port_row_id = OutputPort::create();
         let output_port_updated = OutputPort::create();
@@ -179,7 +240,8 @@ impl MatchedSplitProcessor {
             output_port_updated,
             ops,
             row_id_idx,
-            update_projections,
+            block_name_idx,
+            update_operator,
             target_table_schema,
         })
     }
@@ -313,10 +375,13 @@ impl Processor for MatchedSplitProcessor {
                     current_block.num_rows(),
                     Some(Box::new(RowIdKind::Update)),
                 ));
-                let op = BlockOperator::Project {
-                    projection: self.update_projections.clone(),
-                };
-                current_block = op.execute(&self.ctx.get_function_context()?, current_block)?;
+                if let Some(block_name_idx) = self.block_name_idx {
+                    current_block =
+                        self.add_current_stream_columns(current_block, block_name_idx)?;
+                }
+                current_block = self
+                    .update_operator
+                    .execute(&self.ctx.get_function_context()?, current_block)?;
                 metrics_inc_merge_into_append_blocks_counter(1);
                 metrics_inc_merge_into_append_blocks_rows_counter(current_block.num_rows() as u32);
 
@@ -335,6 +400,59 @@ impl Processor for MatchedSplitProcessor {
 }
 
 impl MatchedSplitProcessor {
+    // add the current block id and row number of the updated rows, the block id is
+    // generated from the block name, and the row number is the offset of the row id.
+    fn add_current_stream_columns(
+        &self,
+        mut current_block: DataBlock,
+        block_name_idx: usize,
+    ) -> Result<DataBlock> {
+        let num_rows = current_block.num_rows();
+        let row_ids = get_row_id(&current_block, self.row_id_idx)?;
+        let entry = current_block.get_by_offset(block_name_idx);
+        let block_names = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+
+        let mut block_ids = Vec::with_capacity(num_rows);
+        let mut row_nums = Vec::with_capacity(num_rows);
+        for (row, row_id) in row_ids.iter().enumerate() {
+            let block_name = match block_names.index(row) {
+                Some(ScalarRef::String(block_name)) => String::from_utf8_lossy(block_name),
+                _ => return Err(ErrorCode::Internal("block name is not string")),
+            };
+            match gen_mutation_stream_meta(None, &block_name)? {
+                StreamColumnMeta::Mutation { block_id, .. } => block_ids.push(block_id as i128),
+                _ => return Err(ErrorCode::Internal("It's a bug")),
+            }
+            let (_, offset) = split_row_id(*row_id);
+            row_nums.push(offset);
+        }
+
+        let block_id_column =
+            Column::Decimal(DecimalColumn::Decimal128(block_ids.into(), DecimalSize {
+                precision: 38,
+                scale: 0,
+            }));
+        let row_num_column = UInt64Type::from_data(row_nums);
+        for (stream_column, column) in [
+            StreamColumn::new(ORIGIN_BLOCK_ID_COL_NAME, StreamColumnType::OriginBlockId),
+            StreamColumn::new(
+                ORIGIN_BLOCK_ROW_NUM_COL_NAME,
+                StreamColumnType::OriginRowNum,
+            ),
+        ]
+        .into_iter()
+        .zip([block_id_column, row_num_column])
+        {
+            current_block.add_column(BlockEntry::new(
+                stream_column.data_type(),
+                Value::Column(column.wrap_nullable(None)),
+            ));
+        }
+        Ok(current_block)
+    }
+
     fn cast_data_type_for_merge(&self, current_block: DataBlock) -> Result<DataBlock> {
         // cornor case: for merge into update, if the target table's column is not null,
         // for example, target table has three columns like (a,b,c), and we use update set target_table.a = xxx,
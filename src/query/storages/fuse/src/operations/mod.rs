@@ -15,6 +15,7 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod clone;
 mod commit;
 pub mod common;
 mod compact;
@@ -35,6 +36,8 @@ mod truncate;
 mod update;
 pub mod util;
 pub use agg_index_sink::AggIndexSink;
+pub use clone::pending_clone_reference;
+pub use clone::CloneReferences;
 pub use common::*;
 pub use compact::CompactOptions;
 pub use delete::MutationBlockPruningContext;
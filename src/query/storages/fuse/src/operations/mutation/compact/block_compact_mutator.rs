@@ -331,7 +331,15 @@ impl SegmentCompactChecker {
 
         if segments.len() == 1 {
             let summary = &segments[0].1.summary;
-            if (summary.block_count == 1 || summary.perfect_block_count == summary.block_count)
+            // The block with deleted rows is never perfect, it needs to be compacted to
+            // fold the deletion vector.
+            let compacted = summary.perfect_block_count == summary.block_count
+                || (summary.block_count == 1
+                    && !segments[0]
+                        .1
+                        .block_metas()
+                        .is_ok_and(|blocks| blocks.iter().any(|b| b.deletion_vector.is_some())));
+            if compacted
                 && (self.cluster_key_id.is_none()
                     || self.cluster_key_id
                         == summary.cluster_stats.as_ref().map(|v| v.cluster_key_id))
@@ -421,7 +429,7 @@ impl CompactTaskBuilder {
             }
         }
 
-        let total_rows = self.total_rows + block.row_count as usize;
+        let total_rows = self.total_rows + block.live_row_count() as usize;
         let total_size = self.total_size + block.block_size as usize;
         if !thresholds.check_large_enough(total_rows, total_size) {
             // blocks < N
@@ -457,6 +465,10 @@ impl CompactTaskBuilder {
     }
 
     fn check_compact(&self, block: &Arc<BlockMeta>) -> bool {
+        if block.deletion_vector.is_some() {
+            return true;
+        }
+
         let column_ids: HashSet<ColumnId> = block.col_metas.keys().cloned().collect();
         if self.column_ids == column_ids {
             // Check if the block needs to be resort.
@@ -549,7 +561,7 @@ impl CompactTaskBuilder {
 
                 let (total_rows, total_size) =
                     blocks.iter().chain(tail.iter()).fold((0, 0), |mut acc, x| {
-                        acc.0 += x.row_count as usize;
+                        acc.0 += x.live_row_count() as usize;
                         acc.1 += x.block_size as usize;
                         acc
                     });
@@ -33,8 +33,10 @@ use common_pipeline_core::processors::Processor;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_sql::evaluator::BlockOperator;
 use common_sql::gen_mutation_stream_operator;
+use roaring::RoaringBitmap;
 use storages_common_table_meta::meta::BlockMeta;
 
+use crate::io::live_rows_bitmap;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::mutation::compact::compact_part::CompactPartInfo;
@@ -48,7 +50,7 @@ use crate::MergeIOReadResult;
 enum State {
     ReadData(Option<PartInfoPtr>),
     Concat {
-        read_res: Vec<MergeIOReadResult>,
+        read_res: Vec<(MergeIOReadResult, Option<RoaringBitmap>)>,
         metas: Vec<Arc<BlockMeta>>,
         index: BlockMetaIndex,
     },
@@ -150,13 +152,14 @@ impl Processor for CompactSource {
                 let blocks = read_res
                     .into_iter()
                     .zip(metas.into_iter())
-                    .map(|(data, meta)| {
+                    .map(|((data, deleted_rows), meta)| {
                         let mut block = self.block_reader.deserialize_chunks_with_meta(
                             &meta,
                             &self.storage_format,
                             data,
                         )?;
-
+                        // The stream columns take the row numbers in the origin block,
+                        // so they are generated before the deletion vector is applied.
                         let num_rows = block.num_rows();
                         let stream_meta = gen_mutation_stream_meta(None, &meta.location.0)?;
                         for stream_column in self.stream_columns.iter() {
@@ -168,6 +171,12 @@ impl Processor for CompactSource {
                             .stream_operators
                             .iter()
                             .try_fold(block, |input, op| op.execute(&self.func_ctx, input))?;
+
+                        // Fold the deletion vector away.
+                        if let Some(deleted_rows) = deleted_rows {
+                            let live_rows = live_rows_bitmap(block.num_rows(), &deleted_rows);
+                            block = block.filter_with_bitmap(&live_rows)?;
+                        }
                         Ok(block)
                     })
                     .collect::<Result<Vec<_>>>()?;
@@ -224,14 +233,18 @@ impl Processor for CompactSource {
                                     metrics_inc_compact_block_read_bytes(block.block_size);
                                 }
 
-                                block_reader
+                                let read_res = block_reader
                                     .read_columns_data_by_merge_io(
                                         &settings,
                                         &block.location.0,
                                         &block.col_metas,
                                         &None,
                                     )
-                                    .await
+                                    .await?;
+                                let deleted_rows = block_reader
+                                    .read_deletion_vector(&block.deletion_vector)
+                                    .await?;
+                                Ok::<_, ErrorCode>((read_res, deleted_rows))
                             });
                         }
 
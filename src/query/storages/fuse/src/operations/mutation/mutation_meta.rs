@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
+use roaring::RoaringBitmap;
+use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 
 use crate::operations::common::BlockMetaIndex;
@@ -25,6 +29,7 @@ pub enum SerializeDataMeta {
     SerializeBlock(SerializeBlock),
     DeletedSegment(DeletedSegmentInfo),
     CompactExtras(CompactExtraInfo),
+    DeletionVector(DeletionVectorBlock),
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -55,3 +60,14 @@ impl SerializeBlock {
         SerializeBlock { index, stats_type }
     }
 }
+
+/// The rows of the block deleted by a mutation, the block itself is kept as is.
+///
+/// It is carried by the data block of the newly deleted rows.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DeletionVectorBlock {
+    pub index: BlockMetaIndex,
+    pub block_meta: Arc<BlockMeta>,
+    /// All the deleted rows of the block, including the ones deleted before.
+    pub deleted_rows: RoaringBitmap,
+}
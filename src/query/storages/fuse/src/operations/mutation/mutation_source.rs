@@ -16,6 +16,8 @@ use std::any::Any;
 use std::ops::Not;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_base::base::ProgressValues;
 use common_catalog::plan::gen_mutation_stream_meta;
 use common_catalog::plan::InternalColumn;
@@ -40,9 +42,13 @@ use common_pipeline_core::processors::OutputPort;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_sql::evaluator::BlockOperator;
+use roaring::RoaringBitmap;
+use storages_common_table_meta::meta::BlockMeta;
 
+use super::mutation_meta::DeletionVectorBlock;
 use super::mutation_meta::SerializeBlock;
 use crate::fuse_part::FusePartInfo;
+use crate::io::live_rows_bitmap;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::common::BlockMetaIndex;
@@ -88,9 +94,15 @@ pub struct MutationSource {
     storage_format: FuseStorageFormat,
     action: MutationAction,
     query_row_id_col: bool,
+    use_deletion_vector: bool,
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+    block_meta: Option<Arc<BlockMeta>>,
+    // The deleted rows of the block being mutated, loaded from its deletion vector.
+    deleted_rows: Option<RoaringBitmap>,
+    // The deletion vector to be written, if the deleted rows are not removed from the block.
+    new_deleted_rows: Option<RoaringBitmap>,
 }
 
 impl MutationSource {
@@ -105,6 +117,7 @@ impl MutationSource {
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
         query_row_id_col: bool,
+        use_deletion_vector: bool,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
@@ -117,8 +130,12 @@ impl MutationSource {
             storage_format,
             action,
             query_row_id_col,
+            use_deletion_vector,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            block_meta: None,
+            deleted_rows: None,
+            new_deleted_rows: None,
         })))
     }
 }
@@ -206,6 +223,16 @@ impl Processor for MutationSource {
                     }
                     assert_eq!(filter.data_type(), &DataType::Boolean);
 
+                    // The rows already deleted by the deletion vector are invisible to the filter.
+                    let live_rows = self
+                        .deleted_rows
+                        .as_ref()
+                        .map(|deleted_rows| live_rows_bitmap(num_rows, deleted_rows));
+                    if let Some(live_rows) = &live_rows {
+                        data_block = data_block.filter_with_bitmap(live_rows)?;
+                    }
+                    let num_live_rows = data_block.num_rows();
+
                     let func_ctx = self.ctx.get_function_context()?;
                     let evaluator = Evaluator::new(&data_block, &func_ctx, &BUILTIN_FUNCTIONS);
 
@@ -218,7 +245,7 @@ impl Processor for MutationSource {
                     let affect_rows = match &predicates {
                         Value::Scalar(v) => {
                             if *v {
-                                num_rows
+                                num_live_rows
                             } else {
                                 0
                             }
@@ -240,7 +267,7 @@ impl Processor for MutationSource {
 
                         match self.action {
                             MutationAction::Deletion => {
-                                if affect_rows == num_rows {
+                                if affect_rows == num_live_rows {
                                     // all the rows should be removed.
                                     let meta = Box::new(SerializeDataMeta::SerializeBlock(
                                         SerializeBlock::create(
@@ -254,8 +281,29 @@ impl Processor for MutationSource {
                                     );
                                 } else {
                                     let predicate_col = predicates.into_column().unwrap();
-                                    let filter = predicate_col.not();
-                                    data_block = data_block.filter_with_bitmap(&filter)?;
+                                    let filter = if self.use_deletion_vector {
+                                        // Keep the deleted rows, they are only used to
+                                        // maintain the statistics of the block.
+                                        let deleted =
+                                            to_physical_rows(live_rows.as_ref(), &predicate_col);
+                                        let mut new_deleted_rows =
+                                            self.deleted_rows.take().unwrap_or_default();
+                                        new_deleted_rows.extend(
+                                            deleted
+                                                .iter()
+                                                .enumerate()
+                                                .filter(|(_, v)| *v)
+                                                .map(|(i, _)| i as u32),
+                                        );
+                                        self.new_deleted_rows = Some(new_deleted_rows);
+                                        data_block =
+                                            data_block.filter_with_bitmap(&predicate_col)?;
+                                        deleted
+                                    } else {
+                                        let remain = predicate_col.not();
+                                        data_block = data_block.filter_with_bitmap(&remain)?;
+                                        to_physical_rows(live_rows.as_ref(), &remain)
+                                    };
                                     if self.remain_reader.is_none() {
                                         self.state = State::PerformOperator(
                                             data_block,
@@ -285,7 +333,7 @@ impl Processor for MutationSource {
                                     self.state = State::ReadRemain {
                                         part,
                                         data_block,
-                                        filter: None,
+                                        filter: live_rows.map(Value::Column),
                                     };
                                 }
                             }
@@ -295,8 +343,12 @@ impl Processor for MutationSource {
                         self.state = State::Output(self.ctx.get_partition(), DataBlock::empty());
                     }
                 } else {
+                    if let Some(deleted_rows) = &self.deleted_rows {
+                        let live_rows = live_rows_bitmap(num_rows, deleted_rows);
+                        data_block = data_block.filter_with_bitmap(&live_rows)?;
+                    }
                     let progress_values = ProgressValues {
-                        rows: num_rows,
+                        rows: data_block.num_rows(),
                         // ignore the bytes.
                         bytes: 0,
                     };
@@ -320,7 +372,7 @@ impl Processor for MutationSource {
                     )?;
 
                     let remain_block = if let Some(filter) = filter {
-                        // for deletion.
+                        // for deletion, or the block has deleted rows.
                         remain_block.filter_boolean_value(&filter)?
                     } else {
                         remain_block
@@ -341,9 +393,18 @@ impl Processor for MutationSource {
                     .operators
                     .iter()
                     .try_fold(data_block, |input, op| op.execute(&func_ctx, input))?;
-                let inner_meta = Box::new(SerializeDataMeta::SerializeBlock(
-                    SerializeBlock::create(self.index.clone(), self.stats_type.clone()),
-                ));
+                let inner_meta = if let Some(deleted_rows) = self.new_deleted_rows.take() {
+                    Box::new(SerializeDataMeta::DeletionVector(DeletionVectorBlock {
+                        index: self.index.clone(),
+                        block_meta: self.block_meta.clone().unwrap(),
+                        deleted_rows,
+                    }))
+                } else {
+                    Box::new(SerializeDataMeta::SerializeBlock(SerializeBlock::create(
+                        self.index.clone(),
+                        self.stats_type.clone(),
+                    )))
+                };
                 let meta: BlockMetaInfoPtr = if self.block_reader.update_stream_columns() {
                     Box::new(gen_mutation_stream_meta(Some(inner_meta), &path)?)
                 } else {
@@ -385,6 +446,9 @@ impl Processor for MutationSource {
                                 ClusterStatsGenType::WithOrigin(part.cluster_stats.clone());
                         }
 
+                        self.block_meta = Some(part.block_meta.clone());
+                        self.deleted_rows = None;
+
                         let inner_part = part.inner_part.clone();
                         let fuse_part = FusePartInfo::from_part(&inner_part)?;
 
@@ -393,7 +457,7 @@ impl Processor for MutationSource {
                         {
                             // whole block deletion.
                             let progress_values = ProgressValues {
-                                rows: fuse_part.nums_rows,
+                                rows: part.block_meta.live_row_count() as usize,
                                 bytes: 0,
                             };
                             self.ctx.get_write_progress().incr(&progress_values);
@@ -414,6 +478,10 @@ impl Processor for MutationSource {
                                     &None,
                                 )
                                 .await?;
+                            self.deleted_rows = self
+                                .block_reader
+                                .read_deletion_vector(&fuse_part.deletion_vector)
+                                .await?;
                             self.state = State::FilterData(inner_part, read_res);
                         }
                     }
@@ -451,3 +519,19 @@ impl Processor for MutationSource {
         Ok(())
     }
 }
+
+/// Map the selection of the live rows to the selection of all the rows in the block.
+fn to_physical_rows(live_rows: Option<&Bitmap>, selection: &Bitmap) -> Bitmap {
+    match live_rows {
+        None => selection.clone(),
+        Some(live_rows) => {
+            let mut bitmap = MutableBitmap::with_capacity(live_rows.len());
+            let mut selection = selection.iter();
+            for live in live_rows.iter() {
+                // the selection only covers the live rows.
+                bitmap.push(live && selection.next().unwrap_or(false));
+            }
+            bitmap.into()
+        }
+    }
+}
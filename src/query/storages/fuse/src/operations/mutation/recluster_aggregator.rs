@@ -30,9 +30,9 @@ use common_pipeline_transforms::processors::AsyncAccumulatingTransform;
 use itertools::Itertools;
 use opendal::Operator;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
-use storages_common_table_meta::meta::Versioned;
 
 use crate::io::SegmentsIO;
 use crate::io::SerializedSegment;
@@ -106,19 +106,16 @@ impl AsyncAccumulatingTransform for ReclusterAggregator {
             // The remain new segments will be append.
             let appended = new_segments.split_off(removed_segments_len);
             for (location, stats) in appended.into_iter().rev() {
-                self.abort_operation.add_segment(location.clone());
-                appended_segments.push((location, SegmentInfo::VERSION));
+                self.abort_operation.add_segment(location.0.clone());
+                appended_segments.push(location);
                 merge_statistics_mut(&mut merged_statistics, &stats, default_cluster_key);
             }
         }
 
         for (i, (location, stats)) in new_segments.into_iter().enumerate() {
             // The old segments will be replaced with the news.
-            self.abort_operation.add_segment(location.clone());
-            replaced_segments.insert(
-                self.removed_segment_indexes[i],
-                (location, SegmentInfo::VERSION),
-            );
+            self.abort_operation.add_segment(location.0.clone());
+            replaced_segments.insert(self.removed_segment_indexes[i], location);
             merge_statistics_mut(&mut merged_statistics, &stats, default_cluster_key);
         }
 
@@ -167,7 +164,7 @@ impl ReclusterAggregator {
         }
     }
 
-    async fn apply(&mut self) -> Result<Vec<(String, Statistics)>> {
+    async fn apply(&mut self) -> Result<Vec<(Location, Statistics)>> {
         // sort ascending.
         self.merged_blocks.sort_by(|a, b| {
             sort_by_cluster_stats(&a.cluster_stats, &b.cluster_stats, self.default_cluster_key)
@@ -200,6 +197,7 @@ impl ReclusterAggregator {
                 }
                 // create new segment info
                 let new_segment = SegmentInfo::new(new_blocks, new_summary.clone());
+                let format_version = new_segment.format_version;
 
                 // write the segment info.
                 let serialized_segment = SerializedSegment {
@@ -207,7 +205,7 @@ impl ReclusterAggregator {
                     segment: Arc::new(new_segment),
                 };
                 SegmentsIO::write_segment(op, serialized_segment).await?;
-                Ok::<_, ErrorCode>((location, new_summary))
+                Ok::<_, ErrorCode>(((location, format_version), new_summary))
             });
         }
 
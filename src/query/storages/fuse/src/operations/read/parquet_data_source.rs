@@ -19,6 +19,7 @@ use common_catalog::plan::PartInfoPtr;
 use common_exception::Result;
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoPtr;
+use roaring::RoaringBitmap;
 use serde::Deserializer;
 use serde::Serializer;
 
@@ -27,7 +28,14 @@ use crate::io::VirtualMergeIOReadResult;
 
 pub enum DataSource {
     AggIndex((PartInfoPtr, MergeIOReadResult)),
-    Normal((MergeIOReadResult, Option<VirtualMergeIOReadResult>)),
+    /// The block data, the virtual columns and the deleted rows of the block.
+    Normal(
+        (
+            MergeIOReadResult,
+            Option<VirtualMergeIOReadResult>,
+            Option<RoaringBitmap>,
+        ),
+    ),
 }
 
 pub struct DataSourceMeta {
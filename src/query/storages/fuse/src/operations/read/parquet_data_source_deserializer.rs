@@ -16,6 +16,9 @@ use std::any::Any;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_arrow::arrow::bitmap::and;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
 use common_catalog::plan::gen_mutation_stream_meta;
@@ -38,6 +41,7 @@ use common_pipeline_core::processors::ProcessorPtr;
 use super::fuse_source::fill_internal_column_meta;
 use super::parquet_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
+use crate::io::live_rows_bitmap;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::io::UncompressedBuffer;
@@ -189,7 +193,7 @@ impl Processor for DeserializeDataTransform {
 
                     self.output_data = Some(block);
                 }
-                DataSource::Normal((data, virtual_data)) => {
+                DataSource::Normal((data, virtual_data, deleted_rows)) => {
                     let start = Instant::now();
                     let columns_chunks = data.columns_chunks()?;
                     let part = FusePartInfo::from_part(&part)?;
@@ -212,6 +216,35 @@ impl Processor for DeserializeDataTransform {
                         )?;
                     }
 
+                    // Skip the rows marked as deleted by the deletion vector and the rows
+                    // not matched by the inverted index, the offsets of the remaining rows
+                    // are kept to generate the row ids.
+                    let num_rows = data_block.num_rows();
+                    let live_rows =
+                        deleted_rows.map(|deleted_rows| live_rows_bitmap(num_rows, &deleted_rows));
+                    let matched_rows = part
+                        .block_meta_index()
+                        .and_then(|meta| meta.matched_rows.as_ref())
+                        .map(|matched_rows| matched_rows_bitmap(num_rows, matched_rows));
+                    let selection = match (live_rows, matched_rows) {
+                        (Some(live_rows), Some(matched_rows)) => {
+                            Some(and(&live_rows, &matched_rows))
+                        }
+                        (live_rows, matched_rows) => live_rows.or(matched_rows),
+                    };
+                    let mut offsets = None;
+                    if let Some(bitmap) = selection {
+                        offsets = Some(
+                            bitmap
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, v)| *v)
+                                .map(|(i, _)| i)
+                                .collect::<Vec<_>>(),
+                        );
+                        data_block = data_block.filter_with_bitmap(&bitmap)?;
+                    }
+
                     // Perf.
                     {
                         metrics_inc_remote_io_deserialize_milliseconds(
@@ -231,7 +264,7 @@ impl Processor for DeserializeDataTransform {
                     // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
                     // `FillInternalColumnProcessor` will generate internal columns using `BlockMetaIndex` in next pipeline.
                     if self.block_reader.query_internal_columns() {
-                        data_block = fill_internal_column_meta(data_block, part, None)?;
+                        data_block = fill_internal_column_meta(data_block, part, offsets)?;
                     }
 
                     if self.block_reader.update_stream_columns() {
@@ -248,3 +281,14 @@ impl Processor for DeserializeDataTransform {
         Ok(())
     }
 }
+
+fn matched_rows_bitmap(num_rows: usize, matched_rows: &[u32]) -> Bitmap {
+    let mut bitmap = MutableBitmap::from_len_zeroed(num_rows);
+    for row in matched_rows {
+        let row = *row as usize;
+        if row < num_rows {
+            bitmap.set(row, true);
+        }
+    }
+    bitmap.into()
+}
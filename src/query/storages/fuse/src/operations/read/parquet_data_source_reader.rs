@@ -101,7 +101,15 @@ impl SyncSource for ReadParquetDataSource<true> {
         match self.partitions.steal_one(self.id) {
             None => Ok(None),
             Some(part) => {
-                if let Some(index_reader) = self.index_reader.as_ref() {
+                // The aggregating index is built on the whole block, it can not be used
+                // once some rows of the block are deleted.
+                let has_deletion_vector = FusePartInfo::from_part(&part)?.deletion_vector.is_some();
+                if let Some(index_reader) = self
+                    .index_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| !has_deletion_vector)
+                {
                     let fuse_part = FusePartInfo::from_part(&part)?;
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
@@ -146,9 +154,13 @@ impl SyncSource for ReadParquetDataSource<true> {
                     ignore_column_ids,
                 )?;
 
+                let deleted_rows = self
+                    .block_reader
+                    .sync_read_deletion_vector(&FusePartInfo::from_part(&part)?.deletion_vector)?;
+
                 Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
                     vec![part],
-                    vec![DataSource::Normal((source, virtual_source))],
+                    vec![DataSource::Normal((source, virtual_source, deleted_rows))],
                 ))))
             }
         }
@@ -206,7 +218,12 @@ impl Processor for ReadParquetDataSource<false> {
                     tokio::spawn(async_backtrace::location!().frame(async move {
                         let part = FusePartInfo::from_part(&part)?;
 
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        let has_deletion_vector = part.deletion_vector.is_some();
+                        if let Some(index_reader) = index_reader
+                            .as_ref()
+                            .as_ref()
+                            .filter(|_| !has_deletion_vector)
+                        {
                             let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &part.location,
@@ -249,7 +266,11 @@ impl Processor for ReadParquetDataSource<false> {
                             )
                             .await?;
 
-                        Ok(DataSource::Normal((source, virtual_source)))
+                        let deleted_rows = block_reader
+                            .read_deletion_vector(&part.deletion_vector)
+                            .await?;
+
+                        Ok(DataSource::Normal((source, virtual_source, deleted_rows)))
                     }))
                     .await
                     .unwrap()
@@ -413,19 +413,32 @@ impl AggregationContext {
                 .value);
         }
 
+        let deleted_rows = self
+            .key_column_reader
+            .read_deletion_vector(&block_meta.deletion_vector)
+            .await?
+            .unwrap_or_default();
+
         let mut bitmap = MutableBitmap::new();
+        let mut delete_nums = 0;
         for row in 0..num_rows {
-            if let Some(hash) = row_hash_of_columns(&columns, row)? {
+            if deleted_rows.contains(row as u32) {
+                // the row has been deleted by the deletion vector
+                bitmap.push(false);
+            } else if let Some(hash) = row_hash_of_columns(&columns, row)? {
                 // some row hash means on-conflict columns of this row contains non-null values
                 // let's check it out
-                bitmap.push(!deleted_key_hashes.contains(&hash));
+                let keep = !deleted_key_hashes.contains(&hash);
+                if !keep {
+                    delete_nums += 1;
+                }
+                bitmap.push(keep);
             } else {
                 // otherwise, keep this row
                 bitmap.push(true);
             }
         }
 
-        let delete_nums = bitmap.unset_bits();
         info!("number of row deleted: {}", delete_nums);
 
         // shortcut: nothing to be deleted
@@ -448,12 +461,11 @@ impl AggregationContext {
             .incr(&progress_values);
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if bitmap.unset_bits() == num_rows {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
             metrics_inc_replace_deleted_blocks_rows(num_rows as u64);
             // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
             let mutation = MutationLogEntry::DeletedBlock {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
@@ -528,6 +540,9 @@ impl AggregationContext {
         if let Some(index_state) = serialized.bloom_index_state {
             write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
         }
+        if let Some(index_state) = serialized.inverted_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
+        }
 
         // generate log
         let mutation = MutationLogEntry::ReplacedBlock {
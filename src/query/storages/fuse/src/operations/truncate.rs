@@ -20,6 +20,8 @@ use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache_manager::CachedObject;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
@@ -67,21 +69,31 @@ impl FuseTable {
 
             let table_id = self.table_info.ident.table_id;
             let table_version = self.table_info.ident.seq;
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            };
+
+            // within an explicit transaction (only reachable by a DELETE that removes all
+            // the rows), buffer the new table meta so that it can be rolled back. The
+            // copied files of the table are kept, they can not be removed in a transaction.
+            let txn_mgr = ctx.txn_mgr();
+            if txn_mgr.lock().is_active() {
+                txn_mgr.lock().update_table_meta(req, &self.table_info);
+                TableSnapshot::cache().put(new_snapshot_loc, Arc::new(new_snapshot));
+                return Ok(());
+            }
+
             let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
 
             // commit table meta to meta server.
             // `truncate_table` is not supposed to be retry-able, thus we use
             // `update_data_table_meta` directly.
-            catalog
-                .update_table_meta(&self.table_info, UpdateTableMetaReq {
-                    table_id,
-                    seq: MatchSeq::Exact(table_version),
-                    new_table_meta,
-                    copied_files: None,
-                    deduplicated_label: None,
-                    update_stream_meta: vec![],
-                })
-                .await?;
+            catalog.update_table_meta(&self.table_info, req).await?;
 
             catalog
                 .truncate_table(&self.table_info, TruncateTableReq {
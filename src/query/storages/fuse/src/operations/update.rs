@@ -217,6 +217,9 @@ impl FuseTable {
         let max_threads = (ctx.get_settings().get_max_threads()? as usize)
             .min(ctx.partition_num())
             .max(1);
+        // UPDATE always rewrites the mutated blocks without the rows deleted by their deletion
+        // vectors, deletion vectors are only written by DELETE.
+        let use_deletion_vector = false;
         // Add source pipe.
         pipeline.add_source(
             |output| {
@@ -230,6 +233,7 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     true,
+                    use_deletion_vector,
                 )
             },
             max_threads,
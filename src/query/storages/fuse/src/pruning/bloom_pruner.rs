@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::ColumnId;
 use common_expression::Expr;
 use common_expression::FunctionContext;
@@ -50,6 +51,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// n-gram indices that should be loaded from filter block
+    ngram_index_fields: Vec<TableField>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -70,6 +74,7 @@ impl BloomPrunerCreator {
         dal: Operator,
         filter_expr: Option<&Expr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
             let bloom_columns_map =
@@ -77,7 +82,12 @@ impl BloomPrunerCreator {
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
 
-            if !point_query_cols.is_empty() {
+            let ngram_columns_map = ngram_index_cols
+                .bloom_index_fields(schema.clone(), BloomIndex::supported_ngram_type)?;
+            let ngram_column_fields = ngram_columns_map.values().cloned().collect::<Vec<_>>();
+            let ngram_query_cols = BloomIndex::find_ngram_columns(expr, ngram_column_fields)?;
+
+            if !point_query_cols.is_empty() || !ngram_query_cols.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -89,9 +99,27 @@ impl BloomPrunerCreator {
                     }
                 }
 
+                let mut ngram_filter_fields = Vec::with_capacity(ngram_query_cols.len());
+                for (field, grams) in ngram_query_cols.into_iter() {
+                    if !ngram_filter_fields.contains(&field) {
+                        ngram_filter_fields.push(field);
+                    }
+                    for gram in grams.into_iter() {
+                        if let Entry::Vacant(e) = scalar_map.entry(gram.clone()) {
+                            let digest = BloomIndex::calculate_scalar_digest(
+                                &func_ctx,
+                                &gram,
+                                &DataType::String,
+                            )?;
+                            e.insert(digest);
+                        }
+                    }
+                }
+
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_index_fields: ngram_filter_fields,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -114,8 +142,8 @@ impl BloomPrunerCreator {
         let version = index_location.1;
 
         // filter out columns that no longer exist in the indexed block
-        let index_columns = self.index_fields.iter().try_fold(
-            Vec::with_capacity(self.index_fields.len()),
+        let mut index_columns = self.index_fields.iter().try_fold(
+            Vec::with_capacity(self.index_fields.len() + self.ngram_index_fields.len()),
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
                     acc.push(BloomIndex::build_filter_column_name(version, field)?);
@@ -123,6 +151,11 @@ impl BloomPrunerCreator {
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        for field in self.ngram_index_fields.iter() {
+            if column_ids_of_indexed_block.contains(&field.column_id()) {
+                index_columns.push(BloomIndex::build_ngram_column_name(version, field)?);
+            }
+        }
         // load the relevant index columns
         let maybe_filter = index_location
             .read_block_filter(self.dal.clone(), &index_columns, index_length)
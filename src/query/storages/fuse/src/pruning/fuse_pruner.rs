@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::sync::Arc;
 
 use common_base::base::tokio::sync::Semaphore;
 use common_base::runtime::Runtime;
 use common_base::runtime::TrySpawn;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::TableSample;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -29,6 +32,7 @@ use common_sql::field_default_value;
 use common_sql::BloomIndexColumns;
 use log::warn;
 use opendal::Operator;
+use siphasher::sip::SipHasher24;
 use storages_common_index::RangeIndex;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_pruner::InternalColumnPruner;
@@ -50,6 +54,7 @@ use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
 
 pub struct PruningContext {
@@ -61,6 +66,7 @@ pub struct PruningContext {
     pub limit_pruner: Arc<dyn Limiter + Send + Sync>,
     pub range_pruner: Arc<dyn RangePruner + Send + Sync>,
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
+    pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
 
@@ -77,6 +83,7 @@ impl PruningContext {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
         max_concurrency: usize,
     ) -> Result<Arc<PruningContext>> {
         let func_ctx = ctx.get_function_context()?;
@@ -89,10 +96,10 @@ impl PruningContext {
         });
 
         // Limit pruner.
-        // if there are ordering/filter clause, ignore limit, even it has been pushed down
+        // if there are ordering/filter/sample clause, ignore limit, even it has been pushed down
         let limit = push_down
             .as_ref()
-            .filter(|p| p.order_by.is_empty() && p.filters.is_none())
+            .filter(|p| p.order_by.is_empty() && p.filters.is_none() && p.sample.is_none())
             .and_then(|p| p.limit);
         // prepare the limiter. in case that limit is none, an unlimited limiter will be returned
         let limit_pruner = LimiterPrunerCreator::create(limit);
@@ -129,6 +136,16 @@ impl PruningContext {
             dal.clone(),
             filter_expr.as_ref(),
             bloom_index_cols,
+            ngram_index_cols,
+        )?;
+
+        // Inverted index pruner.
+        // None will be returned, if there is no `match` on the columns in the filter expression.
+        let inverted_index_pruner = InvertedIndexPruner::try_create(
+            func_ctx.clone(),
+            &table_schema,
+            dal.clone(),
+            filter_expr.as_ref(),
         )?;
 
         // Page pruner, used in native format
@@ -164,6 +181,7 @@ impl PruningContext {
             limit_pruner,
             range_pruner,
             bloom_pruner,
+            inverted_index_pruner,
             page_pruner,
             internal_column_pruner,
             pruning_stats,
@@ -189,6 +207,7 @@ impl FusePruner {
         table_schema: TableSchemaRef,
         push_down: &Option<PushDownInfo>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         Self::create_with_pages(
             ctx,
@@ -198,10 +217,12 @@ impl FusePruner {
             None,
             vec![],
             bloom_index_cols,
+            ngram_index_cols,
         )
     }
 
     // Create fuse pruner with pages.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_with_pages(
         ctx: &Arc<dyn TableContext>,
         dal: Operator,
@@ -210,6 +231,7 @@ impl FusePruner {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         let max_concurrency = {
             let max_io_requests = ctx.get_settings().get_max_storage_io_requests()? as usize;
@@ -232,6 +254,7 @@ impl FusePruner {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            ngram_index_cols,
             max_concurrency,
         )?;
 
@@ -376,12 +399,42 @@ impl FusePruner {
                     // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
                     // will get here, we can prevent other mutations like update and so on.
                     // TopN pruner.
+                    let metas = self.block_sampling(metas);
                     self.topn_pruning(metas)
                 }
             }
         }
     }
 
+    // block sampling:
+    // if there is a block level sample clause, each block is kept with the probability.
+    // With a seed, the choice only depends on the block location, so it's repeatable.
+    fn block_sampling(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Vec<(BlockMetaIndex, Arc<BlockMeta>)> {
+        let Some(TableSample::Block { probability, seed }) =
+            self.push_down.as_ref().and_then(|p| p.sample.as_ref())
+        else {
+            return metas;
+        };
+
+        metas
+            .into_iter()
+            .filter(|(_, meta)| {
+                let key = match seed {
+                    Some(seed) => {
+                        let mut hasher = SipHasher24::new();
+                        meta.location.0.hash(&mut hasher);
+                        TableSample::mix_seed(hasher.finish(), *seed)
+                    }
+                    None => rand::random::<u64>(),
+                };
+                TableSample::hit(key, **probability)
+            })
+            .collect()
+    }
+
     // topn pruner:
     // if there are ordering + limit clause and no filters, use topn pruner
     fn topn_pruning(
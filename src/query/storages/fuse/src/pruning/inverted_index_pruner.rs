@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::TableSchemaRef;
+use log::warn;
+use opendal::Operator;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+use storages_common_table_meta::meta::Location;
+
+/// Prune the blocks by the inverted indexes, with the `match(column, query)` in the filter.
+pub struct InvertedIndexPruner {
+    func_ctx: FunctionContext,
+
+    /// the expression that would be evaluate
+    filter_expression: Expr<String>,
+
+    /// the column ids of the columns used by `match`
+    column_ids: HashMap<String, ColumnId>,
+
+    /// the data accessor
+    dal: Operator,
+}
+
+impl InvertedIndexPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        dal: Operator,
+        filter_expr: Option<&Expr<String>>,
+    ) -> Result<Option<Arc<InvertedIndexPruner>>> {
+        if let Some(expr) = filter_expr {
+            let column_ids = InvertedIndex::find_match_columns(expr)?
+                .into_iter()
+                .filter_map(|name| {
+                    let column_id = schema.field_with_name(&name).ok()?.column_id();
+                    Some((name, column_id))
+                })
+                .collect::<HashMap<_, _>>();
+
+            if !column_ids.is_empty() {
+                return Ok(Some(Arc::new(InvertedIndexPruner {
+                    func_ctx,
+                    filter_expression: expr.clone(),
+                    column_ids,
+                    dal,
+                })));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns false if the block should be pruned, and the offsets of the rows
+    /// which may match the filter, `None` means all the rows.
+    #[async_backtrace::framed]
+    pub async fn should_keep(&self, index_location: &Option<Location>) -> (bool, Option<Vec<u32>>) {
+        if let Some(loc) = index_location {
+            match self.apply(loc).await {
+                Ok(v) => v,
+                Err(e) => {
+                    // swallow exceptions intentionally, corrupted index should not prevent execution
+                    warn!(
+                        "failed to apply inverted index pruner, returning true. {}",
+                        e
+                    );
+                    (true, None)
+                }
+            }
+        } else {
+            // The block is written before the inverted index is created.
+            (true, None)
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn apply(&self, index_location: &Location) -> Result<(bool, Option<Vec<u32>>)> {
+        let data = self.dal.read(&index_location.0).await?;
+        let index = InvertedIndex::from_bytes(&data)?;
+
+        let result = index.apply(
+            &self.func_ctx,
+            self.filter_expression.clone(),
+            &self.column_ids,
+        )?;
+        if result == FilterEvalResult::MustFalse {
+            return Ok((false, None));
+        }
+
+        match index.filter_rows(&self.filter_expression, &self.column_ids) {
+            Some(rows) if rows.is_empty() => Ok((false, None)),
+            Some(rows) => Ok((true, Some(rows.iter().collect()))),
+            None => Ok((true, None)),
+        }
+    }
+}
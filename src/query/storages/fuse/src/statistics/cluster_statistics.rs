@@ -38,6 +38,9 @@ pub struct ClusterStatsGenerator {
 
     level: i32,
     block_thresholds: BlockThresholds,
+    // Keep the whole string value in the statistics, used by the space-filling curve
+    // cluster key whose prefix cannot tell the order of the blocks.
+    keep_full_string: bool,
 
     pub cluster_key_index: Vec<usize>,
     pub operators: Vec<BlockOperator>,
@@ -65,12 +68,18 @@ impl ClusterStatsGenerator {
             max_page_size,
             level,
             block_thresholds,
+            keep_full_string: false,
             operators,
             out_fields,
             func_ctx,
         }
     }
 
+    pub fn with_keep_full_string(mut self, keep_full_string: bool) -> Self {
+        self.keep_full_string = keep_full_string;
+        self
+    }
+
     pub fn is_cluster(&self) -> bool {
         !self.cluster_key_index.is_empty()
     }
@@ -120,7 +129,7 @@ impl ClusterStatsGenerator {
         self.clusters_statistics(&block, origin_stats.level)
     }
 
-    /// for string value, only use the first 8 bytes.
+    /// for string value, only use the first 8 bytes unless `keep_full_string` is set.
     fn clusters_statistics(
         &self,
         data_block: &DataBlock,
@@ -136,21 +145,29 @@ impl ClusterStatsGenerator {
             let val = data_block.get_by_offset(*key);
             let val_ref = val.value.as_ref();
             let left = unsafe { val_ref.index_unchecked(0) }.to_owned();
-            min.push(
-                left.clone()
-                    .trim_min(CLUSTER_STATS_STRING_PREFIX_LEN)
-                    .unwrap_or(left),
-            );
+            if self.keep_full_string {
+                min.push(left);
+            } else {
+                min.push(
+                    left.clone()
+                        .trim_min(CLUSTER_STATS_STRING_PREFIX_LEN)
+                        .unwrap_or(left),
+                );
+            }
 
             // The maximum in cluster statistics neednot larger than the non-trimmed one.
             // So we use trim_min directly.
             let right = unsafe { val_ref.index_unchecked(val_ref.len() - 1) }.to_owned();
-            max.push(
-                right
-                    .clone()
-                    .trim_min(CLUSTER_STATS_STRING_PREFIX_LEN)
-                    .unwrap_or(right),
-            );
+            if self.keep_full_string {
+                max.push(right);
+            } else {
+                max.push(
+                    right
+                        .clone()
+                        .trim_min(CLUSTER_STATS_STRING_PREFIX_LEN)
+                        .unwrap_or(right),
+                );
+            }
         }
 
         let level = if min == max
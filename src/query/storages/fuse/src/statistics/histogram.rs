@@ -0,0 +1,148 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use storages_common_table_meta::meta::ColumnHistogram;
+use storages_common_table_meta::meta::HistogramBucket;
+
+use crate::statistics::Trim;
+use crate::statistics::STATS_STRING_PREFIX_LEN;
+
+pub const HISTOGRAM_BUCKETS: usize = 100;
+pub const HISTOGRAM_MOST_COMMON_VALUES: usize = 20;
+
+/// Whether the histogram of a column of `data_type` can be used by the optimizer.
+pub fn histogram_supported_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        DataType::Boolean
+            | DataType::Number(_)
+            | DataType::Date
+            | DataType::Timestamp
+            | DataType::String
+    )
+}
+
+/// Builds the histogram of a column from the values sampled by `ANALYZE TABLE`.
+#[derive(Default)]
+pub struct HistogramBuilder {
+    values: Vec<Scalar>,
+}
+
+impl HistogramBuilder {
+    /// Samples every `step`-th row of the column, null values are ignored.
+    pub fn add_column(&mut self, column: &Column, step: usize) {
+        for row in (0..column.len()).step_by(step.max(1)) {
+            match column.index(row) {
+                Some(ScalarRef::Null) | None => {}
+                Some(v) => self.values.push(v.to_owned()),
+            }
+        }
+    }
+
+    /// Build the histogram, the counts are scaled to `num_values` non-null rows
+    /// having `ndv` distinct values in total.
+    pub fn build(mut self, num_values: u64, ndv: u64) -> Option<ColumnHistogram> {
+        if self.values.is_empty() {
+            return None;
+        }
+        self.values.sort();
+        let num_samples = self.values.len();
+
+        // Group the sorted values into (value, count) runs.
+        let mut runs: Vec<(Scalar, usize)> = Vec::new();
+        for value in self.values {
+            match runs.last_mut() {
+                Some((last, count)) if *last == value => *count += 1,
+                _ => runs.push((value, 1)),
+            }
+        }
+        let row_scale = num_values as f64 / num_samples as f64;
+
+        // A value is common if it would fill more than one bucket on its own.
+        let threshold = (num_samples / HISTOGRAM_BUCKETS).max(1) + 1;
+        let mut candidates = runs
+            .iter()
+            .enumerate()
+            .filter(|(_, (value, count))| {
+                *count >= threshold
+                    && !matches!(value, Scalar::String(s) if s.len() > STATS_STRING_PREFIX_LEN)
+            })
+            .map(|(idx, (_, count))| (idx, *count))
+            .collect::<Vec<_>>();
+        candidates.sort_by(|a, b| b.1.cmp(&a.1));
+        candidates.truncate(HISTOGRAM_MOST_COMMON_VALUES);
+        let common_indexes = candidates
+            .iter()
+            .map(|(idx, _)| *idx)
+            .collect::<HashSet<_>>();
+        let most_common_values = candidates
+            .iter()
+            .map(|(idx, count)| (runs[*idx].0.clone(), *count as f64 * row_scale))
+            .collect::<Vec<_>>();
+
+        let rest = runs
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| !common_indexes.contains(idx))
+            .map(|(_, run)| run)
+            .collect::<Vec<_>>();
+        let rest_rows: usize = rest.iter().map(|(_, count)| count).sum();
+        // The sample may miss some distinct values, spread the missing ones over the buckets.
+        let rest_ndv = ndv.saturating_sub(most_common_values.len() as u64) as f64;
+        let distinct_scale = (rest_ndv / rest.len().max(1) as f64).max(1.0);
+
+        let num_buckets = HISTOGRAM_BUCKETS.min(rest.len());
+        let mut buckets = Vec::with_capacity(num_buckets);
+        let mut iter = rest.into_iter().peekable();
+        let mut accumulated = 0;
+        for bucket_idx in 0..num_buckets {
+            // Every bucket holds roughly the same number of rows, a value never spans two buckets.
+            let target = rest_rows * (bucket_idx + 1) / num_buckets;
+            let Some((lower_bound, count)) = iter.next() else {
+                break;
+            };
+            let mut upper_bound = lower_bound.clone();
+            let mut bucket_rows = count;
+            let mut bucket_distinct = 1;
+            while accumulated + bucket_rows < target {
+                match iter.next() {
+                    Some((value, count)) => {
+                        upper_bound = value;
+                        bucket_rows += count;
+                        bucket_distinct += 1;
+                    }
+                    None => break,
+                }
+            }
+            accumulated += bucket_rows;
+            buckets.push(HistogramBucket {
+                lower_bound: lower_bound.trim_min(STATS_STRING_PREFIX_LEN)?,
+                upper_bound: upper_bound.trim_max(STATS_STRING_PREFIX_LEN)?,
+                num_values: bucket_rows as f64 * row_scale,
+                num_distinct: bucket_distinct as f64 * distinct_scale,
+            });
+        }
+
+        Some(ColumnHistogram {
+            most_common_values,
+            buckets,
+        })
+    }
+}
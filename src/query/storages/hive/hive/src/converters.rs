@@ -74,18 +74,16 @@ pub fn try_into_table_info(
     };
     let schema = Arc::new(try_into_schema(fields)?);
 
-    let location = if let Some(storage) = &hms_table.sd {
-        storage
-            .location
-            .as_ref()
-            .map(|location| location.to_string())
+    let (location, input_format) = if let Some(storage) = &hms_table.sd {
+        (storage.location.clone(), storage.input_format.clone())
     } else {
-        None
+        (None, None)
     };
 
     let table_options = HiveTableOptions {
         partition_keys,
         location,
+        input_format,
     };
 
     let meta = TableMeta {
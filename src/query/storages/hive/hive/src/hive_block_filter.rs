@@ -31,6 +31,7 @@ use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
+use common_pipeline_sources::input_formats::OrcFileReader;
 use storages_common_index::RangeIndex;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
@@ -95,30 +96,85 @@ impl HiveBlockFilter {
                 }
             }
 
-            for (p_key, p_value) in part_columns {
-                if let Ok(idx) = self.data_schema.index_of(&p_key) {
-                    let mut null_count = 0;
-                    let v = if p_value == HIVE_DEFAULT_PARTITION {
-                        null_count = row_group.num_rows();
-                        Scalar::Null
-                    } else {
-                        Scalar::String(p_value.as_bytes().to_vec())
-                    };
+            return Self::apply(
+                filter,
+                statistics,
+                &self.data_schema,
+                part_columns,
+                row_group.num_rows(),
+            );
+        }
+        false
+    }
 
-                    let col_stats = ColumnStatistics::new(v.clone(), v, null_count as u64, 0, None);
-                    statistics.insert(idx as u32, col_stats);
-                }
-            }
+    // true: stripe is filtered by predict
+    pub fn filter_orc_stripe(
+        &self,
+        reader: &OrcFileReader,
+        stripe: usize,
+        part_columns: HashMap<String, String>,
+    ) -> bool {
+        if let Some(filter) = &self.range_filter {
+            let statistics = Self::partition_statistics(
+                &self.data_schema,
+                part_columns,
+                reader.stripe_num_rows(stripe),
+            );
+            return reader.prune_stripe(
+                stripe,
+                filter,
+                &self.data_schema,
+                &self.projections,
+                statistics,
+            );
+        }
+        false
+    }
 
-            if let Ok(ret) = filter.apply(&statistics, |_| false) {
-                if !ret {
-                    return true;
-                }
+    fn apply(
+        filter: &RangeIndex,
+        mut statistics: StatisticsOfColumns,
+        data_schema: &TableSchema,
+        part_columns: HashMap<String, String>,
+        num_rows: usize,
+    ) -> bool {
+        statistics.extend(Self::partition_statistics(
+            data_schema,
+            part_columns,
+            num_rows,
+        ));
+
+        if let Ok(ret) = filter.apply(&statistics, |_| false) {
+            if !ret {
+                return true;
             }
         }
         false
     }
 
+    fn partition_statistics(
+        data_schema: &TableSchema,
+        part_columns: HashMap<String, String>,
+        num_rows: usize,
+    ) -> StatisticsOfColumns {
+        let mut statistics = StatisticsOfColumns::new();
+        for (p_key, p_value) in part_columns {
+            if let Ok(idx) = data_schema.index_of(&p_key) {
+                let mut null_count = 0;
+                let v = if p_value == HIVE_DEFAULT_PARTITION {
+                    null_count = num_rows;
+                    Scalar::Null
+                } else {
+                    Scalar::String(p_value.as_bytes().to_vec())
+                };
+
+                let col_stats = ColumnStatistics::new(v.clone(), v, null_count as u64, 0, None);
+                statistics.insert(idx as u32, col_stats);
+            }
+        }
+        statistics
+    }
+
     fn get_max_min_stats(
         column_type: &TableDataType,
         stats: &dyn Statistics,
@@ -93,6 +93,8 @@ use thrift::transport::*;
 
 use super::hive_database::HiveDatabase;
 use crate::hive_table::HiveTable;
+use crate::hive_table_options::ORC_INPUT_FORMAT;
+use crate::hive_table_options::PARQUET_INPUT_FORMAT;
 
 pub const HIVE_CATALOG: &str = "hive";
 
@@ -251,9 +253,9 @@ impl HiveCatalog {
     fn handle_table_meta(table_meta: &hive_metastore::Table) -> Result<()> {
         if let Some(sd) = table_meta.sd.as_ref() {
             if let Some(input_format) = sd.input_format.as_ref() {
-                if input_format != "org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat" {
+                if input_format != PARQUET_INPUT_FORMAT && input_format != ORC_INPUT_FORMAT {
                     return Err(ErrorCode::Unimplemented(format!(
-                        "only support parquet, {} not support",
+                        "only support parquet and orc, {} not support",
                         input_format
                     )));
                 }
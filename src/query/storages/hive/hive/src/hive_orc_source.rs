@@ -0,0 +1,222 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_base::base::tokio::time::sleep;
+use common_base::base::tokio::time::Duration;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_sources::input_formats::OrcFileReader;
+use common_pipeline_sources::AsyncSource;
+use log::debug;
+use opendal::Operator;
+
+use crate::utils::str_field_to_scalar;
+use crate::HiveBlockFilter;
+use crate::HivePartInfo;
+
+struct OrcFileStripes {
+    part: HivePartInfo,
+    reader: OrcFileReader,
+    stripes: VecDeque<usize>,
+}
+
+/// Reads hive tables stored as ORC.
+///
+/// Each part is read as a whole file, the stripes that do not belong to the part's range
+/// or are pruned by the column statistics are skipped, the others are read one at a time.
+pub struct HiveOrcSource {
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    prewhere_filter: Arc<Option<Expr>>,
+    delay: usize,
+    hive_block_filter: Arc<HiveBlockFilter>,
+    partition_keys: Arc<HashSet<String>>,
+
+    /// The schema of the blocks read from the stripes, partition columns included.
+    source_schema: DataSchemaRef,
+    /// The final output schema
+    output_schema: DataSchemaRef,
+
+    current: Option<OrcFileStripes>,
+}
+
+impl HiveOrcSource {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        dal: Operator,
+        prewhere_filter: Arc<Option<Expr>>,
+        delay: usize,
+        hive_block_filter: Arc<HiveBlockFilter>,
+        partition_keys: Arc<HashSet<String>>,
+        source_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+    ) -> Self {
+        HiveOrcSource {
+            ctx,
+            dal,
+            prewhere_filter,
+            delay,
+            hive_block_filter,
+            partition_keys,
+            source_schema,
+            output_schema,
+            current: None,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn open_part(&mut self, part: HivePartInfo) -> Result<OrcFileStripes> {
+        let reader = OrcFileReader::read(&self.dal, &part.filename).await?;
+
+        let mut pruned = 0;
+        let mut stripes = VecDeque::new();
+        for stripe in 0..reader.num_stripes() {
+            let range = reader.stripe_byte_range(stripe);
+            let mid = range.start + (range.end - range.start) / 2;
+            if !part.range.contains(&mid) {
+                continue;
+            }
+            if self
+                .hive_block_filter
+                .filter_orc_stripe(&reader, stripe, part.get_partition_map())
+            {
+                pruned += 1;
+            } else {
+                stripes.push_back(stripe);
+            }
+        }
+        debug!(
+            "hive orc predict pushdown have pruned {} stripes of {}",
+            pruned, part.filename
+        );
+
+        Ok(OrcFileStripes {
+            part,
+            reader,
+            stripes,
+        })
+    }
+
+    fn read_stripe(&self, file: &OrcFileStripes, stripe: usize) -> Result<DataBlock> {
+        let mut data_fields = vec![];
+        let mut indices = vec![];
+        for field in self.source_schema.fields() {
+            if self.partition_keys.contains(field.name()) {
+                continue;
+            }
+            match file.reader.field_index(field.name()) {
+                Some(index) => {
+                    data_fields.push(field.clone());
+                    indices.push(index);
+                }
+                None => {
+                    return Err(ErrorCode::TableSchemaMismatch(format!(
+                        "couldn't find column:{} in orc file {}",
+                        field.name(),
+                        file.part.filename
+                    )));
+                }
+            }
+        }
+        let data_block =
+            file.reader
+                .read_stripe(stripe, &indices, &DataSchema::new(data_fields))?;
+
+        // put the partition values in place, so the block matches the source schema.
+        let num_rows = data_block.num_rows();
+        let partition_map = file.part.get_partition_map();
+        let mut data_columns = data_block.columns().iter();
+        let mut columns = Vec::with_capacity(self.source_schema.num_fields());
+        for field in self.source_schema.fields() {
+            if self.partition_keys.contains(field.name()) {
+                let value = partition_map.get(field.name()).ok_or_else(|| {
+                    ErrorCode::TableInfoError(format!(
+                        "couldn't find hive partition info :{}, hive partition maps:{:?}",
+                        field.name(),
+                        partition_map
+                    ))
+                })?;
+                let scalar = str_field_to_scalar(value, field.data_type())?;
+                columns.push(BlockEntry::new(
+                    field.data_type().clone(),
+                    Value::Scalar(scalar),
+                ));
+            } else {
+                columns.push(data_columns.next().unwrap().clone());
+            }
+        }
+        let mut block = DataBlock::new(columns, num_rows);
+
+        if let Some(filter) = self.prewhere_filter.as_ref() {
+            let func_ctx = self.ctx.get_function_context()?;
+            let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+            let predicates = evaluator
+                .run(filter)
+                .map_err(|e| e.add_message("eval prewhere filter failed:"))?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            block = block.filter_boolean_value(&predicates)?;
+        }
+
+        block.resort(&self.source_schema, &self.output_schema)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for HiveOrcSource {
+    const NAME: &'static str = "HiveOrcSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(mut file) = self.current.take() {
+                if let Some(stripe) = file.stripes.pop_front() {
+                    let block = self.read_stripe(&file, stripe).map_err(|e| {
+                        e.add_message(format!(" filename of hive part {}", file.part.filename))
+                    })?;
+                    self.current = Some(file);
+                    return Ok(Some(block));
+                }
+            }
+
+            let part = match self.ctx.get_partition() {
+                None => return Ok(None),
+                Some(part) => part,
+            };
+            if self.delay > 0 {
+                sleep(Duration::from_millis(self.delay as u64)).await;
+                debug!("sleep for {}ms", self.delay);
+                self.delay = 0;
+            }
+            let part = HivePartInfo::from_part(&part)?.clone();
+            self.current = Some(self.open_part(part).await?);
+        }
+    }
+}
@@ -48,6 +48,7 @@ use common_pipeline_core::processors::OutputPort;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_pipeline_core::SourcePipeBuilder;
+use common_pipeline_sources::AsyncSourcer;
 use common_pipeline_sources::SyncSource;
 use common_pipeline_sources::SyncSourcer;
 use common_storage::init_operator;
@@ -66,6 +67,7 @@ use super::hive_catalog::HiveCatalog;
 use super::hive_partition_pruner::HivePartitionPruner;
 use super::hive_table_options::HiveTableOptions;
 use crate::filter_hive_partition_from_partition_keys;
+use crate::hive_orc_source::HiveOrcSource;
 use crate::hive_parquet_block_reader::HiveBlockReader;
 use crate::hive_table_source::HiveTableSource;
 use crate::HiveBlockFilter;
@@ -214,6 +216,32 @@ impl HiveTable {
         }
         let src_schema = DataSchemaRefExt::create(src_fields);
 
+        if self.table_options.is_orc() {
+            let partition_keys = Arc::new(self.get_partition_key_sets());
+            for index in 0..std::cmp::max(1, max_threads) {
+                let output = OutputPort::create();
+                source_builder.add_source(
+                    output.clone(),
+                    AsyncSourcer::create(
+                        ctx.clone(),
+                        output,
+                        HiveOrcSource::create(
+                            ctx.clone(),
+                            self.dal.clone(),
+                            prewhere_filter.clone(),
+                            delay_timer(index),
+                            hive_block_filter.clone(),
+                            partition_keys.clone(),
+                            src_schema.clone(),
+                            output_schema.clone(),
+                        ),
+                    )?,
+                );
+            }
+            pipeline.add_pipe(source_builder.finalize());
+            return Ok(());
+        }
+
         for index in 0..std::cmp::max(1, max_threads) {
             let output = OutputPort::create();
             source_builder.add_source(
@@ -526,7 +554,13 @@ impl HiveTable {
         let all_files = self.list_files_from_dirs(dirs).await?;
         trace!("{} hive files: {:?}", all_files.len(), all_files);
 
-        let splitter = HiveFileSplitter::create(128 * 1024 * 1024_u64);
+        // the footer of an ORC file is needed to read any of its stripes, so the file is not split.
+        let split_size = if self.table_options.is_orc() {
+            u64::MAX
+        } else {
+            128 * 1024 * 1024_u64
+        };
+        let splitter = HiveFileSplitter::create(split_size);
         let partitions = splitter.get_splits(all_files);
 
         info!(
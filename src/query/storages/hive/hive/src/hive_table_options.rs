@@ -20,15 +20,28 @@ use common_exception::Result;
 
 pub const PARTITION_KEYS: &str = "partition_keys";
 pub const LOCATION: &str = "location";
+pub const INPUT_FORMAT: &str = "input_format";
+
+pub const PARQUET_INPUT_FORMAT: &str =
+    "org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat";
+pub const ORC_INPUT_FORMAT: &str = "org.apache.hadoop.hive.ql.io.orc.OrcInputFormat";
 
 // represents hive table schema info
 //
 // partition_keys,  hive partition keys, such as:  "p_date", "p_hour"
 // location,  hive table location, such as: hdfs://namenode:8020/user/hive/warehouse/a.db/b.table/
+// input_format,  hive table input format, parquet if not set, such as: org.apache.hadoop.hive.ql.io.orc.OrcInputFormat
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HiveTableOptions {
     pub partition_keys: Option<Vec<String>>,
     pub location: Option<String>,
+    pub input_format: Option<String>,
+}
+
+impl HiveTableOptions {
+    pub fn is_orc(&self) -> bool {
+        self.input_format.as_deref() == Some(ORC_INPUT_FORMAT)
+    }
 }
 
 impl From<HiveTableOptions> for BTreeMap<String, String> {
@@ -42,6 +55,9 @@ impl From<HiveTableOptions> for BTreeMap<String, String> {
         options
             .location
             .map(|v| map.insert(LOCATION.to_string(), v));
+        options
+            .input_format
+            .map(|v| map.insert(INPUT_FORMAT.to_string(), v));
         map
     }
 }
@@ -64,6 +80,7 @@ impl TryFrom<&BTreeMap<String, String>> for HiveTableOptions {
         let options = HiveTableOptions {
             partition_keys,
             location: Some(location),
+            input_format: options.get(INPUT_FORMAT).cloned(),
         };
         Ok(options)
     }
@@ -86,6 +103,7 @@ mod tests {
         let hive_table_options = HiveTableOptions {
             partition_keys: Some(vec!["a".to_string(), "b".to_string()]),
             location: Some("test".to_string()),
+            input_format: None,
         };
 
         do_test_hive_table_options(hive_table_options);
@@ -93,7 +111,16 @@ mod tests {
         let empty = HiveTableOptions {
             partition_keys: None,
             location: Some("test".to_string()),
+            input_format: None,
         };
         do_test_hive_table_options(empty);
+
+        let orc = HiveTableOptions {
+            partition_keys: None,
+            location: Some("test".to_string()),
+            input_format: Some(super::ORC_INPUT_FORMAT.to_string()),
+        };
+        assert!(orc.is_orc());
+        do_test_hive_table_options(orc);
     }
 }
@@ -214,8 +214,21 @@ impl Catalog for IcebergCatalog {
     }
 
     #[async_backtrace::framed]
-    async fn create_database(&self, _req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
-        unimplemented!()
+    async fn create_database(&self, req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
+        let db_name = &req.name_ident.db_name;
+        let rel_path = format!("{db_name}/");
+
+        let operator = self.operator.operator();
+        if operator.is_exist(&rel_path).await? {
+            if req.if_not_exists {
+                return Ok(CreateDatabaseReply { db_id: 0 });
+            }
+            return Err(ErrorCode::DatabaseAlreadyExists(format!(
+                "Database {db_name} already exists"
+            )));
+        }
+        operator.create_dir(&rel_path).await?;
+        Ok(CreateDatabaseReply { db_id: 0 })
     }
 
     #[async_backtrace::framed]
@@ -284,8 +297,11 @@ impl Catalog for IcebergCatalog {
     }
 
     #[async_backtrace::framed]
-    async fn create_table(&self, _req: CreateTableReq) -> Result<CreateTableReply> {
-        unimplemented!()
+    async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        let db = self
+            .get_database(&req.name_ident.tenant, &req.name_ident.db_name)
+            .await?;
+        db.create_table(req).await
     }
 
     #[async_backtrace::framed]
@@ -482,6 +498,10 @@ impl Catalog for IcebergCatalog {
 
     // Get table engines
     fn get_table_engines(&self) -> Vec<StorageDescription> {
-        unimplemented!()
+        vec![StorageDescription {
+            engine_name: "ICEBERG".to_string(),
+            comment: "ICEBERG Storage Engine".to_string(),
+            support_cluster_key: false,
+        }]
     }
 }
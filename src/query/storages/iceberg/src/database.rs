@@ -21,6 +21,8 @@ use common_catalog::database::Database;
 use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::CreateTableReply;
+use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DatabaseIdent;
 use common_meta_app::schema::DatabaseInfo;
 use common_meta_app::schema::DatabaseMeta;
@@ -30,6 +32,8 @@ use futures::StreamExt;
 use opendal::EntryMode;
 use opendal::Metakey;
 
+use crate::metadata::table_location;
+use crate::metadata::IcebergMetadata;
 use crate::table::IcebergTable;
 
 #[derive(Clone, Debug)]
@@ -103,6 +107,42 @@ impl Database for IcebergDatabase {
         Ok(tbl)
     }
 
+    /// Create an empty unpartitioned table, by committing the first version of its metadata.
+    #[async_backtrace::framed]
+    async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        let table_name = &req.name_ident.table_name;
+        let path = format!("{table_name}/");
+        if self.db_root.operator().is_exist(&path).await? {
+            if req.if_not_exists {
+                return Ok(CreateTableReply {
+                    table_id: 0,
+                    new_table: false,
+                });
+            }
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "table {table_name} already exists"
+            )));
+        }
+
+        let table_sp = self.db_root.params().map_root(|r| format!("{r}{path}"));
+        let tbl_root = DataOperator::try_create(&table_sp).await?;
+        let metadata =
+            IcebergMetadata::create(&table_location(&table_sp)?, &req.table_meta.schema)?;
+        if let Err(e) = metadata.commit(&tbl_root.operator()).await {
+            // Leave nothing behind unless the table is created by others, so that
+            // creating it again won't see a table without metadata.
+            if e.code() != ErrorCode::TABLE_VERSION_MISMATCHED {
+                let _ = self.db_root.operator().remove_all(&path).await;
+            }
+            return Err(e);
+        }
+
+        Ok(CreateTableReply {
+            table_id: 0,
+            new_table: true,
+        })
+    }
+
     #[async_backtrace::framed]
     async fn list_tables(&self) -> Result<Vec<Arc<dyn Table>>> {
         let mut tables = vec![];
@@ -86,6 +86,20 @@
 //! ```sql
 //! SELECT * FROM icb_ctl.default.icbg_tbl_0;
 //! ```
+//!
+//! ## Pruning
+//!
+//! Scans skip the manifests by their partition summaries, and then the data files
+//! by their column bounds and partition values, only identity partitions are used.
+//!
+//! ## Writing
+//!
+//! Unpartitioned tables can be created, inserted and overwritten, in the layout of
+//! the filesystem (hadoop) catalog, see the `metadata` module for how the commits work.
+//! ```sql
+//! CREATE TABLE icb_ctl.db0.tbl2 AS SELECT * FROM icb_ctl.db0.tbl1;
+//! INSERT INTO icb_ctl.db0.tbl2 VALUES (1, 'a');
+//! ```
 
 #![feature(lazy_cell)]
 #![feature(impl_trait_in_assoc_type)]
@@ -93,7 +107,10 @@
 
 mod catalog;
 mod database;
+mod manifest;
+mod metadata;
 mod partition;
+mod sink;
 mod stats;
 mod table;
 mod table_source;
@@ -0,0 +1,472 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Avro manifests and manifest lists of the iceberg snapshots.
+//!
+//! Only the required fields of the spec are written, the data files are always
+//! written to the unpartitioned spec `0`. The readers keep the fields used to plan
+//! scans, including the partition summaries and the column bounds for pruning.
+
+use std::collections::HashMap;
+
+use apache_avro::types::Value;
+use apache_avro::Codec;
+use apache_avro::Reader;
+use apache_avro::Schema;
+use apache_avro::Writer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+const MANIFEST_ENTRY_V1: &str = r#"{
+  "type": "record",
+  "name": "manifest_entry",
+  "fields": [
+    {"name": "status", "type": "int", "field-id": 0},
+    {"name": "snapshot_id", "type": "long", "field-id": 1},
+    {"name": "data_file", "field-id": 2, "type": {
+      "type": "record",
+      "name": "r2",
+      "fields": [
+        {"name": "file_path", "type": "string", "field-id": 100},
+        {"name": "file_format", "type": "string", "field-id": 101},
+        {"name": "partition", "field-id": 102, "type": {"type": "record", "name": "r102", "fields": []}},
+        {"name": "record_count", "type": "long", "field-id": 103},
+        {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+        {"name": "block_size_in_bytes", "type": "long", "field-id": 105}
+      ]
+    }}
+  ]
+}"#;
+
+const MANIFEST_ENTRY_V2: &str = r#"{
+  "type": "record",
+  "name": "manifest_entry",
+  "fields": [
+    {"name": "status", "type": "int", "field-id": 0},
+    {"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1},
+    {"name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3},
+    {"name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4},
+    {"name": "data_file", "field-id": 2, "type": {
+      "type": "record",
+      "name": "r2",
+      "fields": [
+        {"name": "content", "type": "int", "field-id": 134},
+        {"name": "file_path", "type": "string", "field-id": 100},
+        {"name": "file_format", "type": "string", "field-id": 101},
+        {"name": "partition", "field-id": 102, "type": {"type": "record", "name": "r102", "fields": []}},
+        {"name": "record_count", "type": "long", "field-id": 103},
+        {"name": "file_size_in_bytes", "type": "long", "field-id": 104}
+      ]
+    }}
+  ]
+}"#;
+
+const MANIFEST_FILE_V1: &str = r#"{
+  "type": "record",
+  "name": "manifest_file",
+  "fields": [
+    {"name": "manifest_path", "type": "string", "field-id": 500},
+    {"name": "manifest_length", "type": "long", "field-id": 501},
+    {"name": "partition_spec_id", "type": "int", "field-id": 502},
+    {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+    {"name": "added_data_files_count", "type": ["null", "int"], "default": null, "field-id": 504},
+    {"name": "existing_data_files_count", "type": ["null", "int"], "default": null, "field-id": 505},
+    {"name": "deleted_data_files_count", "type": ["null", "int"], "default": null, "field-id": 506},
+    {"name": "added_rows_count", "type": ["null", "long"], "default": null, "field-id": 512},
+    {"name": "existing_rows_count", "type": ["null", "long"], "default": null, "field-id": 513},
+    {"name": "deleted_rows_count", "type": ["null", "long"], "default": null, "field-id": 514}
+  ]
+}"#;
+
+const MANIFEST_FILE_V2: &str = r#"{
+  "type": "record",
+  "name": "manifest_file",
+  "fields": [
+    {"name": "manifest_path", "type": "string", "field-id": 500},
+    {"name": "manifest_length", "type": "long", "field-id": 501},
+    {"name": "partition_spec_id", "type": "int", "field-id": 502},
+    {"name": "content", "type": "int", "field-id": 517},
+    {"name": "sequence_number", "type": "long", "field-id": 515},
+    {"name": "min_sequence_number", "type": "long", "field-id": 516},
+    {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+    {"name": "added_files_count", "type": "int", "field-id": 504},
+    {"name": "existing_files_count", "type": "int", "field-id": 505},
+    {"name": "deleted_files_count", "type": "int", "field-id": 506},
+    {"name": "added_rows_count", "type": "long", "field-id": 512},
+    {"name": "existing_rows_count", "type": "long", "field-id": 513},
+    {"name": "deleted_rows_count", "type": "long", "field-id": 514}
+  ]
+}"#;
+
+/// Spark writes parquet files with the row group size of 128MB, only used by format version 1.
+const BLOCK_SIZE_IN_BYTES: i64 = 128 * 1024 * 1024;
+
+/// A parquet data file written to the table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DataFileInfo {
+    /// path relative to the table root.
+    pub path: String,
+    pub record_count: u64,
+    pub file_size: u64,
+}
+
+/// A manifest added by the snapshot being committed.
+pub struct ManifestFile {
+    pub path: String,
+    pub length: i64,
+    pub snapshot_id: i64,
+    pub sequence_number: i64,
+    pub added_files_count: i32,
+    pub added_rows_count: i64,
+}
+
+impl ManifestFile {
+    fn to_value(&self) -> Value {
+        // Both v1 and v2 names of the counts are given, the record is resolved against
+        // the schema of the manifest list.
+        let added_files = Value::Int(self.added_files_count);
+        let zero_files = Value::Int(0);
+        Value::Record(vec![
+            (
+                "manifest_path".to_string(),
+                Value::String(self.path.clone()),
+            ),
+            ("manifest_length".to_string(), Value::Long(self.length)),
+            ("partition_spec_id".to_string(), Value::Int(0)),
+            ("content".to_string(), Value::Int(0)),
+            (
+                "sequence_number".to_string(),
+                Value::Long(self.sequence_number),
+            ),
+            (
+                "min_sequence_number".to_string(),
+                Value::Long(self.sequence_number),
+            ),
+            (
+                "added_snapshot_id".to_string(),
+                Value::Long(self.snapshot_id),
+            ),
+            ("added_files_count".to_string(), added_files.clone()),
+            ("existing_files_count".to_string(), zero_files.clone()),
+            ("deleted_files_count".to_string(), zero_files.clone()),
+            ("added_data_files_count".to_string(), added_files),
+            ("existing_data_files_count".to_string(), zero_files.clone()),
+            ("deleted_data_files_count".to_string(), zero_files),
+            (
+                "added_rows_count".to_string(),
+                Value::Long(self.added_rows_count),
+            ),
+            ("existing_rows_count".to_string(), Value::Long(0)),
+            ("deleted_rows_count".to_string(), Value::Long(0)),
+        ])
+    }
+}
+
+/// Write the manifest of the added data files, `location` is the table location.
+pub fn write_manifest(
+    format_version: i64,
+    location: &str,
+    schema: &serde_json::Value,
+    snapshot_id: i64,
+    files: &[DataFileInfo],
+) -> Result<Vec<u8>> {
+    let avro_schema = match format_version {
+        1 => MANIFEST_ENTRY_V1,
+        _ => MANIFEST_ENTRY_V2,
+    };
+    let avro_schema = Schema::parse_str(avro_schema).map_err(avro_error)?;
+    let mut writer = Writer::with_codec(&avro_schema, Vec::new(), Codec::Deflate);
+    let metadata = [
+        ("schema", schema.to_string()),
+        ("schema-id", schema["schema-id"].to_string()),
+        ("partition-spec", "[]".to_string()),
+        ("partition-spec-id", "0".to_string()),
+        ("format-version", format_version.to_string()),
+        ("content", "data".to_string()),
+    ];
+    for (key, value) in metadata {
+        writer
+            .add_user_metadata(key.to_string(), value)
+            .map_err(avro_error)?;
+    }
+
+    for file in files {
+        let mut data_file = vec![
+            ("content".to_string(), Value::Int(0)),
+            (
+                "file_path".to_string(),
+                Value::String(format!("{location}/{}", file.path)),
+            ),
+            (
+                "file_format".to_string(),
+                Value::String("PARQUET".to_string()),
+            ),
+            ("partition".to_string(), Value::Record(vec![])),
+            (
+                "record_count".to_string(),
+                Value::Long(file.record_count as i64),
+            ),
+            (
+                "file_size_in_bytes".to_string(),
+                Value::Long(file.file_size as i64),
+            ),
+        ];
+        if format_version == 1 {
+            data_file.push((
+                "block_size_in_bytes".to_string(),
+                Value::Long(BLOCK_SIZE_IN_BYTES),
+            ));
+        }
+        // The sequence numbers of the added files are inherited from the manifest list.
+        let entry = Value::Record(vec![
+            ("status".to_string(), Value::Int(1)),
+            ("snapshot_id".to_string(), Value::Long(snapshot_id)),
+            ("sequence_number".to_string(), Value::Null),
+            ("file_sequence_number".to_string(), Value::Null),
+            ("data_file".to_string(), Value::Record(data_file)),
+        ]);
+        let entry = entry.resolve(&avro_schema).map_err(avro_error)?;
+        writer.append(entry).map_err(avro_error)?;
+    }
+    writer.into_inner().map_err(avro_error)
+}
+
+/// Write the manifest list of a new snapshot.
+///
+/// The manifests of `parent`, the manifest list of the parent snapshot, are kept
+/// in the new list with the schema they were written.
+pub fn write_manifest_list(
+    format_version: i64,
+    snapshot_id: i64,
+    parent_snapshot_id: Option<i64>,
+    parent: Option<&[u8]>,
+    manifest: &ManifestFile,
+) -> Result<Vec<u8>> {
+    let (avro_schema, inherited) = match parent {
+        Some(data) => {
+            let reader = Reader::new(data).map_err(avro_error)?;
+            let schema = reader.writer_schema().clone();
+            let values = reader.collect::<Result<Vec<_>, _>>().map_err(avro_error)?;
+            (schema, values)
+        }
+        None => {
+            let schema = match format_version {
+                1 => MANIFEST_FILE_V1,
+                _ => MANIFEST_FILE_V2,
+            };
+            (Schema::parse_str(schema).map_err(avro_error)?, vec![])
+        }
+    };
+
+    let mut writer = Writer::with_codec(&avro_schema, Vec::new(), Codec::Deflate);
+    let parent_snapshot_id = match parent_snapshot_id {
+        Some(id) => id.to_string(),
+        None => "null".to_string(),
+    };
+    let metadata = [
+        ("snapshot-id", snapshot_id.to_string()),
+        ("parent-snapshot-id", parent_snapshot_id),
+        ("sequence-number", manifest.sequence_number.to_string()),
+        ("format-version", format_version.to_string()),
+    ];
+    for (key, value) in metadata {
+        writer
+            .add_user_metadata(key.to_string(), value)
+            .map_err(avro_error)?;
+    }
+
+    let new_manifest = manifest
+        .to_value()
+        .resolve(&avro_schema)
+        .map_err(avro_error)?;
+    writer.append(new_manifest).map_err(avro_error)?;
+    for value in inherited {
+        writer.append(value).map_err(avro_error)?;
+    }
+    writer.into_inner().map_err(avro_error)
+}
+
+/// Summary of a partition field over all the files of a manifest.
+pub struct FieldSummary {
+    pub contains_null: bool,
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
+}
+
+/// An entry of the manifest list.
+pub struct ManifestListEntry {
+    pub path: String,
+    pub partition_spec_id: i64,
+    /// The manifest tracks delete files instead of data files.
+    pub is_delete: bool,
+    /// Number of the live files, if it is recorded.
+    pub files_count: Option<usize>,
+    /// Summaries of the partition fields, in the order of the partition spec.
+    pub partitions: Vec<FieldSummary>,
+}
+
+/// A live file tracked by a manifest.
+pub struct DataFileEntry {
+    /// `0` for data files, otherwise delete files.
+    pub content: i64,
+    pub file_path: String,
+    pub file_format: String,
+    pub record_count: u64,
+    pub file_size: u64,
+    /// Values of the partition fields, in the order of the partition spec.
+    pub partition: Vec<Value>,
+    /// Keyed by field id, the bounds are in the binary single-value serialization.
+    pub lower_bounds: HashMap<i64, Vec<u8>>,
+    pub upper_bounds: HashMap<i64, Vec<u8>>,
+    pub null_value_counts: HashMap<i64, i64>,
+}
+
+pub fn read_manifest_list(data: &[u8]) -> Result<Vec<ManifestListEntry>> {
+    let reader = Reader::new(data).map_err(read_error)?;
+    let mut entries = vec![];
+    for value in reader {
+        let value = value.map_err(read_error)?;
+        let path = as_string(field(&value, "manifest_path"))
+            .ok_or_else(|| invalid_manifest("manifest_path"))?;
+        let partitions = match field(&value, "partitions") {
+            Some(Value::Array(summaries)) => summaries
+                .iter()
+                .map(|v| FieldSummary {
+                    contains_null: matches!(field(v, "contains_null"), Some(Value::Boolean(true))),
+                    lower_bound: as_bytes(field(v, "lower_bound")),
+                    upper_bound: as_bytes(field(v, "upper_bound")),
+                })
+                .collect(),
+            _ => vec![],
+        };
+        // The counts are named `added_data_files_count` in the manifest lists of v1.
+        let count = |names: [&str; 2]| names.iter().find_map(|n| as_i64(field(&value, n)));
+        let files_count = match (
+            count(["added_files_count", "added_data_files_count"]),
+            count(["existing_files_count", "existing_data_files_count"]),
+        ) {
+            (Some(added), Some(existing)) => Some((added + existing) as usize),
+            _ => None,
+        };
+        entries.push(ManifestListEntry {
+            path,
+            partition_spec_id: as_i64(field(&value, "partition_spec_id")).unwrap_or(0),
+            is_delete: as_i64(field(&value, "content")).is_some_and(|c| c != 0),
+            files_count,
+            partitions,
+        });
+    }
+    Ok(entries)
+}
+
+/// Read the live files of a manifest, the deleted entries are skipped.
+pub fn read_manifest(data: &[u8]) -> Result<Vec<DataFileEntry>> {
+    let reader = Reader::new(data).map_err(read_error)?;
+    let mut entries = vec![];
+    for value in reader {
+        let value = value.map_err(read_error)?;
+        // 0: EXISTING, 1: ADDED, 2: DELETED
+        if as_i64(field(&value, "status")) == Some(2) {
+            continue;
+        }
+        let data_file = field(&value, "data_file").ok_or_else(|| invalid_manifest("data_file"))?;
+        let partition = match field(data_file, "partition") {
+            Some(Value::Record(fields)) => fields
+                .iter()
+                .map(|(_, v)| unwrap_union(v).clone())
+                .collect(),
+            _ => vec![],
+        };
+        entries.push(DataFileEntry {
+            content: as_i64(field(data_file, "content")).unwrap_or(0),
+            file_path: as_string(field(data_file, "file_path"))
+                .ok_or_else(|| invalid_manifest("file_path"))?,
+            file_format: as_string(field(data_file, "file_format")).unwrap_or_default(),
+            record_count: as_i64(field(data_file, "record_count")).unwrap_or(0) as u64,
+            file_size: as_i64(field(data_file, "file_size_in_bytes")).unwrap_or(0) as u64,
+            partition,
+            lower_bounds: int_map(field(data_file, "lower_bounds"), |v| as_bytes(Some(v))),
+            upper_bounds: int_map(field(data_file, "upper_bounds"), |v| as_bytes(Some(v))),
+            null_value_counts: int_map(field(data_file, "null_value_counts"), |v| as_i64(Some(v))),
+        });
+    }
+    Ok(entries)
+}
+
+/// The field `name` of a record, unions are unwrapped.
+fn field<'a>(record: &'a Value, name: &str) -> Option<&'a Value> {
+    match record {
+        Value::Record(fields) => fields
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| unwrap_union(v)),
+        _ => None,
+    }
+}
+
+fn unwrap_union(value: &Value) -> &Value {
+    match value {
+        Value::Union(_, v) => unwrap_union(v),
+        v => v,
+    }
+}
+
+fn as_i64(value: Option<&Value>) -> Option<i64> {
+    match value? {
+        Value::Int(v) => Some(*v as i64),
+        Value::Long(v) => Some(*v),
+        _ => None,
+    }
+}
+
+fn as_string(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(v) => Some(v.clone()),
+        _ => None,
+    }
+}
+
+fn as_bytes(value: Option<&Value>) -> Option<Vec<u8>> {
+    match value? {
+        Value::Bytes(v) | Value::Fixed(_, v) => Some(v.clone()),
+        _ => None,
+    }
+}
+
+/// Maps with int keys are written as arrays of key-value records.
+fn int_map<T>(value: Option<&Value>, f: impl Fn(&Value) -> Option<T>) -> HashMap<i64, T> {
+    match value {
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(|item| {
+                let key = as_i64(field(item, "key"))?;
+                let value = f(field(item, "value")?)?;
+                Some((key, value))
+            })
+            .collect(),
+        _ => HashMap::new(),
+    }
+}
+
+fn invalid_manifest(field: &str) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("invalid iceberg manifest: {field} is missing"))
+}
+
+fn read_error(e: apache_avro::Error) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("fail to read iceberg manifest: {e}"))
+}
+
+fn avro_error(e: apache_avro::Error) -> ErrorCode {
+    ErrorCode::StorageOther(format!("fail to write iceberg manifest: {e}"))
+}
@@ -0,0 +1,470 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metadata of iceberg tables in the layout of the filesystem (hadoop) catalog.
+//!
+//! Every commit writes a new `metadata/v{N}.metadata.json`, and `metadata/version-hint.text`
+//! points to the latest version. A commit fails if the next version already exists, which
+//! gives the optimistic concurrency control of the hadoop catalog.
+
+use arrow_schema::DataType as ArrowDataType;
+use arrow_schema::Field as ArrowField;
+use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::TimeUnit;
+use chrono::Utc;
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_meta_app::storage::StorageParams;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_users::UserApiProvider;
+use log::warn;
+use opendal::Operator;
+use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+use serde_json::json;
+use serde_json::Value;
+
+pub const VERSION_HINT: &str = "metadata/version-hint.text";
+
+/// Prefix of the keys in the meta service claiming the metadata versions being committed.
+const COMMIT_KEY_PREFIX: &str = "__fd_iceberg_commits";
+/// The claims only need to outlive the concurrent commits, since a version whose
+/// metadata file exists is never committed again.
+const COMMIT_KEY_TTL_SECS: u64 = 24 * 60 * 60;
+
+pub fn metadata_path(version: u64) -> String {
+    format!("metadata/v{version}.metadata.json")
+}
+
+/// The location recorded in the metadata of a table created by databend.
+pub fn table_location(params: &StorageParams) -> Result<String> {
+    let location = match params {
+        StorageParams::Fs(v) => format!("file://{}", v.root),
+        StorageParams::S3(v) => format!("s3://{}{}", v.bucket, v.root),
+        StorageParams::Gcs(v) => format!("gs://{}{}", v.bucket, v.root),
+        StorageParams::Oss(v) => format!("oss://{}{}", v.bucket, v.root),
+        StorageParams::Hdfs(v) => format!("{}{}", v.name_node, v.root),
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "creating iceberg tables on storage {params} is not supported yet"
+            )));
+        }
+    };
+    Ok(location.trim_end_matches('/').to_string())
+}
+
+/// A field of a partition spec.
+pub struct PartitionField {
+    /// Field id of the source column in the table schema.
+    pub source_id: i64,
+    pub transform: String,
+}
+
+pub struct IcebergMetadata {
+    /// version of the metadata file, `0` if the table is not committed yet.
+    pub version: u64,
+    pub value: Value,
+}
+
+impl IcebergMetadata {
+    /// Read the latest metadata of the table rooted at `op`.
+    #[async_backtrace::framed]
+    pub async fn read(op: &Operator) -> Result<Self> {
+        let hint = op.read(VERSION_HINT).await?;
+        let version = String::from_utf8_lossy(&hint)
+            .trim()
+            .parse::<u64>()
+            .map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("invalid iceberg version hint: {e}"))
+            })?;
+        let data = op.read(&metadata_path(version)).await?;
+        let value = serde_json::from_slice(&data).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("invalid iceberg table metadata: {e}"))
+        })?;
+        Ok(Self { version, value })
+    }
+
+    /// Metadata of a new unpartitioned table in format version 2.
+    pub fn create(location: &str, schema: &TableSchema) -> Result<Self> {
+        let fields = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                let (ty, required) = match f.data_type() {
+                    TableDataType::Nullable(ty) => (ty.as_ref(), false),
+                    ty => (ty, true),
+                };
+                Ok(json!({
+                    "id": i + 1,
+                    "name": f.name(),
+                    "required": required,
+                    "type": iceberg_type(ty)?,
+                }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let now = Utc::now().timestamp_millis();
+        let value = json!({
+            "format-version": 2,
+            "table-uuid": uuid::Uuid::new_v4().to_string(),
+            "location": location,
+            "last-sequence-number": 0,
+            "last-updated-ms": now,
+            "last-column-id": fields.len(),
+            "current-schema-id": 0,
+            "schemas": [{
+                "type": "struct",
+                "schema-id": 0,
+                "fields": fields,
+            }],
+            "default-spec-id": 0,
+            "partition-specs": [{ "spec-id": 0, "fields": [] }],
+            "last-partition-id": 999,
+            "default-sort-order-id": 0,
+            "sort-orders": [{ "order-id": 0, "fields": [] }],
+            "properties": {},
+            "snapshots": [],
+            "snapshot-log": [],
+            "metadata-log": [],
+        });
+        Ok(Self { version: 0, value })
+    }
+
+    pub fn format_version(&self) -> i64 {
+        self.value["format-version"].as_i64().unwrap_or(1)
+    }
+
+    pub fn location(&self) -> &str {
+        self.value["location"]
+            .as_str()
+            .unwrap_or_default()
+            .trim_end_matches('/')
+    }
+
+    /// Path of a file relative to the table root.
+    pub fn rel_path<'a>(&self, path: &'a str) -> &'a str {
+        path.strip_prefix(self.location())
+            .unwrap_or(path)
+            .trim_start_matches('/')
+    }
+
+    pub fn current_schema(&self) -> Result<&Value> {
+        let schema = match self.value["current-schema-id"].as_i64() {
+            Some(id) => self.value["schemas"]
+                .as_array()
+                .and_then(|schemas| schemas.iter().find(|s| s["schema-id"] == id)),
+            // Format version 1 may only have the `schema` field.
+            None => self.value.get("schema"),
+        };
+        schema.ok_or_else(|| {
+            ErrorCode::ReadTableDataError("iceberg table has no current schema".to_string())
+        })
+    }
+
+    pub fn current_schema_id(&self) -> i64 {
+        self.value["current-schema-id"].as_i64().unwrap_or(0)
+    }
+
+    pub fn is_partitioned(&self) -> bool {
+        let spec_id = self.value["default-spec-id"].as_i64();
+        let fields = match spec_id {
+            Some(id) => self.value["partition-specs"]
+                .as_array()
+                .and_then(|specs| specs.iter().find(|s| s["spec-id"] == id))
+                .map(|s| &s["fields"]),
+            None => self.value.get("partition-spec"),
+        };
+        fields
+            .and_then(|f| f.as_array())
+            .is_some_and(|f| !f.is_empty())
+    }
+
+    /// Fields of the partition spec `spec_id`.
+    pub fn partition_spec(&self, spec_id: i64) -> Vec<PartitionField> {
+        let fields = match self.value["partition-specs"].as_array() {
+            Some(specs) => specs
+                .iter()
+                .find(|s| s["spec-id"] == spec_id)
+                .map(|s| &s["fields"]),
+            // Format version 1 may only have the `partition-spec` field.
+            None => self.value.get("partition-spec"),
+        };
+        fields
+            .and_then(|f| f.as_array())
+            .map(|fields| {
+                fields
+                    .iter()
+                    .map(|f| PartitionField {
+                        source_id: f["source-id"].as_i64().unwrap_or(-1),
+                        transform: f["transform"].as_str().unwrap_or_default().to_string(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn current_snapshot(&self) -> Option<&Value> {
+        let id = self.value["current-snapshot-id"].as_i64()?;
+        self.value["snapshots"]
+            .as_array()?
+            .iter()
+            .find(|s| s["snapshot-id"] == id)
+    }
+
+    pub fn last_sequence_number(&self) -> i64 {
+        self.value["last-sequence-number"].as_i64().unwrap_or(0)
+    }
+
+    /// Add a snapshot and make it the current one.
+    pub fn add_snapshot(&mut self, snapshot: Value) {
+        let snapshot_id = snapshot["snapshot-id"].clone();
+        let timestamp = snapshot["timestamp-ms"].clone();
+        if let Some(sequence_number) = snapshot.get("sequence-number") {
+            self.value["last-sequence-number"] = sequence_number.clone();
+        }
+        if self.version > 0 {
+            let file = format!("{}/{}", self.location(), metadata_path(self.version));
+            let entry = json!({
+                "timestamp-ms": self.value["last-updated-ms"],
+                "metadata-file": file,
+            });
+            push(&mut self.value, "metadata-log", entry);
+        }
+        let log = json!({ "timestamp-ms": timestamp, "snapshot-id": snapshot_id });
+        push(&mut self.value, "snapshot-log", log);
+        push(&mut self.value, "snapshots", snapshot);
+        self.value["current-snapshot-id"] = snapshot_id.clone();
+        self.value["refs"]["main"] = json!({ "snapshot-id": snapshot_id, "type": "branch" });
+        self.value["last-updated-ms"] = timestamp;
+    }
+
+    /// Write the metadata as the next version, fails with `TableVersionMismatched`
+    /// if it has been committed by others.
+    ///
+    /// Object storages can't create a file only if it is absent, so the version is claimed
+    /// in the meta service first, and only the committer holding the claim writes the file.
+    #[async_backtrace::framed]
+    pub async fn commit(&self, op: &Operator) -> Result<()> {
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        self.commit_with(meta_api.as_ref(), op).await
+    }
+
+    #[async_backtrace::framed]
+    async fn commit_with(
+        &self,
+        meta_api: &impl KVApi<Error = MetaError>,
+        op: &Operator,
+    ) -> Result<()> {
+        let version = self.version + 1;
+        let key = format!(
+            "{COMMIT_KEY_PREFIX}/{}/{version}",
+            escape_for_key(self.location())?
+        );
+        let expire_at = Utc::now().timestamp() as u64 + COMMIT_KEY_TTL_SECS;
+        let claim_seq = meta_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(0),
+                Operation::Update(vec![]),
+                Some(KVMeta::new_expire(expire_at)),
+            ))
+            .await?
+            .added_seq_or_else(|_| committed_by_others(version))?;
+
+        let res = self.write_version(op, version).await;
+        if res.is_err() {
+            // Release the claim, otherwise the version can't be committed until it expires.
+            let release = meta_api
+                .upsert_kv(UpsertKVReq::new(
+                    &key,
+                    MatchSeq::Exact(claim_seq),
+                    Operation::Delete,
+                    None,
+                ))
+                .await;
+            if let Err(e) = release {
+                warn!("failed to release the claim of iceberg metadata {key}: {e}");
+            }
+        }
+        res
+    }
+
+    async fn write_version(&self, op: &Operator, version: u64) -> Result<()> {
+        let path = metadata_path(version);
+        // The claim may have expired, or the version is committed by other engines.
+        if op.is_exist(&path).await? {
+            return Err(committed_by_others(version));
+        }
+        let data = serde_json::to_vec_pretty(&self.value)?;
+        op.write(&path, data).await?;
+        if let Err(e) = op.write(VERSION_HINT, version.to_string()).await {
+            // Without the hint, the version would be seen as committed by the next commit.
+            let _ = op.delete(&path).await;
+            return Err(e.into());
+        }
+        Ok(())
+    }
+}
+
+fn committed_by_others(version: u64) -> ErrorCode {
+    ErrorCode::TableVersionMismatched(format!(
+        "iceberg table metadata version {version} has been committed by others"
+    ))
+}
+
+fn push(value: &mut Value, key: &str, item: Value) {
+    match value[key].as_array_mut() {
+        Some(array) => array.push(item),
+        None => value[key] = Value::Array(vec![item]),
+    }
+}
+
+fn iceberg_type(ty: &TableDataType) -> Result<String> {
+    let ty = match ty {
+        TableDataType::Boolean => "boolean".to_string(),
+        TableDataType::Number(ty) => match ty {
+            NumberDataType::Int8
+            | NumberDataType::Int16
+            | NumberDataType::Int32
+            | NumberDataType::UInt8
+            | NumberDataType::UInt16 => "int".to_string(),
+            NumberDataType::Int64 | NumberDataType::UInt32 | NumberDataType::UInt64 => {
+                "long".to_string()
+            }
+            NumberDataType::Float32 => "float".to_string(),
+            NumberDataType::Float64 => "double".to_string(),
+        },
+        TableDataType::Decimal(DecimalDataType::Decimal128(size)) => {
+            format!("decimal({}, {})", size.precision, size.scale)
+        }
+        TableDataType::String => "string".to_string(),
+        TableDataType::Date => "date".to_string(),
+        TableDataType::Timestamp => "timestamp".to_string(),
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "data type {ty} is not supported by iceberg tables yet"
+            )));
+        }
+    };
+    Ok(ty)
+}
+
+/// The arrow schema of the data files, fields carry the iceberg field ids.
+pub fn arrow_schema(iceberg_schema: &Value) -> Result<ArrowSchema> {
+    let fields = iceberg_schema["fields"]
+        .as_array()
+        .ok_or_else(|| ErrorCode::ReadTableDataError("iceberg schema has no fields".to_string()))?;
+    let fields = fields
+        .iter()
+        .map(|f| {
+            let name = f["name"].as_str().unwrap_or_default();
+            let data_type = match f["type"].as_str() {
+                Some(ty) => arrow_type(ty),
+                None => None,
+            }
+            .ok_or_else(|| {
+                ErrorCode::Unimplemented(format!(
+                    "writing column {name} of type {} to iceberg tables is not supported yet",
+                    f["type"]
+                ))
+            })?;
+            let nullable = !f["required"].as_bool().unwrap_or(false);
+            let metadata = [(PARQUET_FIELD_ID_META_KEY.to_string(), f["id"].to_string())];
+            Ok(ArrowField::new(name, data_type, nullable).with_metadata(metadata.into()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(ArrowSchema::new(fields))
+}
+
+fn arrow_type(ty: &str) -> Option<ArrowDataType> {
+    let ty = match ty {
+        "boolean" => ArrowDataType::Boolean,
+        "int" => ArrowDataType::Int32,
+        "long" => ArrowDataType::Int64,
+        "float" => ArrowDataType::Float32,
+        "double" => ArrowDataType::Float64,
+        "date" => ArrowDataType::Date32,
+        "timestamp" => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
+        "timestamptz" => ArrowDataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into())),
+        "string" => ArrowDataType::LargeUtf8,
+        "binary" => ArrowDataType::LargeBinary,
+        _ => {
+            let size = ty.strip_prefix("decimal(")?.strip_suffix(')')?;
+            let (precision, scale) = size.split_once(',')?;
+            let precision = precision.trim().parse().ok()?;
+            let scale = scale.trim().parse().ok()?;
+            ArrowDataType::Decimal128(precision, scale)
+        }
+    };
+    Some(ty)
+}
+
+#[cfg(test)]
+mod tests {
+    use common_exception::ErrorCode;
+    use common_exception::Result;
+    use common_expression::TableDataType;
+    use common_expression::TableField;
+    use common_expression::TableSchema;
+    use common_meta_embedded::MetaEmbedded;
+    use opendal::services::Fs;
+    use opendal::Operator;
+
+    use super::metadata_path;
+    use super::IcebergMetadata;
+    use super::VERSION_HINT;
+
+    #[tokio::test]
+    async fn test_commit_releases_claim_on_failure() -> Result<()> {
+        let meta_api = MetaEmbedded::new_temp().await?;
+        let dir = tempfile::tempdir()?;
+        let mut builder = Fs::default();
+        builder.root(dir.path().to_str().unwrap());
+        let op = Operator::new(builder)?.finish();
+
+        let schema = TableSchema::new(vec![TableField::new("a", TableDataType::String)]);
+        let location = format!("file://{}", dir.path().display());
+        let metadata = IcebergMetadata::create(&location, &schema)?;
+
+        // The metadata file can't be written under a file.
+        std::fs::write(dir.path().join("metadata"), b"")?;
+        assert!(metadata.commit_with(&meta_api, &op).await.is_err());
+        std::fs::remove_file(dir.path().join("metadata"))?;
+
+        // The version hint can't be written over a directory.
+        std::fs::create_dir_all(dir.path().join(VERSION_HINT))?;
+        assert!(metadata.commit_with(&meta_api, &op).await.is_err());
+        assert!(!op.is_exist(&metadata_path(1)).await?);
+        std::fs::remove_dir(dir.path().join(VERSION_HINT))?;
+
+        // The claims of the failed commits are released.
+        metadata.commit_with(&meta_api, &op).await?;
+        let committed = IcebergMetadata::read(&op).await?;
+        assert_eq!(committed.version, 1);
+
+        // The committed version can't be claimed again.
+        let err = metadata.commit_with(&meta_api, &op).await.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::TABLE_VERSION_MISMATCHED);
+        Ok(())
+    }
+}
@@ -0,0 +1,328 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_cast::cast_with_options;
+use arrow_cast::CastOptions;
+use arrow_schema::Schema as ArrowSchema;
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockMetaInfo;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use opendal::Operator;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::basic::ZstdLevel;
+use parquet::file::properties::WriterProperties;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::manifest::write_manifest;
+use crate::manifest::write_manifest_list;
+use crate::manifest::DataFileInfo;
+use crate::manifest::ManifestFile;
+use crate::metadata::arrow_schema;
+use crate::metadata::IcebergMetadata;
+
+/// In memory size of the blocks written into one data file.
+const MAX_FILE_SIZE: usize = 128 * 1024 * 1024;
+const MAX_COMMIT_RETRIES: usize = 10;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct IcebergDataFiles {
+    pub files: Vec<DataFileInfo>,
+}
+
+#[typetag::serde(name = "iceberg_data_files")]
+impl BlockMetaInfo for IcebergDataFiles {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        Self::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// Write the blocks into parquet data files under `data/`, outputs the written files.
+pub struct IcebergDataFileWriter {
+    op: Operator,
+    data_schema: DataSchema,
+    // Loaded from the table metadata before the first file is written.
+    arrow_schema: Option<Arc<ArrowSchema>>,
+    blocks: Vec<DataBlock>,
+    size: usize,
+}
+
+impl IcebergDataFileWriter {
+    pub fn create(op: Operator, data_schema: DataSchema) -> Self {
+        Self {
+            op,
+            data_schema,
+            arrow_schema: None,
+            blocks: vec![],
+            size: 0,
+        }
+    }
+
+    fn to_record_batch(&self, block: DataBlock, schema: &Arc<ArrowSchema>) -> Result<RecordBatch> {
+        let batch = block.to_record_batch(&self.data_schema)?;
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(schema.fields())
+            .map(|(column, field)| cast_with_options(column, field.data_type(), &options))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(RecordBatch::try_new(schema.clone(), columns)?)
+    }
+
+    #[async_backtrace::framed]
+    async fn write_file(&mut self) -> Result<Option<DataBlock>> {
+        if self.blocks.is_empty() {
+            return Ok(None);
+        }
+        let schema = match &self.arrow_schema {
+            Some(schema) => schema.clone(),
+            None => {
+                let metadata = IcebergMetadata::read(&self.op).await?;
+                let schema = Arc::new(arrow_schema(metadata.current_schema()?)?);
+                self.arrow_schema = Some(schema.clone());
+                schema
+            }
+        };
+
+        let props = WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::default()))
+            .build();
+        let mut data = Vec::with_capacity(self.size);
+        let mut writer = ArrowWriter::try_new(&mut data, schema.clone(), Some(props))?;
+        for block in std::mem::take(&mut self.blocks) {
+            writer.write(&self.to_record_batch(block, &schema)?)?;
+        }
+        let file_meta = writer.close()?;
+        self.size = 0;
+
+        let file = DataFileInfo {
+            path: format!("data/{}.parquet", uuid::Uuid::new_v4().simple()),
+            record_count: file_meta.num_rows as u64,
+            file_size: data.len() as u64,
+        };
+        self.op.write(&file.path, data).await?;
+        Ok(Some(DataBlock::empty_with_meta(Box::new(
+            IcebergDataFiles { files: vec![file] },
+        ))))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncAccumulatingTransform for IcebergDataFileWriter {
+    const NAME: &'static str = "IcebergDataFileWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+        self.size += data.memory_size();
+        self.blocks.push(data);
+        if self.size < MAX_FILE_SIZE {
+            return Ok(None);
+        }
+        self.write_file().await
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        self.write_file().await
+    }
+}
+
+/// Collect the written data files and commit them as a new snapshot of the table.
+pub struct IcebergCommitSink {
+    op: Operator,
+    overwrite: bool,
+    files: Vec<DataFileInfo>,
+}
+
+impl IcebergCommitSink {
+    pub fn create(op: Operator, overwrite: bool) -> Self {
+        Self {
+            op,
+            overwrite,
+            files: vec![],
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn try_commit(
+        &self,
+        snapshot_id: i64,
+        manifest: &mut Option<(String, i64)>,
+    ) -> Result<()> {
+        let mut metadata = IcebergMetadata::read(&self.op).await?;
+        if metadata.is_partitioned() {
+            return Err(ErrorCode::Unimplemented(
+                "writing to partitioned iceberg tables is not supported yet",
+            ));
+        }
+        let format_version = metadata.format_version();
+        let location = metadata.location().to_string();
+        let uuid = uuid::Uuid::new_v4().simple().to_string();
+
+        // The manifest does not depend on the parent snapshot, it is kept across the retries.
+        let (manifest_path, manifest_length) = match manifest {
+            Some(manifest) => manifest.clone(),
+            None => {
+                let data = write_manifest(
+                    format_version,
+                    &location,
+                    metadata.current_schema()?,
+                    snapshot_id,
+                    &self.files,
+                )?;
+                let path = format!("metadata/{uuid}-m0.avro");
+                let length = data.len() as i64;
+                self.op.write(&path, data).await?;
+                manifest.insert((path, length)).clone()
+            }
+        };
+
+        let parent = metadata.current_snapshot().cloned();
+        let parent_snapshot_id = parent.as_ref().and_then(|s| s["snapshot-id"].as_i64());
+        let parent_list = match &parent {
+            Some(parent) if !self.overwrite => match parent["manifest-list"].as_str() {
+                Some(path) => Some(self.op.read(metadata.rel_path(path)).await?),
+                None => {
+                    return Err(ErrorCode::Unimplemented(
+                        "iceberg snapshots without manifest list are not supported",
+                    ));
+                }
+            },
+            _ => None,
+        };
+
+        let sequence_number = metadata.last_sequence_number() + 1;
+        let added_rows = self.files.iter().map(|f| f.record_count as i64).sum();
+        let added_size: i64 = self.files.iter().map(|f| f.file_size as i64).sum();
+        let manifest_file = ManifestFile {
+            path: format!("{location}/{manifest_path}"),
+            length: manifest_length,
+            snapshot_id,
+            sequence_number,
+            added_files_count: self.files.len() as i32,
+            added_rows_count: added_rows,
+        };
+        let manifest_list = write_manifest_list(
+            format_version,
+            snapshot_id,
+            parent_snapshot_id,
+            parent_list.as_deref(),
+            &manifest_file,
+        )?;
+        let manifest_list_path = format!("metadata/snap-{snapshot_id}-1-{uuid}.avro");
+        self.op.write(&manifest_list_path, manifest_list).await?;
+
+        // Totals of an append are accumulated on the summary of the parent.
+        let total = |key: &str, added: i64| -> String {
+            let prev = match &parent {
+                Some(parent) if !self.overwrite => parent["summary"][key]
+                    .as_str()
+                    .and_then(|v| v.parse::<i64>().ok())
+                    .unwrap_or(0),
+                _ => 0,
+            };
+            (prev + added).to_string()
+        };
+        let operation = if self.overwrite {
+            "overwrite"
+        } else {
+            "append"
+        };
+        let mut snapshot = json!({
+            "snapshot-id": snapshot_id,
+            "timestamp-ms": Utc::now().timestamp_millis(),
+            "summary": {
+                "operation": operation,
+                "added-data-files": self.files.len().to_string(),
+                "added-records": added_rows.to_string(),
+                "added-files-size": added_size.to_string(),
+                "total-data-files": total("total-data-files", self.files.len() as i64),
+                "total-records": total("total-records", added_rows),
+                "total-files-size": total("total-files-size", added_size),
+            },
+            "manifest-list": format!("{location}/{manifest_list_path}"),
+            "schema-id": metadata.current_schema_id(),
+        });
+        if let Some(parent_snapshot_id) = parent_snapshot_id {
+            snapshot["parent-snapshot-id"] = Value::from(parent_snapshot_id);
+        }
+        if format_version > 1 {
+            snapshot["sequence-number"] = Value::from(sequence_number);
+        }
+        metadata.add_snapshot(snapshot);
+        metadata.commit(&self.op).await
+    }
+}
+
+#[async_trait]
+impl AsyncSink for IcebergCommitSink {
+    const NAME: &'static str = "IcebergCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        if self.files.is_empty() && !self.overwrite {
+            return Ok(());
+        }
+
+        let snapshot_id = (uuid::Uuid::new_v4().as_u64_pair().0 & i64::MAX as u64) as i64;
+        let mut manifest = None;
+        let mut retries = 0;
+        loop {
+            match self.try_commit(snapshot_id, &mut manifest).await {
+                Err(e)
+                    if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+                        && retries < MAX_COMMIT_RETRIES =>
+                {
+                    retries += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block
+            .get_owned_meta()
+            .and_then(IcebergDataFiles::downcast_from)
+        {
+            self.files.extend(meta.files);
+        }
+        Ok(false)
+    }
+}
@@ -14,6 +14,9 @@
 
 use std::collections::HashMap;
 
+use apache_avro::types::Value as AvroValue;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::DecimalDataType;
 use common_expression::types::Number;
 use common_expression::types::NumberDataType;
 use common_expression::types::F32;
@@ -23,57 +26,126 @@ use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
-use icelake::types::DataFile;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
 
-/// Try to convert statistics in [`DataFile`] to [`StatisticsOfColumns`].
-pub fn get_stats_of_data_file(schema: &TableSchema, df: &DataFile) -> Option<StatisticsOfColumns> {
-    match (&df.lower_bounds, &df.upper_bounds, &df.null_value_counts) {
-        (Some(lower), Some(upper), Some(null_counts)) => {
-            let mut stats: HashMap<u32, ColumnStatistics> =
-                HashMap::with_capacity(schema.num_fields());
-            for field in schema.fields.iter() {
-                if let Some(stat) =
-                    get_column_stats(field, lower, upper, null_counts, &df.distinct_counts)
-                {
-                    stats.insert(field.column_id, stat);
-                }
-            }
-            Some(stats)
+use crate::manifest::DataFileEntry;
+use crate::manifest::FieldSummary;
+use crate::metadata::PartitionField;
+
+/// Field ids of the top level columns in the iceberg schema, by name.
+pub fn field_ids(iceberg_schema: &serde_json::Value) -> HashMap<String, i64> {
+    iceberg_schema["fields"]
+        .as_array()
+        .map(|fields| {
+            fields
+                .iter()
+                .filter_map(|f| Some((f["name"].as_str()?.to_string(), f["id"].as_i64()?)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Try to convert the partition summaries of a manifest to [`StatisticsOfColumns`]
+/// of the source columns. Only the identity partitions are used.
+pub fn get_stats_of_manifest(
+    schema: &TableSchema,
+    field_ids: &HashMap<String, i64>,
+    spec: &[PartitionField],
+    summaries: &[FieldSummary],
+) -> StatisticsOfColumns {
+    let mut stats = StatisticsOfColumns::new();
+    for (partition_field, summary) in spec.iter().zip(summaries.iter()) {
+        if partition_field.transform != "identity" {
+            continue;
+        }
+        let Some(field) = source_field(schema, field_ids, partition_field.source_id) else {
+            continue;
+        };
+        let (Some(lo), Some(up)) = (&summary.lower_bound, &summary.upper_bound) else {
+            continue;
+        };
+        if let (Some(min), Some(max)) = (
+            parse_binary_value(&field.data_type, lo),
+            parse_binary_value(&field.data_type, up),
+        ) {
+            let null_count = summary.contains_null as u64;
+            stats.insert(
+                field.column_id,
+                ColumnStatistics::new(min, max, null_count, 0, None),
+            );
+        }
+    }
+    stats
+}
+
+/// Try to convert the column bounds and the identity partition values in
+/// [`DataFileEntry`] to [`StatisticsOfColumns`].
+pub fn get_stats_of_data_file(
+    schema: &TableSchema,
+    field_ids: &HashMap<String, i64>,
+    spec: &[PartitionField],
+    df: &DataFileEntry,
+) -> StatisticsOfColumns {
+    let mut stats: StatisticsOfColumns = HashMap::with_capacity(schema.num_fields());
+    for field in schema.fields.iter() {
+        let Some(field_id) = field_ids.get(field.name()) else {
+            continue;
+        };
+        if let Some(stat) = get_column_stats(field, *field_id, df) {
+            stats.insert(field.column_id, stat);
         }
-        (_, _, _) => None,
     }
+
+    for (partition_field, value) in spec.iter().zip(df.partition.iter()) {
+        if partition_field.transform != "identity" {
+            continue;
+        }
+        let Some(field) = source_field(schema, field_ids, partition_field.source_id) else {
+            continue;
+        };
+        if stats.contains_key(&field.column_id) {
+            continue;
+        }
+        if let Some(value) = parse_avro_value(&field.data_type, value) {
+            let null_count = if value.is_null() { df.record_count } else { 0 };
+            stats.insert(
+                field.column_id,
+                ColumnStatistics::new(value.clone(), value, null_count, 0, None),
+            );
+        }
+    }
+    stats
+}
+
+fn source_field<'a>(
+    schema: &'a TableSchema,
+    field_ids: &HashMap<String, i64>,
+    source_id: i64,
+) -> Option<&'a TableField> {
+    schema
+        .fields
+        .iter()
+        .find(|f| field_ids.get(f.name()) == Some(&source_id))
 }
 
 /// Try get [`ColumnStatistics`] for one column.
 fn get_column_stats(
     field: &TableField,
-    lower: &HashMap<i32, Vec<u8>>,
-    upper: &HashMap<i32, Vec<u8>>,
-    null_counts: &HashMap<i32, i64>,
-    distinct_counts: &Option<HashMap<i32, i64>>,
+    field_id: i64,
+    df: &DataFileEntry,
 ) -> Option<ColumnStatistics> {
-    // The column id in iceberg is 1-based while the column id in Databend is 0-based.
-    let iceberg_col_id = field.column_id as i32 + 1;
     match (
-        lower.get(&iceberg_col_id),
-        upper.get(&iceberg_col_id),
-        null_counts.get(&iceberg_col_id),
+        df.lower_bounds.get(&field_id),
+        df.upper_bounds.get(&field_id),
+        df.null_value_counts.get(&field_id),
     ) {
         (Some(lo), Some(up), Some(nc)) => {
             let min = parse_binary_value(&field.data_type, lo)?;
             let max = parse_binary_value(&field.data_type, up)?;
-            let distinct_of_values = distinct_counts
-                .as_ref()
-                .and_then(|dc| dc.get(&iceberg_col_id))
-                .map(|dc| *dc as u64);
             Some(ColumnStatistics::new(
-                min,
-                max,
-                *nc as u64,
-                0, // this field is not used.
-                distinct_of_values,
+                min, max, *nc as u64, 0, // this field is not used.
+                None,
             ))
         }
         (_, _, _) => None,
@@ -84,19 +156,20 @@ fn get_column_stats(
 fn parse_binary_value(ty: &TableDataType, data: &[u8]) -> Option<Scalar> {
     let ty = ty.remove_nullable();
     match ty {
-        TableDataType::Boolean => Some(Scalar::Boolean(data[0] != 0)),
+        TableDataType::Boolean => Some(Scalar::Boolean(*data.first()? != 0)),
         TableDataType::Number(ty) => with_integer_mapped_type!(|NUM_TYPE| match ty {
             NumberDataType::NUM_TYPE => {
                 let v = NUM_TYPE::from_le_bytes(data.try_into().ok()?);
                 Some(Scalar::Number(NUM_TYPE::upcast_scalar(v)))
             }
+            // NaN bounds can't be compared with, the column is uncertain.
             NumberDataType::Float32 => {
                 let v = f32::from_le_bytes(data.try_into().ok()?);
-                Some(Scalar::Number(F32::upcast_scalar(F32::from(v))))
+                (!v.is_nan()).then(|| Scalar::Number(F32::upcast_scalar(F32::from(v))))
             }
             NumberDataType::Float64 => {
                 let v = f64::from_le_bytes(data.try_into().ok()?);
-                Some(Scalar::Number(F64::upcast_scalar(F64::from(v))))
+                (!v.is_nan()).then(|| Scalar::Number(F64::upcast_scalar(F64::from(v))))
             }
         }),
         TableDataType::Date => {
@@ -108,7 +181,239 @@ fn parse_binary_value(ty: &TableDataType, data: &[u8]) -> Option<Scalar> {
             Some(Scalar::Timestamp(v))
         }
         TableDataType::String => Some(Scalar::String(data.to_vec())),
-        // TODO: support Decimal.
+        TableDataType::Decimal(DecimalDataType::Decimal128(size)) => {
+            // Big-endian two's-complement of the unscaled value.
+            if data.is_empty() || data.len() > 16 {
+                return None;
+            }
+            let mut buf = if data[0] & 0x80 != 0 {
+                [0xff; 16]
+            } else {
+                [0; 16]
+            };
+            buf[16 - data.len()..].copy_from_slice(data);
+            let v = i128::from_be_bytes(buf);
+            Some(Scalar::Decimal(DecimalScalar::Decimal128(v, size)))
+        }
         _ => None, // Not supported.
     }
 }
+
+/// Convert the value of an identity partition field to [`Scalar`].
+fn parse_avro_value(ty: &TableDataType, value: &AvroValue) -> Option<Scalar> {
+    let scalar = match (ty.remove_nullable(), value) {
+        (_, AvroValue::Null) => Scalar::Null,
+        (TableDataType::Boolean, AvroValue::Boolean(v)) => Scalar::Boolean(*v),
+        (TableDataType::Number(ty), AvroValue::Int(_) | AvroValue::Long(_)) => {
+            let v = match value {
+                AvroValue::Int(v) => *v as i64,
+                AvroValue::Long(v) => *v,
+                _ => unreachable!(),
+            };
+            with_integer_mapped_type!(|NUM_TYPE| match ty {
+                NumberDataType::NUM_TYPE => {
+                    Scalar::Number(NUM_TYPE::upcast_scalar(NUM_TYPE::try_from(v).ok()?))
+                }
+                _ => return None,
+            })
+        }
+        (TableDataType::Number(NumberDataType::Float32), AvroValue::Float(v)) if !v.is_nan() => {
+            Scalar::Number(F32::upcast_scalar(F32::from(*v)))
+        }
+        (TableDataType::Number(NumberDataType::Float64), AvroValue::Double(v)) if !v.is_nan() => {
+            Scalar::Number(F64::upcast_scalar(F64::from(*v)))
+        }
+        (TableDataType::String, AvroValue::String(v)) => Scalar::String(v.as_bytes().to_vec()),
+        (TableDataType::String, AvroValue::Bytes(v)) => Scalar::String(v.clone()),
+        (TableDataType::Date, AvroValue::Date(v) | AvroValue::Int(v)) => Scalar::Date(*v),
+        (TableDataType::Timestamp, AvroValue::TimestampMicros(v) | AvroValue::Long(v)) => {
+            Scalar::Timestamp(*v)
+        }
+        _ => return None,
+    };
+    Some(scalar)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use common_exception::Result;
+    use common_expression::type_check::check_function;
+    use common_expression::types::decimal::DecimalScalar;
+    use common_expression::types::DataType;
+    use common_expression::types::DecimalDataType;
+    use common_expression::types::DecimalSize;
+    use common_expression::types::NumberDataType;
+    use common_expression::types::NumberScalar;
+    use common_expression::types::F64;
+    use common_expression::Expr;
+    use common_expression::FunctionContext;
+    use common_expression::Scalar;
+    use common_expression::TableDataType;
+    use common_expression::TableField;
+    use common_expression::TableSchema;
+    use common_functions::BUILTIN_FUNCTIONS;
+    use storages_common_pruner::RangePrunerCreator;
+    use storages_common_table_meta::meta::StatisticsOfColumns;
+
+    use super::get_stats_of_manifest;
+    use super::parse_binary_value;
+    use crate::manifest::FieldSummary;
+    use crate::metadata::PartitionField;
+
+    #[test]
+    fn test_parse_binary_value() {
+        let int = TableDataType::Number(NumberDataType::Int32);
+        assert_eq!(
+            parse_binary_value(&int, &5i32.to_le_bytes()),
+            Some(Scalar::Number(NumberScalar::Int32(5)))
+        );
+        // The bytes of a long can't be read as an int.
+        assert_eq!(parse_binary_value(&int, &5i64.to_le_bytes()), None);
+
+        let long = TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int64)));
+        assert_eq!(
+            parse_binary_value(&long, &(-7i64).to_le_bytes()),
+            Some(Scalar::Number(NumberScalar::Int64(-7)))
+        );
+
+        let double = TableDataType::Number(NumberDataType::Float64);
+        assert_eq!(
+            parse_binary_value(&double, &1.5f64.to_le_bytes()),
+            Some(Scalar::Number(NumberScalar::Float64(F64::from(1.5))))
+        );
+        assert_eq!(parse_binary_value(&double, &f64::NAN.to_le_bytes()), None);
+        let float = TableDataType::Number(NumberDataType::Float32);
+        assert_eq!(parse_binary_value(&float, &f32::NAN.to_le_bytes()), None);
+
+        assert_eq!(
+            parse_binary_value(&TableDataType::Boolean, &[1]),
+            Some(Scalar::Boolean(true))
+        );
+        assert_eq!(
+            parse_binary_value(&TableDataType::Date, &19000i32.to_le_bytes()),
+            Some(Scalar::Date(19000))
+        );
+        assert_eq!(
+            parse_binary_value(&TableDataType::String, b"abc"),
+            Some(Scalar::String(b"abc".to_vec()))
+        );
+
+        // Big-endian two's-complement of -123 in the minimal bytes.
+        let size = DecimalSize {
+            precision: 10,
+            scale: 2,
+        };
+        let decimal = TableDataType::Decimal(DecimalDataType::Decimal128(size));
+        assert_eq!(
+            parse_binary_value(&decimal, &[0xff, 0x85]),
+            Some(Scalar::Decimal(DecimalScalar::Decimal128(-123, size)))
+        );
+        assert_eq!(parse_binary_value(&decimal, &[]), None);
+    }
+
+    #[test]
+    fn test_prune_manifest_by_summaries() -> Result<()> {
+        let schema = Arc::new(TableSchema::new(vec![
+            TableField::new("id", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("v", TableDataType::Number(NumberDataType::Float64)),
+        ]));
+        let field_ids = HashMap::from([("id".to_string(), 1), ("v".to_string(), 2)]);
+        let identity = |source_id| PartitionField {
+            source_id,
+            transform: "identity".to_string(),
+        };
+        let summary = |lo: Vec<u8>, up: Vec<u8>| FieldSummary {
+            contains_null: false,
+            lower_bound: Some(lo),
+            upper_bound: Some(up),
+        };
+
+        let id_eq = |value: i32| -> Result<Expr<String>> {
+            check_function(
+                None,
+                "eq",
+                &[],
+                &[
+                    Expr::ColumnRef {
+                        span: None,
+                        id: "id".to_string(),
+                        data_type: DataType::Number(NumberDataType::Int32),
+                        display_name: "id".to_string(),
+                    },
+                    Expr::Constant {
+                        span: None,
+                        scalar: Scalar::Number(NumberScalar::Int32(value)),
+                        data_type: DataType::Number(NumberDataType::Int32),
+                    },
+                ],
+                &BUILTIN_FUNCTIONS,
+            )
+        };
+        let v_gt = |value: f64| -> Result<Expr<String>> {
+            check_function(
+                None,
+                "gt",
+                &[],
+                &[
+                    Expr::ColumnRef {
+                        span: None,
+                        id: "v".to_string(),
+                        data_type: DataType::Number(NumberDataType::Float64),
+                        display_name: "v".to_string(),
+                    },
+                    Expr::Constant {
+                        span: None,
+                        scalar: Scalar::Number(NumberScalar::Float64(F64::from(value))),
+                        data_type: DataType::Number(NumberDataType::Float64),
+                    },
+                ],
+                &BUILTIN_FUNCTIONS,
+            )
+        };
+        let should_keep = |filter: &Expr<String>, stats: StatisticsOfColumns| -> Result<bool> {
+            let pruner =
+                RangePrunerCreator::try_create(FunctionContext::default(), &schema, Some(filter))?;
+            Ok(pruner.should_keep(&stats, None))
+        };
+
+        // The manifest holds the files of the partitions `id` in [1, 3].
+        let spec = vec![identity(1)];
+        let summaries = vec![summary(
+            1i32.to_le_bytes().to_vec(),
+            3i32.to_le_bytes().to_vec(),
+        )];
+        let stats = get_stats_of_manifest(&schema, &field_ids, &spec, &summaries);
+        assert!(should_keep(&id_eq(2)?, stats.clone())?);
+        assert!(!should_keep(&id_eq(5)?, stats)?);
+
+        // Only the identity partitions are used.
+        let spec = vec![PartitionField {
+            source_id: 1,
+            transform: "bucket[16]".to_string(),
+        }];
+        let stats = get_stats_of_manifest(&schema, &field_ids, &spec, &summaries);
+        assert!(stats.is_empty());
+        assert!(should_keep(&id_eq(5)?, stats)?);
+
+        // The NaN bounds are uncertain.
+        let spec = vec![identity(2)];
+        let summaries = vec![summary(
+            f64::NAN.to_le_bytes().to_vec(),
+            1.0f64.to_le_bytes().to_vec(),
+        )];
+        let stats = get_stats_of_manifest(&schema, &field_ids, &spec, &summaries);
+        assert!(stats.is_empty());
+        assert!(should_keep(&v_gt(2.0)?, stats)?);
+
+        let summaries = vec![summary(
+            0.0f64.to_le_bytes().to_vec(),
+            1.0f64.to_le_bytes().to_vec(),
+        )];
+        let stats = get_stats_of_manifest(&schema, &field_ids, &spec, &summaries);
+        assert!(!should_keep(&v_gt(2.0)?, stats)?);
+        Ok(())
+    }
+}
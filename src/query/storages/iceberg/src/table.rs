@@ -27,6 +27,7 @@ use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::table::AppendMode;
 use common_catalog::table::Table;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::TableContext;
@@ -38,7 +39,12 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::UpdateStreamMetaReq;
+use common_meta_app::schema::UpsertTableCopiedFileReq;
+use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::Pipeline;
+use common_pipeline_sinks::AsyncSinker;
+use common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
 use common_storage::DataOperator;
 use common_storages_parquet::ParquetFilesPart;
 use common_storages_parquet::ParquetPart;
@@ -47,31 +53,31 @@ use common_storages_parquet::ParquetRSReaderBuilder;
 use icelake::catalog::Catalog;
 use opendal::Operator;
 use storages_common_pruner::RangePrunerCreator;
-use tokio::sync::OnceCell;
+use storages_common_table_meta::meta::SnapshotId;
 
+use crate::manifest::read_manifest;
+use crate::manifest::read_manifest_list;
+use crate::manifest::ManifestListEntry;
+use crate::metadata::IcebergMetadata;
 use crate::partition::IcebergPartInfo;
+use crate::sink::IcebergCommitSink;
+use crate::sink::IcebergDataFileWriter;
+use crate::stats::field_ids;
 use crate::stats::get_stats_of_data_file;
+use crate::stats::get_stats_of_manifest;
 use crate::table_source::IcebergTableSource;
 
 /// accessor wrapper as a table
-///
-/// TODO: we should use icelake Table instead.
 pub struct IcebergTable {
     info: TableInfo,
     op: DataOperator,
-
-    table: OnceCell<icelake::Table>,
 }
 
 impl IcebergTable {
     /// create a new table on the table directory
     #[async_backtrace::framed]
     pub fn try_new(dop: DataOperator, info: TableInfo) -> Result<IcebergTable> {
-        Ok(Self {
-            info,
-            op: dop,
-            table: OnceCell::new(),
-        })
+        Ok(Self { info, op: dop })
     }
 
     /// create a new table on the table directory
@@ -134,30 +140,7 @@ impl IcebergTable {
             ..Default::default()
         };
 
-        Ok(Self {
-            info,
-            op: dop,
-            table: OnceCell::new_with(Some(table)),
-        })
-    }
-
-    async fn table(&self) -> Result<&icelake::Table> {
-        self.table
-            .get_or_try_init(|| async {
-                // FIXME: we should implement catalog for icelake.
-                let icelake_catalog = Arc::new(icelake::catalog::StorageCatalog::new(
-                    "databend",
-                    OperatorCreatorWrapper(self.op.clone()),
-                ));
-
-                let table_id = icelake::TableIdentifier::new(vec![""]).unwrap();
-                let table = icelake_catalog.load_table(&table_id).await.map_err(|err| {
-                    ErrorCode::ReadTableDataError(format!("Iceberg catalog load failed: {err:?}"))
-                })?;
-
-                Ok(table)
-            })
-            .await
+        Ok(Self { info, op: dop })
     }
 
     pub fn do_read_data(
@@ -236,11 +219,11 @@ impl IcebergTable {
         ctx: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
-        let table = self.table().await?;
-
-        let data_files = table.current_data_files().await.map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
-        })?;
+        let op = self.op.operator();
+        let meta = IcebergMetadata::read(&op).await?;
+        let Some(snapshot) = meta.current_snapshot() else {
+            return Ok((PartStatistics::default(), Partitions::default()));
+        };
 
         let filter = push_downs.as_ref().and_then(|extra| {
             extra
@@ -250,48 +233,90 @@ impl IcebergTable {
         });
 
         let schema = self.schema();
+        let field_ids = field_ids(meta.current_schema()?);
 
         let pruner =
             RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, filter.as_ref())?;
 
+        let manifests = match snapshot["manifest-list"].as_str() {
+            Some(path) => read_manifest_list(&op.read(meta.rel_path(path)).await?)?,
+            // Snapshots of format version 1 may list the manifests directly.
+            None => snapshot["manifests"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|v| v.as_str())
+                .map(|path| ManifestListEntry {
+                    path: path.to_string(),
+                    partition_spec_id: 0,
+                    is_delete: false,
+                    files_count: None,
+                    partitions: vec![],
+                })
+                .collect(),
+        };
+
+        // Skip the manifests by the partition summaries first.
+        let mut total_files = 0;
+        let mut selected = vec![];
+        for manifest in manifests {
+            if manifest.is_delete {
+                if manifest.files_count == Some(0) {
+                    continue;
+                }
+                return Err(ErrorCode::Unimplemented(
+                    "iceberg tables with delete files are not supported yet",
+                ));
+            }
+            let spec = meta.partition_spec(manifest.partition_spec_id);
+            let stats = get_stats_of_manifest(&schema, &field_ids, &spec, &manifest.partitions);
+            if pruner.should_keep(&stats, None) {
+                selected.push((manifest, spec));
+            } else {
+                total_files += manifest.files_count.unwrap_or_default();
+            }
+        }
+
+        let manifest_data = futures::future::try_join_all(
+            selected
+                .iter()
+                .map(|(manifest, _)| op.read(meta.rel_path(&manifest.path))),
+        )
+        .await?;
+
+        // Then the data files by their column bounds and partition values.
         // TODO: support other file formats. We only support parquet files now.
         let mut read_rows = 0;
         let mut read_bytes = 0;
-        let total_files = data_files.len();
-        let parts = data_files
-            .into_iter()
-            .filter(|df| {
-                if let Some(stats) = get_stats_of_data_file(&schema, df) {
-                    pruner.should_keep(&stats, None)
-                } else {
-                    true
+        let mut parts = vec![];
+        for ((_, spec), data) in selected.iter().zip(manifest_data) {
+            for df in read_manifest(&data)? {
+                total_files += 1;
+                if df.content != 0 {
+                    return Err(ErrorCode::Unimplemented(
+                        "iceberg tables with delete files are not supported yet",
+                    ));
+                }
+                let stats = get_stats_of_data_file(&schema, &field_ids, spec, &df);
+                if !pruner.should_keep(&stats, None) {
+                    continue;
                 }
-            })
-            .map(|v: icelake::types::DataFile| {
-                read_rows += v.record_count as usize;
-                read_bytes += v.file_size_in_bytes as usize;
-                match v.file_format {
-                    icelake::types::DataFileFormat::Parquet => {
-                        let location = table
-                            .rel_path(&v.file_path)
-                            .expect("file path must be rel to table");
-                        Ok(Arc::new(
-                            Box::new(IcebergPartInfo::Parquet(ParquetPart::ParquetFiles(
-                                ParquetFilesPart {
-                                    files: vec![(location, v.file_size_in_bytes as u64)],
-                                    estimated_uncompressed_size: v.file_size_in_bytes as u64, // This field is not used here.
-                                },
-                            ))) as Box<dyn PartInfo>,
-                        ))
-                    }
-                    _ => Err(ErrorCode::Unimplemented(
+                if !df.file_format.eq_ignore_ascii_case("parquet") {
+                    return Err(ErrorCode::Unimplemented(
                         "Only parquet format is supported for iceberg table",
-                    )),
+                    ));
                 }
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        // TODO: more precise pruning.
+                read_rows += df.record_count as usize;
+                read_bytes += df.file_size as usize;
+                let location = meta.rel_path(&df.file_path).to_string();
+                parts.push(Arc::new(Box::new(IcebergPartInfo::Parquet(
+                    ParquetPart::ParquetFiles(ParquetFilesPart {
+                        files: vec![(location, df.file_size)],
+                        estimated_uncompressed_size: df.file_size, // This field is not used here.
+                    }),
+                )) as Box<dyn PartInfo>));
+            }
+        }
 
         Ok((
             PartStatistics::new_estimated(None, read_rows, read_bytes, parts.len(), total_files),
@@ -339,6 +364,41 @@ impl Table for IcebergTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    fn append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _append_mode: AppendMode,
+    ) -> Result<()> {
+        let data_schema = DataSchema::from(self.schema());
+        pipeline.add_transform(|input, output| {
+            let writer = IcebergDataFileWriter::create(self.op.operator(), data_schema.clone());
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input, output, writer,
+            )))
+        })
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+    ) -> Result<()> {
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            let sink = IcebergCommitSink::create(self.op.operator(), overwrite);
+            Ok(ProcessorPtr::create(AsyncSinker::create(
+                input,
+                ctx.clone(),
+                sink,
+            )))
+        })
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -93,6 +93,11 @@ impl MemoryTable {
         Ok(Box::new(table))
     }
 
+    /// Release the data of a dropped table.
+    pub fn purge_data(table_id: u64) {
+        IN_MEMORY_DATA.write().remove(&table_id);
+    }
+
     pub fn description() -> StorageDescription {
         StorageDescription {
             engine_name: "MEMORY".to_string(),
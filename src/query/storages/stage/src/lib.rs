@@ -15,6 +15,7 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod parquet_file;
+mod partitioned_file;
 mod row_based_file;
 mod stage_table;
 
@@ -115,6 +115,7 @@ impl Processor for ParquetFileSink {
         assert!(!self.output_data.is_empty());
         let path = unload_path(
             &self.table_info,
+            None,
             &self.uuid,
             self.group_id,
             self.batch_id,
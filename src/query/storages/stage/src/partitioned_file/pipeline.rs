@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::StageTableInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::input_formats::InputContext;
+use opendal::Operator;
+
+use crate::partitioned_file::sink_processor::PartitionedFileSink;
+
+/// Each sink routes the rows into the Hive style sub directories by the partition columns,
+/// and limits the size of the files of every partition on its own.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn append_data_to_partitioned_files(
+    pipeline: &mut Pipeline,
+    ctx: Arc<dyn TableContext>,
+    table_info: StageTableInfo,
+    op: Operator,
+    max_file_size: usize,
+    uuid: String,
+    group_id: &std::sync::atomic::AtomicUsize,
+) -> Result<()> {
+    let file_format_params = table_info.stage_info.file_format_params.clone();
+    let mut options_ext = FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
+    let output_format =
+        options_ext.get_output_format(table_info.output_schema(), file_format_params.clone())?;
+    let prefix = output_format.serialize_prefix()?;
+    let (is_parquet, compression) = match &file_format_params {
+        FileFormatParams::Parquet(_) => (true, None),
+        _ => (
+            false,
+            InputContext::get_compression_alg_copy(file_format_params.compression(), "")?,
+        ),
+    };
+
+    pipeline.add_sink(|input| {
+        let gid = group_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        PartitionedFileSink::try_create(
+            input,
+            ctx.clone(),
+            table_info.clone(),
+            op.clone(),
+            max_file_size,
+            is_parquet,
+            prefix.clone(),
+            uuid.clone(),
+            gid,
+            compression,
+        )
+    })?;
+    Ok(())
+}
@@ -0,0 +1,311 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::mem;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_base::base::ProgressValues;
+use common_catalog::plan::StageTableInfo;
+use common_catalog::table_context::TableContext;
+use common_compress::CompressAlgorithm;
+use common_compress::CompressCodec;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_formats::output_format::OutputFormat;
+use common_formats::FileFormatOptionsExt;
+use common_pipeline_core::processors::Event;
+use common_pipeline_core::processors::InputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_core::processors::ProcessorPtr;
+use opendal::Operator;
+
+use crate::stage_table::unload_path;
+
+/// The partition value of NULL and empty string, the same as Hive.
+const DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Bound of the data buffered by all the open partitions of a sink. When exceeded, the
+/// largest partitions are flushed into files before they reach `max_file_size`.
+const MAX_BUFFERED_SIZE: usize = 256 * 1024 * 1024;
+
+/// The file being written of a partition.
+struct PartitionFile {
+    output_format: Box<dyn OutputFormat>,
+    // Serialized rows of the row based formats, parquet keeps the blocks in the output format.
+    data: Vec<u8>,
+    size: usize,
+    batch_id: usize,
+}
+
+pub struct PartitionedFileSink {
+    input: Arc<InputPort>,
+    ctx: Arc<dyn TableContext>,
+    table_info: StageTableInfo,
+    data_accessor: Operator,
+
+    input_data: Option<DataBlock>,
+    // (path, data) of the files ready to write.
+    output_files: Vec<(String, Vec<u8>)>,
+    partitions: HashMap<String, PartitionFile>,
+    // Sum of the sizes of the files being written of all the partitions.
+    buffered_size: usize,
+
+    max_file_size: usize,
+    is_parquet: bool,
+    prefix: Vec<u8>,
+    uuid: String,
+    group_id: usize,
+    compression: Option<CompressAlgorithm>,
+}
+
+impl PartitionedFileSink {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        table_info: StageTableInfo,
+        data_accessor: Operator,
+        max_file_size: usize,
+        is_parquet: bool,
+        prefix: Vec<u8>,
+        uuid: String,
+        group_id: usize,
+        compression: Option<CompressAlgorithm>,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Box::new(PartitionedFileSink {
+            input,
+            ctx,
+            table_info,
+            data_accessor,
+            input_data: None,
+            output_files: vec![],
+            partitions: HashMap::new(),
+            buffered_size: 0,
+            max_file_size,
+            is_parquet,
+            prefix,
+            uuid,
+            group_id,
+            compression,
+        })))
+    }
+
+    /// Split the block by the partition columns, which are removed from the output blocks.
+    fn split_block(&self, block: DataBlock) -> Result<Vec<(String, DataBlock)>> {
+        let num_rows = block.num_rows();
+        let num_fields = block.num_columns() - self.table_info.partition_by.len();
+
+        let mut partitions: Vec<(String, Vec<u32>)> = vec![];
+        let mut partition_index = HashMap::new();
+        for row in 0..num_rows {
+            let partition = self.partition_path(&block, num_fields, row);
+            match partition_index.get(&partition) {
+                Some(i) => partitions[*i].1.push(row as u32),
+                None => {
+                    partition_index.insert(partition.clone(), partitions.len());
+                    partitions.push((partition, vec![row as u32]));
+                }
+            }
+        }
+
+        let block = DataBlock::new(block.columns()[..num_fields].to_vec(), num_rows);
+        if partitions.len() == 1 {
+            let (partition, _) = partitions.pop().unwrap();
+            return Ok(vec![(partition, block)]);
+        }
+        partitions
+            .into_iter()
+            .map(|(partition, rows)| Ok((partition, block.take(&rows, &mut None)?)))
+            .collect()
+    }
+
+    fn partition_path(&self, block: &DataBlock, num_fields: usize, row: usize) -> String {
+        let mut path = String::new();
+        for (i, key) in self.table_info.partition_by.iter().enumerate() {
+            if i > 0 {
+                path.push('/');
+            }
+            escape_path_name(key, &mut path);
+            path.push('=');
+            match block.get_by_offset(num_fields + i).value.index(row) {
+                Some(ScalarRef::String(v)) if !v.is_empty() => {
+                    escape_path_name(&String::from_utf8_lossy(v), &mut path)
+                }
+                _ => path.push_str(DEFAULT_PARTITION),
+            }
+        }
+        path
+    }
+
+    fn write_block(&mut self, partition: String, block: DataBlock) -> Result<()> {
+        if !self.partitions.contains_key(&partition) {
+            let mut options_ext =
+                FileFormatOptionsExt::create_from_settings(&self.ctx.get_settings(), false)?;
+            let output_format = options_ext.get_output_format(
+                self.table_info.output_schema(),
+                self.table_info.stage_info.file_format_params.clone(),
+            )?;
+            self.partitions.insert(partition.clone(), PartitionFile {
+                output_format,
+                data: vec![],
+                size: 0,
+                batch_id: 0,
+            });
+        }
+        let file = self.partitions.get_mut(&partition).unwrap();
+
+        let data = file.output_format.serialize_block(&block)?;
+        let size = if self.is_parquet {
+            block.memory_size()
+        } else {
+            data.len()
+        };
+        file.size += size;
+        file.data.extend_from_slice(&data);
+        let file_size = file.size;
+        self.buffered_size += size;
+        let progress_values = ProgressValues {
+            rows: block.num_rows(),
+            bytes: size,
+        };
+        self.ctx.get_write_progress().incr(&progress_values);
+
+        if file_size >= self.max_file_size {
+            self.finish_file(&partition)?;
+        }
+        while self.buffered_size > MAX_BUFFERED_SIZE {
+            let largest = self
+                .partitions
+                .iter()
+                .max_by_key(|(_, file)| file.size)
+                .map(|(partition, _)| partition.clone())
+                .unwrap();
+            self.finish_file(&largest)?;
+        }
+        Ok(())
+    }
+
+    fn finish_file(&mut self, partition: &str) -> Result<()> {
+        let file = self.partitions.get_mut(partition).unwrap();
+        if file.size == 0 {
+            return Ok(());
+        }
+        let (data, compression) = if self.is_parquet {
+            (file.output_format.finalize()?, None)
+        } else {
+            let mut output = Vec::with_capacity(self.prefix.len() + file.data.len());
+            output.extend_from_slice(self.prefix.as_slice());
+            output.append(&mut file.data);
+            if let Some(compression) = self.compression {
+                output = CompressCodec::from(compression).compress_all(&output)?;
+            }
+            (output, self.compression)
+        };
+        let path = unload_path(
+            &self.table_info,
+            Some(partition),
+            &self.uuid,
+            self.group_id,
+            file.batch_id,
+            compression,
+        );
+        self.buffered_size -= file.size;
+        file.size = 0;
+        file.batch_id += 1;
+        self.output_files.push((path, data));
+        Ok(())
+    }
+}
+
+/// Escape the characters not allowed in the Hive partition path with `%XX`.
+fn escape_path_name(name: &str, path: &mut String) {
+    for c in name.chars() {
+        if c.is_ascii_control()
+            || matches!(
+                c,
+                '"' | '#' | '%' | '\'' | '*' | '/' | ':' | '=' | '?' | '\\' | '{' | '[' | ']' | '^'
+            )
+        {
+            path.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            path.push(c);
+        }
+    }
+}
+
+#[async_trait]
+impl Processor for PartitionedFileSink {
+    fn name(&self) -> String {
+        "PartitionedFileSink".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if !self.output_files.is_empty() {
+            self.input.set_not_need_data();
+            Ok(Event::Async)
+        } else if self.input_data.is_some() {
+            self.input.set_not_need_data();
+            Ok(Event::Sync)
+        } else if self.input.is_finished() {
+            self.input.set_not_need_data();
+            if self.partitions.is_empty() {
+                Ok(Event::Finished)
+            } else {
+                // Flush the remaining files of all the partitions.
+                Ok(Event::Sync)
+            }
+        } else if self.input.has_data() {
+            self.input_data = Some(self.input.pull_data().unwrap()?);
+            self.input.set_not_need_data();
+            Ok(Event::Sync)
+        } else {
+            self.input.set_need_data();
+            Ok(Event::NeedData)
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match self.input_data.take() {
+            Some(block) => {
+                for (partition, block) in self.split_block(block)? {
+                    self.write_block(partition, block)?;
+                }
+            }
+            None => {
+                let partitions = self.partitions.keys().cloned().collect::<Vec<_>>();
+                for partition in partitions {
+                    self.finish_file(&partition)?;
+                }
+                self.partitions.clear();
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        for (path, data) in mem::take(&mut self.output_files) {
+            self.data_accessor.write(&path, data).await?;
+        }
+        Ok(())
+    }
+}
@@ -28,7 +28,7 @@ use crate::row_based_file::sink_processor::RowBasedFileSink;
 
 // SerializeProcessor * N: serialize each data block to many small byte buffers.
 // LimitFileSizeProcessor * 1:  group small byte buffers to batches (as a block meta) that are large enough as a file.
-// RowBasedFileSink * N: simply concat small byte buffers to a whole and write out.
+// RowBasedFileSink * N: simply concat small byte buffers to a whole, finalize and write out.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn append_data_to_row_based_files(
     pipeline: &mut Pipeline,
@@ -73,10 +73,17 @@ pub(crate) fn append_data_to_row_based_files(
 
     pipeline.add_sink(|input| {
         let gid = group_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let mut options_ext =
+            FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
+        let output_format = options_ext.get_output_format(
+            table_info.schema(),
+            table_info.stage_info.file_format_params.clone(),
+        )?;
         RowBasedFileSink::try_create(
             input,
             table_info.clone(),
             op.clone(),
+            output_format,
             prefix.clone(),
             uuid.clone(),
             gid,
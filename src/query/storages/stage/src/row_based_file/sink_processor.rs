@@ -23,6 +23,7 @@ use common_compress::CompressCodec;
 use common_exception::Result;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::DataBlock;
+use common_formats::output_format::OutputFormat;
 use common_pipeline_core::processors::Event;
 use common_pipeline_core::processors::InputPort;
 use common_pipeline_core::processors::Processor;
@@ -42,6 +43,8 @@ pub struct RowBasedFileSink {
     output_data: Vec<u8>,
 
     data_accessor: Operator,
+    // finalizes each file, e.g. the end-of-stream marker of Arrow.
+    output_format: Box<dyn OutputFormat>,
     prefix: Vec<u8>,
 
     uuid: String,
@@ -56,6 +59,7 @@ impl RowBasedFileSink {
         input: Arc<InputPort>,
         table_info: StageTableInfo,
         data_accessor: Operator,
+        output_format: Box<dyn OutputFormat>,
         prefix: Vec<u8>,
         uuid: String,
         group_id: usize,
@@ -66,6 +70,7 @@ impl RowBasedFileSink {
             input,
             input_data: None,
             data_accessor,
+            output_format,
             prefix,
             uuid,
             group_id,
@@ -116,6 +121,7 @@ impl Processor for RowBasedFileSink {
         for b in buffers.buffers {
             output.extend_from_slice(b.as_slice());
         }
+        output.extend_from_slice(&self.output_format.finalize()?);
         if let Some(compression) = self.compression {
             output = CompressCodec::from(compression).compress_all(&output)?;
         }
@@ -127,6 +133,7 @@ impl Processor for RowBasedFileSink {
     async fn async_process(&mut self) -> Result<()> {
         let path = unload_path(
             &self.table_info,
+            None,
             &self.uuid,
             self.group_id,
             self.batch_id,
@@ -36,6 +36,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockThresholds;
 use common_expression::TableSchemaRefExt;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableInfo;
@@ -50,8 +51,11 @@ use log::debug;
 use opendal::Operator;
 use opendal::Scheme;
 use parking_lot::Mutex;
+use storages_common_index::RangeIndex;
+use storages_common_table_meta::meta::StatisticsOfColumns;
 
 use crate::parquet_file::append_data_to_parquet_files;
+use crate::partitioned_file::append_data_to_partitioned_files;
 use crate::row_based_file::append_data_to_row_based_files;
 /// TODO: we need to track the data metrics in stage table.
 pub struct StageTable {
@@ -213,7 +217,24 @@ impl Table for StageTable {
             bop.write(STDIN_FD, buffer)?;
         }
 
-        let input_ctx = Arc::new(InputContext::try_create_from_copy(
+        // only the stripes of the orc files are pruned by their statistics.
+        let range_filter = match (&stage_info.file_format_params, &plan.push_downs) {
+            (
+                FileFormatParams::Orc(_),
+                Some(PushDownInfo {
+                    filters: Some(filters),
+                    ..
+                }),
+            ) => Some(RangeIndex::try_create(
+                ctx.get_function_context()?,
+                &filters.filter.as_expr(&BUILTIN_FUNCTIONS),
+                schema.clone(),
+                StatisticsOfColumns::default(),
+            )?),
+            _ => None,
+        };
+
+        let mut input_ctx = InputContext::try_create_from_copy(
             ctx.clone(),
             operator,
             settings,
@@ -226,7 +247,9 @@ impl Table for StageTable {
             self.table_info.is_select,
             projection,
             self.table_info.default_values.clone(),
-        )?);
+        )?;
+        input_ctx.range_filter = range_filter;
+        let input_ctx = Arc::new(input_ctx);
         debug!("start copy splits feeder in {}", ctx.get_cluster().local_id);
         input_ctx.format.exec_copy(input_ctx.clone(), pipeline)?;
         Ok(())
@@ -260,6 +283,15 @@ impl Table for StageTable {
         let uuid = uuid::Uuid::new_v4().to_string();
         let group_id = AtomicUsize::new(0);
         match fmt {
+            _ if !self.table_info.partition_by.is_empty() => append_data_to_partitioned_files(
+                pipeline,
+                ctx.clone(),
+                self.table_info.clone(),
+                op,
+                max_file_size,
+                uuid,
+                &group_id,
+            )?,
             FileFormatParams::Parquet(_) => append_data_to_parquet_files(
                 pipeline,
                 ctx.clone(),
@@ -303,8 +335,10 @@ impl Table for StageTable {
     }
 }
 
+/// `partition` is the Hive style sub directory like `k1=v1/k2=v2` of a partitioned unload.
 pub fn unload_path(
     stage_table_info: &StageTableInfo,
+    partition: Option<&str>,
     uuid: &str,
     group_id: usize,
     batch_id: usize,
@@ -322,7 +356,18 @@ pub fn unload_path(
 
     let path = &stage_table_info.files_info.path;
 
-    if path.ends_with("data_") {
+    if let Some(partition) = partition {
+        format!(
+            "{}/{}/data_{}_{:0>4}_{:0>8}.{}{}",
+            path.trim_end_matches('/'),
+            partition,
+            uuid,
+            group_id,
+            batch_id,
+            format_name,
+            suffix
+        )
+    } else if path.ends_with("data_") {
         format!(
             "{}{}_{:0>4}_{:0>8}.{}{}",
             path, uuid, group_id, batch_id, format_name, suffix
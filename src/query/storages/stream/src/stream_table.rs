@@ -22,11 +22,13 @@ use std::time::Instant;
 use common_base::base::tokio::runtime::Handle;
 use common_base::base::tokio::task::block_in_place;
 use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::ChangeType;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::plan::StreamColumn;
+use common_catalog::plan::OPT_KEY_CHANGE_TYPE;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
@@ -37,8 +39,12 @@ use common_expression::ORIGIN_VERSION_COL_NAME;
 use common_meta_app::schema::TableInfo;
 use common_pipeline_core::Pipeline;
 use common_sql::binder::STREAM_COLUMN_FACTORY;
+use common_sql::evaluator::CompoundBlockOperator;
+use common_sql::gen_mutation_stream_operator;
+use common_sql::TransformStreamKind;
 use common_storages_fuse::io::SegmentsIO;
 use common_storages_fuse::io::SnapshotsIO;
+use common_storages_fuse::operations::TransformAddStreamColumns;
 use common_storages_fuse::FuseTable;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
@@ -54,10 +60,15 @@ pub const OPT_KEY_TABLE_VER: &str = "table_version";
 pub const OPT_KEY_MODE: &str = "mode";
 
 pub const MODE_APPEND_ONLY: &str = "append_only";
+pub const MODE_STANDARD: &str = "standard";
 
 #[derive(Clone)]
 pub enum StreamMode {
+    /// Only the rows appended to the table are captured.
     AppendOnly,
+    /// The rows inserted, updated and deleted are captured by diffing the
+    /// base snapshot against the latest snapshot.
+    Standard,
 }
 
 pub enum StreamStatus {
@@ -70,6 +81,7 @@ impl FromStr for StreamMode {
     fn from_str(s: &str) -> Result<Self> {
         match s {
             MODE_APPEND_ONLY => Ok(StreamMode::AppendOnly),
+            MODE_STANDARD => Ok(StreamMode::Standard),
             _ => Err(ErrorCode::IllegalStream(format!(
                 "invalid stream mode: {}",
                 s
@@ -82,6 +94,7 @@ impl Display for StreamMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", match self {
             StreamMode::AppendOnly => MODE_APPEND_ONLY.to_string(),
+            StreamMode::Standard => MODE_STANDARD.to_string(),
         })
     }
 }
@@ -95,6 +108,8 @@ pub struct StreamTable {
     table_version: u64,
     mode: StreamMode,
     snapshot_location: Option<String>,
+    // Set when reading one side of the changes of a standard stream.
+    change_type: Option<ChangeType>,
 }
 
 impl StreamTable {
@@ -121,6 +136,10 @@ impl StreamTable {
             .and_then(|s| s.parse::<StreamMode>().ok())
             .unwrap_or(StreamMode::AppendOnly);
         let snapshot_location = options.get(OPT_KEY_SNAPSHOT_LOCATION).cloned();
+        let change_type = options
+            .get(OPT_KEY_CHANGE_TYPE)
+            .map(|s| s.parse::<ChangeType>())
+            .transpose()?;
         Ok(Box::new(StreamTable {
             stream_info: table_info,
             table_name,
@@ -129,6 +148,7 @@ impl StreamTable {
             table_version,
             mode,
             snapshot_location,
+            change_type,
         }))
     }
 
@@ -238,11 +258,13 @@ impl StreamTable {
         let diff_in_base = fuse_segment_io
             .read_segments::<SegmentInfo>(&diff_in_base, true)
             .await?;
-        let mut base_blocks = HashSet::new();
+        let mut base_blocks = Vec::new();
+        let mut base_block_locations = HashSet::new();
         for segment in diff_in_base {
             let segment = segment?;
             segment.blocks.into_iter().for_each(|block| {
-                base_blocks.insert(block.location.clone());
+                base_block_locations.insert(block.location.clone());
+                base_blocks.push(block);
             })
         }
 
@@ -254,20 +276,34 @@ impl StreamTable {
             .read_segments::<SegmentInfo>(&diff_in_latest, true)
             .await?;
         let mut latest_blocks = Vec::new();
+        let mut latest_block_locations = HashSet::new();
         for segment in diff_in_latest {
             let segment = segment?;
             segment.blocks.into_iter().for_each(|block| {
-                if !base_blocks.contains(&block.location) {
-                    latest_blocks.push(block);
-                }
+                latest_block_locations.insert(block.location.clone());
+                latest_blocks.push(block);
             });
         }
-        if latest_blocks.is_empty() {
+
+        // The delete side of the changes reads the blocks removed since the base snapshot,
+        // otherwise the blocks added since the base snapshot are read.
+        let blocks = match self.change_type {
+            Some(ChangeType::Delete) => base_blocks
+                .into_iter()
+                .filter(|block| !latest_block_locations.contains(&block.location))
+                .collect::<Vec<_>>(),
+            _ => latest_blocks
+                .into_iter()
+                .filter(|block| !base_block_locations.contains(&block.location))
+                .collect::<Vec<_>>(),
+        };
+        if blocks.is_empty() {
             return Ok((PartStatistics::default(), Partitions::default()));
         }
 
         let table_schema = fuse_table.schema_with_stream();
         let bloom_index_cols = fuse_table.bloom_index_cols();
+        let ngram_index_cols = fuse_table.ngram_index_cols();
         let (cluster_keys, cluster_key_meta) =
             if !fuse_table.is_native() || fuse_table.cluster_key_meta().is_none() {
                 (vec![], None)
@@ -285,9 +321,10 @@ impl StreamTable {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            ngram_index_cols,
         )?;
 
-        let block_metas = stream_pruner.pruning(latest_blocks).await?;
+        let block_metas = stream_pruner.pruning(blocks).await?;
         let pruning_stats = stream_pruner.pruning_stats();
 
         log::info!(
@@ -378,6 +415,37 @@ impl Table for StreamTable {
                 &self.table_name,
             ))
         })?;
-        table.read_data(ctx, plan, pipeline, put_cache)
+
+        let schema = plan.schema();
+        if self.change_type.is_none()
+            || schema.index_of(ORIGIN_BLOCK_ID_COL_NAME).is_err()
+            || schema.index_of(ORIGIN_BLOCK_ROW_NUM_COL_NAME).is_err()
+        {
+            return table.read_data(ctx, plan, pipeline, put_cache);
+        }
+
+        // The row id of the changes is the origin of the row, the rows without origin
+        // are identified by the block they are read from and the position in the block.
+        // So the whole block is read without prewhere, which keeps the row positions.
+        let mut plan = plan.clone();
+        plan.update_stream_columns = true;
+        if let Some(push_downs) = plan.push_downs.as_mut() {
+            push_downs.prewhere = None;
+        }
+        table.read_data(ctx.clone(), &plan, pipeline, put_cache)?;
+
+        let func_ctx = ctx.get_function_context()?;
+        let (stream_columns, operators) = gen_mutation_stream_operator(schema, true)?;
+        pipeline.add_transform(|input, output| {
+            TransformAddStreamColumns::try_create(
+                input,
+                output,
+                TransformStreamKind::Mutation(CompoundBlockOperator {
+                    operators: operators.clone(),
+                    ctx: func_ctx.clone(),
+                }),
+                stream_columns.clone(),
+            )
+        })
     }
 }
@@ -81,6 +81,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 table_options: BTreeMap::new(),
                 as_query: None,
                 transient: false,
+                temporary: false,
             };
             tables.push((drop_table, create_table));
         }
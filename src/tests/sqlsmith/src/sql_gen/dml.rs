@@ -307,6 +307,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
         (table, table_reference)
     }
@@ -511,6 +512,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
         Some((
             AlterTableStmt {
@@ -120,6 +120,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             group_by: None,
             having: None,
             window_list: None,
+            qualify: None,
         };
         let body = SetExpr::Select(Box::new(select));
 
@@ -311,6 +312,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             group_by,
             having: self.gen_selection(),
             window_list: self.gen_window_list(),
+            qualify: None,
         }
     }
 
@@ -477,6 +479,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             pivot: None,
             // TODO
             unpivot: None,
+            sample: None,
         };
         (table_ref, schema)
     }
@@ -662,6 +665,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
         let join = Join {
             op,
             condition,
+            match_condition: None,
             left: Box::new(left_table),
             right: Box::new(right_table),
         };
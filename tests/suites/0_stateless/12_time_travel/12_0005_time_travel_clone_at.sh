@@ -0,0 +1,42 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+## Create table t12_0005
+echo "create table t12_0005(c int)" | $BENDSQL_CLIENT_CONNECT
+echo "two insertions"
+echo "insert into t12_0005 values(1),(2)" | $BENDSQL_CLIENT_CONNECT
+
+echo "insert into t12_0005 values(3)" | $BENDSQL_CLIENT_CONNECT
+
+## Get the previous snapshot id of the latest snapshot
+SNAPSHOT_ID=$(echo "select previous_snapshot_id from fuse_snapshot('default','t12_0005') where row_count=3 " | $BENDSQL_CLIENT_CONNECT)
+
+echo "clone at the first insertion, which should contain 2 rows"
+echo "create table t12_0005_clone clone t12_0005 at (snapshot => '$SNAPSHOT_ID')" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005_clone" | $BENDSQL_CLIENT_CONNECT
+
+echo "the clone can travel to the snapshot it is cloned at"
+echo "select count(*) from t12_0005_clone at (snapshot => '$SNAPSHOT_ID')" | $BENDSQL_CLIENT_CONNECT
+
+echo "the clone is modified independently"
+echo "insert into t12_0005_clone values(4)" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005_clone" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005" | $BENDSQL_CLIENT_CONNECT
+
+echo "the clone still travels back to the snapshot it is cloned at"
+echo "select count(*) from t12_0005_clone at (snapshot => '$SNAPSHOT_ID')" | $BENDSQL_CLIENT_CONNECT
+
+# Get a time point at/after the first insertion.
+TIMEPOINT=$(echo "select timestamp from fuse_snapshot('default', 't12_0005') where row_count=2" | $BENDSQL_CLIENT_CONNECT)
+
+echo "clone at the first insertion by timestamp, which should contain 2 rows"
+echo "create table t12_0005_clone_ts clone t12_0005 at (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP)" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005_clone_ts" | $BENDSQL_CLIENT_CONNECT
+
+## Drop table.
+echo "drop table t12_0005_clone_ts" | $BENDSQL_CLIENT_CONNECT
+echo "drop table t12_0005_clone" | $BENDSQL_CLIENT_CONNECT
+echo "drop table t12_0005" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,33 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="bendsql --user=testuser2 --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo '-- reset user and tables'
+echo "DROP USER IF EXISTS 'testuser2'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP TABLE IF EXISTS t20_0016" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- prepare user and tables for tests'
+echo "CREATE USER 'testuser2' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $BENDSQL_CLIENT_CONNECT
+echo "GRANT CREATE ON default.* TO 'testuser2'" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE TABLE t20_0016(c int not null)" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO t20_0016 VALUES (1), (2)" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- test 1: the session has all the privileges on its own temporary table'
+echo "CREATE TEMP TABLE t20_0016(a int); INSERT INTO t20_0016 VALUES (3); SELECT a FROM t20_0016;" | $TEST_USER_CONNECT
+
+echo '-- test 2: the statements that would work on the normal table with the same name are rejected'
+echo "CREATE TEMP TABLE t20_0016(a int); ALTER TABLE t20_0016 RENAME TO t20_0016_new;" | $TEST_USER_CONNECT || true
+echo "CREATE TEMP TABLE t20_0016(a int); ALTER TABLE t20_0016 ADD COLUMN b int;" | $TEST_USER_CONNECT || true
+echo "CREATE TEMP TABLE t20_0016(a int); ALTER TABLE t20_0016 SET OPTIONS(bloom_index_columns='');" | $TEST_USER_CONNECT || true
+echo "CREATE TEMP TABLE t20_0016(a int); DELETE FROM t20_0016;" | $TEST_USER_CONNECT || true
+
+echo '-- test 3: dropping the temporary table keeps the normal table'
+echo "CREATE TEMP TABLE t20_0016(a int); DROP TABLE t20_0016;" | $TEST_USER_CONNECT
+echo "SELECT * FROM t20_0016 ORDER BY c" | $BENDSQL_CLIENT_CONNECT
+
+echo "DROP TABLE IF EXISTS t20_0016" | $BENDSQL_CLIENT_CONNECT
+echo "DROP USER IF EXISTS 'testuser2'" | $BENDSQL_CLIENT_CONNECT
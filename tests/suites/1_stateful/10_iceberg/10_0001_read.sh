@@ -24,3 +24,8 @@ echo "SELECT * FROM iceberg_ctl.iceberg_db.iceberg_tbl WHERE id = 5;" | $BENDSQL
 echo "SELECT data FROM iceberg_ctl.iceberg_db.iceberg_tbl WHERE id > 3 ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
 
 echo "SELECT data FROM iceberg_ctl.iceberg_db.iceberg_tbl WHERE id > 3 ORDER BY data;" | $BENDSQL_CLIENT_CONNECT
+
+## Each data file holds one row, the files are pruned by their column bounds
+echo "EXPLAIN SELECT * FROM iceberg_ctl.iceberg_db.iceberg_tbl WHERE id = 5;" | $BENDSQL_CLIENT_CONNECT | grep -o "partitions [a-z]*: [0-9]*"
+
+echo "EXPLAIN SELECT data FROM iceberg_ctl.iceberg_db.iceberg_tbl WHERE id > 3;" | $BENDSQL_CLIENT_CONNECT | grep -o "partitions [a-z]*: [0-9]*"
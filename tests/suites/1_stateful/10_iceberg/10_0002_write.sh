@@ -0,0 +1,43 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ICEBERG_ROOT=/tmp/databend_iceberg_write
+rm -rf "$ICEBERG_ROOT" && mkdir -p "$ICEBERG_ROOT"
+
+echo "DROP CATALOG IF EXISTS iceberg_write_ctl" | $BENDSQL_CLIENT_CONNECT
+
+## Create iceberg catalog on local fs
+cat <<EOF | $BENDSQL_CLIENT_CONNECT
+CREATE CATALOG iceberg_write_ctl
+TYPE=ICEBERG
+CONNECTION=(
+    URL='fs://${ICEBERG_ROOT}/'
+);
+EOF
+
+echo "CREATE DATABASE iceberg_write_ctl.db;" | $BENDSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE iceberg_write_ctl.db.t1 (id INT NOT NULL, data STRING NULL);" | $BENDSQL_CLIENT_CONNECT
+
+echo "INSERT INTO iceberg_write_ctl.db.t1 VALUES (1, 'a'), (2, 'b'), (3, NULL);" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO iceberg_write_ctl.db.t1 VALUES (4, 'd');" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT * FROM iceberg_write_ctl.db.t1 ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE iceberg_write_ctl.db.t2 AS SELECT id, data FROM iceberg_write_ctl.db.t1 WHERE id > 2;" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT * FROM iceberg_write_ctl.db.t2 ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
+
+echo "INSERT OVERWRITE iceberg_write_ctl.db.t1 VALUES (5, 'e');" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT * FROM iceberg_write_ctl.db.t1 ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
+
+## Every commit writes a new version of the metadata
+cat "$ICEBERG_ROOT"/db/t1/metadata/version-hint.text
+echo
+ls "$ICEBERG_ROOT"/db/t1/metadata/ | grep -c "metadata.json"
+
+echo "DROP CATALOG iceberg_write_ctl" | $BENDSQL_CLIENT_CONNECT
+rm -rf "$ICEBERG_ROOT"
@@ -0,0 +1,43 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+DELTA_ROOT=/tmp/databend_delta_read
+rm -rf "$DELTA_ROOT" && mkdir -p "$DELTA_ROOT"/_delta_log
+
+## Write the data files of two partitions
+echo "copy into 'fs://${DELTA_ROOT}/p=1/' from (select * from (values (1, 'a'), (2, 'b')) t(id, name)) FILE_FORMAT = (type = PARQUET);" | $BENDSQL_CLIENT_CONNECT > /dev/null
+echo "copy into 'fs://${DELTA_ROOT}/p=2/' from (select * from (values (3, 'c'), (4, 'd')) t(id, name)) FILE_FORMAT = (type = PARQUET);" | $BENDSQL_CLIENT_CONNECT > /dev/null
+
+add_action() {
+	local dir=$1 min=$2 max=$3
+	local file
+	file=$(ls "$DELTA_ROOT/$dir")
+	local size
+	size=$(stat -c %s "$DELTA_ROOT/$dir/$file")
+	echo "{\"add\":{\"path\":\"$dir/$file\",\"partitionValues\":{\"p\":\"${dir#p=}\"},\"size\":$size,\"modificationTime\":0,\"dataChange\":true,\"stats\":\"{\\\"numRecords\\\":2,\\\"minValues\\\":{\\\"id\\\":$min},\\\"maxValues\\\":{\\\"id\\\":$max},\\\"nullCount\\\":{\\\"id\\\":0}}\"}}"
+}
+
+## Version 0 adds the first partition, version 1 adds the second one
+SCHEMA='{\"type\":\"struct\",\"fields\":[{\"name\":\"id\",\"type\":\"integer\",\"nullable\":false,\"metadata\":{}},{\"name\":\"name\",\"type\":\"string\",\"nullable\":true,\"metadata\":{}},{\"name\":\"p\",\"type\":\"integer\",\"nullable\":true,\"metadata\":{}}]}'
+{
+	echo '{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}'
+	echo "{\"metaData\":{\"id\":\"test\",\"format\":{\"provider\":\"parquet\",\"options\":{}},\"schemaString\":\"$SCHEMA\",\"partitionColumns\":[\"p\"],\"configuration\":{},\"createdTime\":0}}"
+	add_action p=1 1 2
+} >"$DELTA_ROOT"/_delta_log/00000000000000000000.json
+add_action p=2 3 4 >"$DELTA_ROOT"/_delta_log/00000000000000000001.json
+
+echo "drop table if exists delta_t" | $BENDSQL_CLIENT_CONNECT
+echo "create table delta_t engine = delta 'fs://${DELTA_ROOT}/'" | $BENDSQL_CLIENT_CONNECT
+
+echo "select * from delta_t order by id" | $BENDSQL_CLIENT_CONNECT
+echo "select id, name from delta_t where p = 2 order by id" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from delta_t where id > 2" | $BENDSQL_CLIENT_CONNECT
+
+## Time travel to the first version
+echo "select * from delta_t at (snapshot => '0') order by id" | $BENDSQL_CLIENT_CONNECT
+echo "select * from delta_t at (snapshot => '2')" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop table delta_t" | $BENDSQL_CLIENT_CONNECT
+rm -rf "$DELTA_ROOT"
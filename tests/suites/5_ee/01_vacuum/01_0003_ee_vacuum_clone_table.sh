@@ -0,0 +1,31 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+## Setup
+echo "drop database if exists test_vacuum_clone" | $BENDSQL_CLIENT_CONNECT
+
+echo "CREATE DATABASE test_vacuum_clone" | $BENDSQL_CLIENT_CONNECT
+echo "create table test_vacuum_clone.a(c int)" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO test_vacuum_clone.a VALUES (1)" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO test_vacuum_clone.a VALUES (2)" | $BENDSQL_CLIENT_CONNECT
+
+# b shares the files of a, and c shares the files of both a and b
+echo "create table test_vacuum_clone.b clone test_vacuum_clone.a" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO test_vacuum_clone.b VALUES (3)" | $BENDSQL_CLIENT_CONNECT
+echo "create table test_vacuum_clone.c clone test_vacuum_clone.b" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop table test_vacuum_clone.a" | $BENDSQL_CLIENT_CONNECT
+echo "drop table test_vacuum_clone.b" | $BENDSQL_CLIENT_CONNECT
+echo "set retention_period=0;vacuum drop table from test_vacuum_clone retain 0 hours" | $BENDSQL_CLIENT_CONNECT
+
+# the files of a and b are still referenced by c, MUST return [1, 2, 3]
+echo "select * from test_vacuum_clone.c order by c" | $BENDSQL_CLIENT_CONNECT
+
+# the references of c are removed once c is vacuumed
+echo "drop table test_vacuum_clone.c" | $BENDSQL_CLIENT_CONNECT
+echo "set retention_period=0;vacuum drop table from test_vacuum_clone retain 0 hours" | $BENDSQL_CLIENT_CONNECT
+echo "set retention_period=0;vacuum drop table from test_vacuum_clone retain 0 hours" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop database if exists test_vacuum_clone" | $BENDSQL_CLIENT_CONNECT